@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 use core::f32;
-use glam::{Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+use glam::{Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
 
 pub struct PCG32 {
     state: u64,
@@ -100,6 +100,10 @@ pub fn random_mat3(rng: &mut PCG32) -> Mat3 {
     Mat3::from_cols(random_vec3(rng), random_vec3(rng), random_vec3(rng))
 }
 
+pub fn random_mat3a(rng: &mut PCG32) -> Mat3A {
+    Mat3A::from_cols(random_vec3a(rng), random_vec3a(rng), random_vec3a(rng))
+}
+
 pub fn random_srt_mat3(rng: &mut PCG32) -> Mat3 {
     Mat3::from_scale_angle_translation(
         random_nonzero_vec2(rng),
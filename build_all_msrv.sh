@@ -1,7 +1,7 @@
 #!/bin/sh
 
-CARGO='rustup run 1.36.0 cargo'
-$CARGO test --features "bytemuck mint rand serde debug-glam-assert transform-types" && \
-$CARGO test --features "scalar-math bytemuck mint rand serde debug-glam-assert transform-types" && \
-$CARGO test --no-default-features --features "libm scalar-math bytemuck mint rand serde debug-glam-assert transform-types" && \
+CARGO='rustup run 1.56.0 cargo'
+$CARGO test --features "approx bytemuck mint nalgebra num-traits rand rkyv bytecheck serde debug-glam-assert gpu-layout transform-types" && \
+$CARGO test --features "scalar-math approx bytemuck mint nalgebra num-traits rand rkyv bytecheck serde debug-glam-assert gpu-layout transform-types" && \
+$CARGO test --no-default-features --features "libm scalar-math approx bytemuck mint nalgebra num-traits rand rkyv bytecheck serde debug-glam-assert gpu-layout transform-types" && \
 $CARGO bench --no-run
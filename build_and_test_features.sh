@@ -5,10 +5,13 @@ set -e
 # Set of features to build & test.
 FEATURE_SETS=(
   # std
-  "std bytemuck mint rand serde debug-glam-assert transform-types"
-  "std scalar-math bytemuck mint rand serde debug-glam-assert transform-types"
+  "std approx arbitrary bytemuck mint nalgebra num-traits proptest rand rkyv bytecheck serde debug-glam-assert gpu-layout transform-types"
+  "std scalar-math approx arbitrary bytemuck mint nalgebra num-traits proptest rand rkyv bytecheck serde debug-glam-assert gpu-layout transform-types"
+  # deterministic results must match with and without SIMD
+  "std deterministic approx arbitrary bytemuck mint nalgebra num-traits proptest rand rkyv bytecheck serde debug-glam-assert gpu-layout transform-types"
+  "std deterministic scalar-math approx arbitrary bytemuck mint nalgebra num-traits proptest rand rkyv bytecheck serde debug-glam-assert gpu-layout transform-types"
   # no_std
-  "libm scalar-math bytemuck mint rand serde debug-glam-assert transform-types"
+  "libm scalar-math approx bytemuck mint nalgebra num-traits rand rkyv bytecheck serde debug-glam-assert gpu-layout transform-types"
 )
 
 for features in "${FEATURE_SETS[@]}"
@@ -0,0 +1,59 @@
+//! Handling of failed `glam_assert!` checks, see the `glam-assert` and `debug-glam-assert`
+//! features.
+
+use core::sync::atomic::{AtomicPtr, Ordering};
+
+/// A failed `glam` assertion.
+#[derive(Clone, Copy, Debug)]
+pub struct AssertFailure {
+    /// The source of the condition which did not hold, e.g. `self.is_finite()`.
+    pub condition: &'static str,
+    /// The `glam` source file containing the assertion.
+    pub file: &'static str,
+    /// The line of the assertion in `file`.
+    pub line: u32,
+}
+
+/// A function called instead of panicking when a `glam` assertion fails.
+pub type AssertHandler = fn(&AssertFailure);
+
+static ASSERT_HANDLER: AtomicPtr<()> = AtomicPtr::new(core::ptr::null_mut());
+
+/// Sets the function called when a `glam` assertion fails, returning the previous handler.
+///
+/// By default failed assertions panic. With a handler set, the handler is called instead and
+/// execution continues, which allows an application to log invalid inputs such as a `NaN` being
+/// normalized without aborting. Passing `None` restores the default panicking behaviour.
+///
+/// Assertions are only checked when the `glam-assert` feature, or the `debug-glam-assert` feature
+/// in debug builds, is enabled.
+pub fn set_assert_handler(handler: Option<AssertHandler>) -> Option<AssertHandler> {
+    let ptr = handler.map_or(core::ptr::null_mut(), |handler| handler as *mut ());
+    to_handler(ASSERT_HANDLER.swap(ptr, Ordering::AcqRel))
+}
+
+#[inline]
+fn to_handler(ptr: *mut ()) -> Option<AssertHandler> {
+    if ptr.is_null() {
+        None
+    } else {
+        // SAFETY: only `AssertHandler` function pointers are stored in `ASSERT_HANDLER`.
+        Some(unsafe { core::mem::transmute::<*mut (), AssertHandler>(ptr) })
+    }
+}
+
+/// Called by `glam_assert!` when `condition` does not hold.
+#[allow(dead_code)]
+#[cold]
+#[inline(never)]
+pub(crate) fn assert_failed(condition: &'static str, file: &'static str, line: u32) {
+    let failure = AssertFailure {
+        condition,
+        file,
+        line,
+    };
+    match to_handler(ASSERT_HANDLER.load(Ordering::Acquire)) {
+        Some(handler) => handler(&failure),
+        None => panic!("assertion failed: {}", condition),
+    }
+}
@@ -0,0 +1,90 @@
+//! Wrappers for values which satisfy the preconditions of `glam` functions.
+//!
+//! The wrappers implement `arbitrary::Arbitrary` and `proptest::arbitrary::Arbitrary` when the
+//! `arbitrary` and `proptest` features are enabled, for generating inputs which are valid for
+//! functions such as `Quat::mul_vec3()` or `Mat4::inverse()` when fuzzing or property testing.
+
+use crate::core::traits::matrix::{FloatMatrix2x2, FloatMatrix3x3, FloatMatrix4x4};
+use crate::{
+    DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3,
+    Vec3A, Vec4,
+};
+
+/// A vector, quaternion or matrix where every element is finite.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Finite<T>(pub T);
+
+/// A vector or quaternion where `is_normalized()` returns `true`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Normalized<T>(pub T);
+
+/// A matrix with a finite non-zero determinant and a finite inverse.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Invertible<T>(pub T);
+
+macro_rules! impl_finite {
+    ($($t:ty),+) => {
+        $(
+            impl Finite<$t> {
+                /// Returns `Some` if every element of `value` is finite.
+                #[inline]
+                pub fn new(value: $t) -> Option<Self> {
+                    if value.is_finite() {
+                        Some(Self(value))
+                    } else {
+                        None
+                    }
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_normalized {
+    ($($t:ty),+) => {
+        $(
+            impl Normalized<$t> {
+                /// Returns `value` normalized, or `None` if it can not be normalized.
+                #[inline]
+                pub fn new(value: $t) -> Option<Self> {
+                    // Normalizing values with very small or very large elements loses precision,
+                    // normalizing again once the value is close to unit length corrects it.
+                    let value = value.try_normalize()?.normalize();
+                    if value.is_normalized() {
+                        Some(Self(value))
+                    } else {
+                        None
+                    }
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_invertible {
+    ($($t:ident),+) => {
+        $(
+            impl Invertible<$t> {
+                /// Returns `Some` if `value` has a finite non-zero determinant and a finite
+                /// inverse.
+                #[inline]
+                pub fn new(value: $t) -> Option<Self> {
+                    // The inverse is computed without `glam_assert!` checks as a non-finite result is
+                    // expected here.
+                    let det = value.determinant();
+                    if det != 0.0 && det.is_finite() && $t(value.0.inverse()).is_finite() {
+                        Some(Self(value))
+                    } else {
+                        None
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_finite!(Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4);
+impl_finite!(DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4);
+impl_normalized!(Quat, Vec2, Vec3, Vec3A, Vec4);
+impl_normalized!(DQuat, DVec2, DVec3, DVec4);
+impl_invertible!(Mat2, Mat3, Mat3A, Mat4, DMat2, DMat3, DMat4);
@@ -1,3 +1,5 @@
+#[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
+use crate::core::storage::XYZF32A16;
 use crate::core::{
     storage::{Vector2x2, Vector3x3, Vector4x4, XY, XYZ, XYZW},
     traits::{
@@ -112,6 +114,8 @@ impl<T: NumEx> MatrixConst for Vector3x3<XYZ<T>> {
 impl<T: NumEx> Matrix<T> for Vector3x3<XYZ<T>> {}
 
 impl<T: NumEx> Matrix3x3<T, XYZ<T>> for Vector3x3<XYZ<T>> {
+    type Column = XYZ<T>;
+
     #[inline(always)]
     fn from_cols(x_axis: XYZ<T>, y_axis: XYZ<T>, z_axis: XYZ<T>) -> Self {
         Self {
@@ -122,13 +126,44 @@ impl<T: NumEx> Matrix3x3<T, XYZ<T>> for Vector3x3<XYZ<T>> {
     }
 
     #[inline(always)]
-    fn as_ref_vector3x3(&self) -> &Vector3x3<XYZ<T>> {
-        self
+    fn x_axis(&self) -> &XYZ<T> {
+        &self.x_axis
     }
 
     #[inline(always)]
-    fn as_mut_vector3x3(&mut self) -> &mut Vector3x3<XYZ<T>> {
-        self
+    fn y_axis(&self) -> &XYZ<T> {
+        &self.y_axis
+    }
+
+    #[inline(always)]
+    fn z_axis(&self) -> &XYZ<T> {
+        &self.z_axis
+    }
+
+    #[inline]
+    fn col_storage(&self, index: usize) -> &XYZ<T> {
+        match index {
+            0 => &self.x_axis,
+            1 => &self.y_axis,
+            2 => &self.z_axis,
+            _ => panic!(
+                "index out of bounds: the len is 3 but the index is {}",
+                index
+            ),
+        }
+    }
+
+    #[inline]
+    fn col_storage_mut(&mut self, index: usize) -> &mut XYZ<T> {
+        match index {
+            0 => &mut self.x_axis,
+            1 => &mut self.y_axis,
+            2 => &mut self.z_axis,
+            _ => panic!(
+                "index out of bounds: the len is 3 but the index is {}",
+                index
+            ),
+        }
     }
 
     #[inline]
@@ -226,6 +261,177 @@ impl<T: FloatEx> FloatMatrix3x3<T, XYZ<T>> for Vector3x3<XYZ<T>> {
     }
 }
 
+// `Vector3x3<XYZF32A16>` is the storage of `Mat3A` when SIMD is not available, its operations are
+// performed on an unaligned copy.
+#[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
+#[inline]
+fn into_unaligned(m: &Vector3x3<XYZF32A16>) -> Vector3x3<XYZ<f32>> {
+    Vector3x3 {
+        x_axis: m.x_axis.into(),
+        y_axis: m.y_axis.into(),
+        z_axis: m.z_axis.into(),
+    }
+}
+
+#[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
+#[inline]
+fn from_unaligned(m: Vector3x3<XYZ<f32>>) -> Vector3x3<XYZF32A16> {
+    Vector3x3 {
+        x_axis: m.x_axis.into(),
+        y_axis: m.y_axis.into(),
+        z_axis: m.z_axis.into(),
+    }
+}
+
+#[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
+impl MatrixConst for Vector3x3<XYZF32A16> {
+    const ZERO: Self = Self {
+        x_axis: XYZF32A16 {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        },
+        y_axis: XYZF32A16 {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        },
+        z_axis: XYZF32A16 {
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        },
+    };
+    const IDENTITY: Self = Self {
+        x_axis: XYZF32A16 {
+            x: 1.0,
+            y: 0.0,
+            z: 0.0,
+        },
+        y_axis: XYZF32A16 {
+            x: 0.0,
+            y: 1.0,
+            z: 0.0,
+        },
+        z_axis: XYZF32A16 {
+            x: 0.0,
+            y: 0.0,
+            z: 1.0,
+        },
+    };
+}
+
+#[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
+impl Matrix<f32> for Vector3x3<XYZF32A16> {}
+
+#[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
+impl Matrix3x3<f32, XYZ<f32>> for Vector3x3<XYZF32A16> {
+    type Column = XYZF32A16;
+
+    #[inline(always)]
+    fn from_cols(x_axis: XYZ<f32>, y_axis: XYZ<f32>, z_axis: XYZ<f32>) -> Self {
+        Self {
+            x_axis: x_axis.into(),
+            y_axis: y_axis.into(),
+            z_axis: z_axis.into(),
+        }
+    }
+
+    #[inline(always)]
+    fn x_axis(&self) -> &XYZ<f32> {
+        unsafe { &*(&self.x_axis as *const XYZF32A16 as *const XYZ<f32>) }
+    }
+
+    #[inline(always)]
+    fn y_axis(&self) -> &XYZ<f32> {
+        unsafe { &*(&self.y_axis as *const XYZF32A16 as *const XYZ<f32>) }
+    }
+
+    #[inline(always)]
+    fn z_axis(&self) -> &XYZ<f32> {
+        unsafe { &*(&self.z_axis as *const XYZF32A16 as *const XYZ<f32>) }
+    }
+
+    #[inline]
+    fn col_storage(&self, index: usize) -> &XYZF32A16 {
+        match index {
+            0 => &self.x_axis,
+            1 => &self.y_axis,
+            2 => &self.z_axis,
+            _ => panic!(
+                "index out of bounds: the len is 3 but the index is {}",
+                index
+            ),
+        }
+    }
+
+    #[inline]
+    fn col_storage_mut(&mut self, index: usize) -> &mut XYZF32A16 {
+        match index {
+            0 => &mut self.x_axis,
+            1 => &mut self.y_axis,
+            2 => &mut self.z_axis,
+            _ => panic!(
+                "index out of bounds: the len is 3 but the index is {}",
+                index
+            ),
+        }
+    }
+
+    #[inline]
+    fn determinant(&self) -> f32 {
+        into_unaligned(self).determinant()
+    }
+
+    #[inline]
+    fn transpose(&self) -> Self {
+        from_unaligned(into_unaligned(self).transpose())
+    }
+
+    #[inline]
+    fn mul_vector(&self, other: XYZ<f32>) -> XYZ<f32> {
+        into_unaligned(self).mul_vector(other)
+    }
+
+    #[inline]
+    fn mul_matrix(&self, other: &Self) -> Self {
+        from_unaligned(into_unaligned(self).mul_matrix(&into_unaligned(other)))
+    }
+
+    #[inline]
+    fn mul_scalar(&self, other: f32) -> Self {
+        from_unaligned(into_unaligned(self).mul_scalar(other))
+    }
+
+    #[inline]
+    fn add_matrix(&self, other: &Self) -> Self {
+        from_unaligned(into_unaligned(self).add_matrix(&into_unaligned(other)))
+    }
+
+    #[inline]
+    fn sub_matrix(&self, other: &Self) -> Self {
+        from_unaligned(into_unaligned(self).sub_matrix(&into_unaligned(other)))
+    }
+}
+
+#[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
+impl FloatMatrix3x3<f32, XYZ<f32>> for Vector3x3<XYZF32A16> {
+    #[inline]
+    fn inverse(&self) -> Self {
+        from_unaligned(into_unaligned(self).inverse())
+    }
+
+    #[inline]
+    fn transform_point2(&self, other: XY<f32>) -> XY<f32> {
+        into_unaligned(self).transform_point2(other)
+    }
+
+    #[inline]
+    fn transform_vector2(&self, other: XY<f32>) -> XY<f32> {
+        into_unaligned(self).transform_vector2(other)
+    }
+}
+
 impl<T: NumEx> MatrixConst for Vector4x4<XYZW<T>> {
     const ZERO: Self = Self {
         x_axis: XYZW::ZERO,
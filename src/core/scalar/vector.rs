@@ -647,6 +647,22 @@ impl<T: NumEx> Vector2<T> for XY<T> {
         self.x.max(self.y)
     }
 
+    #[inline]
+    fn div_euclid(self, rhs: Self) -> Self {
+        Self {
+            x: NumEx::div_euclid(self.x, rhs.x),
+            y: NumEx::div_euclid(self.y, rhs.y),
+        }
+    }
+
+    #[inline]
+    fn rem_euclid(self, rhs: Self) -> Self {
+        Self {
+            x: NumEx::rem_euclid(self.x, rhs.x),
+            y: NumEx::rem_euclid(self.y, rhs.y),
+        }
+    }
+
     #[inline]
     fn dot(self, other: Self) -> T {
         (self.x * other.x) + (self.y * other.y)
@@ -752,6 +768,24 @@ impl<T: NumEx> Vector3<T> for XYZ<T> {
         self.x.max(self.y.max(self.z))
     }
 
+    #[inline]
+    fn div_euclid(self, rhs: Self) -> Self {
+        Self {
+            x: NumEx::div_euclid(self.x, rhs.x),
+            y: NumEx::div_euclid(self.y, rhs.y),
+            z: NumEx::div_euclid(self.z, rhs.z),
+        }
+    }
+
+    #[inline]
+    fn rem_euclid(self, rhs: Self) -> Self {
+        Self {
+            x: NumEx::rem_euclid(self.x, rhs.x),
+            y: NumEx::rem_euclid(self.y, rhs.y),
+            z: NumEx::rem_euclid(self.z, rhs.z),
+        }
+    }
+
     #[inline]
     fn dot(self, other: Self) -> T {
         (self.x * other.x) + (self.y * other.y) + (self.z * other.z)
@@ -870,7 +904,27 @@ impl<T: NumEx> Vector4<T> for XYZW<T> {
 
     #[inline]
     fn max_element(self) -> T {
-        self.x.max(self.y.max(self.z.min(self.w)))
+        self.x.max(self.y.max(self.z.max(self.w)))
+    }
+
+    #[inline]
+    fn div_euclid(self, rhs: Self) -> Self {
+        Self {
+            x: NumEx::div_euclid(self.x, rhs.x),
+            y: NumEx::div_euclid(self.y, rhs.y),
+            z: NumEx::div_euclid(self.z, rhs.z),
+            w: NumEx::div_euclid(self.w, rhs.w),
+        }
+    }
+
+    #[inline]
+    fn rem_euclid(self, rhs: Self) -> Self {
+        Self {
+            x: NumEx::rem_euclid(self.x, rhs.x),
+            y: NumEx::rem_euclid(self.y, rhs.y),
+            z: NumEx::rem_euclid(self.z, rhs.z),
+            w: NumEx::rem_euclid(self.w, rhs.w),
+        }
     }
 
     #[inline]
@@ -1034,6 +1088,61 @@ impl<T: FloatEx> FloatVector2<T> for XY<T> {
         }
     }
 
+    #[inline]
+    fn sin_cos_fast(self) -> (Self, Self) {
+        let (x_sin, x_cos) = self.x.sin_cos_approx();
+        let (y_sin, y_cos) = self.y.sin_cos_approx();
+        (Self { x: x_sin, y: y_sin }, Self { x: x_cos, y: y_cos })
+    }
+
+    #[inline]
+    fn trunc(self) -> Self {
+        Self {
+            x: self.x.trunc(),
+            y: self.y.trunc(),
+        }
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        Self {
+            x: self.x.sqrt(),
+            y: self.y.sqrt(),
+        }
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        Self {
+            x: self.x.ln(),
+            y: self.y.ln(),
+        }
+    }
+
+    #[inline]
+    fn sin(self) -> Self {
+        Self {
+            x: self.x.sin(),
+            y: self.y.sin(),
+        }
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        Self {
+            x: self.x.cos(),
+            y: self.y.cos(),
+        }
+    }
+
+    #[inline]
+    fn copysign(self, sign: Self) -> Self {
+        Self {
+            x: FloatEx::copysign(self.x, sign.x),
+            y: FloatEx::copysign(self.y, sign.y),
+        }
+    }
+
     #[inline]
     fn is_finite(self) -> bool {
         self.x.is_finite() && self.y.is_finite()
@@ -1108,6 +1217,79 @@ impl<T: FloatEx> FloatVector3<T> for XYZ<T> {
         }
     }
 
+    #[inline]
+    fn sin_cos_fast(self) -> (Self, Self) {
+        let (x_sin, x_cos) = self.x.sin_cos_approx();
+        let (y_sin, y_cos) = self.y.sin_cos_approx();
+        let (z_sin, z_cos) = self.z.sin_cos_approx();
+        (
+            Self {
+                x: x_sin,
+                y: y_sin,
+                z: z_sin,
+            },
+            Self {
+                x: x_cos,
+                y: y_cos,
+                z: z_cos,
+            },
+        )
+    }
+
+    #[inline]
+    fn trunc(self) -> Self {
+        Self {
+            x: self.x.trunc(),
+            y: self.y.trunc(),
+            z: self.z.trunc(),
+        }
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        Self {
+            x: self.x.sqrt(),
+            y: self.y.sqrt(),
+            z: self.z.sqrt(),
+        }
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        Self {
+            x: self.x.ln(),
+            y: self.y.ln(),
+            z: self.z.ln(),
+        }
+    }
+
+    #[inline]
+    fn sin(self) -> Self {
+        Self {
+            x: self.x.sin(),
+            y: self.y.sin(),
+            z: self.z.sin(),
+        }
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        Self {
+            x: self.x.cos(),
+            y: self.y.cos(),
+            z: self.z.cos(),
+        }
+    }
+
+    #[inline]
+    fn copysign(self, sign: Self) -> Self {
+        Self {
+            x: FloatEx::copysign(self.x, sign.x),
+            y: FloatEx::copysign(self.y, sign.y),
+            z: FloatEx::copysign(self.z, sign.z),
+        }
+    }
+
     #[inline]
     fn is_finite(self) -> bool {
         self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
@@ -1189,6 +1371,88 @@ impl<T: FloatEx> FloatVector4<T> for XYZW<T> {
         }
     }
 
+    #[inline]
+    fn sin_cos_fast(self) -> (Self, Self) {
+        let (x_sin, x_cos) = self.x.sin_cos_approx();
+        let (y_sin, y_cos) = self.y.sin_cos_approx();
+        let (z_sin, z_cos) = self.z.sin_cos_approx();
+        let (w_sin, w_cos) = self.w.sin_cos_approx();
+        (
+            Self {
+                x: x_sin,
+                y: y_sin,
+                z: z_sin,
+                w: w_sin,
+            },
+            Self {
+                x: x_cos,
+                y: y_cos,
+                z: z_cos,
+                w: w_cos,
+            },
+        )
+    }
+
+    #[inline]
+    fn trunc(self) -> Self {
+        Self {
+            x: self.x.trunc(),
+            y: self.y.trunc(),
+            z: self.z.trunc(),
+            w: self.w.trunc(),
+        }
+    }
+
+    #[inline]
+    fn sqrt(self) -> Self {
+        Self {
+            x: self.x.sqrt(),
+            y: self.y.sqrt(),
+            z: self.z.sqrt(),
+            w: self.w.sqrt(),
+        }
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        Self {
+            x: self.x.ln(),
+            y: self.y.ln(),
+            z: self.z.ln(),
+            w: self.w.ln(),
+        }
+    }
+
+    #[inline]
+    fn sin(self) -> Self {
+        Self {
+            x: self.x.sin(),
+            y: self.y.sin(),
+            z: self.z.sin(),
+            w: self.w.sin(),
+        }
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        Self {
+            x: self.x.cos(),
+            y: self.y.cos(),
+            z: self.z.cos(),
+            w: self.w.cos(),
+        }
+    }
+
+    #[inline]
+    fn copysign(self, sign: Self) -> Self {
+        Self {
+            x: FloatEx::copysign(self.x, sign.x),
+            y: FloatEx::copysign(self.y, sign.y),
+            z: FloatEx::copysign(self.z, sign.z),
+            w: FloatEx::copysign(self.w, sign.w),
+        }
+    }
+
     #[inline]
     fn is_finite(self) -> bool {
         self.x.is_finite() && self.y.is_finite() && self.z.is_finite() && self.w.is_finite()
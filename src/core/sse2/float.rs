@@ -1,3 +1,6 @@
+// The SIMD sin approximation is not used by the deterministic feature.
+#![cfg_attr(feature = "deterministic", allow(dead_code))]
+
 #[cfg(target_arch = "x86")]
 use core::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
@@ -51,15 +54,63 @@ _ps_const_ty!(
     0.0083139502,   /*Est2*/
     -0.00018524670  /*Est3*/
 );
+_ps_const_ty!(
+    PS_COS_COEFFICIENTS1,
+    f32x4,
+    -2.6051615e-07,
+    -0.49992746,   /*Est1*/
+    0.04149392,    /*Est2*/
+    -0.0012712436  /*Est3*/
+);
 _ps_const_ty!(PS_ONE, f32x4, 1.0);
+_ps_const_ty!(PS_NEGATIVE_ONE, f32x4, -1.0);
+_ps_const_ty!(PS_TWO, f32x4, 2.0);
+_ps_const_ty!(PS_HALF, f32x4, 0.5);
+_ps_const_ty!(PS_THREE_HALVES, f32x4, 1.5);
 _ps_const_ty!(PS_TWO_PI, f32x4, core::f32::consts::PI * 2.0);
 _ps_const_ty!(PS_RECIPROCAL_TWO_PI, f32x4, 0.159154943);
+#[cfg(feature = "deterministic")]
+_ps_const_ty!(PS_ROUND_HALF, f32x4, 0.5 - 0.25 * core::f32::EPSILON);
 
 #[inline]
 pub(crate) unsafe fn m128_abs(v: __m128) -> __m128 {
     _mm_and_ps(v, _mm_castsi128_ps(_mm_set1_epi32(0x7f_ff_ff_ff)))
 }
 
+#[inline]
+pub(crate) unsafe fn m128_copysign(v: __m128, sign: __m128) -> __m128 {
+    _mm_or_ps(
+        _mm_and_ps(v, PS_INV_SIGN_MASK.m128),
+        _mm_and_ps(sign, PS_SIGN_MASK.m128),
+    )
+}
+
+#[inline]
+pub(crate) unsafe fn m128_trunc(v: __m128) -> __m128 {
+    // Based on https://github.com/microsoft/DirectXMath `XMVectorTruncate`
+    // To handle NAN, INF and numbers greater than 8388608, use masking
+    let test = _mm_and_si128(_mm_castps_si128(v), PS_INV_SIGN_MASK.m128i);
+    let test = _mm_cmplt_epi32(test, PS_NO_FRACTION.m128i);
+    let vint = _mm_cvttps_epi32(v);
+    let result = _mm_cvtepi32_ps(vint);
+    let result = _mm_and_ps(result, _mm_castsi128_ps(test));
+    let test = _mm_andnot_si128(test, _mm_castps_si128(v));
+    let result = _mm_or_ps(result, _mm_castsi128_ps(test));
+    // Preserve the sign of zero results to match `f32::trunc`
+    _mm_or_ps(result, _mm_and_ps(v, PS_SIGN_MASK.m128))
+}
+
+#[cfg(feature = "deterministic")]
+#[inline]
+pub(crate) unsafe fn m128_round(v: __m128) -> __m128 {
+    // Rounds half away from zero to match `f32::round`. Based on the libm `roundf`
+    // implementation which adds the largest float less than 0.5 and truncates.
+    let sign = _mm_and_ps(v, PS_SIGN_MASK.m128);
+    let half = _mm_or_ps(PS_ROUND_HALF.m128, sign);
+    m128_trunc(_mm_add_ps(v, half))
+}
+
+#[cfg(not(feature = "deterministic"))]
 #[inline]
 pub(crate) unsafe fn m128_round(v: __m128) -> __m128 {
     // Based on https://github.com/microsoft/DirectXMath `XMVectorRound`
@@ -91,7 +142,11 @@ pub(crate) unsafe fn m128_floor(v: __m128) -> __m128 {
     let result = _mm_and_ps(result, _mm_castsi128_ps(test));
     // All others, use the ORIGINAL value
     let test = _mm_andnot_si128(test, _mm_castps_si128(v));
-    _mm_or_ps(result, _mm_castsi128_ps(test))
+    let result = _mm_or_ps(result, _mm_castsi128_ps(test));
+    // Preserve the sign of zero results to match the scalar implementation
+    #[cfg(feature = "deterministic")]
+    let result = _mm_or_ps(result, _mm_and_ps(v, PS_SIGN_MASK.m128));
+    result
 }
 
 #[inline]
@@ -111,17 +166,22 @@ pub(crate) unsafe fn m128_ceil(v: __m128) -> __m128 {
     let result = _mm_and_ps(result, _mm_castsi128_ps(test));
     // All others, use the ORIGINAL value
     let test = _mm_andnot_si128(test, _mm_castps_si128(v));
-    _mm_or_ps(result, _mm_castsi128_ps(test))
+    let result = _mm_or_ps(result, _mm_castsi128_ps(test));
+    // Preserve the sign of zero results to match the scalar implementation
+    #[cfg(feature = "deterministic")]
+    let result = _mm_or_ps(result, _mm_and_ps(v, PS_SIGN_MASK.m128));
+    result
 }
 
 #[inline(always)]
 pub(crate) unsafe fn m128_mul_add(a: __m128, b: __m128, c: __m128) -> __m128 {
-    #[cfg(target_feature = "fma")]
+    // FMA skips the intermediate rounding step which changes results
+    #[cfg(all(target_feature = "fma", not(feature = "deterministic")))]
     {
         _mm_fmadd_ps(a, b, c)
     }
 
-    #[cfg(not(target_feature = "fma"))]
+    #[cfg(any(not(target_feature = "fma"), feature = "deterministic"))]
     {
         _mm_add_ps(_mm_mul_ps(a, b), c)
     }
@@ -190,6 +250,77 @@ pub(crate) unsafe fn m128_sin(v: __m128) -> __m128 {
     result
 }
 
+/// Computes an approximation of the sine and cosine of the angle in each lane of `v` using a
+/// 7-degree and 6-degree minimax polynomial. The maximum absolute error is `1e-5` for angles in
+/// `[-PI, PI]`, the error increases as the input angle drifts outside of this range.
+#[inline]
+pub(crate) unsafe fn m128_sin_cos_fast(v: __m128) -> (__m128, __m128) {
+    // Based on https://github.com/microsoft/DirectXMath `XMVectorSinCosEst`
+
+    // Force the value within the bounds of pi
+    let mut x = m128_mod_angles(v);
+
+    // Map in [-pi/2,pi/2] with sin(y) = sin(x), cos(y) = sign*cos(x).
+    let sign = _mm_and_ps(x, PS_NEGATIVE_ZERO.m128);
+    // pi when x >= 0, -pi when x < 0
+    let c = _mm_or_ps(PS_PI.m128, sign);
+    // |x|
+    let absx = _mm_andnot_ps(sign, x);
+    let rflx = _mm_sub_ps(c, x);
+    let comp = _mm_cmple_ps(absx, PS_HALF_PI.m128);
+    let select0 = _mm_and_ps(comp, x);
+    let select1 = _mm_andnot_ps(comp, rflx);
+    x = _mm_or_ps(select0, select1);
+    let select0 = _mm_and_ps(comp, PS_ONE.m128);
+    let select1 = _mm_andnot_ps(comp, PS_NEGATIVE_ONE.m128);
+    let sign = _mm_or_ps(select0, select1);
+
+    let x2 = _mm_mul_ps(x, x);
+
+    // Compute polynomial approximation of sine
+    const SEC: __m128 = unsafe { PS_SIN_COEFFICIENTS1.m128 };
+    let v_constants_b = _mm_shuffle_ps(SEC, SEC, 0b11_11_11_11);
+    let v_constants = _mm_shuffle_ps(SEC, SEC, 0b10_10_10_10);
+    let result = m128_mul_add(v_constants_b, x2, v_constants);
+    let v_constants = _mm_shuffle_ps(SEC, SEC, 0b01_01_01_01);
+    let result = m128_mul_add(result, x2, v_constants);
+    let result = m128_mul_add(result, x2, PS_ONE.m128);
+    let sin = _mm_mul_ps(result, x);
+
+    // Compute polynomial approximation of cosine
+    const CEC: __m128 = unsafe { PS_COS_COEFFICIENTS1.m128 };
+    let v_constants_b = _mm_shuffle_ps(CEC, CEC, 0b11_11_11_11);
+    let v_constants = _mm_shuffle_ps(CEC, CEC, 0b10_10_10_10);
+    let result = m128_mul_add(v_constants_b, x2, v_constants);
+    let v_constants = _mm_shuffle_ps(CEC, CEC, 0b01_01_01_01);
+    let result = m128_mul_add(result, x2, v_constants);
+    let result = m128_mul_add(result, x2, PS_ONE.m128);
+    let cos = _mm_mul_ps(result, sign);
+
+    (sin, cos)
+}
+
+/// Returns an approximation of `1.0 / v` for each lane of `v` using `_mm_rcp_ps` refined with a
+/// single Newton-Raphson step. The maximum relative error is less than `1e-6`. Zero and infinite
+/// inputs return NaN.
+#[inline]
+pub(crate) unsafe fn m128_recip_fast(v: __m128) -> __m128 {
+    // y1 = y0 * (2 - v * y0)
+    let y0 = _mm_rcp_ps(v);
+    _mm_mul_ps(y0, m128_neg_mul_sub(v, y0, PS_TWO.m128))
+}
+
+/// Returns an approximation of `1.0 / sqrt(v)` for each lane of `v` using `_mm_rsqrt_ps` refined
+/// with a single Newton-Raphson step. The maximum relative error is less than `1e-6`. Zero and
+/// infinite inputs return NaN.
+#[inline]
+pub(crate) unsafe fn m128_rsqrt_fast(v: __m128) -> __m128 {
+    // y1 = y0 * (1.5 - 0.5 * v * y0 * y0)
+    let y0 = _mm_rsqrt_ps(v);
+    let half_v_y0 = _mm_mul_ps(_mm_mul_ps(PS_HALF.m128, v), y0);
+    _mm_mul_ps(y0, m128_neg_mul_sub(half_v_y0, y0, PS_THREE_HALVES.m128))
+}
+
 // Based on http://gruntthepeon.free.fr/ssemath/sse_mathfun.h
 // #[cfg(target_feature = "sse2")]
 // unsafe fn sin_cos_sse2(x: __m128) -> (__m128, __m128) {
@@ -304,3 +435,74 @@ fn test_sse2_m128_sin() {
         a += step;
     }
 }
+
+#[test]
+fn test_sse2_m128_sin_cos_fast() {
+    use crate::core::traits::vector::*;
+    use core::f32::consts::PI;
+
+    fn test_sse2_m128_sin_cos_fast_angle(a: f32) {
+        let (s, c) = unsafe { m128_sin_cos_fast(_mm_set_ps1(a)) };
+        let (a_sin, a_cos) = a.sin_cos();
+        assert!(s.as_ref_xyzw().abs_diff_eq(Vector::splat(a_sin), 1e-5));
+        assert!(c.as_ref_xyzw().abs_diff_eq(Vector::splat(a_cos), 1e-5));
+    }
+
+    let mut a = -PI;
+    let end = PI;
+    let step = PI / 8192.0;
+
+    while a <= end {
+        test_sse2_m128_sin_cos_fast_angle(a);
+        a += step;
+    }
+}
+
+#[test]
+fn test_sse2_m128_recip_rsqrt_fast() {
+    // test 1024 floats between 1.0 and 1.0e6 which covers every mantissa bit pattern the
+    // estimate instructions use
+    let mut a = 1.0_f32;
+    while a < 1.0e6 {
+        let r = unsafe { _mm_cvtss_f32(m128_recip_fast(_mm_set_ps1(a))) };
+        let rsqrt = unsafe { _mm_cvtss_f32(m128_rsqrt_fast(_mm_set_ps1(a))) };
+        let r_exact = (1.0 / a as f64) as f32;
+        let rsqrt_exact = (1.0 / (a as f64).sqrt()) as f32;
+        assert!(((r - r_exact) / r_exact).abs() <= 1e-6, "recip {}", a);
+        assert!(
+            ((rsqrt - rsqrt_exact) / rsqrt_exact).abs() <= 1e-6,
+            "rsqrt {}",
+            a
+        );
+        a *= 1.013_581;
+    }
+}
+
+#[test]
+fn test_sse2_m128_trunc_copysign() {
+    use crate::core::traits::vector::*;
+    for &a in [
+        -1.5_f32,
+        -0.5,
+        -0.0,
+        0.0,
+        0.5,
+        8388609.0,
+        -1.0e20,
+        f32::INFINITY,
+    ]
+    .iter()
+    {
+        let t = unsafe { _mm_cvtss_f32(m128_trunc(_mm_set_ps1(a))) };
+        assert_eq!(a.trunc().to_bits(), t.to_bits(), "trunc {}", a);
+    }
+    assert!(unsafe { _mm_cvtss_f32(m128_trunc(_mm_set_ps1(f32::NAN))) }.is_nan());
+
+    let v = unsafe {
+        m128_copysign(
+            _mm_set_ps(1.0, -2.0, 3.0, -0.0),
+            _mm_set_ps(-0.0, 1.0, -1.0, 1.0),
+        )
+    };
+    assert_eq!(Vector4::into_array(v), [0.0, -3.0, 2.0, -1.0]);
+}
@@ -5,14 +5,20 @@ use core::arch::x86_64::*;
 
 use core::mem::MaybeUninit;
 
+#[cfg(feature = "deterministic")]
+use crate::core::storage::XYZW;
+
 use crate::{
     const_m128,
     core::{
-        storage::{Align16, Vector2x2, Vector4x4, XY, XYZ},
+        storage::{Align16, Vector2x2, Vector3x3, Vector4x4, XY, XYZ},
         traits::{
-            matrix::{FloatMatrix2x2, FloatMatrix4x4, Matrix, Matrix2x2, Matrix4x4, MatrixConst},
+            matrix::{
+                FloatMatrix2x2, FloatMatrix3x3, FloatMatrix4x4, Matrix, Matrix2x2, Matrix3x3,
+                Matrix4x4, MatrixConst,
+            },
             projection::ProjectionMatrix,
-            vector::{FloatVector4, Vector, Vector4, Vector4Const},
+            vector::{FloatVector3, FloatVector4, Vector, Vector3, Vector4, Vector4Const},
         },
     },
 };
@@ -132,6 +138,193 @@ impl FloatMatrix2x2<f32, XY<f32>> for __m128 {
     }
 }
 
+// Vector3x3<__m128> as a Matrix3x3, the `w` element of each column is unused
+impl MatrixConst for Vector3x3<__m128> {
+    const ZERO: Vector3x3<__m128> = Vector3x3 {
+        x_axis: __m128::ZERO,
+        y_axis: __m128::ZERO,
+        z_axis: __m128::ZERO,
+    };
+    const IDENTITY: Vector3x3<__m128> = Vector3x3 {
+        x_axis: __m128::UNIT_X,
+        y_axis: __m128::UNIT_Y,
+        z_axis: __m128::UNIT_Z,
+    };
+}
+
+impl Matrix<f32> for Vector3x3<__m128> {}
+
+impl Matrix3x3<f32, __m128> for Vector3x3<__m128> {
+    type Column = __m128;
+
+    #[inline(always)]
+    fn from_cols(x_axis: __m128, y_axis: __m128, z_axis: __m128) -> Self {
+        Self {
+            x_axis,
+            y_axis,
+            z_axis,
+        }
+    }
+
+    #[inline(always)]
+    fn x_axis(&self) -> &__m128 {
+        &self.x_axis
+    }
+
+    #[inline(always)]
+    fn y_axis(&self) -> &__m128 {
+        &self.y_axis
+    }
+
+    #[inline(always)]
+    fn z_axis(&self) -> &__m128 {
+        &self.z_axis
+    }
+
+    #[inline]
+    fn col_storage(&self, index: usize) -> &__m128 {
+        match index {
+            0 => &self.x_axis,
+            1 => &self.y_axis,
+            2 => &self.z_axis,
+            _ => panic!(
+                "index out of bounds: the len is 3 but the index is {}",
+                index
+            ),
+        }
+    }
+
+    #[inline]
+    fn col_storage_mut(&mut self, index: usize) -> &mut __m128 {
+        match index {
+            0 => &mut self.x_axis,
+            1 => &mut self.y_axis,
+            2 => &mut self.z_axis,
+            _ => panic!(
+                "index out of bounds: the len is 3 but the index is {}",
+                index
+            ),
+        }
+    }
+
+    #[inline]
+    fn determinant(&self) -> f32 {
+        Vector3::dot(self.z_axis, self.x_axis.cross(self.y_axis))
+    }
+
+    #[inline]
+    fn transpose(&self) -> Self {
+        unsafe {
+            let tmp0 = _mm_shuffle_ps(self.x_axis, self.y_axis, 0b01_00_01_00);
+            let tmp1 = _mm_shuffle_ps(self.x_axis, self.y_axis, 0b11_10_11_10);
+
+            Self {
+                x_axis: _mm_shuffle_ps(tmp0, self.z_axis, 0b00_00_10_00),
+                y_axis: _mm_shuffle_ps(tmp0, self.z_axis, 0b01_01_11_01),
+                z_axis: _mm_shuffle_ps(tmp1, self.z_axis, 0b10_10_10_00),
+            }
+        }
+    }
+
+    #[inline]
+    fn mul_vector(&self, other: __m128) -> __m128 {
+        let mut res = self.x_axis.mul(Vector3::splat_x(other));
+        res = self.y_axis.mul_add(Vector3::splat_y(other), res);
+        res = self.z_axis.mul_add(Vector3::splat_z(other), res);
+        res
+    }
+
+    #[inline]
+    fn mul_matrix(&self, other: &Self) -> Self {
+        Self::from_cols(
+            self.mul_vector(other.x_axis),
+            self.mul_vector(other.y_axis),
+            self.mul_vector(other.z_axis),
+        )
+    }
+
+    #[inline]
+    fn mul_scalar(&self, other: f32) -> Self {
+        let other = __m128::splat(other);
+        Self::from_cols(
+            self.x_axis.mul(other),
+            self.y_axis.mul(other),
+            self.z_axis.mul(other),
+        )
+    }
+
+    #[inline]
+    fn add_matrix(&self, other: &Self) -> Self {
+        Self::from_cols(
+            self.x_axis.add(other.x_axis),
+            self.y_axis.add(other.y_axis),
+            self.z_axis.add(other.z_axis),
+        )
+    }
+
+    #[inline]
+    fn sub_matrix(&self, other: &Self) -> Self {
+        Self::from_cols(
+            self.x_axis.sub(other.x_axis),
+            self.y_axis.sub(other.y_axis),
+            self.z_axis.sub(other.z_axis),
+        )
+    }
+}
+
+impl FloatMatrix3x3<f32, __m128> for Vector3x3<__m128> {
+    #[inline]
+    fn inverse(&self) -> Self {
+        let tmp0 = self.y_axis.cross(self.z_axis);
+        let tmp1 = self.z_axis.cross(self.x_axis);
+        let tmp2 = self.x_axis.cross(self.y_axis);
+        let det = Vector3::dot_into_vec(self.z_axis, tmp2);
+        glam_assert!(Vector3::dot(self.z_axis, tmp2) != 0.0);
+        let inv_det = FloatVector3::recip(det);
+        Self::from_cols(tmp0.mul(inv_det), tmp1.mul(inv_det), tmp2.mul(inv_det)).transpose()
+    }
+
+    #[inline]
+    fn transform_point2(&self, other: XY<f32>) -> XY<f32> {
+        let mut res = self.x_axis.mul(__m128::splat(other.x));
+        res = self.y_axis.mul_add(__m128::splat(other.y), res);
+        res = self.z_axis.add(res);
+        res = res.mul(FloatVector3::recip(Vector3::splat_z(res)));
+        res.into()
+    }
+
+    #[inline]
+    fn transform_vector2(&self, other: XY<f32>) -> XY<f32> {
+        let mut res = self.x_axis.mul(__m128::splat(other.x));
+        res = self.y_axis.mul_add(__m128::splat(other.y), res);
+        res.into()
+    }
+}
+
+// The SIMD determinant and inverse compute terms in a different order to the scalar
+// implementation so the deterministic feature uses the scalar code instead.
+#[cfg(feature = "deterministic")]
+#[inline]
+fn into_scalar(m: &Vector4x4<__m128>) -> Vector4x4<XYZW<f32>> {
+    Vector4x4 {
+        x_axis: m.x_axis.into(),
+        y_axis: m.y_axis.into(),
+        z_axis: m.z_axis.into(),
+        w_axis: m.w_axis.into(),
+    }
+}
+
+#[cfg(feature = "deterministic")]
+#[inline]
+fn from_scalar(m: &Vector4x4<XYZW<f32>>) -> Vector4x4<__m128> {
+    Vector4x4 {
+        x_axis: m.x_axis.into(),
+        y_axis: m.y_axis.into(),
+        z_axis: m.z_axis.into(),
+        w_axis: m.w_axis.into(),
+    }
+}
+
 impl MatrixConst for Vector4x4<__m128> {
     const ZERO: Vector4x4<__m128> = Vector4x4 {
         x_axis: __m128::ZERO,
@@ -190,6 +383,13 @@ impl Matrix4x4<f32, __m128> for Vector4x4<__m128> {
         unsafe { &mut *(self as *mut Self as *mut Vector4x4<__m128>) }
     }
 
+    #[cfg(feature = "deterministic")]
+    #[inline]
+    fn determinant(&self) -> f32 {
+        into_scalar(self).determinant()
+    }
+
+    #[cfg(not(feature = "deterministic"))]
     #[inline]
     fn determinant(&self) -> f32 {
         unsafe {
@@ -225,7 +425,7 @@ impl Matrix4x4<f32, __m128> for Vector4x4<__m128> {
             let addres = _mm_add_ps(subres, mulfacc);
             let detcof = _mm_mul_ps(addres, _mm_setr_ps(1.0, -1.0, 1.0, -1.0));
 
-            self.x_axis.dot(detcof)
+            Vector4::dot(self.x_axis, detcof)
         }
     }
 
@@ -251,6 +451,12 @@ impl Matrix4x4<f32, __m128> for Vector4x4<__m128> {
 impl FloatMatrix4x4<f32, __m128> for Vector4x4<__m128> {
     type SIMDVector3 = __m128;
 
+    #[cfg(feature = "deterministic")]
+    fn inverse(&self) -> Self {
+        from_scalar(&into_scalar(self).inverse())
+    }
+
+    #[cfg(not(feature = "deterministic"))]
     fn inverse(&self) -> Self {
         unsafe {
             // Based on https://github.com/g-truc/glm `glm_mat4_inverse`
@@ -379,7 +585,7 @@ impl FloatMatrix4x4<f32, __m128> for Vector4x4<__m128> {
             let row1 = _mm_shuffle_ps(inv2, inv3, 0b00_00_00_00);
             let row2 = _mm_shuffle_ps(row0, row1, 0b10_00_10_00);
 
-            let dot0 = self.x_axis.dot(row2);
+            let dot0 = Vector4::dot(self.x_axis, row2);
             glam_assert!(dot0 != 0.0);
 
             let rcp0 = _mm_set1_ps(dot0.recip());
@@ -405,19 +611,19 @@ impl FloatMatrix4x4<f32, __m128> for Vector4x4<__m128> {
 
     #[inline]
     fn transform_float4_as_point3(&self, other: __m128) -> __m128 {
-        let mut res = self.x_axis.mul(other.splat_x());
-        res = self.y_axis.mul_add(other.splat_y(), res);
-        res = self.z_axis.mul_add(other.splat_z(), res);
+        let mut res = self.x_axis.mul(Vector4::splat_x(other));
+        res = self.y_axis.mul_add(Vector4::splat_y(other), res);
+        res = self.z_axis.mul_add(Vector4::splat_z(other), res);
         res = self.w_axis.add(res);
-        res = res.mul(res.splat_w().recip());
+        res = res.mul(FloatVector4::recip(Vector4::splat_w(res)));
         res
     }
 
     #[inline]
     fn transform_float4_as_vector3(&self, other: __m128) -> __m128 {
-        let mut res = self.x_axis.mul(other.splat_x());
-        res = self.y_axis.mul_add(other.splat_y(), res);
-        res = self.z_axis.mul_add(other.splat_z(), res);
+        let mut res = self.x_axis.mul(Vector4::splat_x(other));
+        res = self.y_axis.mul_add(Vector4::splat_y(other), res);
+        res = self.z_axis.mul_add(Vector4::splat_z(other), res);
         res
     }
 }
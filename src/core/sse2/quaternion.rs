@@ -3,6 +3,7 @@ use core::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use core::arch::x86_64::*;
 
+#[cfg(not(feature = "deterministic"))]
 use super::float::*;
 use crate::const_m128;
 use crate::core::{
@@ -31,7 +32,11 @@ impl Quaternion<f32> for __m128 {
             let dot = Vector4::dot_into_vec(start, end);
             // Calculate the bias, if the dot product is positive or zero, there is no bias
             // but if it is negative, we want to flip the 'end' rotation XYZW components
+            #[cfg(not(feature = "deterministic"))]
             let bias = _mm_and_ps(dot, NEG_ZERO);
+            // Treat NaN the same as the scalar implementation
+            #[cfg(feature = "deterministic")]
+            let bias = _mm_and_ps(_mm_cmpnge_ps(dot, Self::ZERO), NEG_ZERO);
             let interpolated = _mm_add_ps(
                 _mm_mul_ps(_mm_sub_ps(_mm_xor_ps(end, bias), start), _mm_set_ps1(s)),
                 start,
@@ -57,11 +62,24 @@ impl Quaternion<f32> for __m128 {
             // assumes scalar_acos clamps the input to [-1.0, 1.0]
             let theta = dot.acos_approx();
 
-            let x = 1.0 - s;
-            let y = s;
-            let z = 1.0;
+            #[cfg(feature = "deterministic")]
+            {
+                // Use the same sin and reciprocal as the scalar implementation
+                let scale1 = Float::sin(theta * (1.0 - s));
+                let scale2 = Float::sin(theta * s);
+                let theta_sin = Float::sin(theta);
 
+                self.mul_scalar(scale1)
+                    .add(end.mul_scalar(scale2))
+                    .mul_scalar(Float::recip(theta_sin))
+            }
+
+            #[cfg(not(feature = "deterministic"))]
             unsafe {
+                let x = 1.0 - s;
+                let y = s;
+                let z = 1.0;
+
                 let tmp = _mm_mul_ps(_mm_set_ps1(theta), _mm_set_ps(0.0, z, y, x));
                 let tmp = m128_sin(tmp);
 
@@ -109,8 +127,17 @@ impl Quaternion<f32> for __m128 {
             let result0 = _mm_add_ps(lxrw_lyrw_lzrw_lwrw, lwrx_nlzrx_lyrx_nlxrx);
 
             let nlyrz_lxrz_lwrz_wlzrz = _mm_mul_ps(lyrz_lxrz_lwrz_lzrz, CONTROL_YXWZ);
-            let result1 = _mm_add_ps(lzry_lwry_nlxry_nlyry, nlyrz_lxrz_lwrz_wlzrz);
-            _mm_add_ps(result0, result1)
+            #[cfg(not(feature = "deterministic"))]
+            {
+                let result1 = _mm_add_ps(lzry_lwry_nlxry_nlyry, nlyrz_lxrz_lwrz_wlzrz);
+                _mm_add_ps(result0, result1)
+            }
+            // Sum in the same order as the scalar implementation
+            #[cfg(feature = "deterministic")]
+            {
+                let result1 = _mm_add_ps(result0, lzry_lwry_nlxry_nlyry);
+                _mm_add_ps(result1, nlyrz_lxrz_lwrz_wlzrz)
+            }
         }
     }
 
@@ -150,10 +150,23 @@ unsafe fn dot3_in_x(lhs: __m128, rhs: __m128) -> __m128 {
 #[inline(always)]
 unsafe fn dot4_in_x(lhs: __m128, rhs: __m128) -> __m128 {
     let x2_y2_z2_w2 = _mm_mul_ps(lhs, rhs);
-    let z2_w2_0_0 = _mm_shuffle_ps(x2_y2_z2_w2, x2_y2_z2_w2, 0b00_00_11_10);
-    let x2z2_y2w2_0_0 = _mm_add_ps(x2_y2_z2_w2, z2_w2_0_0);
-    let y2w2_0_0_0 = _mm_shuffle_ps(x2z2_y2w2_0_0, x2z2_y2w2_0_0, 0b00_00_00_01);
-    _mm_add_ps(x2z2_y2w2_0_0, y2w2_0_0_0)
+    #[cfg(not(feature = "deterministic"))]
+    {
+        let z2_w2_0_0 = _mm_shuffle_ps(x2_y2_z2_w2, x2_y2_z2_w2, 0b00_00_11_10);
+        let x2z2_y2w2_0_0 = _mm_add_ps(x2_y2_z2_w2, z2_w2_0_0);
+        let y2w2_0_0_0 = _mm_shuffle_ps(x2z2_y2w2_0_0, x2z2_y2w2_0_0, 0b00_00_00_01);
+        _mm_add_ps(x2z2_y2w2_0_0, y2w2_0_0_0)
+    }
+    #[cfg(feature = "deterministic")]
+    {
+        // Sum in the same order as the scalar implementation
+        let y2_0_0_0 = _mm_shuffle_ps(x2_y2_z2_w2, x2_y2_z2_w2, 0b00_00_00_01);
+        let z2_0_0_0 = _mm_shuffle_ps(x2_y2_z2_w2, x2_y2_z2_w2, 0b00_00_00_10);
+        let w2_0_0_0 = _mm_shuffle_ps(x2_y2_z2_w2, x2_y2_z2_w2, 0b00_00_00_11);
+        let x2y2_0_0_0 = _mm_add_ss(x2_y2_z2_w2, y2_0_0_0);
+        let x2y2z2_0_0_0 = _mm_add_ss(x2y2_0_0_0, z2_0_0_0);
+        _mm_add_ss(x2y2z2_0_0_0, w2_0_0_0)
+    }
 }
 
 impl VectorConst for __m128 {
@@ -374,6 +387,34 @@ impl Vector3<f32> for __m128 {
         }
     }
 
+    #[inline]
+    fn div_euclid(self, rhs: Self) -> Self {
+        let (x, y, z) = Vector3::into_tuple(self);
+        let (rx, ry, rz) = Vector3::into_tuple(rhs);
+        unsafe {
+            _mm_set_ps(
+                0.0,
+                NumEx::div_euclid(z, rz),
+                NumEx::div_euclid(y, ry),
+                NumEx::div_euclid(x, rx),
+            )
+        }
+    }
+
+    #[inline]
+    fn rem_euclid(self, rhs: Self) -> Self {
+        let (x, y, z) = Vector3::into_tuple(self);
+        let (rx, ry, rz) = Vector3::into_tuple(rhs);
+        unsafe {
+            _mm_set_ps(
+                0.0,
+                NumEx::rem_euclid(z, rz),
+                NumEx::rem_euclid(y, ry),
+                NumEx::rem_euclid(x, rx),
+            )
+        }
+    }
+
     #[inline]
     fn dot(self, other: Self) -> f32 {
         unsafe { _mm_cvtss_f32(dot3_in_x(self, other)) }
@@ -521,6 +562,34 @@ impl Vector4<f32> for __m128 {
         }
     }
 
+    #[inline]
+    fn div_euclid(self, rhs: Self) -> Self {
+        let (x, y, z, w) = Vector4::into_tuple(self);
+        let (rx, ry, rz, rw) = Vector4::into_tuple(rhs);
+        unsafe {
+            _mm_set_ps(
+                NumEx::div_euclid(w, rw),
+                NumEx::div_euclid(z, rz),
+                NumEx::div_euclid(y, ry),
+                NumEx::div_euclid(x, rx),
+            )
+        }
+    }
+
+    #[inline]
+    fn rem_euclid(self, rhs: Self) -> Self {
+        let (x, y, z, w) = Vector4::into_tuple(self);
+        let (rx, ry, rz, rw) = Vector4::into_tuple(rhs);
+        unsafe {
+            _mm_set_ps(
+                NumEx::rem_euclid(w, rw),
+                NumEx::rem_euclid(z, rz),
+                NumEx::rem_euclid(y, ry),
+                NumEx::rem_euclid(x, rx),
+            )
+        }
+    }
+
     #[inline]
     fn dot(self, other: Self) -> f32 {
         unsafe { _mm_cvtss_f32(dot4_in_x(self, other)) }
@@ -595,16 +664,74 @@ impl FloatVector3<f32> for __m128 {
         unsafe { _mm_div_ps(Self::ONE, self) }
     }
 
+    // The results of _mm_rcp_ps and _mm_rsqrt_ps vary between CPU vendors so the deterministic
+    // feature uses the default full precision implementations instead.
+    #[cfg(not(feature = "deterministic"))]
+    #[inline]
+    fn recip_fast(self) -> Self {
+        unsafe { m128_recip_fast(self) }
+    }
+
+    #[cfg(not(feature = "deterministic"))]
+    #[inline]
+    fn length_recip_fast(self) -> f32 {
+        unsafe { _mm_cvtss_f32(m128_rsqrt_fast(dot3_in_x(self, self))) }
+    }
+
+    #[cfg(not(feature = "deterministic"))]
+    #[inline]
+    fn normalize_fast(self) -> Self {
+        unsafe { _mm_mul_ps(self, m128_rsqrt_fast(Vector3::dot_into_vec(self, self))) }
+    }
+
+    #[inline]
+    fn sin_cos_fast(self) -> (Self, Self) {
+        unsafe { m128_sin_cos_fast(self) }
+    }
+
     #[inline]
     fn exp(self) -> Self {
         let (x, y, z) = Vector3::into_tuple(self);
-        unsafe { _mm_set_ps(0.0, z.exp(), y.exp(), x.exp()) }
+        unsafe { _mm_set_ps(0.0, Float::exp(z), Float::exp(y), Float::exp(x)) }
     }
 
     #[inline]
     fn powf(self, n: f32) -> Self {
         let (x, y, z) = Vector3::into_tuple(self);
-        unsafe { _mm_set_ps(0.0, z.powf(n), y.powf(n), x.powf(n)) }
+        unsafe { _mm_set_ps(0.0, Float::powf(z, n), Float::powf(y, n), Float::powf(x, n)) }
+    }
+
+    #[inline]
+    fn trunc(self) -> Self {
+        unsafe { m128_trunc(self) }
+    }
+
+    #[inline(always)]
+    fn sqrt(self) -> Self {
+        unsafe { _mm_sqrt_ps(self) }
+    }
+
+    #[inline]
+    fn copysign(self, sign: Self) -> Self {
+        unsafe { m128_copysign(self, sign) }
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        let (x, y, z) = Vector3::into_tuple(self);
+        unsafe { _mm_set_ps(0.0, Float::ln(z), Float::ln(y), Float::ln(x)) }
+    }
+
+    #[inline]
+    fn sin(self) -> Self {
+        let (x, y, z) = Vector3::into_tuple(self);
+        unsafe { _mm_set_ps(0.0, Float::sin(z), Float::sin(y), Float::sin(x)) }
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        let (x, y, z) = Vector3::into_tuple(self);
+        unsafe { _mm_set_ps(0.0, Float::cos(z), Float::cos(y), Float::cos(x)) }
     }
 
     #[inline]
@@ -628,7 +755,15 @@ impl FloatVector3<f32> for __m128 {
     fn normalize(self) -> Self {
         unsafe {
             let dot = Vector3::dot_into_vec(self, self);
-            _mm_div_ps(self, _mm_sqrt_ps(dot))
+            #[cfg(not(feature = "deterministic"))]
+            {
+                _mm_div_ps(self, _mm_sqrt_ps(dot))
+            }
+            // Multiply by the reciprocal length to match the scalar implementation
+            #[cfg(feature = "deterministic")]
+            {
+                _mm_mul_ps(self, _mm_div_ps(Self::ONE, _mm_sqrt_ps(dot)))
+            }
         }
     }
 }
@@ -686,16 +821,81 @@ impl FloatVector4<f32> for __m128 {
         unsafe { _mm_div_ps(Self::ONE, self) }
     }
 
+    // The results of _mm_rcp_ps and _mm_rsqrt_ps vary between CPU vendors so the deterministic
+    // feature uses the default full precision implementations instead.
+    #[cfg(not(feature = "deterministic"))]
+    #[inline]
+    fn recip_fast(self) -> Self {
+        unsafe { m128_recip_fast(self) }
+    }
+
+    #[cfg(not(feature = "deterministic"))]
+    #[inline]
+    fn length_recip_fast(self) -> f32 {
+        unsafe { _mm_cvtss_f32(m128_rsqrt_fast(dot4_in_x(self, self))) }
+    }
+
+    #[cfg(not(feature = "deterministic"))]
+    #[inline]
+    fn normalize_fast(self) -> Self {
+        unsafe { _mm_mul_ps(self, m128_rsqrt_fast(Vector4::dot_into_vec(self, self))) }
+    }
+
+    #[inline]
+    fn sin_cos_fast(self) -> (Self, Self) {
+        unsafe { m128_sin_cos_fast(self) }
+    }
+
     #[inline]
     fn exp(self) -> Self {
         let (x, y, z, w) = Vector4::into_tuple(self);
-        unsafe { _mm_set_ps(w.exp(), z.exp(), y.exp(), x.exp()) }
+        unsafe { _mm_set_ps(Float::exp(w), Float::exp(z), Float::exp(y), Float::exp(x)) }
     }
 
     #[inline]
     fn powf(self, n: f32) -> Self {
         let (x, y, z, w) = Vector4::into_tuple(self);
-        unsafe { _mm_set_ps(w.powf(n), z.powf(n), y.powf(n), x.powf(n)) }
+        unsafe {
+            _mm_set_ps(
+                Float::powf(w, n),
+                Float::powf(z, n),
+                Float::powf(y, n),
+                Float::powf(x, n),
+            )
+        }
+    }
+
+    #[inline]
+    fn trunc(self) -> Self {
+        unsafe { m128_trunc(self) }
+    }
+
+    #[inline(always)]
+    fn sqrt(self) -> Self {
+        unsafe { _mm_sqrt_ps(self) }
+    }
+
+    #[inline]
+    fn copysign(self, sign: Self) -> Self {
+        unsafe { m128_copysign(self, sign) }
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        let (x, y, z, w) = Vector4::into_tuple(self);
+        unsafe { _mm_set_ps(Float::ln(w), Float::ln(z), Float::ln(y), Float::ln(x)) }
+    }
+
+    #[inline]
+    fn sin(self) -> Self {
+        let (x, y, z, w) = Vector4::into_tuple(self);
+        unsafe { _mm_set_ps(Float::sin(w), Float::sin(z), Float::sin(y), Float::sin(x)) }
+    }
+
+    #[inline]
+    fn cos(self) -> Self {
+        let (x, y, z, w) = Vector4::into_tuple(self);
+        unsafe { _mm_set_ps(Float::cos(w), Float::cos(z), Float::cos(y), Float::cos(x)) }
     }
 
     #[inline]
@@ -719,7 +919,15 @@ impl FloatVector4<f32> for __m128 {
     fn normalize(self) -> Self {
         unsafe {
             let dot = Vector4::dot_into_vec(self, self);
-            _mm_div_ps(self, _mm_sqrt_ps(dot))
+            #[cfg(not(feature = "deterministic"))]
+            {
+                _mm_div_ps(self, _mm_sqrt_ps(dot))
+            }
+            // Multiply by the reciprocal length to match the scalar implementation
+            #[cfg(feature = "deterministic")]
+            {
+                _mm_mul_ps(self, _mm_div_ps(Self::ONE, _mm_sqrt_ps(dot)))
+            }
         }
     }
 }
@@ -25,6 +25,48 @@ pub struct XYZW<T> {
     pub w: T,
 }
 
+/// A 16 byte aligned `XYZ<f32>`, used for the columns of `Mat3A` when SIMD is not available.
+#[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+#[cfg_attr(not(target_arch = "spirv"), repr(C, align(16)))]
+pub struct XYZF32A16 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+#[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
+impl XYZF32A16 {
+    #[inline(always)]
+    pub const fn from_xyz(v: XYZ<f32>) -> Self {
+        Self {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+}
+
+#[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
+impl From<XYZ<f32>> for XYZF32A16 {
+    #[inline(always)]
+    fn from(v: XYZ<f32>) -> Self {
+        Self::from_xyz(v)
+    }
+}
+
+#[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
+impl From<XYZF32A16> for XYZ<f32> {
+    #[inline(always)]
+    fn from(v: XYZF32A16) -> Self {
+        Self {
+            x: v.x,
+            y: v.y,
+            z: v.z,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
 #[cfg_attr(not(target_arch = "spirv"), repr(C))]
 pub struct Vector2x2<V2> {
@@ -49,6 +91,38 @@ pub struct Vector4x4<V4> {
     pub w_axis: V4,
 }
 
+#[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+#[cfg_attr(not(target_arch = "spirv"), repr(C))]
+pub struct Vector2x3<V3> {
+    pub x_axis: V3,
+    pub y_axis: V3,
+}
+
+#[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+#[cfg_attr(not(target_arch = "spirv"), repr(C))]
+pub struct Vector3x2<V2> {
+    pub x_axis: V2,
+    pub y_axis: V2,
+    pub z_axis: V2,
+}
+
+#[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+#[cfg_attr(not(target_arch = "spirv"), repr(C))]
+pub struct Vector3x4<V4> {
+    pub x_axis: V4,
+    pub y_axis: V4,
+    pub z_axis: V4,
+}
+
+#[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+#[cfg_attr(not(target_arch = "spirv"), repr(C))]
+pub struct Vector4x3<V3> {
+    pub x_axis: V3,
+    pub y_axis: V3,
+    pub z_axis: V3,
+    pub w_axis: V3,
+}
+
 #[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
 #[repr(align(16))]
 pub(crate) struct Align16<T>(pub T);
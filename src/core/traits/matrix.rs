@@ -1,5 +1,5 @@
 use crate::core::{
-    storage::{Vector2x2, Vector3x3, Vector4x4, XY, XYZ, XYZW},
+    storage::{Vector2x2, Vector4x4, XY, XYZ, XYZW},
     traits::{
         quaternion::Quaternion,
         scalar::{FloatEx, NumEx},
@@ -35,11 +35,6 @@ pub trait Matrix2x2<T: NumEx, V2: Vector2<T>>: Matrix<T> {
     fn as_ref_vector2x2(&self) -> &Vector2x2<V2>;
     fn as_mut_vector2x2(&mut self) -> &mut Vector2x2<V2>;
 
-    #[inline(always)]
-    fn from_cols_array(m: &[T; 4]) -> Self {
-        Self::new(m[0], m[1], m[2], m[3])
-    }
-
     #[rustfmt::skip]
     #[inline(always)]
     fn to_cols_array(&self) -> [T; 4] {
@@ -50,13 +45,66 @@ pub trait Matrix2x2<T: NumEx, V2: Vector2<T>>: Matrix<T> {
     }
 
     #[inline(always)]
-    fn from_cols_array_2d(m: &[[T; 2]; 2]) -> Self {
-        Self::from_cols(V2::from_array(m[0]), V2::from_array(m[1]))
+    fn to_cols_array_2d(&self) -> [[T; 2]; 2] {
+        [self.x_axis().into_array(), self.y_axis().into_array()]
     }
 
     #[inline(always)]
-    fn to_cols_array_2d(&self) -> [[T; 2]; 2] {
-        [self.x_axis().into_array(), self.y_axis().into_array()]
+    fn from_rows(x_row: V2, y_row: V2) -> Self {
+        Self::from_cols(x_row, y_row).transpose()
+    }
+
+    #[rustfmt::skip]
+    #[inline(always)]
+    fn from_rows_array(m: &[T; 4]) -> Self {
+        Self::new(
+            m[0], m[2],
+            m[1], m[3])
+    }
+
+    #[inline(always)]
+    fn to_rows_array(&self) -> [T; 4] {
+        self.transpose().to_cols_array()
+    }
+
+    #[inline]
+    fn col(&self, index: usize) -> &V2 {
+        match index {
+            0 => self.x_axis(),
+            1 => self.y_axis(),
+            _ => panic!(
+                "index out of bounds: the len is 2 but the index is {}",
+                index
+            ),
+        }
+    }
+
+    #[inline]
+    fn col_mut(&mut self, index: usize) -> &mut V2 {
+        let m = self.as_mut_vector2x2();
+        match index {
+            0 => &mut m.x_axis,
+            1 => &mut m.y_axis,
+            _ => panic!(
+                "index out of bounds: the len is 2 but the index is {}",
+                index
+            ),
+        }
+    }
+
+    #[inline]
+    fn row(&self, index: usize) -> V2 {
+        let [x_axis, y_axis] = self.to_cols_array_2d();
+        V2::new(x_axis[index], y_axis[index])
+    }
+
+    #[inline]
+    fn set_row(&mut self, index: usize, row: V2) {
+        let [mut x_axis, mut y_axis] = self.to_cols_array_2d();
+        let [row_x, row_y] = row.into_array();
+        x_axis[index] = row_x;
+        y_axis[index] = row_y;
+        *self = Self::from_cols(V2::from_array(x_axis), V2::from_array(y_axis));
     }
 
     #[rustfmt::skip]
@@ -87,6 +135,24 @@ pub trait FloatMatrix2x2<T: FloatEx, V2: FloatVector2<T>>: Matrix2x2<T, V2> {
             && self.y_axis().abs_diff_eq(*other.y_axis(), max_abs_diff)
     }
 
+    #[inline]
+    fn relative_eq(&self, other: &Self, max_abs_diff: T, max_relative: T) -> bool {
+        self.x_axis()
+            .relative_eq(*other.x_axis(), max_abs_diff, max_relative)
+            && self
+                .y_axis()
+                .relative_eq(*other.y_axis(), max_abs_diff, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, max_abs_diff: T, max_ulps: u32) -> bool {
+        self.x_axis()
+            .ulps_eq(*other.x_axis(), max_abs_diff, max_ulps)
+            && self
+                .y_axis()
+                .ulps_eq(*other.y_axis(), max_abs_diff, max_ulps)
+    }
+
     #[inline]
     fn from_scale_angle(scale: V2, angle: T) -> Self {
         let (sin, cos) = angle.sin_cos();
@@ -105,6 +171,10 @@ pub trait FloatMatrix2x2<T: FloatEx, V2: FloatVector2<T>>: Matrix2x2<T, V2> {
 }
 
 pub trait Matrix3x3<T: NumEx, V3: Vector3<T>>: Matrix<T> {
+    /// The type the columns are stored as, this is larger than `V3` when the columns are padded
+    /// to 16 bytes.
+    type Column;
+
     fn from_cols(x_axis: V3, y_axis: V3, z_axis: V3) -> Self;
 
     #[inline(always)]
@@ -116,32 +186,14 @@ pub trait Matrix3x3<T: NumEx, V3: Vector3<T>>: Matrix<T> {
         )
     }
 
-    #[inline(always)]
-    fn x_axis(&self) -> &V3 {
-        &self.as_ref_vector3x3().x_axis
-    }
+    fn x_axis(&self) -> &V3;
+    fn y_axis(&self) -> &V3;
+    fn z_axis(&self) -> &V3;
 
-    #[inline(always)]
-    fn y_axis(&self) -> &V3 {
-        &self.as_ref_vector3x3().y_axis
-    }
-
-    #[inline(always)]
-    fn z_axis(&self) -> &V3 {
-        &self.as_ref_vector3x3().z_axis
-    }
-
-    fn as_ref_vector3x3(&self) -> &Vector3x3<V3>;
-    fn as_mut_vector3x3(&mut self) -> &mut Vector3x3<V3>;
-
-    #[rustfmt::skip]
-    #[inline(always)]
-    fn from_cols_array(m: &[T; 9]) -> Self {
-        Self::new(
-            m[0], m[1], m[2],
-            m[3], m[4], m[5],
-            m[6], m[7], m[8])
-    }
+    /// Returns the storage of the column for the given `index`, including any padding.
+    fn col_storage(&self, index: usize) -> &Self::Column;
+    /// Returns the mutable storage of the column for the given `index`, including any padding.
+    fn col_storage_mut(&mut self, index: usize) -> &mut Self::Column;
 
     #[rustfmt::skip]
     #[inline(always)]
@@ -156,15 +208,6 @@ pub trait Matrix3x3<T: NumEx, V3: Vector3<T>>: Matrix<T> {
         ]
     }
 
-    #[inline(always)]
-    fn from_cols_array_2d(m: &[[T; 3]; 3]) -> Self {
-        Self::from_cols(
-            V3::from_array(m[0]),
-            V3::from_array(m[1]),
-            V3::from_array(m[2]),
-        )
-    }
-
     #[inline(always)]
     fn to_cols_array_2d(&self) -> [[T; 3]; 3] {
         [
@@ -174,6 +217,59 @@ pub trait Matrix3x3<T: NumEx, V3: Vector3<T>>: Matrix<T> {
         ]
     }
 
+    #[inline(always)]
+    fn from_rows(x_row: V3, y_row: V3, z_row: V3) -> Self {
+        Self::from_cols(x_row, y_row, z_row).transpose()
+    }
+
+    #[rustfmt::skip]
+    #[inline(always)]
+    fn from_rows_array(m: &[T; 9]) -> Self {
+        Self::new(
+            m[0], m[3], m[6],
+            m[1], m[4], m[7],
+            m[2], m[5], m[8],
+        )
+    }
+
+    #[inline(always)]
+    fn to_rows_array(&self) -> [T; 9] {
+        self.transpose().to_cols_array()
+    }
+
+    #[inline]
+    fn col(&self, index: usize) -> &V3 {
+        match index {
+            0 => self.x_axis(),
+            1 => self.y_axis(),
+            2 => self.z_axis(),
+            _ => panic!(
+                "index out of bounds: the len is 3 but the index is {}",
+                index
+            ),
+        }
+    }
+
+    #[inline]
+    fn row(&self, index: usize) -> V3 {
+        let [x_axis, y_axis, z_axis] = self.to_cols_array_2d();
+        V3::new(x_axis[index], y_axis[index], z_axis[index])
+    }
+
+    #[inline]
+    fn set_row(&mut self, index: usize, row: V3) {
+        let [mut x_axis, mut y_axis, mut z_axis] = self.to_cols_array_2d();
+        let [row_x, row_y, row_z] = row.into_array();
+        x_axis[index] = row_x;
+        y_axis[index] = row_y;
+        z_axis[index] = row_z;
+        *self = Self::from_cols(
+            V3::from_array(x_axis),
+            V3::from_array(y_axis),
+            V3::from_array(z_axis),
+        );
+    }
+
     #[rustfmt::skip]
     #[inline(always)]
     fn from_scale(scale: V3) -> Self {
@@ -206,6 +302,30 @@ pub trait FloatMatrix3x3<T: FloatEx, V3: FloatVector3<T>>: Matrix3x3<T, V3> {
             && self.z_axis().abs_diff_eq(*other.z_axis(), max_abs_diff)
     }
 
+    #[inline]
+    fn relative_eq(&self, other: &Self, max_abs_diff: T, max_relative: T) -> bool {
+        self.x_axis()
+            .relative_eq(*other.x_axis(), max_abs_diff, max_relative)
+            && self
+                .y_axis()
+                .relative_eq(*other.y_axis(), max_abs_diff, max_relative)
+            && self
+                .z_axis()
+                .relative_eq(*other.z_axis(), max_abs_diff, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, max_abs_diff: T, max_ulps: u32) -> bool {
+        self.x_axis()
+            .ulps_eq(*other.x_axis(), max_abs_diff, max_ulps)
+            && self
+                .y_axis()
+                .ulps_eq(*other.y_axis(), max_abs_diff, max_ulps)
+            && self
+                .z_axis()
+                .ulps_eq(*other.z_axis(), max_abs_diff, max_ulps)
+    }
+
     #[rustfmt::skip]
     #[inline]
     fn from_scale_angle_translation(scale: XY<T>, angle: T, translation: XY<T>) -> Self {
@@ -308,22 +428,6 @@ pub trait FloatMatrix3x3<T: FloatEx, V3: FloatVector3<T>>: Matrix3x3<T, V3> {
 }
 
 pub trait Matrix4x4<T: NumEx, V4: Vector4<T>>: Matrix<T> {
-    #[rustfmt::skip]
-    #[inline(always)]
-    fn new(
-        m00: T, m01: T, m02: T, m03: T,
-        m10: T, m11: T, m12: T, m13: T,
-        m20: T, m21: T, m22: T, m23: T,
-        m30: T, m31: T, m32: T, m33: T,
-        ) -> Self {
-        Self::from_cols(
-            V4::new(m00, m01, m02, m03),
-            V4::new(m10, m11, m12, m13),
-            V4::new(m20, m21, m22, m23),
-            V4::new(m30, m31, m32, m33),
-        )
-    }
-
     fn from_cols(x_axis: V4, y_axis: V4, z_axis: V4, w_axis: V4) -> Self;
 
     fn x_axis(&self) -> &V4;
@@ -334,16 +438,6 @@ pub trait Matrix4x4<T: NumEx, V4: Vector4<T>>: Matrix<T> {
     fn as_ref_vector4x4(&self) -> &Vector4x4<V4>;
     fn as_mut_vector4x4(&mut self) -> &mut Vector4x4<V4>;
 
-    #[rustfmt::skip]
-    #[inline(always)]
-    fn from_cols_array(m: &[T; 16]) -> Self {
-        Self::new(
-             m[0],  m[1],  m[2],  m[3],
-             m[4],  m[5],  m[6],  m[7],
-             m[8],  m[9], m[10], m[11],
-            m[12], m[13], m[14], m[15])
-    }
-
     #[rustfmt::skip]
     #[inline(always)]
     fn to_cols_array(&self) -> [T; 16] {
@@ -359,16 +453,6 @@ pub trait Matrix4x4<T: NumEx, V4: Vector4<T>>: Matrix<T> {
         ]
     }
 
-    #[inline(always)]
-    fn from_cols_array_2d(m: &[[T; 4]; 4]) -> Self {
-        Self::from_cols(
-            Vector4::from_array(m[0]),
-            Vector4::from_array(m[1]),
-            Vector4::from_array(m[2]),
-            Vector4::from_array(m[3]),
-        )
-    }
-
     #[inline(always)]
     fn to_cols_array_2d(&self) -> [[T; 4]; 4] {
         [
@@ -379,6 +463,78 @@ pub trait Matrix4x4<T: NumEx, V4: Vector4<T>>: Matrix<T> {
         ]
     }
 
+    #[inline(always)]
+    fn from_rows(x_row: V4, y_row: V4, z_row: V4, w_row: V4) -> Self {
+        Self::from_cols(x_row, y_row, z_row, w_row).transpose()
+    }
+
+    #[rustfmt::skip]
+    #[inline(always)]
+    fn from_rows_array(m: &[T; 16]) -> Self {
+        Self::from_cols(
+            V4::new(m[0], m[4], m[8], m[12]),
+            V4::new(m[1], m[5], m[9], m[13]),
+            V4::new(m[2], m[6], m[10], m[14]),
+            V4::new(m[3], m[7], m[11], m[15]),
+        )
+    }
+
+    #[inline(always)]
+    fn to_rows_array(&self) -> [T; 16] {
+        self.transpose().to_cols_array()
+    }
+
+    #[inline]
+    fn col(&self, index: usize) -> &V4 {
+        match index {
+            0 => self.x_axis(),
+            1 => self.y_axis(),
+            2 => self.z_axis(),
+            3 => self.w_axis(),
+            _ => panic!(
+                "index out of bounds: the len is 4 but the index is {}",
+                index
+            ),
+        }
+    }
+
+    #[inline]
+    fn col_mut(&mut self, index: usize) -> &mut V4 {
+        let m = self.as_mut_vector4x4();
+        match index {
+            0 => &mut m.x_axis,
+            1 => &mut m.y_axis,
+            2 => &mut m.z_axis,
+            3 => &mut m.w_axis,
+            _ => panic!(
+                "index out of bounds: the len is 4 but the index is {}",
+                index
+            ),
+        }
+    }
+
+    #[inline]
+    fn row(&self, index: usize) -> V4 {
+        let [x_axis, y_axis, z_axis, w_axis] = self.to_cols_array_2d();
+        V4::new(x_axis[index], y_axis[index], z_axis[index], w_axis[index])
+    }
+
+    #[inline]
+    fn set_row(&mut self, index: usize, row: V4) {
+        let [mut x_axis, mut y_axis, mut z_axis, mut w_axis] = self.to_cols_array_2d();
+        let [row_x, row_y, row_z, row_w] = row.into_array();
+        x_axis[index] = row_x;
+        y_axis[index] = row_y;
+        z_axis[index] = row_z;
+        w_axis[index] = row_w;
+        *self = Self::from_cols(
+            V4::from_array(x_axis),
+            V4::from_array(y_axis),
+            V4::from_array(z_axis),
+            V4::from_array(w_axis),
+        );
+    }
+
     #[inline(always)]
     fn from_scale(scale: XYZ<T>) -> Self {
         // Do not panic as long as any component is non-zero
@@ -473,6 +629,36 @@ pub trait FloatMatrix4x4<T: FloatEx, V4: FloatVector4<T> + Quaternion<T>>:
             && self.w_axis().abs_diff_eq(*other.w_axis(), max_abs_diff)
     }
 
+    #[inline]
+    fn relative_eq(&self, other: &Self, max_abs_diff: T, max_relative: T) -> bool {
+        self.x_axis()
+            .relative_eq(*other.x_axis(), max_abs_diff, max_relative)
+            && self
+                .y_axis()
+                .relative_eq(*other.y_axis(), max_abs_diff, max_relative)
+            && self
+                .z_axis()
+                .relative_eq(*other.z_axis(), max_abs_diff, max_relative)
+            && self
+                .w_axis()
+                .relative_eq(*other.w_axis(), max_abs_diff, max_relative)
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, max_abs_diff: T, max_ulps: u32) -> bool {
+        self.x_axis()
+            .ulps_eq(*other.x_axis(), max_abs_diff, max_ulps)
+            && self
+                .y_axis()
+                .ulps_eq(*other.y_axis(), max_abs_diff, max_ulps)
+            && self
+                .z_axis()
+                .ulps_eq(*other.z_axis(), max_abs_diff, max_ulps)
+            && self
+                .w_axis()
+                .ulps_eq(*other.w_axis(), max_abs_diff, max_ulps)
+    }
+
     #[inline]
     fn quaternion_to_axes(rotation: V4) -> (V4, V4, V4) {
         glam_assert!(rotation.is_normalized());
@@ -150,6 +150,28 @@ pub trait Quaternion<T: FloatEx>: FloatVector4<T> {
         positive_w_angle < threshold_angle
     }
 
+    #[inline]
+    fn slerp_fast(self, end: Self, s: T) -> Self {
+        glam_assert!(FloatVector4::is_normalized(self));
+        glam_assert!(FloatVector4::is_normalized(end));
+
+        let dot = Vector4::dot(self, end);
+
+        if dot > T::from_f32(0.9995) {
+            // the dot product is positive so there is no need to flip `end`
+            self.add(end.sub(self).mul_scalar(s)).normalize_fast()
+        } else {
+            // assumes scalar_acos clamps the input to [-1.0, 1.0]
+            let theta = dot.acos_approx();
+            let (sin, _) =
+                Self::new(theta * (T::ONE - s), theta * s, theta, T::ZERO).sin_cos_fast();
+            let scale1 = sin.splat_x();
+            let scale2 = sin.splat_y();
+            let theta_sin_recip = sin.splat_z().recip_fast();
+            self.mul(scale1).add(end.mul(scale2)).mul(theta_sin_recip)
+        }
+    }
+
     fn conjugate(self) -> Self;
     fn lerp(self, end: Self, s: T) -> Self;
     fn slerp(self, end: Self, s: T) -> Self;
@@ -1,30 +1,34 @@
 // num_traits is optional as it adds 70% to compile times. It is needed by no_std builds
-#[cfg(feature = "libm")]
+#[cfg(all(feature = "libm", not(feature = "deterministic")))]
 pub use num_traits::{Float, Num, Signed};
 
 use core::{
     marker::Sized,
-    ops::{Add, Div, Mul, Sub},
+    ops::{Add, Div, Mul, Rem, Sub},
 };
 
 // Stub the necessary parts of num traits
-#[cfg(not(feature = "libm"))]
+#[cfg(any(not(feature = "libm"), feature = "deterministic"))]
 pub trait Num: PartialEq {}
 
-#[cfg(not(feature = "libm"))]
+#[cfg(any(not(feature = "libm"), feature = "deterministic"))]
 pub trait Signed: Sized + Num + core::ops::Neg<Output = Self> {
     fn abs(self) -> Self;
     fn signum(self) -> Self;
 }
 
-#[cfg(not(feature = "libm"))]
+#[cfg(any(not(feature = "libm"), feature = "deterministic"))]
 pub trait Float: Num + Copy + core::ops::Neg<Output = Self> {
     fn acos(self) -> Self;
+    #[cfg(feature = "mint")]
+    fn atan2(self, other: Self) -> Self;
     fn ceil(self) -> Self;
+    fn cos(self) -> Self;
     fn exp(self) -> Self;
     fn floor(self) -> Self;
     fn is_finite(self) -> bool;
     fn is_nan(self) -> bool;
+    fn ln(self) -> Self;
     fn powf(self, n: Self) -> Self;
     fn recip(self) -> Self;
     fn round(self) -> Self;
@@ -32,16 +36,17 @@ pub trait Float: Num + Copy + core::ops::Neg<Output = Self> {
     fn sin(self) -> Self;
     fn sin_cos(self) -> (Self, Self);
     fn tan(self) -> Self;
+    fn trunc(self) -> Self;
 }
 
-#[cfg(not(feature = "libm"))]
+#[cfg(any(not(feature = "libm"), feature = "deterministic"))]
 macro_rules! impl_num_trait {
     ($t:ident) => {
         impl Num for $t {}
     };
 }
 
-#[cfg(not(feature = "libm"))]
+#[cfg(any(not(feature = "libm"), feature = "deterministic"))]
 macro_rules! impl_signed_trait {
     ($t:ident) => {
         impl_num_trait!($t);
@@ -59,7 +64,7 @@ macro_rules! impl_signed_trait {
     };
 }
 
-#[cfg(not(feature = "libm"))]
+#[cfg(not(any(feature = "libm", feature = "deterministic")))]
 macro_rules! impl_float_trait {
     ($t:ident) => {
         impl_signed_trait!($t);
@@ -69,11 +74,20 @@ macro_rules! impl_float_trait {
             fn acos(self) -> Self {
                 $t::acos(self)
             }
+            #[cfg(feature = "mint")]
+            #[inline(always)]
+            fn atan2(self, other: Self) -> Self {
+                $t::atan2(self, other)
+            }
             #[inline(always)]
             fn ceil(self) -> Self {
                 $t::ceil(self)
             }
             #[inline(always)]
+            fn cos(self) -> Self {
+                $t::cos(self)
+            }
+            #[inline(always)]
             fn exp(self) -> Self {
                 $t::exp(self)
             }
@@ -90,6 +104,10 @@ macro_rules! impl_float_trait {
                 $t::is_nan(self)
             }
             #[inline(always)]
+            fn ln(self) -> Self {
+                $t::ln(self)
+            }
+            #[inline(always)]
             fn powf(self, n: Self) -> Self {
                 $t::powf(self, n)
             }
@@ -117,17 +135,129 @@ macro_rules! impl_float_trait {
             fn tan(self) -> Self {
                 $t::tan(self)
             }
+            #[inline(always)]
+            fn trunc(self) -> Self {
+                $t::trunc(self)
+            }
         }
     };
 }
 
-#[cfg(not(feature = "libm"))]
+#[cfg(not(any(feature = "libm", feature = "deterministic")))]
 impl_float_trait!(f32);
-#[cfg(not(feature = "libm"))]
+#[cfg(not(any(feature = "libm", feature = "deterministic")))]
 impl_float_trait!(f64);
-#[cfg(not(feature = "libm"))]
+#[cfg(feature = "deterministic")]
+macro_rules! impl_deterministic_float_trait {
+    ($t:ident, $acos:ident, $atan2:ident, $ceil:ident, $copysign:ident, $cos:ident, $exp:ident,
+     $fabs:ident, $floor:ident, $log:ident, $pow:ident, $round:ident, $sin:ident, $sincos:ident,
+     $sqrt:ident, $tan:ident, $trunc:ident) => {
+        impl_num_trait!($t);
+
+        impl Signed for $t {
+            #[inline(always)]
+            fn abs(self) -> Self {
+                libm_pure::$fabs(self)
+            }
+            #[inline(always)]
+            fn signum(self) -> Self {
+                if self.is_nan() {
+                    self
+                } else {
+                    libm_pure::$copysign(1.0, self)
+                }
+            }
+        }
+
+        impl Float for $t {
+            #[inline(always)]
+            fn acos(self) -> Self {
+                libm_pure::$acos(self)
+            }
+            #[cfg(feature = "mint")]
+            #[inline(always)]
+            fn atan2(self, other: Self) -> Self {
+                libm_pure::$atan2(self, other)
+            }
+            #[inline(always)]
+            fn ceil(self) -> Self {
+                libm_pure::$ceil(self)
+            }
+            #[inline(always)]
+            fn cos(self) -> Self {
+                libm_pure::$cos(self)
+            }
+            #[inline(always)]
+            fn exp(self) -> Self {
+                libm_pure::$exp(self)
+            }
+            #[inline(always)]
+            fn floor(self) -> Self {
+                libm_pure::$floor(self)
+            }
+            #[inline(always)]
+            fn is_finite(self) -> bool {
+                $t::is_finite(self)
+            }
+            #[inline(always)]
+            fn is_nan(self) -> bool {
+                $t::is_nan(self)
+            }
+            #[inline(always)]
+            fn ln(self) -> Self {
+                libm_pure::$log(self)
+            }
+            #[inline(always)]
+            fn powf(self, n: Self) -> Self {
+                libm_pure::$pow(self, n)
+            }
+            #[inline(always)]
+            fn recip(self) -> Self {
+                1.0 / self
+            }
+            #[inline(always)]
+            fn round(self) -> Self {
+                libm_pure::$round(self)
+            }
+            #[inline(always)]
+            fn sin(self) -> Self {
+                libm_pure::$sin(self)
+            }
+            #[inline(always)]
+            fn sin_cos(self) -> (Self, Self) {
+                libm_pure::$sincos(self)
+            }
+            #[inline(always)]
+            fn sqrt(self) -> Self {
+                libm_pure::$sqrt(self)
+            }
+            #[inline(always)]
+            fn tan(self) -> Self {
+                libm_pure::$tan(self)
+            }
+            #[inline(always)]
+            fn trunc(self) -> Self {
+                libm_pure::$trunc(self)
+            }
+        }
+    };
+}
+
+// The deterministic feature always uses the pure Rust libm implementations so that
+// transcendental functions return the same result on every platform.
+#[cfg(feature = "deterministic")]
+impl_deterministic_float_trait!(
+    f32, acosf, atan2f, ceilf, copysignf, cosf, expf, fabsf, floorf, logf, powf, roundf, sinf,
+    sincosf, sqrtf, tanf, truncf
+);
+#[cfg(feature = "deterministic")]
+impl_deterministic_float_trait!(
+    f64, acos, atan2, ceil, copysign, cos, exp, fabs, floor, log, pow, round, sin, sincos, sqrt,
+    tan, trunc
+);
+#[cfg(any(not(feature = "libm"), feature = "deterministic"))]
 impl_signed_trait!(i32);
-#[cfg(not(feature = "libm"))]
+#[cfg(any(not(feature = "libm"), feature = "deterministic"))]
 impl_num_trait!(u32);
 
 pub trait MaskConst: Sized {
@@ -143,6 +273,7 @@ pub trait FloatConstEx: Sized {
     const NEG_ONE: Self;
     const TWO: Self;
     const HALF: Self;
+    const PI: Self;
 }
 
 pub trait NumEx:
@@ -155,18 +286,68 @@ pub trait NumEx:
     + Add<Output = Self>
     + Div<Output = Self>
     + Mul<Output = Self>
+    + Rem<Output = Self>
     + Sub<Output = Self>
 {
     fn min(self, other: Self) -> Self;
     fn max(self, other: Self) -> Self;
+    fn div_euclid(self, rhs: Self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
 }
 
 pub trait SignedEx: Signed + NumEx {}
 
 pub trait FloatEx: Float + FloatConstEx + SignedEx {
     fn acos_approx(self) -> Self;
+    fn sin_cos_approx(self) -> (Self, Self);
+    fn copysign(self, sign: Self) -> Self;
     fn from_f32(f: f32) -> Self;
     fn from_f64(f: f64) -> Self;
+    fn ulps_eq(self, other: Self, max_abs_diff: Self, max_ulps: u32) -> bool;
+
+    /// Returns true if `self` and `other` are within `max_abs_diff` of each other or if their
+    /// difference is within `max_relative` of the larger magnitude, matching the `approx` crate.
+    #[inline]
+    fn relative_eq(self, other: Self, max_abs_diff: Self, max_relative: Self) -> bool {
+        if self == other {
+            return true;
+        }
+        if !self.is_finite() || !other.is_finite() {
+            return false;
+        }
+        let abs_diff = FloatEx::copysign(self - other, Self::ONE);
+        if abs_diff <= max_abs_diff {
+            return true;
+        }
+        let largest = NumEx::max(
+            FloatEx::copysign(self, Self::ONE),
+            FloatEx::copysign(other, Self::ONE),
+        );
+        abs_diff <= largest * max_relative
+    }
+}
+
+// Returns true if the float values are within `max_abs_diff` of each other or `max_ulps`
+// representable values apart, matching the `approx` crate.
+macro_rules! impl_ulps_eq {
+    ($ubits:ty) => {
+        #[inline]
+        fn ulps_eq(self, other: Self, max_abs_diff: Self, max_ulps: u32) -> bool {
+            if FloatEx::copysign(self - other, 1.0) <= max_abs_diff {
+                return true;
+            }
+            if self.is_nan()
+                || other.is_nan()
+                || self.is_sign_negative() != other.is_sign_negative()
+            {
+                return false;
+            }
+            // values with the same sign are ordered the same as their bits
+            let (a, b) = (self.to_bits(), other.to_bits());
+            let ulps = if a > b { a - b } else { b - a };
+            ulps <= <$ubits>::from(max_ulps)
+        }
+    };
 }
 
 impl NumConstEx for f32 {
@@ -178,6 +359,7 @@ impl FloatConstEx for f32 {
     const NEG_ONE: Self = -1.0;
     const TWO: Self = 2.0;
     const HALF: Self = 0.5;
+    const PI: Self = core::f32::consts::PI;
 }
 
 impl NumEx for f32 {
@@ -189,11 +371,40 @@ impl NumEx for f32 {
     fn max(self, other: Self) -> Self {
         f32::max(self, other)
     }
+    #[inline]
+    fn div_euclid(self, rhs: Self) -> Self {
+        // Same as the std `div_euclid` implementation which is not available in `core`
+        let q = Float::trunc(self / rhs);
+        if self % rhs < 0.0 {
+            return if rhs > 0.0 { q - 1.0 } else { q + 1.0 };
+        }
+        q
+    }
+    #[inline]
+    fn rem_euclid(self, rhs: Self) -> Self {
+        // Same as the std `rem_euclid` implementation which is not available in `core`
+        let r = self % rhs;
+        if r < 0.0 {
+            if rhs < 0.0 {
+                r - rhs
+            } else {
+                r + rhs
+            }
+        } else {
+            r
+        }
+    }
 }
 
 impl SignedEx for f32 {}
 
 impl FloatEx for f32 {
+    impl_ulps_eq!(u32);
+    #[inline]
+    fn copysign(self, sign: Self) -> Self {
+        const SIGN_MASK: u32 = 1 << 31;
+        Self::from_bits((self.to_bits() & !SIGN_MASK) | (sign.to_bits() & SIGN_MASK))
+    }
     #[inline(always)]
     fn from_f32(v: f32) -> Self {
         v
@@ -235,6 +446,37 @@ impl FloatEx for f32 {
             core::f32::consts::PI - result
         }
     }
+    #[inline]
+    fn sin_cos_approx(self) -> (Self, Self) {
+        // Based on https://github.com/microsoft/DirectXMath `XMScalarSinCosEst`
+        // Operations are ordered the same as `m128_sin_cos_fast` so both give the same result.
+        use core::f32::consts::{FRAC_PI_2, PI};
+        const RECIPROCAL_TWO_PI: f32 = 0.159_154_94;
+        const TWO_PI: f32 = PI * 2.0;
+
+        // Map self to y in [-pi,pi], x = 2*pi*quotient + remainder.
+        let quotient = (self * RECIPROCAL_TWO_PI).round();
+        let y = self - TWO_PI * quotient;
+
+        // Map y to [-pi/2,pi/2] with sin(y) = sin(self).
+        let (y, sign) = if y.abs() <= FRAC_PI_2 {
+            (y, 1.0)
+        } else if y >= 0.0 {
+            (PI - y, -1.0)
+        } else {
+            (-PI - y, -1.0)
+        };
+
+        let y2 = y * y;
+
+        // 7-degree minimax approximation
+        let sin = (((-0.000_185_246_7 * y2 + 0.008_313_95) * y2 - 0.166_658_52) * y2 + 1.0) * y;
+
+        // 6-degree minimax approximation
+        let cos = (((-0.001_271_243_6 * y2 + 0.041_493_92) * y2 - 0.499_927_46) * y2 + 1.0) * sign;
+
+        (sin, cos)
+    }
 }
 
 impl NumConstEx for f64 {
@@ -246,6 +488,7 @@ impl FloatConstEx for f64 {
     const NEG_ONE: Self = -1.0;
     const TWO: Self = 2.0;
     const HALF: Self = 0.5;
+    const PI: Self = core::f64::consts::PI;
 }
 
 impl NumEx for f64 {
@@ -257,11 +500,40 @@ impl NumEx for f64 {
     fn max(self, other: Self) -> Self {
         f64::max(self, other)
     }
+    #[inline]
+    fn div_euclid(self, rhs: Self) -> Self {
+        // Same as the std `div_euclid` implementation which is not available in `core`
+        let q = Float::trunc(self / rhs);
+        if self % rhs < 0.0 {
+            return if rhs > 0.0 { q - 1.0 } else { q + 1.0 };
+        }
+        q
+    }
+    #[inline]
+    fn rem_euclid(self, rhs: Self) -> Self {
+        // Same as the std `rem_euclid` implementation which is not available in `core`
+        let r = self % rhs;
+        if r < 0.0 {
+            if rhs < 0.0 {
+                r - rhs
+            } else {
+                r + rhs
+            }
+        } else {
+            r
+        }
+    }
 }
 
 impl SignedEx for f64 {}
 
 impl FloatEx for f64 {
+    impl_ulps_eq!(u64);
+    #[inline]
+    fn copysign(self, sign: Self) -> Self {
+        const SIGN_MASK: u64 = 1 << 63;
+        Self::from_bits((self.to_bits() & !SIGN_MASK) | (sign.to_bits() & SIGN_MASK))
+    }
     #[inline(always)]
     fn from_f32(v: f32) -> Self {
         v as Self
@@ -273,7 +545,11 @@ impl FloatEx for f64 {
     #[inline(always)]
     fn acos_approx(self) -> Self {
         // TODO: clamp range
-        f64::acos(self)
+        Float::acos(self)
+    }
+    #[inline(always)]
+    fn sin_cos_approx(self) -> (Self, Self) {
+        Float::sin_cos(self)
     }
 }
 
@@ -291,6 +567,14 @@ impl NumEx for i32 {
     fn max(self, other: Self) -> Self {
         core::cmp::max(self, other)
     }
+    #[inline(always)]
+    fn div_euclid(self, rhs: Self) -> Self {
+        i32::div_euclid(self, rhs)
+    }
+    #[inline(always)]
+    fn rem_euclid(self, rhs: Self) -> Self {
+        i32::rem_euclid(self, rhs)
+    }
 }
 
 impl SignedEx for i32 {}
@@ -309,6 +593,14 @@ impl NumEx for u32 {
     fn max(self, other: Self) -> Self {
         core::cmp::max(self, other)
     }
+    #[inline(always)]
+    fn div_euclid(self, rhs: Self) -> Self {
+        u32::div_euclid(self, rhs)
+    }
+    #[inline(always)]
+    fn rem_euclid(self, rhs: Self) -> Self {
+        u32::rem_euclid(self, rhs)
+    }
 }
 
 #[cfg(test)]
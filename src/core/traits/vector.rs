@@ -1,4 +1,4 @@
-use super::scalar::{FloatEx, SignedEx};
+use super::scalar::{FloatEx, NumEx, SignedEx};
 use crate::core::storage::{XY, XYZ, XYZW};
 
 pub trait MaskVectorConst: Sized {
@@ -90,6 +90,11 @@ pub trait Vector<T>: Sized + Copy + Clone {
 
     fn min(self, other: Self) -> Self;
     fn max(self, other: Self) -> Self;
+
+    #[inline]
+    fn clamp(self, min: Self, max: Self) -> Self {
+        self.max(min).min(max)
+    }
 }
 
 pub trait Vector2<T>: Vector<T> + Vector2Const {
@@ -110,6 +115,75 @@ pub trait Vector2<T>: Vector<T> + Vector2Const {
     fn min_element(self) -> T;
     fn max_element(self) -> T;
 
+    fn div_euclid(self, rhs: Self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn element_sum(self) -> T
+    where
+        T: NumEx,
+    {
+        let a = self.into_array();
+        a[0] + a[1]
+    }
+
+    #[inline]
+    fn element_product(self) -> T
+    where
+        T: NumEx,
+    {
+        let a = self.into_array();
+        a[0] * a[1]
+    }
+
+    #[inline]
+    fn min_position(self) -> usize
+    where
+        T: NumEx,
+    {
+        let a = self.into_array();
+        let mut index = 0;
+        for i in 1..2 {
+            if a[i] < a[index] {
+                index = i;
+            }
+        }
+        index
+    }
+
+    #[inline]
+    fn max_position(self) -> usize
+    where
+        T: NumEx,
+    {
+        let a = self.into_array();
+        let mut index = 0;
+        for i in 1..2 {
+            if a[i] > a[index] {
+                index = i;
+            }
+        }
+        index
+    }
+
+    #[inline]
+    fn pow(self, exp: u32) -> Self {
+        // Exponentiation by squaring
+        let mut base = self;
+        let mut exp = exp;
+        let mut result = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.mul(base);
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.mul(base);
+            }
+        }
+        result
+    }
+
     fn dot(self, other: Self) -> T;
 
     #[inline(always)]
@@ -149,6 +223,75 @@ pub trait Vector3<T>: Vector<T> + Vector3Const {
     fn min_element(self) -> T;
     fn max_element(self) -> T;
 
+    fn div_euclid(self, rhs: Self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn element_sum(self) -> T
+    where
+        T: NumEx,
+    {
+        let a = self.into_array();
+        a[0] + a[1] + a[2]
+    }
+
+    #[inline]
+    fn element_product(self) -> T
+    where
+        T: NumEx,
+    {
+        let a = self.into_array();
+        a[0] * a[1] * a[2]
+    }
+
+    #[inline]
+    fn min_position(self) -> usize
+    where
+        T: NumEx,
+    {
+        let a = self.into_array();
+        let mut index = 0;
+        for i in 1..3 {
+            if a[i] < a[index] {
+                index = i;
+            }
+        }
+        index
+    }
+
+    #[inline]
+    fn max_position(self) -> usize
+    where
+        T: NumEx,
+    {
+        let a = self.into_array();
+        let mut index = 0;
+        for i in 1..3 {
+            if a[i] > a[index] {
+                index = i;
+            }
+        }
+        index
+    }
+
+    #[inline]
+    fn pow(self, exp: u32) -> Self {
+        // Exponentiation by squaring
+        let mut base = self;
+        let mut exp = exp;
+        let mut result = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.mul(base);
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.mul(base);
+            }
+        }
+        result
+    }
+
     fn dot(self, other: Self) -> T;
 
     #[inline(always)]
@@ -190,6 +333,75 @@ pub trait Vector4<T>: Vector<T> + Vector4Const {
     fn min_element(self) -> T;
     fn max_element(self) -> T;
 
+    fn div_euclid(self, rhs: Self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+
+    #[inline]
+    fn element_sum(self) -> T
+    where
+        T: NumEx,
+    {
+        let a = self.into_array();
+        a[0] + a[1] + a[2] + a[3]
+    }
+
+    #[inline]
+    fn element_product(self) -> T
+    where
+        T: NumEx,
+    {
+        let a = self.into_array();
+        a[0] * a[1] * a[2] * a[3]
+    }
+
+    #[inline]
+    fn min_position(self) -> usize
+    where
+        T: NumEx,
+    {
+        let a = self.into_array();
+        let mut index = 0;
+        for i in 1..4 {
+            if a[i] < a[index] {
+                index = i;
+            }
+        }
+        index
+    }
+
+    #[inline]
+    fn max_position(self) -> usize
+    where
+        T: NumEx,
+    {
+        let a = self.into_array();
+        let mut index = 0;
+        for i in 1..4 {
+            if a[i] > a[index] {
+                index = i;
+            }
+        }
+        index
+    }
+
+    #[inline]
+    fn pow(self, exp: u32) -> Self {
+        // Exponentiation by squaring
+        let mut base = self;
+        let mut exp = exp;
+        let mut result = Self::ONE;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = result.mul(base);
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.mul(base);
+            }
+        }
+        result
+    }
+
     fn dot(self, other: Self) -> T;
 
     #[inline(always)]
@@ -229,6 +441,43 @@ pub trait FloatVector2<T: FloatEx>: SignedVector2<T> {
     fn is_finite(self) -> bool;
     fn is_nan(self) -> bool;
     fn is_nan_mask(self) -> Self::Mask;
+    fn sin_cos_fast(self) -> (Self, Self);
+    fn trunc(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn ln(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn copysign(self, sign: Self) -> Self;
+
+    #[inline]
+    fn fract(self) -> Self {
+        self.sub(self.floor())
+    }
+
+    #[inline]
+    fn step(self, edge: Self) -> Self {
+        Self::select(self.cmplt(edge), Self::ZERO, Self::ONE)
+    }
+
+    #[inline]
+    fn smoothstep(self, edge0: Self, edge1: Self) -> Self {
+        let t = self
+            .sub(edge0)
+            .div(edge1.sub(edge0))
+            .clamp(Self::ZERO, Self::ONE);
+        t.mul(t)
+            .mul(Self::splat(T::from_f32(3.0)).sub(t.mul_scalar(T::TWO)))
+    }
+
+    #[inline]
+    fn mix(self, other: Self, a: Self) -> Self {
+        self.add(other.sub(self).mul(a))
+    }
+
+    #[inline]
+    fn recip_fast(self) -> Self {
+        self.recip()
+    }
 
     #[inline]
     fn length(self) -> T {
@@ -245,6 +494,126 @@ pub trait FloatVector2<T: FloatEx>: SignedVector2<T> {
         self.mul_scalar(self.length_recip())
     }
 
+    #[inline]
+    fn length_recip_fast(self) -> T {
+        self.length_recip()
+    }
+
+    #[inline]
+    fn normalize_fast(self) -> Self {
+        self.mul_scalar(self.length_recip_fast())
+    }
+
+    #[inline]
+    fn try_normalize(self) -> Option<Self> {
+        let rcp = self.length_recip();
+        if rcp.is_finite() && rcp > T::ZERO {
+            Some(self.mul_scalar(rcp))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn normalize_or(self, fallback: Self) -> Self {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    #[inline]
+    fn normalize_or_zero(self) -> Self {
+        self.normalize_or(Self::ZERO)
+    }
+
+    #[inline]
+    fn length_and_direction(self) -> (T, Self) {
+        let length = self.length();
+        let rcp = length.recip();
+        if rcp.is_finite() && rcp > T::ZERO {
+            (length, self.mul_scalar(rcp))
+        } else {
+            (length, Self::ZERO)
+        }
+    }
+
+    #[inline]
+    fn reflect(self, normal: Self) -> Self {
+        glam_assert!(normal.is_normalized());
+        self.sub(normal.mul_scalar(T::TWO * self.dot(normal)))
+    }
+
+    #[inline]
+    fn refract(self, normal: Self, eta: T) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(normal.is_normalized());
+        let n_dot_i = normal.dot(self);
+        let k = T::ONE - eta * eta * (T::ONE - n_dot_i * n_dot_i);
+        if k >= T::ZERO {
+            self.mul_scalar(eta)
+                .sub(normal.mul_scalar(eta * n_dot_i + k.sqrt()))
+        } else {
+            Self::ZERO
+        }
+    }
+
+    #[inline]
+    fn project_onto(self, other: Self) -> Self {
+        other.mul_scalar(self.dot(other) / other.dot(other))
+    }
+
+    #[inline]
+    fn project_onto_normalized(self, other: Self) -> Self {
+        glam_assert!(other.is_normalized());
+        other.mul_scalar(self.dot(other))
+    }
+
+    #[inline]
+    fn reject_from(self, other: Self) -> Self {
+        self.sub(self.project_onto(other))
+    }
+
+    #[inline]
+    fn slide(self, normal: Self) -> Self {
+        self.sub(self.project_onto_normalized(normal))
+    }
+
+    fn rotate_towards(self, target: Self, max_angle: T) -> Self {
+        let (from, to) = match (self.try_normalize(), target.try_normalize()) {
+            (Some(from), Some(to)) => (from, to),
+            _ => return self,
+        };
+        let length = self.length();
+        let cos_between = from.dot(to);
+        let angle_between = if cos_between >= T::ONE {
+            T::ZERO
+        } else if cos_between <= T::NEG_ONE {
+            T::PI
+        } else {
+            cos_between.acos_approx()
+        };
+        if max_angle >= angle_between {
+            return to.mul_scalar(length);
+        }
+        let angle = if max_angle < angle_between - T::PI {
+            angle_between - T::PI
+        } else {
+            max_angle
+        };
+        let ortho = to
+            .sub(from.mul_scalar(cos_between))
+            .try_normalize()
+            .unwrap_or_else(|| from.any_orthonormal_vector());
+        let (sin, cos) = angle.sin_cos();
+        from.mul_scalar(cos)
+            .add(ortho.mul_scalar(sin))
+            .mul_scalar(length)
+    }
+
+    #[inline]
+    fn any_orthonormal_vector(self) -> Self {
+        glam_assert!(self.is_normalized());
+        self.perp()
+    }
+
     #[inline(always)]
     fn length_squared(self) -> T {
         self.dot(self)
@@ -264,6 +633,22 @@ pub trait FloatVector2<T: FloatEx>: SignedVector2<T> {
         self.sub(other).abs().cmple(Self::splat(max_abs_diff)).all()
     }
 
+    #[inline]
+    fn relative_eq(self, other: Self, max_abs_diff: T, max_relative: T) -> bool {
+        let (a, b) = (self.into_array(), other.into_array());
+        a.iter()
+            .zip(b.iter())
+            .all(|(a, b)| a.relative_eq(*b, max_abs_diff, max_relative))
+    }
+
+    #[inline]
+    fn ulps_eq(self, other: Self, max_abs_diff: T, max_ulps: u32) -> bool {
+        let (a, b) = (self.into_array(), other.into_array());
+        a.iter()
+            .zip(b.iter())
+            .all(|(a, b)| a.ulps_eq(*b, max_abs_diff, max_ulps))
+    }
+
     #[inline]
     fn angle_between(self, other: Self) -> T {
         let angle = (self.dot(other) / (self.length_squared() * other.length_squared()).sqrt())
@@ -287,6 +672,43 @@ pub trait FloatVector3<T: FloatEx>: SignedVector3<T> {
     fn is_finite(self) -> bool;
     fn is_nan(self) -> bool;
     fn is_nan_mask(self) -> Self::Mask;
+    fn sin_cos_fast(self) -> (Self, Self);
+    fn trunc(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn ln(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn copysign(self, sign: Self) -> Self;
+
+    #[inline]
+    fn fract(self) -> Self {
+        self.sub(self.floor())
+    }
+
+    #[inline]
+    fn step(self, edge: Self) -> Self {
+        Self::select(self.cmplt(edge), Self::ZERO, Self::ONE)
+    }
+
+    #[inline]
+    fn smoothstep(self, edge0: Self, edge1: Self) -> Self {
+        let t = self
+            .sub(edge0)
+            .div(edge1.sub(edge0))
+            .clamp(Self::ZERO, Self::ONE);
+        t.mul(t)
+            .mul(Self::splat(T::from_f32(3.0)).sub(t.mul_scalar(T::TWO)))
+    }
+
+    #[inline]
+    fn mix(self, other: Self, a: Self) -> Self {
+        self.add(other.sub(self).mul(a))
+    }
+
+    #[inline]
+    fn recip_fast(self) -> Self {
+        self.recip()
+    }
 
     #[inline]
     fn length(self) -> T {
@@ -303,6 +725,145 @@ pub trait FloatVector3<T: FloatEx>: SignedVector3<T> {
         self.mul_scalar(self.length_recip())
     }
 
+    #[inline]
+    fn length_recip_fast(self) -> T {
+        self.length_recip()
+    }
+
+    #[inline]
+    fn normalize_fast(self) -> Self {
+        self.mul_scalar(self.length_recip_fast())
+    }
+
+    #[inline]
+    fn try_normalize(self) -> Option<Self> {
+        let rcp = self.length_recip();
+        if rcp.is_finite() && rcp > T::ZERO {
+            Some(self.mul_scalar(rcp))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn normalize_or(self, fallback: Self) -> Self {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    #[inline]
+    fn normalize_or_zero(self) -> Self {
+        self.normalize_or(Self::ZERO)
+    }
+
+    #[inline]
+    fn length_and_direction(self) -> (T, Self) {
+        let length = self.length();
+        let rcp = length.recip();
+        if rcp.is_finite() && rcp > T::ZERO {
+            (length, self.mul_scalar(rcp))
+        } else {
+            (length, Self::ZERO)
+        }
+    }
+
+    #[inline]
+    fn reflect(self, normal: Self) -> Self {
+        glam_assert!(normal.is_normalized());
+        self.sub(normal.mul_scalar(T::TWO * self.dot(normal)))
+    }
+
+    #[inline]
+    fn refract(self, normal: Self, eta: T) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(normal.is_normalized());
+        let n_dot_i = normal.dot(self);
+        let k = T::ONE - eta * eta * (T::ONE - n_dot_i * n_dot_i);
+        if k >= T::ZERO {
+            self.mul_scalar(eta)
+                .sub(normal.mul_scalar(eta * n_dot_i + k.sqrt()))
+        } else {
+            Self::ZERO
+        }
+    }
+
+    #[inline]
+    fn project_onto(self, other: Self) -> Self {
+        other.mul_scalar(self.dot(other) / other.dot(other))
+    }
+
+    #[inline]
+    fn project_onto_normalized(self, other: Self) -> Self {
+        glam_assert!(other.is_normalized());
+        other.mul_scalar(self.dot(other))
+    }
+
+    #[inline]
+    fn reject_from(self, other: Self) -> Self {
+        self.sub(self.project_onto(other))
+    }
+
+    #[inline]
+    fn slide(self, normal: Self) -> Self {
+        self.sub(self.project_onto_normalized(normal))
+    }
+
+    fn rotate_towards(self, target: Self, max_angle: T) -> Self {
+        let (from, to) = match (self.try_normalize(), target.try_normalize()) {
+            (Some(from), Some(to)) => (from, to),
+            _ => return self,
+        };
+        let length = self.length();
+        let cos_between = from.dot(to);
+        let angle_between = if cos_between >= T::ONE {
+            T::ZERO
+        } else if cos_between <= T::NEG_ONE {
+            T::PI
+        } else {
+            cos_between.acos_approx()
+        };
+        if max_angle >= angle_between {
+            return to.mul_scalar(length);
+        }
+        let angle = if max_angle < angle_between - T::PI {
+            angle_between - T::PI
+        } else {
+            max_angle
+        };
+        let ortho = to
+            .sub(from.mul_scalar(cos_between))
+            .try_normalize()
+            .unwrap_or_else(|| from.any_orthonormal_vector());
+        let (sin, cos) = angle.sin_cos();
+        from.mul_scalar(cos)
+            .add(ortho.mul_scalar(sin))
+            .mul_scalar(length)
+    }
+
+    #[inline]
+    fn any_orthonormal_vector(self) -> Self {
+        glam_assert!(self.is_normalized());
+        // From https://graphics.pixar.com/library/OrthonormalB/paper.pdf
+        let (x, y, z) = self.into_tuple();
+        let sign = if z >= T::ZERO { T::ONE } else { T::NEG_ONE };
+        let a = T::NEG_ONE / (sign + z);
+        let b = x * y * a;
+        Self::new(b, sign + y * y * a, -y)
+    }
+
+    #[inline]
+    fn any_orthonormal_pair(self) -> (Self, Self) {
+        glam_assert!(self.is_normalized());
+        // From https://graphics.pixar.com/library/OrthonormalB/paper.pdf
+        let (x, y, z) = self.into_tuple();
+        let sign = if z >= T::ZERO { T::ONE } else { T::NEG_ONE };
+        let a = T::NEG_ONE / (sign + z);
+        let b = x * y * a;
+        (
+            Self::new(T::ONE + sign * x * x * a, sign * b, -sign * x),
+            Self::new(b, sign + y * y * a, -y),
+        )
+    }
+
     #[inline(always)]
     fn length_squared(self) -> T {
         self.dot(self)
@@ -322,6 +883,22 @@ pub trait FloatVector3<T: FloatEx>: SignedVector3<T> {
         self.sub(other).abs().cmple(Self::splat(max_abs_diff)).all()
     }
 
+    #[inline]
+    fn relative_eq(self, other: Self, max_abs_diff: T, max_relative: T) -> bool {
+        let (a, b) = (self.into_array(), other.into_array());
+        a.iter()
+            .zip(b.iter())
+            .all(|(a, b)| a.relative_eq(*b, max_abs_diff, max_relative))
+    }
+
+    #[inline]
+    fn ulps_eq(self, other: Self, max_abs_diff: T, max_ulps: u32) -> bool {
+        let (a, b) = (self.into_array(), other.into_array());
+        a.iter()
+            .zip(b.iter())
+            .all(|(a, b)| a.ulps_eq(*b, max_abs_diff, max_ulps))
+    }
+
     fn angle_between(self, other: Self) -> T {
         self.dot(other)
             .div(self.length_squared().mul(other.length_squared()).sqrt())
@@ -339,6 +916,43 @@ pub trait FloatVector4<T: FloatEx>: SignedVector4<T> {
     fn is_finite(self) -> bool;
     fn is_nan(self) -> bool;
     fn is_nan_mask(self) -> Self::Mask;
+    fn sin_cos_fast(self) -> (Self, Self);
+    fn trunc(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn ln(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn copysign(self, sign: Self) -> Self;
+
+    #[inline]
+    fn fract(self) -> Self {
+        self.sub(self.floor())
+    }
+
+    #[inline]
+    fn step(self, edge: Self) -> Self {
+        Self::select(self.cmplt(edge), Self::ZERO, Self::ONE)
+    }
+
+    #[inline]
+    fn smoothstep(self, edge0: Self, edge1: Self) -> Self {
+        let t = self
+            .sub(edge0)
+            .div(edge1.sub(edge0))
+            .clamp(Self::ZERO, Self::ONE);
+        t.mul(t)
+            .mul(Self::splat(T::from_f32(3.0)).sub(t.mul_scalar(T::TWO)))
+    }
+
+    #[inline]
+    fn mix(self, other: Self, a: Self) -> Self {
+        self.add(other.sub(self).mul(a))
+    }
+
+    #[inline]
+    fn recip_fast(self) -> Self {
+        self.recip()
+    }
 
     #[inline]
     fn length(self) -> T {
@@ -355,6 +969,138 @@ pub trait FloatVector4<T: FloatEx>: SignedVector4<T> {
         self.mul_scalar(self.length_recip())
     }
 
+    #[inline]
+    fn length_recip_fast(self) -> T {
+        self.length_recip()
+    }
+
+    #[inline]
+    fn normalize_fast(self) -> Self {
+        self.mul_scalar(self.length_recip_fast())
+    }
+
+    #[inline]
+    fn try_normalize(self) -> Option<Self> {
+        let rcp = self.length_recip();
+        if rcp.is_finite() && rcp > T::ZERO {
+            Some(self.mul_scalar(rcp))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn normalize_or(self, fallback: Self) -> Self {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    #[inline]
+    fn normalize_or_zero(self) -> Self {
+        self.normalize_or(Self::ZERO)
+    }
+
+    #[inline]
+    fn length_and_direction(self) -> (T, Self) {
+        let length = self.length();
+        let rcp = length.recip();
+        if rcp.is_finite() && rcp > T::ZERO {
+            (length, self.mul_scalar(rcp))
+        } else {
+            (length, Self::ZERO)
+        }
+    }
+
+    #[inline]
+    fn reflect(self, normal: Self) -> Self {
+        glam_assert!(normal.is_normalized());
+        self.sub(normal.mul_scalar(T::TWO * self.dot(normal)))
+    }
+
+    #[inline]
+    fn refract(self, normal: Self, eta: T) -> Self {
+        glam_assert!(self.is_normalized());
+        glam_assert!(normal.is_normalized());
+        let n_dot_i = normal.dot(self);
+        let k = T::ONE - eta * eta * (T::ONE - n_dot_i * n_dot_i);
+        if k >= T::ZERO {
+            self.mul_scalar(eta)
+                .sub(normal.mul_scalar(eta * n_dot_i + k.sqrt()))
+        } else {
+            Self::ZERO
+        }
+    }
+
+    #[inline]
+    fn project_onto(self, other: Self) -> Self {
+        other.mul_scalar(self.dot(other) / other.dot(other))
+    }
+
+    #[inline]
+    fn project_onto_normalized(self, other: Self) -> Self {
+        glam_assert!(other.is_normalized());
+        other.mul_scalar(self.dot(other))
+    }
+
+    #[inline]
+    fn reject_from(self, other: Self) -> Self {
+        self.sub(self.project_onto(other))
+    }
+
+    #[inline]
+    fn slide(self, normal: Self) -> Self {
+        self.sub(self.project_onto_normalized(normal))
+    }
+
+    fn rotate_towards(self, target: Self, max_angle: T) -> Self {
+        let (from, to) = match (self.try_normalize(), target.try_normalize()) {
+            (Some(from), Some(to)) => (from, to),
+            _ => return self,
+        };
+        let length = self.length();
+        let cos_between = from.dot(to);
+        let angle_between = if cos_between >= T::ONE {
+            T::ZERO
+        } else if cos_between <= T::NEG_ONE {
+            T::PI
+        } else {
+            cos_between.acos_approx()
+        };
+        if max_angle >= angle_between {
+            return to.mul_scalar(length);
+        }
+        let angle = if max_angle < angle_between - T::PI {
+            angle_between - T::PI
+        } else {
+            max_angle
+        };
+        let ortho = to
+            .sub(from.mul_scalar(cos_between))
+            .try_normalize()
+            .unwrap_or_else(|| from.any_orthonormal_vector());
+        let (sin, cos) = angle.sin_cos();
+        from.mul_scalar(cos)
+            .add(ortho.mul_scalar(sin))
+            .mul_scalar(length)
+    }
+
+    #[inline]
+    fn any_orthonormal_vector(self) -> Self {
+        glam_assert!(self.is_normalized());
+        // Remove the component of `self` from the axis it is least aligned with.
+        let v = self.into_array();
+        let mut index = 0;
+        for i in 1..4 {
+            if v[i].abs() < v[index].abs() {
+                index = i;
+            }
+        }
+        let mut axis = [T::ZERO; 4];
+        axis[index] = T::ONE;
+        Self::from_array(axis)
+            .sub(self.mul_scalar(v[index]))
+            .normalize()
+    }
+
     #[inline(always)]
     fn length_squared(self) -> T {
         self.dot(self)
@@ -373,4 +1119,20 @@ pub trait FloatVector4<T: FloatEx>: SignedVector4<T> {
     {
         self.sub(other).abs().cmple(Self::splat(max_abs_diff)).all()
     }
+
+    #[inline]
+    fn relative_eq(self, other: Self, max_abs_diff: T, max_relative: T) -> bool {
+        let (a, b) = (self.into_array(), other.into_array());
+        a.iter()
+            .zip(b.iter())
+            .all(|(a, b)| a.relative_eq(*b, max_abs_diff, max_relative))
+    }
+
+    #[inline]
+    fn ulps_eq(self, other: Self, max_abs_diff: T, max_ulps: u32) -> bool {
+        let (a, b) = (self.into_array(), other.into_array());
+        a.iter()
+            .zip(b.iter())
+            .all(|(a, b)| a.ulps_eq(*b, max_abs_diff, max_ulps))
+    }
 }
@@ -0,0 +1,32 @@
+use core::fmt;
+
+/// Writes `elements` as a bracketed, comma separated list.
+///
+/// Each element is formatted with the options of `f`, so precision, width and sign flags such as
+/// `{:+.2}` apply to every element rather than to the list as a whole.
+pub(crate) fn fmt_elements<T: fmt::Display>(f: &mut fmt::Formatter, elements: &[T]) -> fmt::Result {
+    f.write_str("[")?;
+    for (i, element) in elements.iter().enumerate() {
+        if i != 0 {
+            f.write_str(", ")?;
+        }
+        fmt::Display::fmt(element, f)?;
+    }
+    f.write_str("]")
+}
+
+/// Writes the columns of a matrix as a bracketed list of vectors.
+///
+/// The alternate flag `{:#}` writes one column per line, which with a width such as `{:#8.3}`
+/// lines up the elements of each row.
+pub(crate) fn fmt_columns<T: fmt::Display>(f: &mut fmt::Formatter, columns: &[T]) -> fmt::Result {
+    let separator = if f.alternate() { ",\n " } else { ", " };
+    f.write_str("[")?;
+    for (i, column) in columns.iter().enumerate() {
+        if i != 0 {
+            f.write_str(separator)?;
+        }
+        fmt::Display::fmt(column, f)?;
+    }
+    f.write_str("]")
+}
@@ -0,0 +1,134 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+// The inherent comparison methods take `self` and `other` by value or by reference depending
+// on the type, these forward the `approx` trait arguments accordingly.
+macro_rules! by_value {
+    ($f:path, $a:ident, $b:ident, $($arg:ident),+) => {
+        $f(*$a, *$b, $($arg),+)
+    };
+}
+
+macro_rules! by_ref_value {
+    ($f:path, $a:ident, $b:ident, $($arg:ident),+) => {
+        $f($a, *$b, $($arg),+)
+    };
+}
+
+macro_rules! by_ref {
+    ($f:path, $a:ident, $b:ident, $($arg:ident),+) => {
+        $f($a, $b, $($arg),+)
+    };
+}
+
+macro_rules! impl_approx {
+    ($t:ident, $call:ident, $($type:ident),+) => {
+        $(
+            impl AbsDiffEq for $type {
+                type Epsilon = $t;
+                #[inline]
+                fn default_epsilon() -> $t {
+                    $t::EPSILON
+                }
+                #[inline]
+                fn abs_diff_eq(&self, other: &Self, epsilon: $t) -> bool {
+                    $call!($type::abs_diff_eq, self, other, epsilon)
+                }
+            }
+
+            impl RelativeEq for $type {
+                #[inline]
+                fn default_max_relative() -> $t {
+                    $t::EPSILON
+                }
+                #[inline]
+                fn relative_eq(&self, other: &Self, epsilon: $t, max_relative: $t) -> bool {
+                    $call!($type::relative_eq, self, other, epsilon, max_relative)
+                }
+            }
+
+            impl UlpsEq for $type {
+                #[inline]
+                fn default_max_ulps() -> u32 {
+                    4
+                }
+                #[inline]
+                fn ulps_eq(&self, other: &Self, epsilon: $t, max_ulps: u32) -> bool {
+                    $call!($type::ulps_eq, self, other, epsilon, max_ulps)
+                }
+            }
+        )+
+    };
+}
+
+mod f32 {
+    use super::*;
+    use crate::{Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+    #[cfg(feature = "transform-types")]
+    use crate::{TransformRT, TransformSRT};
+
+    impl_approx!(f32, by_value, Vec2, Vec3, Vec3A, Vec4, Quat);
+    impl_approx!(f32, by_ref, Mat2);
+    impl_approx!(f32, by_ref_value, Mat3, Mat3A, Mat4);
+    #[cfg(feature = "transform-types")]
+    impl_approx!(f32, by_ref_value, TransformRT, TransformSRT);
+}
+
+mod f64 {
+    use super::*;
+    use crate::{DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
+
+    impl_approx!(f64, by_value, DVec2, DVec3, DVec4, DQuat);
+    impl_approx!(f64, by_ref, DMat2);
+    impl_approx!(f64, by_ref_value, DMat3, DMat4);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{DMat4, DVec3, Mat2, Mat3, Mat3A, Quat, Vec2, Vec3A, Vec4};
+    use approx::{
+        assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, assert_ulps_eq, assert_ulps_ne,
+    };
+
+    #[test]
+    fn test_approx_vec() {
+        let a = Vec4::new(1.0, 1.0e6, -1.0e-6, 0.0);
+        let b = Vec4::new(1.0 + f32::EPSILON, 1.000_001e6, -1.000_001e-6, -0.0);
+        assert_relative_eq!(a, b, max_relative = 2.0e-6);
+        assert_relative_ne!(a, b);
+        assert_abs_diff_eq!(Vec2::ONE, Vec2::splat(1.1), epsilon = 0.11);
+        assert_ulps_eq!(Vec3A::splat(1.0), Vec3A::splat(1.0 + f32::EPSILON));
+        assert_ulps_ne!(Vec3A::splat(1.0), Vec3A::splat(1.0 + 5.0 * f32::EPSILON));
+        assert_relative_eq!(
+            DVec3::splat(1.0e100),
+            DVec3::splat(1.0e100 * (1.0 + f64::EPSILON))
+        );
+    }
+
+    #[test]
+    fn test_approx_quat_mat() {
+        let q = Quat::from_rotation_y(0.5);
+        assert_ulps_eq!(q, q * Quat::IDENTITY);
+        assert_relative_eq!(q * q.conjugate(), Quat::IDENTITY, epsilon = 1.0e-6);
+        let m = Mat3::from_quat(q);
+        assert_relative_eq!(m * m.inverse(), Mat3::IDENTITY, epsilon = 1.0e-6);
+        let m = Mat3A::from_quat(q);
+        assert_relative_eq!(m * m.inverse(), Mat3A::IDENTITY, epsilon = 1.0e-6);
+        let m = Mat2::from_angle(0.5);
+        assert_relative_eq!(m * m.inverse(), Mat2::IDENTITY, epsilon = 1.0e-6);
+        let m = DMat4::from_rotation_z(0.5);
+        assert_ulps_eq!(m.transpose().transpose(), m);
+        assert_relative_eq!(m * m.inverse(), DMat4::IDENTITY, epsilon = 1.0e-12);
+    }
+
+    #[cfg(feature = "transform-types")]
+    #[test]
+    fn test_approx_transform() {
+        use crate::{TransformSRT, Vec3};
+        let t = TransformSRT::from_scale_rotation_translation(
+            Vec3::splat(2.0),
+            Quat::from_rotation_x(0.5),
+            Vec3::new(1.0, 2.0, 3.0),
+        );
+        assert_relative_eq!(t * TransformSRT::identity(), t, epsilon = 1.0e-6);
+    }
+}
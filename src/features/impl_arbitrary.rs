@@ -0,0 +1,245 @@
+use crate::constrained::{Finite, Invertible, Normalized};
+use crate::{
+    BVec2, BVec3, BVec4, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, IVec2, IVec3, IVec4,
+    Mat2, Mat3, Mat3A, Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
+};
+#[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+use crate::{BVec3A, BVec4A};
+#[cfg(feature = "transform-types")]
+use crate::{TransformRT, TransformSRT};
+use arbitrary::{size_hint, Arbitrary, Result, Unstructured};
+
+/// Maps infinities and NaNs to finite values with the same sign and mantissa bits so every
+/// input still produces a distinct value.
+#[inline]
+fn finite_f32(x: f32) -> f32 {
+    if x.is_finite() {
+        x
+    } else {
+        f32::from_bits(x.to_bits() & !0x4000_0000)
+    }
+}
+
+#[inline]
+fn finite_f64(x: f64) -> f64 {
+    if x.is_finite() {
+        x
+    } else {
+        f64::from_bits(x.to_bits() & !0x4000_0000_0000_0000)
+    }
+}
+
+macro_rules! impl_arbitrary {
+    ($t:ty, [$e:ty; $n:expr], $from:expr) => {
+        impl<'a> Arbitrary<'a> for $t {
+            #[inline]
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                let from: fn([$e; $n]) -> $t = $from;
+                Ok(from(u.arbitrary()?))
+            }
+
+            #[inline]
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                <[$e; $n] as Arbitrary>::size_hint(depth)
+            }
+        }
+    };
+}
+
+macro_rules! impl_arbitrary_finite {
+    ($t:ty, [$e:ty; $n:expr], $from:expr, $finite:ident) => {
+        impl_arbitrary!($t, [$e; $n], $from);
+
+        impl<'a> Arbitrary<'a> for Finite<$t> {
+            #[inline]
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                let from: fn([$e; $n]) -> $t = $from;
+                let a: [$e; $n] = u.arbitrary()?;
+                Ok(Finite(from(a.map($finite))))
+            }
+
+            #[inline]
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                <[$e; $n] as Arbitrary>::size_hint(depth)
+            }
+        }
+    };
+}
+
+// Values which do not satisfy the constraint are replaced with `$fallback` rather than returning
+// an error so that fuzzers do not waste inputs.
+macro_rules! impl_arbitrary_constrained {
+    ($wrapper:ident, $t:ty, $fallback:expr) => {
+        impl<'a> Arbitrary<'a> for $wrapper<$t> {
+            #[inline]
+            fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+                let Finite(value) = u.arbitrary()?;
+                Ok($wrapper::<$t>::new(value).unwrap_or($wrapper($fallback)))
+            }
+
+            #[inline]
+            fn size_hint(depth: usize) -> (usize, Option<usize>) {
+                <Finite<$t> as Arbitrary>::size_hint(depth)
+            }
+        }
+    };
+}
+
+macro_rules! impl_float_types {
+    ($t:ty, $finite:ident, $mat2:ident, $mat3:ident, $mat4:ident, $quat:ident, $vec2:ident, $vec3:ident, $vec4:ident) => {
+        impl_arbitrary_finite!($vec2, [$t; 2], $vec2::from_array, $finite);
+        impl_arbitrary_finite!($vec3, [$t; 3], $vec3::from_array, $finite);
+        impl_arbitrary_finite!($vec4, [$t; 4], $vec4::from_array, $finite);
+        impl_arbitrary_finite!($quat, [$t; 4], $quat::from_array, $finite);
+        impl_arbitrary_finite!($mat2, [$t; 4], |a| $mat2::from_cols_array(&a), $finite);
+        impl_arbitrary_finite!($mat3, [$t; 9], |a| $mat3::from_cols_array(&a), $finite);
+        impl_arbitrary_finite!($mat4, [$t; 16], |a| $mat4::from_cols_array(&a), $finite);
+
+        impl_arbitrary_constrained!(Normalized, $vec2, $vec2::X);
+        impl_arbitrary_constrained!(Normalized, $vec3, $vec3::X);
+        impl_arbitrary_constrained!(Normalized, $vec4, $vec4::X);
+        impl_arbitrary_constrained!(Normalized, $quat, $quat::IDENTITY);
+        impl_arbitrary_constrained!(Invertible, $mat2, $mat2::IDENTITY);
+        impl_arbitrary_constrained!(Invertible, $mat3, $mat3::IDENTITY);
+        impl_arbitrary_constrained!(Invertible, $mat4, $mat4::IDENTITY);
+    };
+}
+
+impl_float_types!(f32, finite_f32, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
+impl_float_types!(f64, finite_f64, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4);
+
+impl_arbitrary_finite!(Vec3A, [f32; 3], Vec3A::from_array, finite_f32);
+impl_arbitrary_constrained!(Normalized, Vec3A, Vec3A::X);
+impl_arbitrary_finite!(Mat3A, [f32; 9], |a| Mat3A::from_cols_array(&a), finite_f32);
+impl_arbitrary_constrained!(Invertible, Mat3A, Mat3A::IDENTITY);
+
+impl_arbitrary!(IVec2, [i32; 2], IVec2::from_array);
+impl_arbitrary!(IVec3, [i32; 3], IVec3::from_array);
+impl_arbitrary!(IVec4, [i32; 4], IVec4::from_array);
+impl_arbitrary!(UVec2, [u32; 2], UVec2::from_array);
+impl_arbitrary!(UVec3, [u32; 3], UVec3::from_array);
+impl_arbitrary!(UVec4, [u32; 4], UVec4::from_array);
+
+impl_arbitrary!(BVec2, [bool; 2], |[x, y]| BVec2::new(x, y));
+impl_arbitrary!(BVec3, [bool; 3], |[x, y, z]| BVec3::new(x, y, z));
+impl_arbitrary!(BVec4, [bool; 4], |[x, y, z, w]| BVec4::new(x, y, z, w));
+#[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+impl_arbitrary!(BVec3A, [bool; 3], |[x, y, z]| BVec3A::new(x, y, z));
+#[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+impl_arbitrary!(BVec4A, [bool; 4], |[x, y, z, w]| BVec4A::new(x, y, z, w));
+
+#[cfg(feature = "transform-types")]
+impl<'a> Arbitrary<'a> for TransformRT {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::from_rotation_translation(
+            u.arbitrary()?,
+            u.arbitrary()?,
+        ))
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and(Quat::size_hint(depth), Vec3::size_hint(depth))
+    }
+}
+
+#[cfg(feature = "transform-types")]
+impl<'a> Arbitrary<'a> for TransformSRT {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self::from_scale_rotation_translation(
+            u.arbitrary()?,
+            u.arbitrary()?,
+            u.arbitrary()?,
+        ))
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and_all(&[
+            Vec3::size_hint(depth),
+            Quat::size_hint(depth),
+            Vec3::size_hint(depth),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand_xoshiro::{
+        rand_core::{RngCore, SeedableRng},
+        Xoshiro256Plus,
+    };
+
+    macro_rules! assert_constrained {
+        ($u:expr, $($t:ty),+) => {
+            $(
+                let Finite(v) = $u.arbitrary::<Finite<$t>>().unwrap();
+                assert!(v.is_finite(), "{:?}", v);
+            )+
+        };
+    }
+
+    fn check_constraints(u: &mut Unstructured) {
+        assert_constrained!(u, Vec2, Vec3, Vec3A, Vec4, Quat, Mat2, Mat3, Mat3A, Mat4);
+        assert_constrained!(u, DVec2, DVec3, DVec4, DQuat, DMat2, DMat3, DMat4);
+
+        let Normalized(v) = u.arbitrary::<Normalized<Vec3>>().unwrap();
+        assert!(v.is_normalized(), "{:?}", v);
+        let Normalized(v) = u.arbitrary::<Normalized<Vec3A>>().unwrap();
+        assert!(v.is_normalized(), "{:?}", v);
+        let Normalized(v) = u.arbitrary::<Normalized<DVec2>>().unwrap();
+        assert!(v.is_normalized(), "{:?}", v);
+        let Normalized(q) = u.arbitrary::<Normalized<Quat>>().unwrap();
+        assert!(q.is_normalized(), "{:?}", q);
+        let Normalized(q) = u.arbitrary::<Normalized<DQuat>>().unwrap();
+        assert!(q.is_normalized(), "{:?}", q);
+
+        let Invertible(m) = u.arbitrary::<Invertible<Mat3>>().unwrap();
+        assert!(m.determinant() != 0.0 && m.inverse().is_finite(), "{:?}", m);
+        let Invertible(m) = u.arbitrary::<Invertible<Mat3A>>().unwrap();
+        assert!(m.determinant() != 0.0 && m.inverse().is_finite(), "{:?}", m);
+        let Invertible(m) = u.arbitrary::<Invertible<DMat4>>().unwrap();
+        assert!(m.determinant() != 0.0 && m.inverse().is_finite(), "{:?}", m);
+    }
+
+    #[test]
+    fn test_arbitrary() {
+        // all bits set is a NaN for every float element
+        let bytes = [0xff; 1024];
+        let mut u = Unstructured::new(&bytes);
+        assert!(!u.arbitrary::<Vec4>().unwrap().is_finite());
+        assert!(!u.arbitrary::<DMat2>().unwrap().is_finite());
+        assert_eq!(u.arbitrary::<IVec3>().unwrap(), IVec3::splat(-1));
+        assert_eq!(u.arbitrary::<UVec2>().unwrap(), UVec2::splat(u32::MAX));
+        assert_eq!(
+            u.arbitrary::<BVec4>().unwrap(),
+            BVec4::new(true, true, true, true)
+        );
+        check_constraints(&mut u);
+
+        let bytes = [0; 1024];
+        let mut u = Unstructured::new(&bytes);
+        assert_eq!(u.arbitrary::<Mat4>().unwrap(), Mat4::ZERO);
+        assert_eq!(u.arbitrary::<Invertible<Mat4>>().unwrap().0, Mat4::IDENTITY);
+        assert_eq!(u.arbitrary::<Normalized<Vec2>>().unwrap().0, Vec2::X);
+        check_constraints(&mut u);
+
+        let mut rng = Xoshiro256Plus::seed_from_u64(0);
+        let mut bytes = [0; 1024];
+        for _ in 0..100 {
+            rng.fill_bytes(&mut bytes);
+            check_constraints(&mut Unstructured::new(&bytes));
+        }
+    }
+
+    #[test]
+    fn test_size_hint() {
+        assert_eq!(Vec3::size_hint(0), (12, Some(12)));
+        assert_eq!(Finite::<DMat4>::size_hint(0), (128, Some(128)));
+        assert_eq!(Normalized::<Quat>::size_hint(0), (16, Some(16)));
+        assert_eq!(BVec3::size_hint(0), (3, Some(3)));
+    }
+}
@@ -1,7 +1,10 @@
 use crate::{
-    DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, IVec2, IVec3, IVec4, Mat2, Mat3, Mat4, Quat,
-    UVec2, UVec3, UVec4, Vec2, Vec3, Vec4,
+    BVec2, BVec3, BVec4, DMat2, DMat2x3, DMat3, DMat3x2, DMat3x4, DMat4, DMat4x3, DQuat, DVec2,
+    DVec3, DVec4, IVec2, IVec3, IVec4, Mat2, Mat2x3, Mat3, Mat3A, Mat3x2, Mat3x4, Mat4, Mat4x3,
+    Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
 };
+#[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+use crate::{BVec3A, BVec4A};
 #[cfg(feature = "transform-types")]
 use crate::{TransformRT, TransformSRT};
 use bytemuck::{Pod, Zeroable};
@@ -12,6 +15,14 @@ unsafe impl Pod for Mat3 {}
 unsafe impl Zeroable for Mat3 {}
 unsafe impl Pod for Mat4 {}
 unsafe impl Zeroable for Mat4 {}
+unsafe impl Pod for Mat2x3 {}
+unsafe impl Zeroable for Mat2x3 {}
+unsafe impl Pod for Mat3x2 {}
+unsafe impl Zeroable for Mat3x2 {}
+unsafe impl Pod for Mat3x4 {}
+unsafe impl Zeroable for Mat3x4 {}
+unsafe impl Pod for Mat4x3 {}
+unsafe impl Zeroable for Mat4x3 {}
 
 unsafe impl Pod for Quat {}
 unsafe impl Zeroable for Quat {}
@@ -23,12 +34,31 @@ unsafe impl Zeroable for Vec3 {}
 unsafe impl Pod for Vec4 {}
 unsafe impl Zeroable for Vec4 {}
 
+// `Vec3A` is only padding free when it is stored as a SIMD vector, use `Vec3A::to_padded_array()`
+// to get a `Pod` value otherwise.
+#[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+unsafe impl Pod for Vec3A {}
+unsafe impl Zeroable for Vec3A {}
+
+// Likewise `Mat3A` columns are only padding free when stored as SIMD vectors.
+#[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+unsafe impl Pod for Mat3A {}
+unsafe impl Zeroable for Mat3A {}
+
 unsafe impl Pod for DMat2 {}
 unsafe impl Zeroable for DMat2 {}
 unsafe impl Pod for DMat3 {}
 unsafe impl Zeroable for DMat3 {}
 unsafe impl Pod for DMat4 {}
 unsafe impl Zeroable for DMat4 {}
+unsafe impl Pod for DMat2x3 {}
+unsafe impl Zeroable for DMat2x3 {}
+unsafe impl Pod for DMat3x2 {}
+unsafe impl Zeroable for DMat3x2 {}
+unsafe impl Pod for DMat3x4 {}
+unsafe impl Zeroable for DMat3x4 {}
+unsafe impl Pod for DMat4x3 {}
+unsafe impl Zeroable for DMat4x3 {}
 
 unsafe impl Pod for DQuat {}
 unsafe impl Zeroable for DQuat {}
@@ -54,11 +84,28 @@ unsafe impl Zeroable for UVec3 {}
 unsafe impl Pod for UVec4 {}
 unsafe impl Zeroable for UVec4 {}
 
-#[cfg(feature = "transform-types")]
+// Masks are not `Pod` as not every bit pattern is a valid `bool`.
+unsafe impl Zeroable for BVec2 {}
+unsafe impl Zeroable for BVec3 {}
+unsafe impl Zeroable for BVec4 {}
+
+#[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+unsafe impl Zeroable for BVec3A {}
+#[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+unsafe impl Zeroable for BVec4A {}
+
+// A 16 byte aligned SIMD `Quat` adds padding after the `Vec3` fields of the transform types.
+#[cfg(all(
+    feature = "transform-types",
+    any(not(target_feature = "sse2"), feature = "scalar-math")
+))]
 unsafe impl Pod for TransformRT {}
 #[cfg(feature = "transform-types")]
 unsafe impl Zeroable for TransformRT {}
-#[cfg(feature = "transform-types")]
+#[cfg(all(
+    feature = "transform-types",
+    any(not(target_feature = "sse2"), feature = "scalar-math")
+))]
 unsafe impl Pod for TransformSRT {}
 #[cfg(feature = "transform-types")]
 unsafe impl Zeroable for TransformSRT {}
@@ -66,8 +113,9 @@ unsafe impl Zeroable for TransformSRT {}
 #[cfg(test)]
 mod test {
     use crate::{
-        DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, IVec2, IVec3, IVec4, Mat2, Mat3, Mat4,
-        Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec4,
+        BVec3, BVec4A, DMat2, DMat2x3, DMat3, DMat3x2, DMat3x4, DMat4, DMat4x3, DQuat, DVec2,
+        DVec3, DVec4, IVec2, IVec3, IVec4, Mat2, Mat2x3, Mat3, Mat3A, Mat3x2, Mat3x4, Mat4, Mat4x3,
+        Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
     };
     use bytemuck;
     use core::mem;
@@ -87,6 +135,10 @@ mod test {
     test_t!(mat2, Mat2);
     test_t!(mat3, Mat3);
     test_t!(mat4, Mat4);
+    test_t!(mat2x3, Mat2x3);
+    test_t!(mat3x2, Mat3x2);
+    test_t!(mat3x4, Mat3x4);
+    test_t!(mat4x3, Mat4x3);
     test_t!(quat, Quat);
     test_t!(vec2, Vec2);
     test_t!(vec3, Vec3);
@@ -95,6 +147,10 @@ mod test {
     test_t!(dmat2, DMat2);
     test_t!(dmat3, DMat3);
     test_t!(dmat4, DMat4);
+    test_t!(dmat2x3, DMat2x3);
+    test_t!(dmat3x2, DMat3x2);
+    test_t!(dmat3x4, DMat3x4);
+    test_t!(dmat4x3, DMat4x3);
     test_t!(dquat, DQuat);
     test_t!(dvec2, DVec2);
     test_t!(dvec3, DVec3);
@@ -107,4 +163,33 @@ mod test {
     test_t!(uvec2, UVec2);
     test_t!(uvec3, UVec3);
     test_t!(uvec4, UVec4);
+
+    #[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+    test_t!(vec3a, Vec3A);
+
+    #[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+    #[test]
+    fn mat3a() {
+        let m = Mat3A::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+        let b: &[f32] = bytemuck::cast_slice(bytemuck::bytes_of(&m));
+        assert_eq!(&b[..3], &[1.0, 2.0, 3.0]);
+        assert_eq!(&b[8..11], &[7.0, 8.0, 9.0]);
+        assert_eq!(b.len(), 12);
+    }
+
+    #[test]
+    fn zeroable() {
+        assert_eq!(Vec3A::zero(), bytemuck::Zeroable::zeroed());
+        assert_eq!(Mat3A::zero(), bytemuck::Zeroable::zeroed());
+        assert_eq!(BVec3::default(), bytemuck::Zeroable::zeroed());
+        assert_eq!(BVec4A::default(), bytemuck::Zeroable::zeroed());
+    }
+
+    #[test]
+    fn padded_array() {
+        let a = [Vec3A::new(1.0, 2.0, 3.0), Vec3A::new(4.0, 5.0, 6.0)].map(Vec3A::to_padded_array);
+        let b: &[f32] = bytemuck::cast_slice(&a);
+        assert_eq!(b, [1.0, 2.0, 3.0, 0.0, 4.0, 5.0, 6.0, 0.0]);
+        assert_eq!(bytemuck::bytes_of(&a[0]).len(), mem::size_of::<Vec3A>());
+    }
 }
@@ -1,9 +1,62 @@
+use crate::core::traits::scalar::FloatEx;
 use crate::{
-    DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, IVec2, IVec3, IVec4, Mat2, Mat3, Mat4, Quat,
-    UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
+    DMat2, DMat2x3, DMat3, DMat3x2, DMat3x4, DMat4, DMat4x3, DQuat, DVec2, DVec3, DVec4, IVec2,
+    IVec3, IVec4, Mat2, Mat2x3, Mat3, Mat3A, Mat3x2, Mat3x4, Mat4, Mat4x3, Quat, UVec2, UVec3,
+    UVec4, Vec2, Vec3, Vec3A, Vec4,
 };
 use mint;
 
+/// Computes the intrinsic XYZ Euler angles of the rotation matrix given by its columns.
+///
+/// When the Y rotation is at +/-90 degrees the X and Z axes line up and only their sum is
+/// defined, in which case the Z angle is set to zero.
+fn intra_xyz_from_cols<T: FloatEx>(x_axis: [T; 3], y_axis: [T; 3], z_axis: [T; 3]) -> [T; 3] {
+    let cos_b = (x_axis[0] * x_axis[0] + y_axis[0] * y_axis[0]).sqrt();
+    let b = z_axis[0].atan2(cos_b);
+    if cos_b > T::from_f32(1e-6) {
+        let a = (-z_axis[1]).atan2(z_axis[2]);
+        let c = (-y_axis[0]).atan2(x_axis[0]);
+        [a, b, c]
+    } else {
+        let a = y_axis[2].atan2(y_axis[1]);
+        [a, b, T::ZERO]
+    }
+}
+
+/// Computes the intrinsic ZYX Euler angles of the rotation matrix given by its columns.
+///
+/// When the Y rotation is at +/-90 degrees the Z and X axes line up and only their sum is
+/// defined, in which case the X angle is set to zero.
+fn intra_zyx_from_cols<T: FloatEx>(x_axis: [T; 3], y_axis: [T; 3], z_axis: [T; 3]) -> [T; 3] {
+    let cos_b = (x_axis[0] * x_axis[0] + x_axis[1] * x_axis[1]).sqrt();
+    let b = (-x_axis[2]).atan2(cos_b);
+    if cos_b > T::from_f32(1e-6) {
+        let a = x_axis[1].atan2(x_axis[0]);
+        let c = y_axis[2].atan2(z_axis[2]);
+        [a, b, c]
+    } else {
+        let a = (-y_axis[0]).atan2(y_axis[1]);
+        [a, b, T::ZERO]
+    }
+}
+
+/// Computes the intrinsic ZXZ Euler angles of the rotation matrix given by its columns.
+///
+/// When the X rotation is at 0 or 180 degrees both Z rotations are about the same axis and
+/// only their sum is defined, in which case the second Z angle is set to zero.
+fn intra_zxz_from_cols<T: FloatEx>(x_axis: [T; 3], y_axis: [T; 3], z_axis: [T; 3]) -> [T; 3] {
+    let sin_b = (x_axis[2] * x_axis[2] + y_axis[2] * y_axis[2]).sqrt();
+    let b = sin_b.atan2(z_axis[2]);
+    if sin_b > T::from_f32(1e-6) {
+        let a = z_axis[0].atan2(-z_axis[1]);
+        let c = x_axis[2].atan2(y_axis[2]);
+        [a, b, c]
+    } else {
+        let a = x_axis[1].atan2(x_axis[0]);
+        [a, b, T::ZERO]
+    }
+}
+
 macro_rules! impl_vec_types {
     ($t:ty, $vec2:ty, $vec3:ty, $vec4:ty) => {
         impl From<mint::Point2<$t>> for $vec2 {
@@ -81,6 +134,164 @@ macro_rules! impl_vec_types {
     };
 }
 
+macro_rules! impl_euler_angles {
+    (
+        $t:ty,
+        $quat:ty,
+        $mat3:ty,
+        $intra:ident,
+        $extra:ident,
+        $a:ident,
+        $b:ident,
+        $c:ident,
+        $intra_from_cols:ident,
+        $reversed_from_cols:ident
+    ) => {
+        impl From<mint::EulerAngles<$t, mint::$intra>> for $quat {
+            fn from(e: mint::EulerAngles<$t, mint::$intra>) -> Self {
+                Self::$a(e.a) * Self::$b(e.b) * Self::$c(e.c)
+            }
+        }
+
+        impl From<mint::EulerAngles<$t, mint::$extra>> for $quat {
+            fn from(e: mint::EulerAngles<$t, mint::$extra>) -> Self {
+                Self::$c(e.c) * Self::$b(e.b) * Self::$a(e.a)
+            }
+        }
+
+        impl From<$quat> for mint::EulerAngles<$t, mint::$intra> {
+            fn from(q: $quat) -> Self {
+                let m = <$mat3>::from_quat(q);
+                Self::from($intra_from_cols(
+                    m.x_axis.into(),
+                    m.y_axis.into(),
+                    m.z_axis.into(),
+                ))
+            }
+        }
+
+        impl From<$quat> for mint::EulerAngles<$t, mint::$extra> {
+            fn from(q: $quat) -> Self {
+                // extrinsic rotations are the intrinsic ones in reverse order
+                let m = <$mat3>::from_quat(q);
+                let [c, b, a] =
+                    $reversed_from_cols(m.x_axis.into(), m.y_axis.into(), m.z_axis.into());
+                Self::from([a, b, c])
+            }
+        }
+    };
+}
+
+macro_rules! impl_mat3_types {
+    ($t:ty, $mat3:ty) => {
+        impl From<mint::RowMatrix3<$t>> for $mat3 {
+            fn from(m: mint::RowMatrix3<$t>) -> Self {
+                Self::from_cols(m.x.into(), m.y.into(), m.z.into()).transpose()
+            }
+        }
+
+        impl From<$mat3> for mint::RowMatrix3<$t> {
+            fn from(m: $mat3) -> Self {
+                let mt = m.transpose();
+                Self {
+                    x: mt.x_axis.into(),
+                    y: mt.y_axis.into(),
+                    z: mt.z_axis.into(),
+                }
+            }
+        }
+
+        impl From<mint::ColumnMatrix3<$t>> for $mat3 {
+            fn from(m: mint::ColumnMatrix3<$t>) -> Self {
+                Self::from_cols(m.x.into(), m.y.into(), m.z.into())
+            }
+        }
+
+        impl From<$mat3> for mint::ColumnMatrix3<$t> {
+            fn from(m: $mat3) -> Self {
+                Self {
+                    x: m.x_axis.into(),
+                    y: m.y_axis.into(),
+                    z: m.z_axis.into(),
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_non_square_types {
+    (
+        $t:ty,
+        $mat:ty,
+        $mat_t:ty,
+        $column:ident[$($cf:ident: $ca:ident),+],
+        $row:ident[$($rf:ident: $ra:ident),+]
+    ) => {
+        impl From<mint::$row<$t>> for $mat {
+            fn from(m: mint::$row<$t>) -> Self {
+                <$mat_t>::from_cols($(m.$rf.into()),+).transpose()
+            }
+        }
+
+        impl From<$mat> for mint::$row<$t> {
+            fn from(m: $mat) -> Self {
+                let mt = m.transpose();
+                Self {
+                    $($rf: mt.$ra.into(),)+
+                }
+            }
+        }
+
+        impl From<mint::$column<$t>> for $mat {
+            fn from(m: mint::$column<$t>) -> Self {
+                Self::from_cols($(m.$cf.into()),+)
+            }
+        }
+
+        impl From<$mat> for mint::$column<$t> {
+            fn from(m: $mat) -> Self {
+                Self {
+                    $($cf: m.$ca.into(),)+
+                }
+            }
+        }
+    };
+}
+
+// mint names matrices rows x columns while glam uses columns x rows
+macro_rules! impl_non_square_float_types {
+    ($t:ty, $mat2x3:ty, $mat3x2:ty, $mat3x4:ty, $mat4x3:ty) => {
+        impl_non_square_types!(
+            $t,
+            $mat2x3,
+            $mat3x2,
+            ColumnMatrix3x2[x: x_axis, y: y_axis],
+            RowMatrix3x2[x: x_axis, y: y_axis, z: z_axis]
+        );
+        impl_non_square_types!(
+            $t,
+            $mat3x2,
+            $mat2x3,
+            ColumnMatrix2x3[x: x_axis, y: y_axis, z: z_axis],
+            RowMatrix2x3[x: x_axis, y: y_axis]
+        );
+        impl_non_square_types!(
+            $t,
+            $mat3x4,
+            $mat4x3,
+            ColumnMatrix4x3[x: x_axis, y: y_axis, z: z_axis],
+            RowMatrix4x3[x: x_axis, y: y_axis, z: z_axis, w: w_axis]
+        );
+        impl_non_square_types!(
+            $t,
+            $mat4x3,
+            $mat3x4,
+            ColumnMatrix3x4[x: x_axis, y: y_axis, z: z_axis, w: w_axis],
+            RowMatrix3x4[x: x_axis, y: y_axis, z: z_axis]
+        );
+    };
+}
+
 macro_rules! impl_float_types {
     ($t:ty, $mat2:ty, $mat3:ty, $mat4:ty, $quat:ty, $vec2:ty, $vec3:ty, $vec4:ty) => {
         impl_vec_types!($t, $vec2, $vec3, $vec4);
@@ -104,6 +315,43 @@ macro_rules! impl_float_types {
             }
         }
 
+        impl_euler_angles!(
+            $t,
+            $quat,
+            $mat3,
+            IntraXYZ,
+            ExtraXYZ,
+            from_rotation_x,
+            from_rotation_y,
+            from_rotation_z,
+            intra_xyz_from_cols,
+            intra_zyx_from_cols
+        );
+        impl_euler_angles!(
+            $t,
+            $quat,
+            $mat3,
+            IntraZXZ,
+            ExtraZXZ,
+            from_rotation_z,
+            from_rotation_x,
+            from_rotation_z,
+            intra_zxz_from_cols,
+            intra_zxz_from_cols
+        );
+        impl_euler_angles!(
+            $t,
+            $quat,
+            $mat3,
+            IntraZYX,
+            ExtraZYX,
+            from_rotation_z,
+            from_rotation_y,
+            from_rotation_x,
+            intra_zyx_from_cols,
+            intra_xyz_from_cols
+        );
+
         impl From<mint::RowMatrix2<$t>> for $mat2 {
             fn from(m: mint::RowMatrix2<$t>) -> Self {
                 Self::from_cols(m.x.into(), m.y.into()).transpose()
@@ -135,38 +383,7 @@ macro_rules! impl_float_types {
             }
         }
 
-        impl From<mint::RowMatrix3<$t>> for $mat3 {
-            fn from(m: mint::RowMatrix3<$t>) -> Self {
-                Self::from_cols(m.x.into(), m.y.into(), m.z.into()).transpose()
-            }
-        }
-
-        impl From<$mat3> for mint::RowMatrix3<$t> {
-            fn from(m: $mat3) -> Self {
-                let mt = m.transpose();
-                Self {
-                    x: mt.x_axis.into(),
-                    y: mt.y_axis.into(),
-                    z: mt.z_axis.into(),
-                }
-            }
-        }
-
-        impl From<mint::ColumnMatrix3<$t>> for $mat3 {
-            fn from(m: mint::ColumnMatrix3<$t>) -> Self {
-                Self::from_cols(m.x.into(), m.y.into(), m.z.into())
-            }
-        }
-
-        impl From<$mat3> for mint::ColumnMatrix3<$t> {
-            fn from(m: $mat3) -> Self {
-                Self {
-                    x: m.x_axis.into(),
-                    y: m.y_axis.into(),
-                    z: m.z_axis.into(),
-                }
-            }
-        }
+        impl_mat3_types!($t, $mat3);
 
         impl From<mint::RowMatrix4<$t>> for $mat4 {
             fn from(m: mint::RowMatrix4<$t>) -> Self {
@@ -239,6 +456,9 @@ impl From<Vec3A> for mint::Vector3<f32> {
 
 impl_float_types!(f32, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
 impl_float_types!(f64, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4);
+impl_non_square_float_types!(f32, Mat2x3, Mat3x2, Mat3x4, Mat4x3);
+impl_non_square_float_types!(f64, DMat2x3, DMat3x2, DMat3x4, DMat4x3);
+impl_mat3_types!(f32, Mat3A);
 impl_vec_types!(i32, IVec2, IVec3, IVec4);
 impl_vec_types!(u32, UVec2, UVec3, UVec4);
 
@@ -311,10 +531,23 @@ mod test {
     }
 
     macro_rules! impl_float_tests {
-        ($t:ty, $mat2:ident, $mat3:ident, $mat4:ident, $quat:ident, $vec2:ident, $vec3:ident, $vec4:ident) => {
+        (
+            $t:ty,
+            $mat2:ident,
+            $mat3:ident,
+            $mat4:ident,
+            $mat2x3:ident,
+            $mat3x2:ident,
+            $mat3x4:ident,
+            $mat4x3:ident,
+            $quat:ident,
+            $vec2:ident,
+            $vec3:ident,
+            $vec4:ident
+        ) => {
             impl_vec_tests!($t, $vec2, $vec3, $vec4);
 
-            use crate::{$mat2, $mat3, $mat4, $quat};
+            use crate::{$mat2, $mat2x3, $mat3, $mat3x2, $mat3x4, $mat4, $mat4x3, $quat};
 
             #[test]
             fn test_quaternion() {
@@ -331,6 +564,93 @@ mod test {
                 assert_eq!(m, g.into());
             }
 
+            #[test]
+            fn test_euler_angles() {
+                use core::marker::PhantomData;
+                let (a, b, c) = (0.1, 0.2, 0.3);
+                let m = mint::EulerAngles::<$t, mint::IntraXYZ> {
+                    a,
+                    b,
+                    c,
+                    marker: PhantomData,
+                };
+                let g = $quat::from(m);
+                let expected = $mat3::from_rotation_x(a)
+                    * $mat3::from_rotation_y(b)
+                    * $mat3::from_rotation_z(c);
+                assert!($mat3::from_quat(g).abs_diff_eq(expected, 1e-6));
+                // extrinsic ZYX is the same rotation as intrinsic XYZ with the angles reversed
+                let m = mint::EulerAngles::<$t, mint::ExtraZYX>::from(m);
+                assert_eq!([c, b, a], <[$t; 3]>::from(m));
+                assert!(g.abs_diff_eq($quat::from(m), 1e-6));
+
+                let m = mint::EulerAngles::<$t, mint::ExtraXYZ> {
+                    a,
+                    b,
+                    c,
+                    marker: PhantomData,
+                };
+                let expected = $mat3::from_rotation_z(c)
+                    * $mat3::from_rotation_y(b)
+                    * $mat3::from_rotation_x(a);
+                assert!($mat3::from_quat($quat::from(m)).abs_diff_eq(expected, 1e-6));
+
+                let m = mint::EulerAngles::<$t, mint::IntraZXZ>::from([a, b, c]);
+                let expected = $mat3::from_rotation_z(a)
+                    * $mat3::from_rotation_x(b)
+                    * $mat3::from_rotation_z(c);
+                assert!($mat3::from_quat($quat::from(m)).abs_diff_eq(expected, 1e-6));
+            }
+
+            #[test]
+            fn test_quat_to_euler_angles() {
+                use mint::EulerAngles;
+                fn check<B>(g: $quat, a: $t, b: $t, c: $t)
+                where
+                    $quat: From<EulerAngles<$t, B>>,
+                    EulerAngles<$t, B>: From<$quat> + Into<[$t; 3]>,
+                {
+                    let q = $quat::from(EulerAngles::<$t, B>::from(g));
+                    assert!(g.abs_diff_eq(q, 1e-5) || g.abs_diff_eq(-q, 1e-5));
+                    let e: [$t; 3] = EulerAngles::<$t, B>::from(g).into();
+                    assert!((e[0] - a).abs() < 1e-5);
+                    assert!((e[1] - b).abs() < 1e-5);
+                    assert!((e[2] - c).abs() < 1e-5);
+                }
+
+                let (a, b, c) = (0.1, -0.2, 0.3);
+                let g = $quat::from(EulerAngles::<$t, mint::IntraXYZ>::from([a, b, c]));
+                check::<mint::IntraXYZ>(g, a, b, c);
+                check::<mint::ExtraZYX>(g, c, b, a);
+                let g = $quat::from(EulerAngles::<$t, mint::IntraZYX>::from([a, b, c]));
+                check::<mint::IntraZYX>(g, a, b, c);
+                check::<mint::ExtraXYZ>(g, c, b, a);
+                let g = $quat::from(EulerAngles::<$t, mint::IntraZXZ>::from([a, -b, c]));
+                check::<mint::IntraZXZ>(g, a, -b, c);
+                check::<mint::ExtraZXZ>(g, c, -b, a);
+
+                // gimbal lock, only the sum of the outer angles is defined
+                let b = <$t>::asin(1.0);
+                let g = $quat::from(EulerAngles::<$t, mint::IntraXYZ>::from([a, b, c]));
+                let e = EulerAngles::<$t, mint::IntraXYZ>::from(g);
+                assert_eq!(0.0, e.c);
+                assert!(
+                    g.abs_diff_eq($quat::from(e), 1e-5) || g.abs_diff_eq(-$quat::from(e), 1e-5)
+                );
+                let g = $quat::from(EulerAngles::<$t, mint::IntraZYX>::from([a, b, c]));
+                let e = EulerAngles::<$t, mint::IntraZYX>::from(g);
+                assert_eq!(0.0, e.c);
+                assert!(
+                    g.abs_diff_eq($quat::from(e), 1e-5) || g.abs_diff_eq(-$quat::from(e), 1e-5)
+                );
+                let g = $quat::from(EulerAngles::<$t, mint::IntraZXZ>::from([a, 0.0, c]));
+                let e = EulerAngles::<$t, mint::IntraZXZ>::from(g);
+                assert_eq!(0.0, e.c);
+                assert!(
+                    g.abs_diff_eq($quat::from(e), 1e-5) || g.abs_diff_eq(-$quat::from(e), 1e-5)
+                );
+            }
+
             #[test]
             fn test_matrix2() {
                 let g = $mat2::from_cols_array_2d(&[[1.0, 2.0], [3.0, 4.0]]);
@@ -377,11 +697,93 @@ mod test {
                 );
                 assert_eq!(g, $mat4::from(mt));
             }
+
+            #[test]
+            fn test_matrix2x3() {
+                let g = $mat2x3::from_cols_array_2d(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+                let m = mint::ColumnMatrix3x2::from(g);
+                assert_eq!(
+                    m,
+                    mint::ColumnMatrix3x2::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]])
+                );
+                assert_eq!(g, $mat2x3::from(m));
+                let mt = mint::RowMatrix3x2::from(g);
+                assert_eq!(
+                    mt,
+                    mint::RowMatrix3x2::from([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]])
+                );
+                assert_eq!(g, $mat2x3::from(mt));
+            }
+
+            #[test]
+            fn test_matrix3x2() {
+                let g = $mat3x2::from_cols_array_2d(&[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+                let m = mint::ColumnMatrix2x3::from(g);
+                assert_eq!(
+                    m,
+                    mint::ColumnMatrix2x3::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]])
+                );
+                assert_eq!(g, $mat3x2::from(m));
+                let mt = mint::RowMatrix2x3::from(g);
+                assert_eq!(
+                    mt,
+                    mint::RowMatrix2x3::from([[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]])
+                );
+                assert_eq!(g, $mat3x2::from(mt));
+            }
+
+            #[test]
+            fn test_matrix3x4() {
+                let g = $mat3x4::from_cols_array_2d(&[
+                    [1.0, 2.0, 3.0, 4.0],
+                    [5.0, 6.0, 7.0, 8.0],
+                    [9.0, 10.0, 11.0, 12.0],
+                ]);
+                let m = mint::ColumnMatrix4x3::from(g);
+                assert_eq!(m.z, mint::Vector4::from([9.0, 10.0, 11.0, 12.0]));
+                assert_eq!(g, $mat3x4::from(m));
+                let mt = mint::RowMatrix4x3::from(g);
+                assert_eq!(
+                    mt,
+                    mint::RowMatrix4x3::from([
+                        [1.0, 5.0, 9.0],
+                        [2.0, 6.0, 10.0],
+                        [3.0, 7.0, 11.0],
+                        [4.0, 8.0, 12.0]
+                    ])
+                );
+                assert_eq!(g, $mat3x4::from(mt));
+            }
+
+            #[test]
+            fn test_matrix4x3() {
+                let g = $mat4x3::from_cols_array_2d(&[
+                    [1.0, 2.0, 3.0],
+                    [4.0, 5.0, 6.0],
+                    [7.0, 8.0, 9.0],
+                    [10.0, 11.0, 12.0],
+                ]);
+                let m = mint::ColumnMatrix3x4::from(g);
+                assert_eq!(m.w, mint::Vector3::from([10.0, 11.0, 12.0]));
+                assert_eq!(g, $mat4x3::from(m));
+                let mt = mint::RowMatrix3x4::from(g);
+                assert_eq!(
+                    mt,
+                    mint::RowMatrix3x4::from([
+                        [1.0, 4.0, 7.0, 10.0],
+                        [2.0, 5.0, 8.0, 11.0],
+                        [3.0, 6.0, 9.0, 12.0]
+                    ])
+                );
+                assert_eq!(g, $mat4x3::from(mt));
+            }
         };
     }
 
     mod f32 {
-        impl_float_tests!(f32, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
+        impl_float_tests!(
+            f32, Mat2, Mat3, Mat4, Mat2x3, Mat3x2, Mat3x4, Mat4x3, Quat, Vec2, Vec3, Vec4
+        );
 
         #[test]
         fn test_point3a() {
@@ -396,6 +798,18 @@ mod test {
             assert_eq!(m, g.into());
         }
 
+        #[test]
+        fn test_matrix3a() {
+            use crate::{Mat3A, Vec3A};
+            let g = Mat3A::from_cols_array_2d(&[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+            let m = mint::ColumnMatrix3::from(g);
+            assert_eq!(m.y, Vec3A::new(4.0, 5.0, 6.0).into());
+            assert_eq!(g, Mat3A::from(m));
+            let m = mint::RowMatrix3::from(g);
+            assert_eq!(m.y, Vec3A::new(2.0, 5.0, 8.0).into());
+            assert_eq!(g, Mat3A::from(m));
+        }
+
         #[test]
         fn test_vector3a() {
             use crate::Vec3A;
@@ -411,7 +825,10 @@ mod test {
     }
 
     mod f64 {
-        impl_float_tests!(f64, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4);
+        impl_float_tests!(
+            f64, DMat2, DMat3, DMat4, DMat2x3, DMat3x2, DMat3x4, DMat4x3, DQuat, DVec2, DVec3,
+            DVec4
+        );
     }
 
     mod i32 {
@@ -0,0 +1,460 @@
+use nalgebra as na;
+
+macro_rules! impl_vec_types {
+    ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident) => {
+        impl From<na::Vector2<$t>> for $vec2 {
+            #[inline]
+            fn from(v: na::Vector2<$t>) -> Self {
+                Self::new(v.x, v.y)
+            }
+        }
+
+        impl From<$vec2> for na::Vector2<$t> {
+            #[inline]
+            fn from(v: $vec2) -> Self {
+                Self::new(v.x, v.y)
+            }
+        }
+
+        impl From<na::Point2<$t>> for $vec2 {
+            #[inline]
+            fn from(p: na::Point2<$t>) -> Self {
+                Self::new(p.x, p.y)
+            }
+        }
+
+        impl From<$vec2> for na::Point2<$t> {
+            #[inline]
+            fn from(v: $vec2) -> Self {
+                Self::new(v.x, v.y)
+            }
+        }
+
+        impl From<na::Vector3<$t>> for $vec3 {
+            #[inline]
+            fn from(v: na::Vector3<$t>) -> Self {
+                Self::new(v.x, v.y, v.z)
+            }
+        }
+
+        impl From<$vec3> for na::Vector3<$t> {
+            #[inline]
+            fn from(v: $vec3) -> Self {
+                Self::new(v.x, v.y, v.z)
+            }
+        }
+
+        impl From<na::Point3<$t>> for $vec3 {
+            #[inline]
+            fn from(p: na::Point3<$t>) -> Self {
+                Self::new(p.x, p.y, p.z)
+            }
+        }
+
+        impl From<$vec3> for na::Point3<$t> {
+            #[inline]
+            fn from(v: $vec3) -> Self {
+                Self::new(v.x, v.y, v.z)
+            }
+        }
+
+        impl From<na::Vector4<$t>> for $vec4 {
+            #[inline]
+            fn from(v: na::Vector4<$t>) -> Self {
+                Self::new(v.x, v.y, v.z, v.w)
+            }
+        }
+
+        impl From<$vec4> for na::Vector4<$t> {
+            #[inline]
+            fn from(v: $vec4) -> Self {
+                Self::new(v.x, v.y, v.z, v.w)
+            }
+        }
+
+        impl From<na::Point4<$t>> for $vec4 {
+            #[inline]
+            fn from(p: na::Point4<$t>) -> Self {
+                Self::new(p.x, p.y, p.z, p.w)
+            }
+        }
+
+        impl From<$vec4> for na::Point4<$t> {
+            #[inline]
+            fn from(v: $vec4) -> Self {
+                Self::new(v.x, v.y, v.z, v.w)
+            }
+        }
+    };
+}
+
+macro_rules! impl_float_types {
+    ($t:ty, $mat2:ident, $mat3:ident, $mat4:ident, $quat:ident, $vec2:ident, $vec3:ident, $vec4:ident) => {
+        impl_vec_types!($t, $vec2, $vec3, $vec4);
+
+        impl From<na::Quaternion<$t>> for $quat {
+            #[inline]
+            fn from(q: na::Quaternion<$t>) -> Self {
+                Self::from_xyzw(q.i, q.j, q.k, q.w)
+            }
+        }
+
+        impl From<$quat> for na::Quaternion<$t> {
+            #[inline]
+            fn from(q: $quat) -> Self {
+                Self::new(q.w, q.x, q.y, q.z)
+            }
+        }
+
+        impl From<na::UnitQuaternion<$t>> for $quat {
+            #[inline]
+            fn from(q: na::UnitQuaternion<$t>) -> Self {
+                Self::from_xyzw(q.i, q.j, q.k, q.w)
+            }
+        }
+
+        impl From<$quat> for na::UnitQuaternion<$t> {
+            /// Converts a quaternion to a `UnitQuaternion`, normalizing it.
+            #[inline]
+            fn from(q: $quat) -> Self {
+                Self::from_quaternion(q.into())
+            }
+        }
+
+        impl From<na::Matrix2<$t>> for $mat2 {
+            #[inline]
+            fn from(m: na::Matrix2<$t>) -> Self {
+                Self::from_cols_array_2d(&m.into())
+            }
+        }
+
+        impl From<$mat2> for na::Matrix2<$t> {
+            #[inline]
+            fn from(m: $mat2) -> Self {
+                m.to_cols_array_2d().into()
+            }
+        }
+
+        impl From<na::Matrix3<$t>> for $mat3 {
+            #[inline]
+            fn from(m: na::Matrix3<$t>) -> Self {
+                Self::from_cols_array_2d(&m.into())
+            }
+        }
+
+        impl From<$mat3> for na::Matrix3<$t> {
+            #[inline]
+            fn from(m: $mat3) -> Self {
+                m.to_cols_array_2d().into()
+            }
+        }
+
+        impl From<na::Matrix4<$t>> for $mat4 {
+            #[inline]
+            fn from(m: na::Matrix4<$t>) -> Self {
+                Self::from_cols_array_2d(&m.into())
+            }
+        }
+
+        impl From<$mat4> for na::Matrix4<$t> {
+            #[inline]
+            fn from(m: $mat4) -> Self {
+                m.to_cols_array_2d().into()
+            }
+        }
+    };
+}
+
+mod f32 {
+    use super::*;
+    use crate::{Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+
+    impl_float_types!(f32, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
+
+    impl From<na::Matrix3<f32>> for Mat3A {
+        #[inline]
+        fn from(m: na::Matrix3<f32>) -> Self {
+            Self::from_cols_array_2d(&m.into())
+        }
+    }
+
+    impl From<Mat3A> for na::Matrix3<f32> {
+        #[inline]
+        fn from(m: Mat3A) -> Self {
+            m.to_cols_array_2d().into()
+        }
+    }
+
+    impl From<na::Vector3<f32>> for Vec3A {
+        #[inline]
+        fn from(v: na::Vector3<f32>) -> Self {
+            Self::new(v.x, v.y, v.z)
+        }
+    }
+
+    impl From<Vec3A> for na::Vector3<f32> {
+        #[inline]
+        fn from(v: Vec3A) -> Self {
+            Self::new(v.x, v.y, v.z)
+        }
+    }
+
+    impl From<na::Point3<f32>> for Vec3A {
+        #[inline]
+        fn from(p: na::Point3<f32>) -> Self {
+            Self::new(p.x, p.y, p.z)
+        }
+    }
+
+    impl From<Vec3A> for na::Point3<f32> {
+        #[inline]
+        fn from(v: Vec3A) -> Self {
+            Self::new(v.x, v.y, v.z)
+        }
+    }
+}
+
+mod f64 {
+    use super::*;
+    use crate::{DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
+
+    impl_float_types!(f64, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4);
+}
+
+mod i32 {
+    use super::*;
+    use crate::{IVec2, IVec3, IVec4};
+
+    impl_vec_types!(i32, IVec2, IVec3, IVec4);
+}
+
+mod u32 {
+    use super::*;
+    use crate::{UVec2, UVec3, UVec4};
+
+    impl_vec_types!(u32, UVec2, UVec3, UVec4);
+}
+
+/// An error returned when converting a `TransformSRT` to a `nalgebra::Similarity3` fails.
+#[cfg(feature = "transform-types")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SimilarityError {
+    /// The scale is not the same along all axes.
+    NonUniformScale,
+    /// The scale is zero.
+    ZeroScale,
+}
+
+#[cfg(feature = "transform-types")]
+impl core::fmt::Display for SimilarityError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match *self {
+            SimilarityError::NonUniformScale => write!(f, "the scale is not uniform"),
+            SimilarityError::ZeroScale => write!(f, "the scale is zero"),
+        }
+    }
+}
+
+#[cfg(all(feature = "transform-types", feature = "std"))]
+impl std::error::Error for SimilarityError {}
+
+#[cfg(feature = "transform-types")]
+mod transform {
+    use super::*;
+    use crate::{TransformRT, TransformSRT, Vec3};
+    use core::convert::TryFrom;
+
+    impl From<na::Isometry3<f32>> for TransformRT {
+        #[inline]
+        fn from(iso: na::Isometry3<f32>) -> Self {
+            Self::from_rotation_translation(iso.rotation.into(), iso.translation.vector.into())
+        }
+    }
+
+    impl From<TransformRT> for na::Isometry3<f32> {
+        #[inline]
+        fn from(t: TransformRT) -> Self {
+            Self::from_parts(na::Vector3::from(t.translation).into(), t.rotation.into())
+        }
+    }
+
+    impl From<na::Similarity3<f32>> for TransformSRT {
+        #[inline]
+        fn from(sim: na::Similarity3<f32>) -> Self {
+            Self::from_scale_rotation_translation(
+                Vec3::splat(sim.scaling()),
+                sim.isometry.rotation.into(),
+                sim.isometry.translation.vector.into(),
+            )
+        }
+    }
+
+    impl TryFrom<TransformSRT> for na::Similarity3<f32> {
+        type Error = SimilarityError;
+
+        /// Converts a transform with a uniform, non-zero scale to a `Similarity3`.
+        ///
+        /// A `Similarity3` can not represent a non-uniform or zero scale, an error is returned
+        /// for these instead.
+        #[inline]
+        fn try_from(t: TransformSRT) -> Result<Self, Self::Error> {
+            if t.scale.x != t.scale.y || t.scale.x != t.scale.z {
+                return Err(SimilarityError::NonUniformScale);
+            }
+            if t.scale.x == 0.0 {
+                return Err(SimilarityError::ZeroScale);
+            }
+            Ok(Self::from_isometry(
+                na::Isometry3::from_parts(
+                    na::Vector3::from(t.translation).into(),
+                    t.rotation.into(),
+                ),
+                t.scale.x,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use nalgebra as na;
+
+    macro_rules! impl_float_tests {
+        ($t:ty, $mat2:ident, $mat3:ident, $mat4:ident, $quat:ident, $vec2:ident, $vec3:ident, $vec4:ident) => {
+            use crate::{$mat2, $mat3, $mat4, $quat, $vec2, $vec3, $vec4};
+
+            #[test]
+            fn test_vec_conversions() {
+                let v = $vec2::new(1.0, 2.0);
+                assert_eq!(na::Vector2::new(1.0, 2.0), v.into());
+                assert_eq!(v, na::Vector2::from(v).into());
+                assert_eq!(v, na::Point2::from(v).into());
+
+                let v = $vec3::new(1.0, 2.0, 3.0);
+                assert_eq!(na::Vector3::new(1.0, 2.0, 3.0), v.into());
+                assert_eq!(v, na::Vector3::from(v).into());
+                assert_eq!(na::Point3::new(1.0, 2.0, 3.0), v.into());
+                assert_eq!(v, na::Point3::from(v).into());
+
+                let v = $vec4::new(1.0, 2.0, 3.0, 4.0);
+                assert_eq!(v, na::Vector4::from(v).into());
+                assert_eq!(v, na::Point4::from(v).into());
+            }
+
+            #[test]
+            fn test_quat_conversions() {
+                let q = $quat::from_rotation_ypr(0.1, 0.2, 0.3);
+                let nq = na::UnitQuaternion::<$t>::from(q);
+                assert!(q.abs_diff_eq(nq.into(), 1e-6));
+                let nq = na::UnitQuaternion::<$t>::from($quat::from_xyzw(0.0, 0.0, 0.0, 2.0));
+                assert_eq!($quat::IDENTITY, nq.into());
+                assert_eq!(q, na::Quaternion::from(q).into());
+
+                let nq = na::UnitQuaternion::<$t>::from_euler_angles(0.3, 0.2, 0.1);
+                let q = $quat::from(nq);
+                let v = $vec3::new(1.0, 2.0, 3.0);
+                assert!((q * v).abs_diff_eq((nq * na::Vector3::from(v)).into(), 1e-6));
+            }
+
+            #[test]
+            fn test_mat_conversions() {
+                let m = $mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]);
+                let nm = na::Matrix2::from(m);
+                assert_eq!(nm, na::Matrix2::new(1.0, 3.0, 2.0, 4.0));
+                assert_eq!(m, nm.into());
+
+                let m = $mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+                let nm = na::Matrix3::from(m);
+                assert_eq!(m.x_axis, nm.column(0).into_owned().into());
+                assert_eq!(m, nm.into());
+
+                let m = $mat4::from_scale_rotation_translation(
+                    $vec3::new(1.0, 2.0, 3.0),
+                    $quat::from_rotation_x(0.5),
+                    $vec3::new(4.0, 5.0, 6.0),
+                );
+                let nm = na::Matrix4::from(m);
+                let p = $vec3::new(1.0, -1.0, 2.0);
+                assert_eq!(m.transform_point3(p), nm.transform_point(&p.into()).into());
+                assert_eq!(m, nm.into());
+            }
+        };
+    }
+
+    mod f32 {
+        use super::*;
+        use crate::{Mat3A, Vec3A};
+
+        impl_float_tests!(f32, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
+
+        #[test]
+        fn test_vec3a_conversions() {
+            let v = Vec3A::new(1.0, 2.0, 3.0);
+            assert_eq!(v, na::Vector3::from(v).into());
+            assert_eq!(v, na::Point3::from(v).into());
+        }
+
+        #[test]
+        fn test_mat3a_conversions() {
+            let m = Mat3A::from_rotation_z(0.5);
+            let nm = na::Matrix3::from(m);
+            assert_eq!(m.y_axis, nm.column(1).into_owned().into());
+            assert_eq!(m, nm.into());
+        }
+    }
+
+    mod f64 {
+        use super::*;
+
+        impl_float_tests!(f64, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4);
+    }
+
+    #[cfg(feature = "transform-types")]
+    mod transform {
+        use super::*;
+        use crate::features::impl_nalgebra::SimilarityError;
+        use crate::{Quat, TransformRT, TransformSRT, Vec3};
+        use core::convert::TryFrom;
+
+        #[test]
+        fn test_transform_conversions() {
+            let rotation = Quat::from_rotation_ypr(0.1, 0.2, 0.3);
+            let translation = Vec3::new(1.0, 2.0, 3.0);
+            let p = Vec3::new(-1.0, 0.5, 2.0);
+
+            let t = TransformRT::from_rotation_translation(rotation, translation);
+            let iso = na::Isometry3::from(t);
+            assert_eq!(t, iso.into());
+            assert!(t
+                .transform_vec3(p)
+                .abs_diff_eq((iso * na::Point3::from(p)).into(), 1e-6));
+
+            let t = TransformSRT::from_scale_rotation_translation(
+                Vec3::splat(2.0),
+                rotation,
+                translation,
+            );
+            let sim = na::Similarity3::try_from(t).unwrap();
+            assert_eq!(t, sim.into());
+            assert!(t
+                .transform_vec3(p)
+                .abs_diff_eq((sim * na::Point3::from(p)).into(), 1e-6));
+
+            let t = TransformSRT::from_scale_rotation_translation(
+                Vec3::new(1.0, 5.0, 9.0),
+                rotation,
+                translation,
+            );
+            assert_eq!(
+                Err(SimilarityError::NonUniformScale),
+                na::Similarity3::try_from(t)
+            );
+            let t =
+                TransformSRT::from_scale_rotation_translation(Vec3::ZERO, rotation, translation);
+            assert_eq!(
+                Err(SimilarityError::ZeroScale),
+                na::Similarity3::try_from(t)
+            );
+        }
+    }
+}
@@ -0,0 +1,283 @@
+use num_traits::{Bounded, Inv, MulAdd, One, Pow, Zero};
+
+macro_rules! impl_vec_types {
+    ($t:ty, $($vecn:ident),+) => {
+        $(
+            impl Zero for $vecn {
+                #[inline(always)]
+                fn zero() -> Self {
+                    Self::ZERO
+                }
+
+                #[inline]
+                fn is_zero(&self) -> bool {
+                    *self == Self::ZERO
+                }
+            }
+
+            impl One for $vecn {
+                #[inline(always)]
+                fn one() -> Self {
+                    Self::ONE
+                }
+            }
+
+            impl Bounded for $vecn {
+                #[inline(always)]
+                fn min_value() -> Self {
+                    Self::splat(<$t>::MIN)
+                }
+
+                #[inline(always)]
+                fn max_value() -> Self {
+                    Self::splat(<$t>::MAX)
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_float_vec_types {
+    ($t:ty, $($vecn:ident),+) => {
+        impl_vec_types!($t, $($vecn),+);
+
+        $(
+            impl Inv for $vecn {
+                type Output = Self;
+                #[inline(always)]
+                fn inv(self) -> Self {
+                    self.recip()
+                }
+            }
+
+            impl Pow<$t> for $vecn {
+                type Output = Self;
+                #[inline(always)]
+                fn pow(self, n: $t) -> Self {
+                    self.powf(n)
+                }
+            }
+
+            impl MulAdd for $vecn {
+                type Output = Self;
+                #[inline(always)]
+                fn mul_add(self, a: Self, b: Self) -> Self {
+                    $vecn::mul_add(self, a, b)
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_int_vec_type {
+    ($t:ty, $vecn:ident, $($c:ident),+) => {
+        impl_vec_types!($t, $vecn);
+
+        impl Pow<u32> for $vecn {
+            type Output = Self;
+            #[inline(always)]
+            fn pow(self, exp: u32) -> Self {
+                $vecn::pow(self, exp)
+            }
+        }
+
+        impl CheckedAdd for $vecn {
+            #[inline]
+            fn checked_add(&self, v: &Self) -> Option<Self> {
+                Some(Self::new($(self.$c.checked_add(v.$c)?),+))
+            }
+        }
+
+        impl CheckedSub for $vecn {
+            #[inline]
+            fn checked_sub(&self, v: &Self) -> Option<Self> {
+                Some(Self::new($(self.$c.checked_sub(v.$c)?),+))
+            }
+        }
+
+        impl CheckedMul for $vecn {
+            #[inline]
+            fn checked_mul(&self, v: &Self) -> Option<Self> {
+                Some(Self::new($(self.$c.checked_mul(v.$c)?),+))
+            }
+        }
+
+        impl CheckedDiv for $vecn {
+            #[inline]
+            fn checked_div(&self, v: &Self) -> Option<Self> {
+                Some(Self::new($(self.$c.checked_div(v.$c)?),+))
+            }
+        }
+
+        impl WrappingAdd for $vecn {
+            #[inline]
+            fn wrapping_add(&self, v: &Self) -> Self {
+                Self::new($(self.$c.wrapping_add(v.$c)),+)
+            }
+        }
+
+        impl WrappingSub for $vecn {
+            #[inline]
+            fn wrapping_sub(&self, v: &Self) -> Self {
+                Self::new($(self.$c.wrapping_sub(v.$c)),+)
+            }
+        }
+
+        impl WrappingMul for $vecn {
+            #[inline]
+            fn wrapping_mul(&self, v: &Self) -> Self {
+                Self::new($(self.$c.wrapping_mul(v.$c)),+)
+            }
+        }
+    };
+}
+
+macro_rules! impl_float_types {
+    ($t:ty, $quat:ident, $($matn:ident),+) => {
+        impl One for $quat {
+            #[inline(always)]
+            fn one() -> Self {
+                Self::IDENTITY
+            }
+        }
+
+        impl Inv for $quat {
+            type Output = Self;
+            /// Returns the inverse of `self`, which does not need to be normalized.
+            #[inline]
+            fn inv(self) -> Self {
+                self.conjugate() / self.length_squared()
+            }
+        }
+
+        $(
+            impl Zero for $matn {
+                #[inline(always)]
+                fn zero() -> Self {
+                    Self::ZERO
+                }
+
+                #[inline]
+                fn is_zero(&self) -> bool {
+                    *self == Self::ZERO
+                }
+            }
+
+            impl One for $matn {
+                #[inline(always)]
+                fn one() -> Self {
+                    Self::IDENTITY
+                }
+            }
+
+            impl Inv for $matn {
+                type Output = Self;
+                #[inline(always)]
+                fn inv(self) -> Self {
+                    self.inverse()
+                }
+            }
+        )+
+    };
+}
+
+mod f32 {
+    use super::*;
+    use crate::{Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+
+    impl_float_vec_types!(f32, Vec2, Vec3, Vec3A, Vec4);
+    impl_float_types!(f32, Quat, Mat2, Mat3, Mat3A, Mat4);
+}
+
+mod f64 {
+    use super::*;
+    use crate::{DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
+
+    impl_float_vec_types!(f64, DVec2, DVec3, DVec4);
+    impl_float_types!(f64, DQuat, DMat2, DMat3, DMat4);
+}
+
+mod i32 {
+    use super::*;
+    use crate::{IVec2, IVec3, IVec4};
+    use num_traits::{
+        CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, WrappingAdd, WrappingMul, WrappingSub,
+    };
+
+    impl_int_vec_type!(i32, IVec2, x, y);
+    impl_int_vec_type!(i32, IVec3, x, y, z);
+    impl_int_vec_type!(i32, IVec4, x, y, z, w);
+}
+
+mod u32 {
+    use super::*;
+    use crate::{UVec2, UVec3, UVec4};
+    use num_traits::{
+        CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, WrappingAdd, WrappingMul, WrappingSub,
+    };
+
+    impl_int_vec_type!(u32, UVec2, x, y);
+    impl_int_vec_type!(u32, UVec3, x, y, z);
+    impl_int_vec_type!(u32, UVec4, x, y, z, w);
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{DMat4, DQuat, DVec3, IVec2, Mat2, Quat, UVec3, Vec3, Vec4};
+    use num_traits::{Bounded, CheckedAdd, CheckedDiv, Inv, MulAdd, One, Pow, WrappingSub, Zero};
+
+    // a generic numeric algorithm, taking one explicit Euler step
+    fn euler_step<T: Zero + MulAdd<Output = T> + Copy>(x: T, dx: T, dt: T) -> T {
+        if dx.is_zero() {
+            x
+        } else {
+            dx.mul_add(dt, x)
+        }
+    }
+
+    fn product<T: One + Copy>(values: &[T]) -> T {
+        values.iter().fold(T::one(), |acc, &v| acc * v)
+    }
+
+    #[test]
+    fn test_num_traits_vec() {
+        let x = euler_step(Vec3::ONE, Vec3::new(1.0, 2.0, 3.0), Vec3::splat(0.5));
+        assert_eq!(Vec3::new(1.5, 2.0, 2.5), x);
+        assert!(Vec4::zero().is_zero());
+        assert!(!Vec4::X.is_zero());
+        assert_eq!(Vec4::splat(f32::MAX), Vec4::max_value());
+        assert_eq!(Vec3::new(0.5, 0.25, 2.0), Vec3::new(2.0, 4.0, 0.5).inv());
+        assert_eq!(
+            DVec3::new(1.0, 4.0, 9.0),
+            DVec3::new(1.0, 2.0, 3.0).pow(2.0)
+        );
+
+        assert_eq!(IVec2::new(8, -27), IVec2::new(2, -3).pow(3));
+        assert_eq!(
+            Some(IVec2::new(3, 0)),
+            IVec2::ONE.checked_add(&IVec2::new(2, -1))
+        );
+        assert_eq!(None, IVec2::new(1, i32::MAX).checked_add(&IVec2::ONE));
+        assert_eq!(None, UVec3::ONE.checked_div(&UVec3::new(1, 0, 1)));
+        assert_eq!(
+            UVec3::splat(u32::MAX),
+            UVec3::X.wrapping_sub(&UVec3::new(2, 1, 1))
+        );
+        assert_eq!(UVec3::splat(u32::MIN), UVec3::min_value());
+    }
+
+    #[test]
+    fn test_num_traits_mat_quat() {
+        let m = Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(m, product(&[m, Mat2::one()]));
+        assert_eq!(m.inverse(), m.inv());
+        assert!(DMat4::zero().is_zero());
+        assert_eq!(DMat4::IDENTITY, DMat4::one());
+
+        let q = Quat::from_rotation_x(1.0);
+        assert_eq!(q, product(&[Quat::one(), q]));
+        assert!((q * q.inv()).abs_diff_eq(Quat::IDENTITY, 1e-6));
+        let q = DQuat::from_xyzw(0.0, 2.0, 0.0, 0.0);
+        assert_eq!(DQuat::from_xyzw(0.0, -0.5, 0.0, 0.0), q.inv());
+    }
+}
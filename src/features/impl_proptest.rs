@@ -0,0 +1,211 @@
+use crate::constrained::{Finite, Invertible, Normalized};
+use crate::{
+    BVec2, BVec3, BVec4, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, IVec2, IVec3, IVec4,
+    Mat2, Mat3, Mat3A, Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
+};
+#[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+use crate::{BVec3A, BVec4A};
+#[cfg(feature = "transform-types")]
+use crate::{TransformRT, TransformSRT};
+use core::fmt::Debug;
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    array::{uniform, UniformArrayStrategy},
+    num,
+    strategy::{BoxedStrategy, Map, Strategy},
+};
+
+/// Returns a strategy for values where every element is finite.
+///
+/// Implemented for float vector, quaternion and matrix types.
+pub fn finite<T>() -> impl Strategy<Value = T>
+where
+    Finite<T>: Arbitrary,
+    T: Debug,
+{
+    any::<Finite<T>>().prop_map(|Finite(value)| value)
+}
+
+/// Returns a strategy for vectors and quaternions where `is_normalized()` returns `true`.
+pub fn normalized<T>() -> impl Strategy<Value = T>
+where
+    Normalized<T>: Arbitrary,
+    T: Debug,
+{
+    any::<Normalized<T>>().prop_map(|Normalized(value)| value)
+}
+
+/// Returns a strategy for matrices with a finite non-zero determinant and a finite inverse.
+pub fn invertible<T>() -> impl Strategy<Value = T>
+where
+    Invertible<T>: Arbitrary,
+    T: Debug,
+{
+    any::<Invertible<T>>().prop_map(|Invertible(value)| value)
+}
+
+macro_rules! impl_arbitrary {
+    ($t:ty, [$e:ty; $n:expr], $from:expr) => {
+        impl Arbitrary for $t {
+            type Parameters = ();
+            type Strategy = Map<
+                UniformArrayStrategy<<$e as Arbitrary>::Strategy, [$e; $n]>,
+                fn([$e; $n]) -> Self,
+            >;
+
+            #[inline]
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                any::<[$e; $n]>().prop_map($from)
+            }
+        }
+    };
+}
+
+macro_rules! impl_arbitrary_finite {
+    ($t:ty, [$e:ident; $n:expr], $from:expr) => {
+        impl_arbitrary!($t, [$e; $n], $from);
+
+        impl Arbitrary for Finite<$t> {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                let from: fn([$e; $n]) -> $t = $from;
+                let element = num::$e::POSITIVE
+                    | num::$e::NEGATIVE
+                    | num::$e::NORMAL
+                    | num::$e::SUBNORMAL
+                    | num::$e::ZERO;
+                uniform::<_, $n>(element)
+                    .prop_map(move |a| Finite(from(a)))
+                    .boxed()
+            }
+        }
+    };
+}
+
+macro_rules! impl_arbitrary_constrained {
+    ($wrapper:ident, $t:ty, $reason:expr) => {
+        impl Arbitrary for $wrapper<$t> {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                any::<Finite<$t>>()
+                    .prop_filter_map($reason, |Finite(value)| $wrapper::<$t>::new(value))
+                    .boxed()
+            }
+        }
+    };
+}
+
+macro_rules! impl_float_types {
+    ($t:ident, $mat2:ident, $mat3:ident, $mat4:ident, $quat:ident, $vec2:ident, $vec3:ident, $vec4:ident) => {
+        impl_arbitrary_finite!($vec2, [$t; 2], $vec2::from_array);
+        impl_arbitrary_finite!($vec3, [$t; 3], $vec3::from_array);
+        impl_arbitrary_finite!($vec4, [$t; 4], $vec4::from_array);
+        impl_arbitrary_finite!($quat, [$t; 4], $quat::from_array);
+        impl_arbitrary_finite!($mat2, [$t; 4], |a| $mat2::from_cols_array(&a));
+        impl_arbitrary_finite!($mat3, [$t; 9], |a| $mat3::from_cols_array(&a));
+        impl_arbitrary_finite!($mat4, [$t; 16], |a| $mat4::from_cols_array(&a));
+
+        impl_arbitrary_constrained!(Normalized, $vec2, "zero length vector");
+        impl_arbitrary_constrained!(Normalized, $vec3, "zero length vector");
+        impl_arbitrary_constrained!(Normalized, $vec4, "zero length vector");
+        impl_arbitrary_constrained!(Normalized, $quat, "zero length quaternion");
+        impl_arbitrary_constrained!(Invertible, $mat2, "singular matrix");
+        impl_arbitrary_constrained!(Invertible, $mat3, "singular matrix");
+        impl_arbitrary_constrained!(Invertible, $mat4, "singular matrix");
+    };
+}
+
+impl_float_types!(f32, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
+impl_float_types!(f64, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4);
+
+impl_arbitrary_finite!(Vec3A, [f32; 3], Vec3A::from_array);
+impl_arbitrary_constrained!(Normalized, Vec3A, "zero length vector");
+impl_arbitrary_finite!(Mat3A, [f32; 9], |a| Mat3A::from_cols_array(&a));
+impl_arbitrary_constrained!(Invertible, Mat3A, "singular matrix");
+
+impl_arbitrary!(IVec2, [i32; 2], IVec2::from_array);
+impl_arbitrary!(IVec3, [i32; 3], IVec3::from_array);
+impl_arbitrary!(IVec4, [i32; 4], IVec4::from_array);
+impl_arbitrary!(UVec2, [u32; 2], UVec2::from_array);
+impl_arbitrary!(UVec3, [u32; 3], UVec3::from_array);
+impl_arbitrary!(UVec4, [u32; 4], UVec4::from_array);
+
+impl_arbitrary!(BVec2, [bool; 2], |[x, y]| BVec2::new(x, y));
+impl_arbitrary!(BVec3, [bool; 3], |[x, y, z]| BVec3::new(x, y, z));
+impl_arbitrary!(BVec4, [bool; 4], |[x, y, z, w]| BVec4::new(x, y, z, w));
+#[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+impl_arbitrary!(BVec3A, [bool; 3], |[x, y, z]| BVec3A::new(x, y, z));
+#[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+impl_arbitrary!(BVec4A, [bool; 4], |[x, y, z, w]| BVec4A::new(x, y, z, w));
+
+#[cfg(feature = "transform-types")]
+impl Arbitrary for TransformRT {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<Quat>(), any::<Vec3>())
+            .prop_map(|(rotation, translation)| {
+                Self::from_rotation_translation(rotation, translation)
+            })
+            .boxed()
+    }
+}
+
+#[cfg(feature = "transform-types")]
+impl Arbitrary for TransformSRT {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<Vec3>(), any::<Quat>(), any::<Vec3>())
+            .prop_map(|(scale, rotation, translation)| {
+                Self::from_scale_rotation_translation(scale, rotation, translation)
+            })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::BVec3A;
+    use proptest::proptest;
+
+    proptest! {
+        #[test]
+        fn test_finite(v in finite::<Vec3A>(), q in finite::<DQuat>(), m in finite::<Mat4>()) {
+            assert!(v.is_finite() && q.is_finite() && m.is_finite());
+        }
+
+        #[test]
+        fn test_normalized(
+            v in normalized::<Vec2>(),
+            dv in normalized::<DVec4>(),
+            q in normalized::<Quat>(),
+        ) {
+            assert!(v.is_normalized() && dv.is_normalized() && q.is_normalized());
+            assert!((q * Vec3::X).is_normalized());
+        }
+
+        #[test]
+        fn test_invertible(
+            m in invertible::<Mat3>(),
+            ma in invertible::<Mat3A>(),
+            dm in invertible::<DMat4>(),
+        ) {
+            assert!(m.inverse().is_finite() && ma.inverse().is_finite() && dm.inverse().is_finite());
+        }
+
+        #[test]
+        fn test_any(v in any::<Vec4>(), i in any::<IVec3>(), b in any::<BVec3A>()) {
+            assert_eq!(v.is_nan(), v.cmpne(v).any());
+            assert_eq!(i.min(i), i);
+            assert_eq!(b.any(), b.bitmask() != 0);
+        }
+    }
+}
@@ -1,3 +1,232 @@
+/// Samples points uniformly distributed on the surface of the unit sphere.
+#[derive(Clone, Copy, Debug)]
+pub struct UnitSphere;
+
+/// Samples points uniformly distributed inside the unit sphere.
+#[derive(Clone, Copy, Debug)]
+pub struct UnitBall;
+
+/// Samples points uniformly distributed on the unit circle.
+#[derive(Clone, Copy, Debug)]
+pub struct UnitCircle;
+
+/// Samples points uniformly distributed inside the unit circle.
+#[derive(Clone, Copy, Debug)]
+pub struct UnitDisk;
+
+/// Samples unit vectors uniformly distributed on the hemisphere around the given normal.
+///
+/// The normal is expected to be normalized.
+#[derive(Clone, Copy, Debug)]
+pub struct Hemisphere<V>(pub V);
+
+/// Samples unit vectors on the hemisphere around the given normal, with a density proportional
+/// to the cosine of the angle to the normal.
+///
+/// This is the distribution of directions reflected off a perfectly diffuse surface. The normal
+/// is expected to be normalized.
+#[derive(Clone, Copy, Debug)]
+pub struct CosineHemisphere<V>(pub V);
+
+/// Samples points uniformly distributed inside the axis aligned box between the given minimum
+/// and maximum corners.
+#[derive(Clone, Copy, Debug)]
+pub struct InAabb<V>(pub V, pub V);
+
+/// Samples points uniformly distributed on the triangle with the given vertices.
+#[derive(Clone, Copy, Debug)]
+pub struct OnTriangle<V>(pub V, pub V, pub V);
+
+macro_rules! impl_linear_distributions {
+    ($t:ty, $($vecn:ident),+) => {
+        $(
+            impl Distribution<$vecn> for InAabb<$vecn> {
+                #[inline]
+                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vecn {
+                    self.0 + (self.1 - self.0) * rng.gen::<$vecn>()
+                }
+            }
+
+            impl Distribution<$vecn> for OnTriangle<$vecn> {
+                #[inline]
+                fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vecn {
+                    let (mut u, mut v): ($t, $t) = rng.gen();
+                    if u + v > 1.0 {
+                        // reflect samples from the other half of the parallelogram
+                        u = 1.0 - u;
+                        v = 1.0 - v;
+                    }
+                    self.0 + (self.1 - self.0) * u + (self.2 - self.0) * v
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_vec2_distributions {
+    ($t:ty, $vec2:ident) => {
+        impl Distribution<$vec2> for UnitCircle {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vec2 {
+                let (sin, cos) = Float::sin_cos(rng.gen::<$t>() * 2.0 * PI);
+                $vec2::new(cos, sin)
+            }
+        }
+
+        impl Distribution<$vec2> for UnitDisk {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vec2 {
+                let v: $vec2 = UnitCircle.sample(rng);
+                v * Float::sqrt(rng.gen::<$t>())
+            }
+        }
+
+        #[test]
+        fn test_vec2_distributions() {
+            use rand::SeedableRng;
+            use rand_xoshiro::Xoshiro256Plus;
+            const N: usize = 10_000;
+            let mut rng = Xoshiro256Plus::seed_from_u64(0);
+            let (min, max) = ($vec2::new(-1.0, 2.0), $vec2::new(3.0, 4.0));
+            let (a, b, c) = ($vec2::ZERO, $vec2::new(3.0, 0.0), $vec2::new(0.0, 3.0));
+            let mut circle_sum = $vec2::ZERO;
+            let mut disk_inner = 0;
+            let mut aabb_sum = $vec2::ZERO;
+            let mut triangle_sum = $vec2::ZERO;
+            for _ in 0..N {
+                let v: $vec2 = UnitCircle.sample(&mut rng);
+                assert!(v.is_normalized());
+                circle_sum += v;
+
+                let v: $vec2 = UnitDisk.sample(&mut rng);
+                assert!(v.length_squared() <= 1.0);
+                if v.length_squared() < 0.25 {
+                    disk_inner += 1;
+                }
+
+                let v = InAabb(min, max).sample(&mut rng);
+                assert!(v.cmpge(min).all() && v.cmplt(max).all());
+                aabb_sum += v;
+
+                let v = OnTriangle(a, b, c).sample(&mut rng);
+                assert!(v.cmpge($vec2::ZERO).all() && v.x + v.y <= 3.0);
+                triangle_sum += v;
+            }
+            let n = N as $t;
+            assert!((circle_sum / n).length() < 0.03);
+            // a disk of radius 0.5 covers a quarter of the unit disk
+            assert!((0.24..0.26).contains(&(disk_inner as $t / n)));
+            assert!((aabb_sum / n).abs_diff_eq((min + max) * 0.5, 0.03));
+            assert!((triangle_sum / n).abs_diff_eq((a + b + c) / 3.0, 0.03));
+        }
+    };
+}
+
+macro_rules! impl_vec3_distributions {
+    ($t:ty, $vec3:ident) => {
+        impl Distribution<$vec3> for UnitSphere {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vec3 {
+                let z = rng.gen::<$t>() * 2.0 - 1.0;
+                let (sin, cos) = Float::sin_cos(rng.gen::<$t>() * 2.0 * PI);
+                let r = Float::sqrt(1.0 - z * z);
+                $vec3::new(r * cos, r * sin, z)
+            }
+        }
+
+        impl Distribution<$vec3> for UnitBall {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vec3 {
+                loop {
+                    let v = rng.gen::<$vec3>() * 2.0 - $vec3::ONE;
+                    if v.length_squared() <= 1.0 {
+                        return v;
+                    }
+                }
+            }
+        }
+
+        impl Distribution<$vec3> for Hemisphere<$vec3> {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vec3 {
+                let v: $vec3 = UnitSphere.sample(rng);
+                if v.dot(self.0) < 0.0 {
+                    -v
+                } else {
+                    v
+                }
+            }
+        }
+
+        impl Distribution<$vec3> for CosineHemisphere<$vec3> {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $vec3 {
+                // offsetting a point on the unit sphere by the normal gives a cosine distribution
+                loop {
+                    let v: $vec3 = UnitSphere.sample(rng);
+                    if let Some(v) = (self.0 + v).try_normalize() {
+                        return v;
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_vec3_distributions() {
+            use rand::SeedableRng;
+            use rand_xoshiro::Xoshiro256Plus;
+            const N: usize = 10_000;
+            let mut rng = Xoshiro256Plus::seed_from_u64(0);
+            let normal = $vec3::new(1.0, 2.0, 2.0) / 3.0;
+            let (min, max) = ($vec3::new(-1.0, 2.0, 0.0), $vec3::new(3.0, 4.0, 1.0));
+            let (a, b, c) = ($vec3::X, $vec3::Y, $vec3::Z);
+            let mut sphere_sum = $vec3::ZERO;
+            let mut ball_inner = 0;
+            let mut hemisphere_cos = 0.0;
+            let mut cosine_cos = 0.0;
+            let mut aabb_sum = $vec3::ZERO;
+            let mut triangle_sum = $vec3::ZERO;
+            for _ in 0..N {
+                let v: $vec3 = UnitSphere.sample(&mut rng);
+                assert!(v.is_normalized());
+                sphere_sum += v;
+
+                let v: $vec3 = UnitBall.sample(&mut rng);
+                assert!(v.length_squared() <= 1.0);
+                if v.length_squared() < 0.25 {
+                    ball_inner += 1;
+                }
+
+                let v = Hemisphere(normal).sample(&mut rng);
+                assert!(v.is_normalized() && v.dot(normal) >= 0.0);
+                hemisphere_cos += v.dot(normal);
+
+                let v = CosineHemisphere(normal).sample(&mut rng);
+                assert!(v.is_normalized() && v.dot(normal) >= 0.0);
+                cosine_cos += v.dot(normal);
+
+                let v = InAabb(min, max).sample(&mut rng);
+                assert!(v.cmpge(min).all() && v.cmplt(max).all());
+                aabb_sum += v;
+
+                let v = OnTriangle(a, b, c).sample(&mut rng);
+                assert!(v.cmpge($vec3::ZERO).all());
+                assert!((0.999..1.001).contains(&v.dot($vec3::ONE)));
+                triangle_sum += v;
+            }
+            let n = N as $t;
+            assert!((sphere_sum / n).length() < 0.03);
+            // a ball of radius 0.5 has an eighth of the volume of the unit ball
+            assert!((0.115..0.135).contains(&(ball_inner as $t / n)));
+            // the mean cosine is 1/2 for uniform and 2/3 for cosine weighted hemispheres
+            assert!((0.49..0.51).contains(&(hemisphere_cos / n)));
+            assert!((0.657..0.677).contains(&(cosine_cos / n)));
+            assert!((aabb_sum / n).abs_diff_eq((min + max) * 0.5, 0.03));
+            assert!((triangle_sum / n).abs_diff_eq((a + b + c) / 3.0, 0.03));
+        }
+    };
+}
+
 macro_rules! impl_vec_types {
     ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident) => {
         impl Distribution<$vec2> for Standard {
@@ -59,6 +288,9 @@ macro_rules! impl_vec_types {
 macro_rules! impl_float_types {
     ($t:ident, $mat2:ident, $mat3:ident, $mat4:ident, $quat:ident, $vec2:ident, $vec3:ident, $vec4:ident) => {
         impl_vec_types!($t, $vec2, $vec3, $vec4);
+        impl_linear_distributions!($t, $vec2, $vec3, $vec4);
+        impl_vec2_distributions!($t, $vec2);
+        impl_vec3_distributions!($t, $vec3);
 
         impl Distribution<$mat2> for Standard {
             #[inline]
@@ -84,10 +316,14 @@ macro_rules! impl_float_types {
         impl Distribution<$quat> for Standard {
             #[inline]
             fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $quat {
-                let yaw = -PI + rng.gen::<$t>() * 2.0 * PI;
-                let pitch = -PI + rng.gen::<$t>() * 2.0 * PI;
-                let roll = -PI + rng.gen::<$t>() * 2.0 * PI;
-                $quat::from_rotation_ypr(yaw, pitch, roll)
+                // Shoemake's method for sampling rotations uniformly, see "Uniform random
+                // rotations" in Graphics Gems III
+                let (u1, u2, u3): ($t, $t, $t) = rng.gen();
+                let (sin2, cos2) = Float::sin_cos(u2 * 2.0 * PI);
+                let (sin3, cos3) = Float::sin_cos(u3 * 2.0 * PI);
+                let a = Float::sqrt(1.0 - u1);
+                let b = Float::sqrt(u1);
+                $quat::from_xyzw(a * sin2, a * cos2, b * sin3, b * cos3)
             }
         }
 
@@ -135,11 +371,40 @@ macro_rules! impl_float_types {
             let b: $quat = rng2.gen();
             assert_eq!(a, b);
         }
+
+        #[test]
+        fn test_quat_rand_uniform() {
+            use rand::{Rng, SeedableRng};
+            use rand_xoshiro::Xoshiro256Plus;
+            const N: usize = 10_000;
+            let mut rng = Xoshiro256Plus::seed_from_u64(0);
+            let mut sum = $vec3::ZERO;
+            let mut z_squared = 0.0;
+            let mut w_squared = 0.0;
+            for _ in 0..N {
+                let q: $quat = rng.gen();
+                assert!(q.is_normalized());
+                // uniform rotations map a fixed direction uniformly onto the sphere
+                let v = q * $vec3::Z;
+                sum += v;
+                z_squared += v.z * v.z;
+                w_squared += q.w * q.w;
+            }
+            let n = N as $t;
+            assert!((sum / n).length() < 0.03);
+            assert!((0.323..0.343).contains(&(z_squared / n)));
+            assert!((0.24..0.26).contains(&(w_squared / n)));
+        }
     };
 }
 
 mod f32 {
-    use crate::{Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+    use super::{
+        CosineHemisphere, Hemisphere, InAabb, OnTriangle, UnitBall, UnitCircle, UnitDisk,
+        UnitSphere,
+    };
+    use crate::core::traits::scalar::Float;
+    use crate::{Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
     use core::f32::consts::PI;
     use rand::{
         distributions::{Distribution, Standard},
@@ -148,6 +413,12 @@ mod f32 {
 
     impl_float_types!(f32, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
 
+    mod vec3a {
+        use super::*;
+        impl_linear_distributions!(f32, Vec3A);
+        impl_vec3_distributions!(f32, Vec3A);
+    }
+
     impl Distribution<Vec3A> for Standard {
         #[inline]
         fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec3A {
@@ -155,6 +426,13 @@ mod f32 {
         }
     }
 
+    impl Distribution<Mat3A> for Standard {
+        #[inline]
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Mat3A {
+            Mat3A::from_cols_array(&rng.gen())
+        }
+    }
+
     #[test]
     fn test_vec3a_rand() {
         use rand::{Rng, SeedableRng};
@@ -165,9 +443,25 @@ mod f32 {
         let b: Vec3A = rng2.gen();
         assert_eq!(a, b.into());
     }
+
+    #[test]
+    fn test_mat3a_rand() {
+        use rand::{Rng, SeedableRng};
+        use rand_xoshiro::Xoshiro256Plus;
+        let mut rng1 = Xoshiro256Plus::seed_from_u64(0);
+        let a = Mat3::from_cols_array(&rng1.gen::<[f32; 9]>());
+        let mut rng2 = Xoshiro256Plus::seed_from_u64(0);
+        let b = rng2.gen::<Mat3A>();
+        assert_eq!(a, b.into());
+    }
 }
 
 mod f64 {
+    use super::{
+        CosineHemisphere, Hemisphere, InAabb, OnTriangle, UnitBall, UnitCircle, UnitDisk,
+        UnitSphere,
+    };
+    use crate::core::traits::scalar::Float;
     use crate::{DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
     use core::f64::consts::PI;
     use rand::{
@@ -0,0 +1,280 @@
+//! The archived form of each type is the type itself, so archived values keep the alignment of
+//! SIMD types and can be used in place without deserializing them.
+
+use crate::{
+    DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, IVec2, IVec3, IVec4, Mat2, Mat3, Mat3A, Mat4,
+    Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
+};
+#[cfg(feature = "transform-types")]
+use crate::{TransformRT, TransformSRT};
+use rkyv::{Archive, Deserialize, Fallible, Serialize};
+
+#[cfg(feature = "bytecheck")]
+use core::convert::Infallible;
+#[cfg(feature = "bytecheck")]
+use rkyv::bytecheck::CheckBytes;
+
+macro_rules! impl_rkyv {
+    ($t:ty) => {
+        impl<S: Fallible + ?Sized> Serialize<S> for $t {
+            #[inline]
+            fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+                Ok(())
+            }
+        }
+
+        impl<D: Fallible + ?Sized> Deserialize<$t, D> for $t {
+            #[inline]
+            fn deserialize(&self, _: &mut D) -> Result<$t, D::Error> {
+                Ok(*self)
+            }
+        }
+
+        // Every bit pattern is a valid value, only the pointer needs to be checked which is done
+        // by the validator before calling `check_bytes`.
+        #[cfg(feature = "bytecheck")]
+        impl<C: ?Sized> CheckBytes<C> for $t {
+            type Error = Infallible;
+
+            #[inline]
+            unsafe fn check_bytes<'a>(
+                value: *const Self,
+                _: &mut C,
+            ) -> Result<&'a Self, Self::Error> {
+                Ok(&*value)
+            }
+        }
+    };
+}
+
+macro_rules! impl_rkyv_types {
+    ($($t:ty),+) => {
+        $(
+            impl Archive for $t {
+                type Archived = $t;
+                type Resolver = ();
+
+                #[inline]
+                unsafe fn resolve(&self, _: usize, _: Self::Resolver, out: *mut Self::Archived) {
+                    out.write(*self);
+                }
+            }
+
+            impl_rkyv!($t);
+        )+
+    };
+}
+
+impl_rkyv_types!(Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
+impl_rkyv_types!(DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4);
+impl_rkyv_types!(IVec2, IVec3, IVec4);
+impl_rkyv_types!(UVec2, UVec3, UVec4);
+
+impl Archive for Vec3A {
+    type Archived = Vec3A;
+    type Resolver = ();
+
+    /// Writes the vector with a zeroed fourth element so no padding or unused SIMD lane ends up
+    /// in the archive.
+    #[inline]
+    unsafe fn resolve(&self, _: usize, _: Self::Resolver, out: *mut Self::Archived) {
+        out.cast::<[f32; 4]>().write(self.to_padded_array());
+    }
+}
+
+impl_rkyv!(Vec3A);
+
+impl Archive for Mat3A {
+    type Archived = Mat3A;
+    type Resolver = ();
+
+    /// Writes each column with a zeroed fourth element, see `Vec3A`.
+    #[inline]
+    unsafe fn resolve(&self, _: usize, _: Self::Resolver, out: *mut Self::Archived) {
+        out.cast::<[[f32; 4]; 3]>().write([
+            self.x_axis.to_padded_array(),
+            self.y_axis.to_padded_array(),
+            self.z_axis.to_padded_array(),
+        ]);
+    }
+}
+
+impl_rkyv!(Mat3A);
+
+// A SIMD `Quat` adds padding to the transform types, the fields are written individually so the
+// padding stays zeroed.
+#[cfg(feature = "transform-types")]
+impl Archive for TransformRT {
+    type Archived = TransformRT;
+    type Resolver = ();
+
+    #[inline]
+    unsafe fn resolve(&self, _: usize, _: Self::Resolver, out: *mut Self::Archived) {
+        core::ptr::addr_of_mut!((*out).rotation).write(self.rotation);
+        core::ptr::addr_of_mut!((*out).translation).write(self.translation);
+    }
+}
+
+#[cfg(feature = "transform-types")]
+impl_rkyv!(TransformRT);
+
+#[cfg(feature = "transform-types")]
+impl Archive for TransformSRT {
+    type Archived = TransformSRT;
+    type Resolver = ();
+
+    #[inline]
+    unsafe fn resolve(&self, _: usize, _: Self::Resolver, out: *mut Self::Archived) {
+        core::ptr::addr_of_mut!((*out).scale).write(self.scale);
+        core::ptr::addr_of_mut!((*out).rotation).write(self.rotation);
+        core::ptr::addr_of_mut!((*out).translation).write(self.translation);
+    }
+}
+
+#[cfg(feature = "transform-types")]
+impl_rkyv!(TransformSRT);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rkyv::{
+        ser::{serializers::BufferSerializer, Serializer},
+        AlignedBytes,
+    };
+
+    /// Archives `value` after a single byte so the archived value needs to be aligned.
+    fn archive<T: Serialize<BufferSerializer<AlignedBytes<256>>>>(
+        value: &T,
+    ) -> (AlignedBytes<256>, usize) {
+        let mut serializer = BufferSerializer::new(AlignedBytes::default());
+        serializer.serialize_value(&1_u8).unwrap();
+        let pos = serializer.serialize_value(value).unwrap();
+        assert_eq!(pos % core::mem::align_of::<T::Archived>(), 0);
+        let end = serializer.pos();
+        (serializer.into_inner(), end)
+    }
+
+    macro_rules! assert_round_trip {
+        ($t:ty, $value:expr) => {{
+            let value: $t = $value;
+            let (bytes, end) = archive(&value);
+            let archived = unsafe { rkyv::archived_root::<$t>(&bytes[..end]) };
+            assert_eq!(&value, archived);
+            let deserialized: $t = archived.deserialize(&mut rkyv::Infallible).unwrap();
+            assert_eq!(value, deserialized);
+            #[cfg(feature = "bytecheck")]
+            assert_eq!(
+                &value,
+                rkyv::check_archived_root::<$t>(&bytes[..end]).unwrap()
+            );
+        }};
+    }
+
+    #[test]
+    fn test_vec_rkyv() {
+        assert_round_trip!(Vec2, Vec2::new(1.0, 2.0));
+        assert_round_trip!(Vec3, Vec3::new(1.0, 2.0, 3.0));
+        assert_round_trip!(Vec3A, Vec3A::new(1.0, 2.0, 3.0));
+        assert_round_trip!(Vec4, Vec4::new(1.0, 2.0, 3.0, 4.0));
+        assert_round_trip!(DVec2, DVec2::new(1.0, 2.0));
+        assert_round_trip!(DVec3, DVec3::new(1.0, 2.0, 3.0));
+        assert_round_trip!(DVec4, DVec4::new(1.0, 2.0, 3.0, 4.0));
+        assert_round_trip!(IVec2, IVec2::new(1, -2));
+        assert_round_trip!(IVec3, IVec3::new(1, -2, 3));
+        assert_round_trip!(IVec4, IVec4::new(1, -2, 3, -4));
+        assert_round_trip!(UVec2, UVec2::new(1, 2));
+        assert_round_trip!(UVec3, UVec3::new(1, 2, 3));
+        assert_round_trip!(UVec4, UVec4::new(1, 2, 3, 4));
+    }
+
+    #[test]
+    fn test_quat_mat_rkyv() {
+        assert_round_trip!(Quat, Quat::from_rotation_ypr(0.1, 0.2, 0.3));
+        assert_round_trip!(DQuat, DQuat::from_rotation_ypr(0.1, 0.2, 0.3));
+        assert_round_trip!(Mat2, Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]));
+        assert_round_trip!(DMat2, DMat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]));
+        assert_round_trip!(
+            Mat3,
+            Mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0])
+        );
+        assert_round_trip!(
+            Mat3A,
+            Mat3A::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0])
+        );
+        assert_round_trip!(
+            DMat3,
+            DMat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0])
+        );
+        assert_round_trip!(
+            Mat4,
+            Mat4::from_scale_rotation_translation(
+                Vec3::new(1.0, 2.0, 3.0),
+                Quat::from_rotation_x(0.5),
+                Vec3::new(4.0, 5.0, 6.0),
+            )
+        );
+        assert_round_trip!(
+            DMat4,
+            DMat4::from_scale_rotation_translation(
+                DVec3::new(1.0, 2.0, 3.0),
+                DQuat::from_rotation_x(0.5),
+                DVec3::new(4.0, 5.0, 6.0),
+            )
+        );
+    }
+
+    #[test]
+    fn test_vec3a_padding_rkyv() {
+        let (bytes, end) = archive(&Vec3A::new(1.0, 2.0, 3.0));
+        assert_eq!(end, 32);
+        assert_eq!(bytes[28..32], [0; 4]);
+
+        let (bytes, end) = archive(&Mat3A::from_cols_array(&[1.0; 9]));
+        assert_eq!(end, 64);
+        assert_eq!(bytes[60..64], [0; 4]);
+    }
+
+    #[cfg(feature = "bytecheck")]
+    #[test]
+    fn test_check_archived_root() {
+        fn check<T: Archive + Serialize<BufferSerializer<AlignedBytes<256>>>>(value: &T)
+        where
+            T::Archived: for<'a> CheckBytes<rkyv::validation::validators::DefaultValidator<'a>>,
+        {
+            let (bytes, end) = archive(value);
+            assert!(rkyv::check_archived_root::<T>(&bytes[..end]).is_ok());
+            // the root is read from the end of the archive so this moves it off its alignment
+            assert!(rkyv::check_archived_root::<T>(&bytes[..end + 1]).is_err());
+            // too short to hold the root value
+            let size = core::mem::size_of::<T::Archived>();
+            assert!(rkyv::check_archived_root::<T>(&bytes[..size - 1]).is_err());
+        }
+
+        check(&Vec2::new(1.0, 2.0));
+        check(&Vec3A::new(1.0, 2.0, 3.0));
+        check(&DVec4::new(1.0, 2.0, 3.0, 4.0));
+        check(&UVec3::new(1, 2, 3));
+        check(&Quat::IDENTITY);
+        check(&Mat3A::IDENTITY);
+        check(&DMat4::IDENTITY);
+    }
+
+    #[cfg(feature = "transform-types")]
+    #[test]
+    fn test_transform_rkyv() {
+        let rotation = Quat::from_rotation_ypr(0.1, 0.2, 0.3);
+        let translation = Vec3::new(1.0, 2.0, 3.0);
+        assert_round_trip!(
+            TransformRT,
+            TransformRT::from_rotation_translation(rotation, translation)
+        );
+        assert_round_trip!(
+            TransformSRT,
+            TransformSRT::from_scale_rotation_translation(
+                Vec3::new(4.0, 5.0, 6.0),
+                rotation,
+                translation,
+            )
+        );
+    }
+}
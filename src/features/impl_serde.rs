@@ -1,3 +1,226 @@
+use core::{fmt, marker::PhantomData};
+use serde::{
+    de::{self, Deserialize, DeserializeSeed, Deserializer, MapAccess, Unexpected, Visitor},
+    ser::{Serialize, SerializeStruct, Serializer},
+};
+
+/// The field names of the map form of vectors, quaternions and masks.
+const VEC_FIELDS: &[&str] = &["x", "y", "z", "w"];
+
+/// The field names of the map form of matrices.
+const MAT_FIELDS: &[&str] = &["x_axis", "y_axis", "z_axis", "w_axis"];
+
+/// Deserializes both the sequence and the map form of `T`.
+struct ElementsVisitor<T>(PhantomData<T>);
+
+/// Deserializes `T` from its sequence form or, for self describing formats such as JSON, either
+/// its sequence or its map form.
+fn deserialize_any_form<'de, D, T>(
+    deserializer: D,
+    name: &'static str,
+    len: usize,
+) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    ElementsVisitor<T>: Visitor<'de, Value = T>,
+{
+    let visitor = ElementsVisitor(PhantomData);
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(visitor)
+    } else {
+        deserializer.deserialize_tuple_struct(name, len, visitor)
+    }
+}
+
+/// Deserializes a map key as the index of one of the field names.
+struct FieldIndex(&'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for FieldIndex {
+    type Value = usize;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<usize, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for FieldIndex {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "one of {:?}", self.0)
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<usize, E>
+    where
+        E: de::Error,
+    {
+        if v < self.0.len() as u64 {
+            Ok(v as usize)
+        } else {
+            Err(de::Error::invalid_value(Unexpected::Unsigned(v), &self))
+        }
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<usize, E>
+    where
+        E: de::Error,
+    {
+        self.0
+            .iter()
+            .position(|&field| field == v)
+            .ok_or_else(|| de::Error::unknown_field(v, self.0))
+    }
+}
+
+/// Deserializes the values of a map with the first `N` of `fields` as keys, in any order.
+fn visit_map_fields<'de, A, T, const N: usize>(
+    mut map: A,
+    fields: &'static [&'static str],
+) -> Result<[T; N], A::Error>
+where
+    A: MapAccess<'de>,
+    T: Deserialize<'de> + Copy + Default,
+{
+    let fields = &fields[..N];
+    let mut values = [None; N];
+    while let Some(i) = map.next_key_seed(FieldIndex(fields))? {
+        if values[i].is_some() {
+            return Err(de::Error::duplicate_field(fields[i]));
+        }
+        values[i] = Some(map.next_value()?);
+    }
+    let mut out = [T::default(); N];
+    for (i, (out, value)) in out.iter_mut().zip(values.iter()).enumerate() {
+        *out = value.ok_or_else(|| de::Error::missing_field(fields[i]))?;
+    }
+    Ok(out)
+}
+
+/// Serializes a vector or quaternion in the map form.
+fn serialize_vec_map<S, T>(
+    serializer: S,
+    name: &'static str,
+    elements: &[T],
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Serialize,
+{
+    let mut state = serializer.serialize_struct(name, elements.len())?;
+    for (field, element) in VEC_FIELDS.iter().zip(elements) {
+        state.serialize_field(field, element)?;
+    }
+    state.end()
+}
+
+/// Serializes a matrix in the map form, with each column in the map form.
+fn serialize_mat_map<S, C>(serializer: S, name: &'static str, cols: &[C]) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    C: as_map::AsMap,
+{
+    struct MapForm<'a, C>(&'a C);
+
+    impl<C: as_map::AsMap> Serialize for MapForm<'_, C> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.0.serialize_as_map(serializer)
+        }
+    }
+
+    let mut state = serializer.serialize_struct(name, cols.len())?;
+    for (field, col) in MAT_FIELDS.iter().zip(cols) {
+        state.serialize_field(field, &MapForm(col))?;
+    }
+    state.end()
+}
+
+/// Serialize and deserialize vectors, quaternions and matrices as maps, e.g. `{"x":1.0,"y":2.0}`.
+///
+/// Matrices are maps of their `x_axis`, `y_axis` etc. columns, each in the map form. Use with the
+/// `#[serde(with = "glam::serde::as_map")]` field attribute.
+///
+/// The default `Deserialize` implementations accept the map form in self describing formats
+/// too, this is only needed to serialize the map form or to deserialize it from formats which
+/// are not self describing.
+pub mod as_map {
+    use serde::{Deserializer, Serializer};
+
+    /// A type with a map form.
+    pub trait AsMap: Sized {
+        /// Serializes `self` in the map form.
+        fn serialize_as_map<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer;
+
+        /// Deserializes a value in the map form.
+        fn deserialize_as_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>;
+    }
+
+    /// Serializes `value` in the map form.
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AsMap,
+        S: Serializer,
+    {
+        value.serialize_as_map(serializer)
+    }
+
+    /// Deserializes a value in the map form.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: AsMap,
+        D: Deserializer<'de>,
+    {
+        T::deserialize_as_map(deserializer)
+    }
+}
+
+/// Serialize and deserialize matrices as nested column arrays, e.g. `[[1.0,2.0],[3.0,4.0]]`.
+///
+/// Use with the `#[serde(with = "glam::serde::as_nested_cols")]` field attribute.
+pub mod as_nested_cols {
+    use serde::{Deserializer, Serializer};
+
+    /// A matrix type with a nested column array form.
+    pub trait AsNestedCols: Sized {
+        /// Serializes `self` as an array of column arrays.
+        fn serialize_nested_cols<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer;
+
+        /// Deserializes a value from an array of column arrays.
+        fn deserialize_nested_cols<'de, D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>;
+    }
+
+    /// Serializes `value` as an array of column arrays.
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AsNestedCols,
+        S: Serializer,
+    {
+        value.serialize_nested_cols(serializer)
+    }
+
+    /// Deserializes a value from an array of column arrays.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: AsNestedCols,
+        D: Deserializer<'de>,
+    {
+        T::deserialize_nested_cols(deserializer)
+    }
+}
+
 macro_rules! impl_serde_vec2 {
     ($t:ty, $vec2:ident) => {
         impl Serialize for $vec2 {
@@ -12,35 +235,61 @@ macro_rules! impl_serde_vec2 {
             }
         }
 
+        impl<'de> Visitor<'de> for ElementsVisitor<$vec2> {
+            type Value = $vec2;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str(concat!("struct ", stringify!($vec2)))
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<$vec2, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let x = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let y = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                Ok($vec2::new(x, y))
+            }
+
+            fn visit_map<A>(self, map: A) -> Result<$vec2, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let [x, y] = visit_map_fields(map, VEC_FIELDS)?;
+                Ok($vec2::new(x, y))
+            }
+        }
+
         impl<'de> Deserialize<'de> for $vec2 {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
             where
                 D: Deserializer<'de>,
             {
-                struct Vec2Visitor;
-
-                impl<'de> Visitor<'de> for Vec2Visitor {
-                    type Value = $vec2;
-
-                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                        formatter.write_str(concat!("struct ", stringify!($vec2)))
-                    }
-
-                    fn visit_seq<V>(self, mut seq: V) -> Result<$vec2, V::Error>
-                    where
-                        V: SeqAccess<'de>,
-                    {
-                        let x = seq
-                            .next_element()?
-                            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
-                        let y = seq
-                            .next_element()?
-                            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
-                        Ok($vec2::new(x, y))
-                    }
-                }
+                deserialize_any_form(deserializer, stringify!($vec2), 2)
+            }
+        }
+
+        impl as_map::AsMap for $vec2 {
+            fn serialize_as_map<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serialize_vec_map(serializer, stringify!($vec2), &[self.x, self.y])
+            }
 
-                deserializer.deserialize_tuple_struct(stringify!($vec2), 2, Vec2Visitor)
+            fn deserialize_as_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_struct(
+                    stringify!($vec2),
+                    &VEC_FIELDS[..2],
+                    ElementsVisitor::<Self>(PhantomData),
+                )
             }
         }
 
@@ -79,38 +328,64 @@ macro_rules! impl_serde_vec3 {
             }
         }
 
+        impl<'de> Visitor<'de> for ElementsVisitor<$vec3> {
+            type Value = $vec3;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str(concat!("struct ", stringify!($vec3)))
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<$vec3, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let x = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let y = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let z = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                Ok($vec3::new(x, y, z))
+            }
+
+            fn visit_map<A>(self, map: A) -> Result<$vec3, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let [x, y, z] = visit_map_fields(map, VEC_FIELDS)?;
+                Ok($vec3::new(x, y, z))
+            }
+        }
+
         impl<'de> Deserialize<'de> for $vec3 {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
             where
                 D: Deserializer<'de>,
             {
-                struct Vec3Visitor;
-
-                impl<'de> Visitor<'de> for Vec3Visitor {
-                    type Value = $vec3;
-
-                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                        formatter.write_str(concat!("struct ", stringify!($vec3)))
-                    }
-
-                    fn visit_seq<V>(self, mut seq: V) -> Result<$vec3, V::Error>
-                    where
-                        V: SeqAccess<'de>,
-                    {
-                        let x = seq
-                            .next_element()?
-                            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
-                        let y = seq
-                            .next_element()?
-                            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
-                        let z = seq
-                            .next_element()?
-                            .ok_or_else(|| de::Error::invalid_length(2, &self))?;
-                        Ok($vec3::new(x, y, z))
-                    }
-                }
+                deserialize_any_form(deserializer, stringify!($vec3), 3)
+            }
+        }
 
-                deserializer.deserialize_tuple_struct(stringify!($vec3), 3, Vec3Visitor)
+        impl as_map::AsMap for $vec3 {
+            fn serialize_as_map<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serialize_vec_map(serializer, stringify!($vec3), &[self.x, self.y, self.z])
+            }
+
+            fn deserialize_as_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_struct(
+                    stringify!($vec3),
+                    &VEC_FIELDS[..3],
+                    ElementsVisitor::<Self>(PhantomData),
+                )
             }
         }
 
@@ -149,41 +424,71 @@ macro_rules! impl_serde_vec4 {
             }
         }
 
+        impl<'de> Visitor<'de> for ElementsVisitor<$vec4> {
+            type Value = $vec4;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str(concat!("struct ", stringify!($vec4)))
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<$vec4, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let x = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let y = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let z = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                let w = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(3, &self))?;
+                Ok($vec4::new(x, y, z, w))
+            }
+
+            fn visit_map<A>(self, map: A) -> Result<$vec4, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let [x, y, z, w] = visit_map_fields(map, VEC_FIELDS)?;
+                Ok($vec4::new(x, y, z, w))
+            }
+        }
+
         impl<'de> Deserialize<'de> for $vec4 {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
             where
                 D: Deserializer<'de>,
             {
-                struct Vec4Visitor;
-
-                impl<'de> Visitor<'de> for Vec4Visitor {
-                    type Value = $vec4;
-
-                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                        formatter.write_str(concat!("struct ", stringify!($vec4)))
-                    }
-
-                    fn visit_seq<V>(self, mut seq: V) -> Result<$vec4, V::Error>
-                    where
-                        V: SeqAccess<'de>,
-                    {
-                        let x = seq
-                            .next_element()?
-                            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
-                        let y = seq
-                            .next_element()?
-                            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
-                        let z = seq
-                            .next_element()?
-                            .ok_or_else(|| de::Error::invalid_length(2, &self))?;
-                        let w = seq
-                            .next_element()?
-                            .ok_or_else(|| de::Error::invalid_length(3, &self))?;
-                        Ok($vec4::new(x, y, z, w))
-                    }
-                }
+                deserialize_any_form(deserializer, stringify!($vec4), 4)
+            }
+        }
+
+        impl as_map::AsMap for $vec4 {
+            fn serialize_as_map<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serialize_vec_map(
+                    serializer,
+                    stringify!($vec4),
+                    &[self.x, self.y, self.z, self.w],
+                )
+            }
 
-                deserializer.deserialize_tuple_struct(stringify!($vec4), 4, Vec4Visitor)
+            fn deserialize_as_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_struct(
+                    stringify!($vec4),
+                    &VEC_FIELDS[..4],
+                    ElementsVisitor::<Self>(PhantomData),
+                )
             }
         }
 
@@ -224,41 +529,71 @@ macro_rules! impl_serde_quat {
             }
         }
 
+        impl<'de> Visitor<'de> for ElementsVisitor<$quat> {
+            type Value = $quat;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str(concat!("struct ", stringify!($quat)))
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<$quat, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let x = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let y = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                let z = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+                let w = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(3, &self))?;
+                Ok($quat::from_xyzw(x, y, z, w))
+            }
+
+            fn visit_map<A>(self, map: A) -> Result<$quat, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let [x, y, z, w] = visit_map_fields(map, VEC_FIELDS)?;
+                Ok($quat::from_xyzw(x, y, z, w))
+            }
+        }
+
         impl<'de> Deserialize<'de> for $quat {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
             where
                 D: Deserializer<'de>,
             {
-                struct QuatVisitor;
-
-                impl<'de> Visitor<'de> for QuatVisitor {
-                    type Value = $quat;
-
-                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                        formatter.write_str(concat!("struct ", stringify!($quat)))
-                    }
-
-                    fn visit_seq<V>(self, mut seq: V) -> Result<$quat, V::Error>
-                    where
-                        V: SeqAccess<'de>,
-                    {
-                        let x = seq
-                            .next_element()?
-                            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
-                        let y = seq
-                            .next_element()?
-                            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
-                        let z = seq
-                            .next_element()?
-                            .ok_or_else(|| de::Error::invalid_length(2, &self))?;
-                        let w = seq
-                            .next_element()?
-                            .ok_or_else(|| de::Error::invalid_length(3, &self))?;
-                        Ok($quat::from_xyzw(x, y, z, w))
-                    }
-                }
+                deserialize_any_form(deserializer, stringify!($quat), 4)
+            }
+        }
+
+        impl as_map::AsMap for $quat {
+            fn serialize_as_map<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serialize_vec_map(
+                    serializer,
+                    stringify!($quat),
+                    &[self.x, self.y, self.z, self.w],
+                )
+            }
 
-                deserializer.deserialize_tuple_struct(stringify!($quat), 4, QuatVisitor)
+            fn deserialize_as_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_struct(
+                    stringify!($quat),
+                    &VEC_FIELDS[..4],
+                    ElementsVisitor::<Self>(PhantomData),
+                )
             }
         }
 
@@ -284,7 +619,7 @@ macro_rules! impl_serde_quat {
 }
 
 macro_rules! impl_serde_mat2 {
-    ($t:ty, $mat2:ident) => {
+    ($t:ty, $mat2:ident, $vec2:ident) => {
         impl Serialize for $mat2 {
             fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
             where
@@ -300,35 +635,78 @@ macro_rules! impl_serde_mat2 {
             }
         }
 
+        impl<'de> Visitor<'de> for ElementsVisitor<$mat2> {
+            type Value = $mat2;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str(concat!("struct ", stringify!($mat2)))
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<$mat2, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let mut f = { [0.0; 4] };
+                for i in 0..4 {
+                    f[i] = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                }
+                Ok($mat2::from_cols_array(&f))
+            }
+
+            fn visit_map<A>(self, map: A) -> Result<$mat2, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let [x_axis, y_axis]: [$vec2; 2] = visit_map_fields(map, MAT_FIELDS)?;
+                Ok($mat2::from_cols(x_axis, y_axis))
+            }
+        }
+
         impl<'de> Deserialize<'de> for $mat2 {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
             where
                 D: Deserializer<'de>,
             {
-                struct Mat2Visitor;
-
-                impl<'de> Visitor<'de> for Mat2Visitor {
-                    type Value = $mat2;
-
-                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                        formatter.write_str(concat!("struct ", stringify!($mat2)))
-                    }
-
-                    fn visit_seq<V>(self, mut seq: V) -> Result<$mat2, V::Error>
-                    where
-                        V: SeqAccess<'de>,
-                    {
-                        let mut f = { [0.0; 4] };
-                        for i in 0..4 {
-                            f[i] = seq
-                                .next_element()?
-                                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
-                        }
-                        Ok($mat2::from_cols_array(&f))
-                    }
-                }
+                deserialize_any_form(deserializer, stringify!($mat2), 4)
+            }
+        }
+
+        impl as_map::AsMap for $mat2 {
+            fn serialize_as_map<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serialize_mat_map(serializer, stringify!($mat2), &[self.x_axis, self.y_axis])
+            }
+
+            fn deserialize_as_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_struct(
+                    stringify!($mat2),
+                    &MAT_FIELDS[..2],
+                    ElementsVisitor::<Self>(PhantomData),
+                )
+            }
+        }
+
+        impl as_nested_cols::AsNestedCols for $mat2 {
+            fn serialize_nested_cols<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                self.to_cols_array_2d().serialize(serializer)
+            }
 
-                deserializer.deserialize_tuple_struct(stringify!($mat2), 4, Mat2Visitor)
+            fn deserialize_nested_cols<'de, D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let cols = <[[$t; 2]; 2]>::deserialize(deserializer)?;
+                Ok($mat2::from_cols_array_2d(&cols))
             }
         }
 
@@ -356,7 +734,10 @@ macro_rules! impl_serde_mat2 {
 }
 
 macro_rules! impl_serde_mat3 {
-    ($t:ty, $mat3:ident) => {
+    ($t:ty, $mat3:ident, $vec3:ident) => {
+        impl_serde_mat3!($t, $mat3, $vec3, test_mat3_serde);
+    };
+    ($t:ty, $mat3:ident, $vec3:ident, $test_name:ident) => {
         impl Serialize for $mat3 {
             fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
             where
@@ -380,40 +761,87 @@ macro_rules! impl_serde_mat3 {
             }
         }
 
+        impl<'de> Visitor<'de> for ElementsVisitor<$mat3> {
+            type Value = $mat3;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str(concat!("struct ", stringify!($mat3)))
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<$mat3, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let mut f = { [0.0; 9] };
+                for i in 0..9 {
+                    f[i] = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                }
+                Ok($mat3::from_cols_array(&f))
+            }
+
+            fn visit_map<A>(self, map: A) -> Result<$mat3, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let [x_axis, y_axis, z_axis]: [$vec3; 3] = visit_map_fields(map, MAT_FIELDS)?;
+                Ok($mat3::from_cols(x_axis, y_axis, z_axis))
+            }
+        }
+
         impl<'de> Deserialize<'de> for $mat3 {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
             where
                 D: Deserializer<'de>,
             {
-                struct Mat3Visitor;
-
-                impl<'de> Visitor<'de> for Mat3Visitor {
-                    type Value = $mat3;
-
-                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                        formatter.write_str(concat!("struct ", stringify!($mat3)))
-                    }
-
-                    fn visit_seq<V>(self, mut seq: V) -> Result<$mat3, V::Error>
-                    where
-                        V: SeqAccess<'de>,
-                    {
-                        let mut f = { [0.0; 9] };
-                        for i in 0..9 {
-                            f[i] = seq
-                                .next_element()?
-                                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
-                        }
-                        Ok($mat3::from_cols_array(&f))
-                    }
-                }
+                deserialize_any_form(deserializer, stringify!($mat3), 9)
+            }
+        }
+
+        impl as_map::AsMap for $mat3 {
+            fn serialize_as_map<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serialize_mat_map(
+                    serializer,
+                    stringify!($mat3),
+                    &[self.x_axis, self.y_axis, self.z_axis],
+                )
+            }
+
+            fn deserialize_as_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_struct(
+                    stringify!($mat3),
+                    &MAT_FIELDS[..3],
+                    ElementsVisitor::<Self>(PhantomData),
+                )
+            }
+        }
+
+        impl as_nested_cols::AsNestedCols for $mat3 {
+            fn serialize_nested_cols<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                self.to_cols_array_2d().serialize(serializer)
+            }
 
-                deserializer.deserialize_tuple_struct(stringify!($mat3), 9, Mat3Visitor)
+            fn deserialize_nested_cols<'de, D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let cols = <[[$t; 3]; 3]>::deserialize(deserializer)?;
+                Ok($mat3::from_cols_array_2d(&cols))
             }
         }
 
         #[test]
-        fn test_mat3_serde() {
+        fn $test_name() {
             let a = $mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
             let serialized = serde_json::to_string(&a).unwrap();
             assert_eq!(serialized, "[1.0,2.0,3.0,4.0,5.0,6.0,7.0,8.0,9.0]");
@@ -437,7 +865,7 @@ macro_rules! impl_serde_mat3 {
 }
 
 macro_rules! impl_serde_mat4 {
-    ($t:ty, $mat4:ident) => {
+    ($t:ty, $mat4:ident, $vec4:ident) => {
         impl Serialize for $mat4 {
             fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
             where
@@ -451,35 +879,83 @@ macro_rules! impl_serde_mat4 {
             }
         }
 
+        impl<'de> Visitor<'de> for ElementsVisitor<$mat4> {
+            type Value = $mat4;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str(concat!("struct ", stringify!($mat4)))
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<$mat4, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let mut f = { [0.0; 16] };
+                for i in 0..16 {
+                    f[i] = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                }
+                Ok($mat4::from_cols_array(&f))
+            }
+
+            fn visit_map<A>(self, map: A) -> Result<$mat4, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let [x_axis, y_axis, z_axis, w_axis]: [$vec4; 4] =
+                    visit_map_fields(map, MAT_FIELDS)?;
+                Ok($mat4::from_cols(x_axis, y_axis, z_axis, w_axis))
+            }
+        }
+
         impl<'de> Deserialize<'de> for $mat4 {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
             where
                 D: Deserializer<'de>,
             {
-                struct Mat4Visitor;
-
-                impl<'de> Visitor<'de> for Mat4Visitor {
-                    type Value = $mat4;
-
-                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                        formatter.write_str(concat!("struct ", stringify!($mat4)))
-                    }
-
-                    fn visit_seq<V>(self, mut seq: V) -> Result<$mat4, V::Error>
-                    where
-                        V: SeqAccess<'de>,
-                    {
-                        let mut f = { [0.0; 16] };
-                        for i in 0..16 {
-                            f[i] = seq
-                                .next_element()?
-                                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
-                        }
-                        Ok($mat4::from_cols_array(&f))
-                    }
-                }
+                deserialize_any_form(deserializer, stringify!($mat4), 16)
+            }
+        }
+
+        impl as_map::AsMap for $mat4 {
+            fn serialize_as_map<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serialize_mat_map(
+                    serializer,
+                    stringify!($mat4),
+                    &[self.x_axis, self.y_axis, self.z_axis, self.w_axis],
+                )
+            }
+
+            fn deserialize_as_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_struct(
+                    stringify!($mat4),
+                    &MAT_FIELDS[..4],
+                    ElementsVisitor::<Self>(PhantomData),
+                )
+            }
+        }
 
-                deserializer.deserialize_tuple_struct(stringify!($mat4), 16, Mat4Visitor)
+        impl as_nested_cols::AsNestedCols for $mat4 {
+            fn serialize_nested_cols<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                self.to_cols_array_2d().serialize(serializer)
+            }
+
+            fn deserialize_nested_cols<'de, D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                let cols = <[[$t; 4]; 4]>::deserialize(deserializer)?;
+                Ok($mat4::from_cols_array_2d(&cols))
             }
         }
 
@@ -527,16 +1003,192 @@ macro_rules! impl_serde_vec_types {
 
 macro_rules! impl_serde_float_types {
     ($t:ty, $mat2:ident, $mat3:ident, $mat4:ident, $quat:ident, $vec2:ident, $vec3:ident, $vec4:ident) => {
-        impl_serde_mat2!($t, $mat2);
-        impl_serde_mat3!($t, $mat3);
-        impl_serde_mat4!($t, $mat4);
+        impl_serde_mat2!($t, $mat2, $vec2);
+        impl_serde_mat3!($t, $mat3, $vec3);
+        impl_serde_mat4!($t, $mat4, $vec4);
         impl_serde_quat!($t, $quat);
         impl_serde_vec_types!($t, $vec2, $vec3, $vec4);
+
+        #[test]
+        fn test_map_serde() {
+            let v = $vec3::new(1.0, 2.0, 3.0);
+            let deserialized: $vec3 = serde_json::from_str(r#"{"z":3,"x":1,"y":2}"#).unwrap();
+            assert_eq!(v, deserialized);
+            let deserialized: $quat =
+                serde_json::from_str(r#"{"x":1.0,"y":2.0,"z":3.0,"w":4.0}"#).unwrap();
+            assert_eq!($quat::from_xyzw(1.0, 2.0, 3.0, 4.0), deserialized);
+
+            // columns may be in either form
+            let m = $mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]);
+            let deserialized: $mat2 =
+                serde_json::from_str(r#"{"x_axis":{"x":1.0,"y":2.0},"y_axis":[3.0,4.0]}"#).unwrap();
+            assert_eq!(m, deserialized);
+
+            let err = serde_json::from_str::<$vec2>(r#"{"x":1.0,"z":2.0}"#).unwrap_err();
+            assert!(err.to_string().starts_with("unknown field `z`"));
+            let err = serde_json::from_str::<$vec2>(r#"{"x":1.0,"x":2.0}"#).unwrap_err();
+            assert!(err.to_string().starts_with("duplicate field `x`"));
+            let err = serde_json::from_str::<$vec2>(r#"{"y":2.0}"#).unwrap_err();
+            assert!(err.to_string().starts_with("missing field `x`"));
+            let err = serde_json::from_str::<$mat2>(r#"{"x_axis":[1.0,2.0]}"#).unwrap_err();
+            assert!(err.to_string().starts_with("missing field `y_axis`"));
+        }
+
+        #[test]
+        fn test_as_map_serde() {
+            #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+            struct Asset {
+                #[serde(with = "crate::serde::as_map")]
+                v: $vec4,
+                #[serde(with = "crate::serde::as_map")]
+                q: $quat,
+                #[serde(with = "crate::serde::as_map")]
+                m: $mat2,
+            }
+            let a = Asset {
+                v: $vec4::new(1.0, 2.0, 3.0, 4.0),
+                q: $quat::IDENTITY,
+                m: $mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]),
+            };
+            let serialized = serde_json::to_string(&a).unwrap();
+            assert_eq!(
+                serialized,
+                concat!(
+                    r#"{"v":{"x":1.0,"y":2.0,"z":3.0,"w":4.0},"#,
+                    r#""q":{"x":0.0,"y":0.0,"z":0.0,"w":1.0},"#,
+                    r#""m":{"x_axis":{"x":1.0,"y":2.0},"y_axis":{"x":3.0,"y":4.0}}}"#
+                )
+            );
+            let deserialized: Asset = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+
+            let m = $mat4::from_cols_array_2d(&[
+                [1.0, 2.0, 3.0, 4.0],
+                [5.0, 6.0, 7.0, 8.0],
+                [9.0, 10.0, 11.0, 12.0],
+                [13.0, 14.0, 15.0, 16.0],
+            ]);
+            let mut serialized = Vec::new();
+            crate::serde::as_map::serialize(&m, &mut serde_json::Serializer::new(&mut serialized))
+                .unwrap();
+            let deserialized: $mat4 = crate::serde::as_map::deserialize(
+                &mut serde_json::Deserializer::from_slice(&serialized),
+            )
+            .unwrap();
+            assert_eq!(m, deserialized);
+        }
+
+        #[test]
+        fn test_as_nested_cols_serde() {
+            #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+            struct Asset {
+                #[serde(with = "crate::serde::as_nested_cols")]
+                m2: $mat2,
+                #[serde(with = "crate::serde::as_nested_cols")]
+                m3: $mat3,
+            }
+            let a = Asset {
+                m2: $mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]),
+                m3: $mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]),
+            };
+            let serialized = serde_json::to_string(&a).unwrap();
+            assert_eq!(
+                serialized,
+                r#"{"m2":[[1.0,2.0],[3.0,4.0]],"m3":[[1.0,2.0,3.0],[4.0,5.0,6.0],[7.0,8.0,9.0]]}"#
+            );
+            let deserialized: Asset = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(a, deserialized);
+            assert!(serde_json::from_str::<Asset>(
+                r#"{"m2":[1.0,2.0,3.0,4.0],"m3":[[1.0,2.0,3.0],[4.0,5.0,6.0],[7.0,8.0,9.0]]}"#
+            )
+            .is_err());
+        }
+    };
+}
+
+macro_rules! impl_serde_vec_mask {
+    ($mask:ident, $len:expr, $($field:ident),+) => {
+        impl Serialize for $mask {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let b: [bool; $len] = (*self).into();
+                let mut state = serializer.serialize_tuple_struct(stringify!($mask), $len)?;
+                for b in &b {
+                    state.serialize_field(b)?;
+                }
+                state.end()
+            }
+        }
+
+        impl<'de> Visitor<'de> for ElementsVisitor<$mask> {
+            type Value = $mask;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str(concat!("struct ", stringify!($mask)))
+            }
+
+            fn visit_seq<V>(self, mut seq: V) -> Result<$mask, V::Error>
+            where
+                V: SeqAccess<'de>,
+            {
+                let mut b = [false; $len];
+                for (i, b) in b.iter_mut().enumerate() {
+                    *b = seq
+                        .next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                }
+                let [$($field),+] = b;
+                Ok($mask::new($($field),+))
+            }
+
+            fn visit_map<A>(self, map: A) -> Result<$mask, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let [$($field),+] = visit_map_fields(map, VEC_FIELDS)?;
+                Ok($mask::new($($field),+))
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $mask {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserialize_any_form(deserializer, stringify!($mask), $len)
+            }
+        }
+
+        impl as_map::AsMap for $mask {
+            fn serialize_as_map<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let b: [bool; $len] = (*self).into();
+                serialize_vec_map(serializer, stringify!($mask), &b)
+            }
+
+            fn deserialize_as_map<'de, D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_struct(
+                    stringify!($mask),
+                    &VEC_FIELDS[..$len],
+                    ElementsVisitor::<Self>(PhantomData),
+                )
+            }
+        }
     };
 }
 
 #[cfg(test)]
 mod test_float {
+    extern crate std;
+    pub use std::{string::ToString, vec::Vec};
+
     pub const SX0: &str = "[]";
     pub const SX1: &str = "[1.0]";
     pub const SX2: &str = "[1.0,2.0]";
@@ -558,26 +1210,21 @@ mod test_int {
 mod f32 {
     #[cfg(test)]
     use super::test_float::*;
-    use crate::{Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
-    use core::fmt;
-    use serde::{
-        de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
-        ser::{Serialize, SerializeTupleStruct, Serializer},
-    };
+    use super::*;
+    use crate::{Mat2, Mat3, Mat3A, Mat4, Quat, Vec2, Vec3, Vec3A, Vec4};
+    use serde::{de::SeqAccess, ser::SerializeTupleStruct};
 
     impl_serde_float_types!(f32, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec4);
     impl_serde_vec3!(f32, Vec3A, test_vec3a_serde);
+    impl_serde_mat3!(f32, Mat3A, Vec3A, test_mat3a_serde);
 }
 
 mod f64 {
     #[cfg(test)]
     use super::test_float::*;
+    use super::*;
     use crate::{DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4};
-    use core::fmt;
-    use serde::{
-        de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
-        ser::{Serialize, SerializeTupleStruct, Serializer},
-    };
+    use serde::{de::SeqAccess, ser::SerializeTupleStruct};
 
     impl_serde_float_types!(f64, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4);
 }
@@ -585,12 +1232,9 @@ mod f64 {
 mod i32 {
     #[cfg(test)]
     use super::test_int::*;
+    use super::*;
     use crate::{IVec2, IVec3, IVec4};
-    use core::fmt;
-    use serde::{
-        de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
-        ser::{Serialize, SerializeTupleStruct, Serializer},
-    };
+    use serde::{de::SeqAccess, ser::SerializeTupleStruct};
 
     impl_serde_vec_types!(i32, IVec2, IVec3, IVec4);
 }
@@ -598,12 +1242,73 @@ mod i32 {
 mod u32 {
     #[cfg(test)]
     use super::test_int::*;
+    use super::*;
     use crate::{UVec2, UVec3, UVec4};
-    use core::fmt;
-    use serde::{
-        de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
-        ser::{Serialize, SerializeTupleStruct, Serializer},
-    };
+    use serde::{de::SeqAccess, ser::SerializeTupleStruct};
 
     impl_serde_vec_types!(u32, UVec2, UVec3, UVec4);
 }
+
+mod bool {
+    use super::*;
+    use crate::{BVec2, BVec3, BVec4};
+    #[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+    use crate::{BVec3A, BVec4A};
+    use serde::{de::SeqAccess, ser::SerializeTupleStruct};
+
+    impl_serde_vec_mask!(BVec2, 2, x, y);
+    impl_serde_vec_mask!(BVec3, 3, x, y, z);
+    impl_serde_vec_mask!(BVec4, 4, x, y, z, w);
+
+    #[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+    impl_serde_vec_mask!(BVec3A, 3, x, y, z);
+    #[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+    impl_serde_vec_mask!(BVec4A, 4, x, y, z, w);
+
+    #[test]
+    fn test_vec_mask_serde() {
+        let a = BVec3::new(true, false, true);
+        let serialized = serde_json::to_string(&a).unwrap();
+        assert_eq!(serialized, "[true,false,true]");
+        let deserialized: BVec3 = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(a, deserialized);
+        let deserialized: BVec3 = serde_json::from_str(r#"{"z":true,"x":true,"y":false}"#).unwrap();
+        assert_eq!(a, deserialized);
+        assert!(serde_json::from_str::<BVec3>("[true,false]").is_err());
+        assert!(serde_json::from_str::<BVec3>("[true,false,true,true]").is_err());
+        assert!(serde_json::from_str::<BVec2>(r#"{"x":true,"y":false,"z":true}"#).is_err());
+
+        let a = crate::BVec4A::new(true, false, false, true);
+        let serialized = serde_json::to_string(&a).unwrap();
+        assert_eq!(serialized, "[true,false,false,true]");
+        let deserialized: crate::BVec4A = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(a, deserialized);
+    }
+}
+
+#[cfg(feature = "transform-types")]
+mod transform {
+    #[test]
+    fn test_transform_serde() {
+        use crate::{Quat, TransformRT, TransformSRT, Vec3};
+        let a = TransformSRT::from_scale_rotation_translation(
+            Vec3::new(1.0, 2.0, 3.0),
+            Quat::IDENTITY,
+            Vec3::new(4.0, 5.0, 6.0),
+        );
+        let serialized = serde_json::to_string(&a).unwrap();
+        assert_eq!(
+            serialized,
+            r#"{"scale":[1.0,2.0,3.0],"rotation":[0.0,0.0,0.0,1.0],"translation":[4.0,5.0,6.0]}"#
+        );
+        let deserialized: TransformSRT = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(a, deserialized);
+
+        let a = TransformRT::from_rotation_translation(Quat::IDENTITY, Vec3::new(4.0, 5.0, 6.0));
+        let deserialized: TransformRT = serde_json::from_str(
+            r#"{"rotation":{"x":0.0,"y":0.0,"z":0.0,"w":1.0},"translation":{"x":4,"y":5,"z":6}}"#,
+        )
+        .unwrap();
+        assert_eq!(a, deserialized);
+    }
+}
@@ -1,11 +1,29 @@
+#[cfg(feature = "approx")]
+pub mod impl_approx;
+
+#[cfg(feature = "arbitrary")]
+pub mod impl_arbitrary;
+
 #[cfg(feature = "bytemuck")]
 pub mod impl_bytemuck;
 
 #[cfg(feature = "mint")]
 pub mod impl_mint;
 
+#[cfg(feature = "nalgebra")]
+pub mod impl_nalgebra;
+
+#[cfg(feature = "num-traits")]
+pub mod impl_num_traits;
+
+#[cfg(feature = "proptest")]
+pub mod impl_proptest;
+
 #[cfg(feature = "rand")]
 pub mod impl_rand;
 
+#[cfg(feature = "rkyv")]
+pub mod impl_rkyv;
+
 #[cfg(feature = "serde")]
 pub mod impl_serde;
@@ -0,0 +1,113 @@
+use crate::{DVec2, DVec3, DVec4, Vec2, Vec3, Vec3A, Vec4};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+/// A wrapper which gives float vectors a total order, equality and hashing, so they can be used
+/// as keys in a `HashMap` or `BTreeMap`.
+///
+/// Elements are compared by their bit patterns after canonicalizing `-0.0` to `0.0` and all `NaN`
+/// values to a single positive `NaN`, which sorts after positive infinity. Vectors are ordered
+/// lexicographically.
+///
+/// ```
+/// use glam::{FloatOrd, Vec2};
+/// let a = FloatOrd(Vec2::new(0.0, f32::NAN));
+/// let b = FloatOrd(Vec2::new(-0.0, -f32::NAN));
+/// assert_eq!(a, b);
+/// assert!(a > FloatOrd(Vec2::new(0.0, f32::INFINITY)));
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[repr(transparent)]
+pub struct FloatOrd<T>(pub T);
+
+trait TotalOrdBits {
+    type Bits: Ord + Hash;
+    /// Returns an integer which is equal for `-0.0` and `0.0` and for all `NaN` values and
+    /// otherwise orders the same as the float value.
+    fn total_ord_bits(self) -> Self::Bits;
+}
+
+macro_rules! impl_total_ord_bits {
+    ($t:ident, $bits:ty, $ubits:ty) => {
+        impl TotalOrdBits for $t {
+            type Bits = $bits;
+            #[inline]
+            fn total_ord_bits(self) -> $bits {
+                let v = if self.is_nan() {
+                    $t::NAN
+                } else if self == 0.0 {
+                    0.0
+                } else {
+                    self
+                };
+                // flip the magnitude bits of negative values so they order as integers
+                let bits = v.to_bits() as $bits;
+                bits ^ (((bits >> (<$bits>::BITS - 1)) as $ubits) >> 1) as $bits
+            }
+        }
+    };
+}
+
+impl_total_ord_bits!(f32, i32, u32);
+impl_total_ord_bits!(f64, i64, u64);
+
+macro_rules! impl_float_ord {
+    ($t:ty, $size:literal, $vecn:ident) => {
+        impl FloatOrd<$vecn> {
+            #[inline]
+            fn total_ord_bits(&self) -> [<$t as TotalOrdBits>::Bits; $size] {
+                let a: &[$t; $size] = self.0.as_ref();
+                let mut bits = [0; $size];
+                for (b, v) in bits.iter_mut().zip(a.iter()) {
+                    *b = v.total_ord_bits();
+                }
+                bits
+            }
+        }
+
+        impl PartialEq for FloatOrd<$vecn> {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.total_ord_bits() == other.total_ord_bits()
+            }
+        }
+
+        impl Eq for FloatOrd<$vecn> {}
+
+        impl Ord for FloatOrd<$vecn> {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.total_ord_bits().cmp(&other.total_ord_bits())
+            }
+        }
+
+        impl PartialOrd for FloatOrd<$vecn> {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Hash for FloatOrd<$vecn> {
+            #[inline]
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.total_ord_bits().hash(state);
+            }
+        }
+
+        impl From<$vecn> for FloatOrd<$vecn> {
+            #[inline(always)]
+            fn from(v: $vecn) -> Self {
+                Self(v)
+            }
+        }
+    };
+}
+
+impl_float_ord!(f32, 2, Vec2);
+impl_float_ord!(f32, 3, Vec3);
+impl_float_ord!(f32, 3, Vec3A);
+impl_float_ord!(f32, 4, Vec4);
+impl_float_ord!(f64, 2, DVec2);
+impl_float_ord!(f64, 3, DVec3);
+impl_float_ord!(f64, 4, DVec4);
@@ -0,0 +1,1664 @@
+/*!
+Traits implemented by the public vector, quaternion and matrix types.
+
+These traits make it possible to write code which is generic over the scalar type, for example a
+function which works with both `Vec3` and `DVec3`:
+
+```
+use glam::generic::{FloatScalar, FloatVec3};
+
+fn triangle_area<V: FloatVec3>(a: V, b: V, c: V) -> V::Scalar {
+    (b - a).cross(c - a).length() * V::Scalar::from_f32(0.5)
+}
+
+let area = triangle_area(glam::Vec3::zero(), glam::Vec3::X, glam::Vec3::Y);
+assert_eq!(area, 0.5_f32);
+
+let area = triangle_area(glam::DVec3::zero(), glam::DVec3::X, glam::DVec3::Y);
+assert_eq!(area, 0.5_f64);
+```
+
+The traits only forward to the inherent methods of each type so there is no runtime cost to using
+them. Methods are only added to the traits once they are available on all of the implementing
+types.
+*/
+
+use crate::core::traits::scalar::{Float, FloatEx};
+#[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+use crate::BVec4A;
+use crate::{
+    BVec2, BVec3, BVec3A, BVec4, DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, IVec2, IVec3,
+    IVec4, Mat2, Mat3, Mat3A, Mat4, Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
+};
+use core::ops::*;
+
+/// A primitive type that can be used as the element of a vector.
+pub trait Scalar:
+    Copy
+    + Clone
+    + Default
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + RemAssign
+{
+    /// The additive identity, `0`.
+    const ZERO: Self;
+    /// The multiplicative identity, `1`.
+    const ONE: Self;
+}
+
+/// A primitive floating point type that can be used as the element of a float vector,
+/// quaternion or matrix.
+pub trait FloatScalar: Scalar + Neg<Output = Self> {
+    /// Converts a `f32` to `Self`.
+    fn from_f32(v: f32) -> Self;
+    /// Converts a `f64` to `Self`, possibly losing precision.
+    fn from_f64(v: f64) -> Self;
+    /// Returns the absolute value of `self`.
+    fn abs(self) -> Self;
+    /// Returns the square root of `self`.
+    fn sqrt(self) -> Self;
+    /// Returns `1.0 / self`.
+    fn recip(self) -> Self;
+    /// Returns the sine of `self` in radians.
+    fn sin(self) -> Self;
+    /// Returns the sine and cosine of `self` in radians.
+    fn sin_cos(self) -> (Self, Self);
+    /// Returns the arccosine of `self` in radians.
+    fn acos(self) -> Self;
+    /// Returns `true` if `self` is neither infinite nor `NaN`.
+    fn is_finite(self) -> bool;
+    /// Returns `true` if `self` is `NaN`.
+    fn is_nan(self) -> bool;
+}
+
+macro_rules! impl_scalar {
+    ($t:ty, $zero:expr, $one:expr) => {
+        impl Scalar for $t {
+            const ZERO: Self = $zero;
+            const ONE: Self = $one;
+        }
+    };
+}
+
+impl_scalar!(f32, 0.0, 1.0);
+impl_scalar!(f64, 0.0, 1.0);
+impl_scalar!(i32, 0, 1);
+impl_scalar!(u32, 0, 1);
+
+macro_rules! impl_float_scalar {
+    ($t:ty) => {
+        impl FloatScalar for $t {
+            #[inline(always)]
+            fn from_f32(v: f32) -> Self {
+                FloatEx::from_f32(v)
+            }
+            #[inline(always)]
+            fn from_f64(v: f64) -> Self {
+                FloatEx::from_f64(v)
+            }
+            #[inline(always)]
+            fn abs(self) -> Self {
+                FloatEx::copysign(self, 1.0)
+            }
+            #[inline(always)]
+            fn sqrt(self) -> Self {
+                Float::sqrt(self)
+            }
+            #[inline(always)]
+            fn recip(self) -> Self {
+                Float::recip(self)
+            }
+            #[inline(always)]
+            fn sin(self) -> Self {
+                Float::sin(self)
+            }
+            #[inline(always)]
+            fn sin_cos(self) -> (Self, Self) {
+                Float::sin_cos(self)
+            }
+            #[inline(always)]
+            fn acos(self) -> Self {
+                Float::acos(self)
+            }
+            #[inline(always)]
+            fn is_finite(self) -> bool {
+                Float::is_finite(self)
+            }
+            #[inline(always)]
+            fn is_nan(self) -> bool {
+                Float::is_nan(self)
+            }
+        }
+    };
+}
+
+impl_float_scalar!(f32);
+impl_float_scalar!(f64);
+
+/// A vector mask, the result of comparing two vectors.
+pub trait BVecLike:
+    Copy
+    + Clone
+    + Default
+    + PartialEq
+    + Eq
+    + BitAnd<Output = Self>
+    + BitAndAssign
+    + BitOr<Output = Self>
+    + BitOrAssign
+    + Not<Output = Self>
+{
+    /// Returns a bitmask with the lowest bits set to the sign bits from the elements of `self`.
+    fn bitmask(self) -> u32;
+    /// Returns true if any of the elements are true, false otherwise.
+    fn any(self) -> bool;
+    /// Returns true if all the elements are true, false otherwise.
+    fn all(self) -> bool;
+}
+
+macro_rules! impl_bvec_like {
+    ($($mask:ident),+) => {
+        $(
+            impl BVecLike for $mask {
+                #[inline(always)]
+                fn bitmask(self) -> u32 {
+                    $mask::bitmask(self)
+                }
+                #[inline(always)]
+                fn any(self) -> bool {
+                    $mask::any(self)
+                }
+                #[inline(always)]
+                fn all(self) -> bool {
+                    $mask::all(self)
+                }
+            }
+        )+
+    };
+}
+
+impl_bvec_like!(BVec2, BVec3, BVec4);
+#[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+impl_bvec_like!(BVec3A, BVec4A);
+
+/// Methods common to all vector types.
+pub trait VecLike:
+    Copy
+    + Clone
+    + Default
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + AddAssign
+    + Sub<Output = Self>
+    + SubAssign
+    + Mul<Output = Self>
+    + MulAssign
+    + Mul<<Self as VecLike>::Scalar, Output = Self>
+    + MulAssign<<Self as VecLike>::Scalar>
+    + Div<Output = Self>
+    + DivAssign
+    + Div<<Self as VecLike>::Scalar, Output = Self>
+    + DivAssign<<Self as VecLike>::Scalar>
+    + Index<usize, Output = <Self as VecLike>::Scalar>
+    + IndexMut<usize>
+{
+    /// The element type.
+    type Scalar: Scalar;
+    /// The vector mask type returned by comparisons.
+    type Mask: BVecLike;
+    /// The number of elements in the vector.
+    const DIM: usize;
+
+    /// Creates a vector with all elements set to `0`.
+    fn zero() -> Self;
+    /// Creates a vector with all elements set to `1`.
+    fn one() -> Self;
+    /// Creates a vector with all elements set to `v`.
+    fn splat(v: Self::Scalar) -> Self;
+    /// Creates a vector from the elements in `if_true` and `if_false`, selecting which to use for
+    /// each element of `self`.
+    fn select(mask: Self::Mask, if_true: Self, if_false: Self) -> Self;
+    /// Computes the dot product of `self` and `other`.
+    fn dot(self, other: Self) -> Self::Scalar;
+    /// Returns a vector containing the minimum values for each element of `self` and `other`.
+    fn min(self, other: Self) -> Self;
+    /// Returns a vector containing the maximum values for each element of `self` and `other`.
+    fn max(self, other: Self) -> Self;
+    /// Component-wise clamping of values.
+    fn clamp(self, min: Self, max: Self) -> Self;
+    /// Returns the horizontal minimum of `self`.
+    fn min_element(self) -> Self::Scalar;
+    /// Returns the horizontal maximum of `self`.
+    fn max_element(self) -> Self::Scalar;
+    /// Returns the index of the first minimum element of `self`.
+    fn min_position(self) -> usize;
+    /// Returns the index of the first maximum element of `self`.
+    fn max_position(self) -> usize;
+    /// Returns the sum of all elements of `self`.
+    fn element_sum(self) -> Self::Scalar;
+    /// Returns the product of all elements of `self`.
+    fn element_product(self) -> Self::Scalar;
+    /// Returns the element-wise quotient of Euclidean division of `self` by `rhs`.
+    fn div_euclid(self, rhs: Self) -> Self;
+    /// Returns the element-wise remainder of Euclidean division of `self` by `rhs`.
+    fn rem_euclid(self, rhs: Self) -> Self;
+    /// Returns a vector mask containing the result of a `==` comparison for each element.
+    fn cmpeq(self, other: Self) -> Self::Mask;
+    /// Returns a vector mask containing the result of a `!=` comparison for each element.
+    fn cmpne(self, other: Self) -> Self::Mask;
+    /// Returns a vector mask containing the result of a `>=` comparison for each element.
+    fn cmpge(self, other: Self) -> Self::Mask;
+    /// Returns a vector mask containing the result of a `>` comparison for each element.
+    fn cmpgt(self, other: Self) -> Self::Mask;
+    /// Returns a vector mask containing the result of a `<=` comparison for each element.
+    fn cmple(self, other: Self) -> Self::Mask;
+    /// Returns a vector mask containing the result of a `<` comparison for each element.
+    fn cmplt(self, other: Self) -> Self::Mask;
+    /// Creates a vector from the first `DIM` values in `slice`.
+    fn from_slice_unaligned(slice: &[Self::Scalar]) -> Self;
+    /// Writes the elements of `self` to the first `DIM` elements in `slice`.
+    fn write_to_slice_unaligned(self, slice: &mut [Self::Scalar]);
+}
+
+/// Methods common to all signed vector types.
+pub trait SignedVecLike: VecLike + Neg<Output = Self> {
+    /// Returns a vector containing the absolute value of each element of `self`.
+    fn abs(self) -> Self;
+    /// Returns a vector with elements representing the sign of `self`.
+    fn signum(self) -> Self;
+}
+
+/// Methods common to all floating point vector types.
+pub trait FloatVecLike: SignedVecLike + VecLike<Scalar = <Self as FloatVecLike>::Float> {
+    /// The floating point element type, this is always the same as `Scalar`.
+    type Float: FloatScalar;
+
+    /// Returns `true` if, and only if, all elements are finite.
+    fn is_finite(self) -> bool;
+    /// Returns `true` if any elements are `NaN`.
+    fn is_nan(self) -> bool;
+    /// Computes the length of `self`.
+    fn length(self) -> Self::Scalar;
+    /// Computes the squared length of `self`.
+    fn length_squared(self) -> Self::Scalar;
+    /// Computes `1.0 / length()`.
+    fn length_recip(self) -> Self::Scalar;
+    /// Computes the Euclidean distance between two points in space.
+    fn distance(self, other: Self) -> Self::Scalar;
+    /// Compute the squared euclidean distance between two points in space.
+    fn distance_squared(self, other: Self) -> Self::Scalar;
+    /// Returns `self` normalized to length 1.0.
+    fn normalize(self) -> Self;
+    /// Returns `self` normalized to length 1.0 if possible, else returns `None`.
+    fn try_normalize(self) -> Option<Self>;
+    /// Returns `self` normalized to length 1.0 if possible, else returns zero.
+    fn normalize_or_zero(self) -> Self;
+    /// Returns whether `self` is length `1.0` or not.
+    fn is_normalized(self) -> bool;
+    /// Returns a vector containing the nearest integer to each element of `self`.
+    fn round(self) -> Self;
+    /// Returns a vector containing the largest integer less than or equal to each element.
+    fn floor(self) -> Self;
+    /// Returns a vector containing the smallest integer greater than or equal to each element.
+    fn ceil(self) -> Self;
+    /// Returns a vector containing the integer part of each element of `self`.
+    fn trunc(self) -> Self;
+    /// Returns a vector containing `self - self.floor()`.
+    fn fract(self) -> Self;
+    /// Returns a vector containing the square root of each element of `self`.
+    fn sqrt(self) -> Self;
+    /// Returns a vector containing the reciprocal `1.0/n` of each element of `self`.
+    fn recip(self) -> Self;
+    /// Performs a linear interpolation between `self` and `other` based on the value `s`.
+    fn lerp(self, other: Self, s: Self::Scalar) -> Self;
+    /// Returns true if the absolute difference of all elements between `self` and `other` is
+    /// less than or equal to `max_abs_diff`.
+    fn abs_diff_eq(self, other: Self, max_abs_diff: Self::Scalar) -> bool;
+    /// Returns true if the difference of all elements between `self` and `other` is less than
+    /// or equal to `max_abs_diff` or to `max_relative` times the larger magnitude.
+    fn relative_eq(
+        self,
+        other: Self,
+        max_abs_diff: Self::Scalar,
+        max_relative: Self::Scalar,
+    ) -> bool;
+    /// Returns true if all elements of `self` and `other` are within `max_abs_diff` of each other
+    /// or at most `max_ulps` representable values apart.
+    fn ulps_eq(self, other: Self, max_abs_diff: Self::Scalar, max_ulps: u32) -> bool;
+    /// Returns the reflection of `self` about a plane with the given `normal`.
+    fn reflect(self, normal: Self) -> Self;
+    /// Returns the vector projection of `self` onto `other`.
+    fn project_onto(self, other: Self) -> Self;
+    /// Returns the vector rejection of `self` from `other`.
+    fn reject_from(self, other: Self) -> Self;
+}
+
+/// Methods common to all 2D vector types.
+pub trait Vec2Like: VecLike {
+    /// The 3D vector type with the same element type.
+    type Vec3: Vec3Like<Scalar = Self::Scalar>;
+
+    /// Creates a new vector.
+    fn new(x: Self::Scalar, y: Self::Scalar) -> Self;
+    /// Creates a new vector from an array.
+    fn from_array(a: [Self::Scalar; 2]) -> Self;
+    /// Returns the elements of `self` as an array.
+    fn to_array(self) -> [Self::Scalar; 2];
+    /// Creates a 3D vector from `self` and the given `z` value.
+    fn extend(self, z: Self::Scalar) -> Self::Vec3;
+}
+
+/// Methods common to all 3D vector types.
+pub trait Vec3Like: VecLike {
+    /// The 2D vector type with the same element type.
+    type Vec2: Vec2Like<Scalar = Self::Scalar>;
+    /// The 4D vector type with the same element type.
+    type Vec4: Vec4Like<Scalar = Self::Scalar>;
+
+    /// Creates a new vector.
+    fn new(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar) -> Self;
+    /// Creates a new vector from an array.
+    fn from_array(a: [Self::Scalar; 3]) -> Self;
+    /// Returns the elements of `self` as an array.
+    fn to_array(self) -> [Self::Scalar; 3];
+    /// Creates a 4D vector from `self` and the given `w` value.
+    fn extend(self, w: Self::Scalar) -> Self::Vec4;
+    /// Creates a 2D vector from the `x` and `y` elements of `self`, discarding `z`.
+    fn truncate(self) -> Self::Vec2;
+}
+
+/// Methods common to all 4D vector types.
+pub trait Vec4Like: VecLike {
+    /// The 3D vector type with the same element type.
+    type Vec3: Vec3Like<Scalar = Self::Scalar>;
+
+    /// Creates a new vector.
+    fn new(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar, w: Self::Scalar) -> Self;
+    /// Creates a new vector from an array.
+    fn from_array(a: [Self::Scalar; 4]) -> Self;
+    /// Returns the elements of `self` as an array.
+    fn to_array(self) -> [Self::Scalar; 4];
+    /// Creates a 3D vector from the `x`, `y` and `z` elements of `self`, discarding `w`.
+    fn truncate(self) -> Self::Vec3;
+}
+
+/// Methods common to all 2D floating point vector types.
+pub trait FloatVec2: FloatVecLike + Vec2Like {
+    /// Returns a vector that is equal to `self` rotated by 90 degrees.
+    fn perp(self) -> Self;
+    /// The perpendicular dot product of `self` and `other`.
+    fn perp_dot(self, other: Self) -> Self::Scalar;
+    /// Returns the angle (in radians) between `self` and `other`.
+    fn angle_between(self, other: Self) -> Self::Scalar;
+}
+
+/// Methods common to all 3D floating point vector types.
+pub trait FloatVec3: FloatVecLike + Vec3Like {
+    /// Computes the cross product of `self` and `other`.
+    fn cross(self, other: Self) -> Self;
+    /// Returns the angle (in radians) between `self` and `other`.
+    fn angle_between(self, other: Self) -> Self::Scalar;
+    /// Returns some unit vector that is orthogonal to `self`.
+    fn any_orthonormal_vector(self) -> Self;
+    /// Returns two unit vectors that are orthogonal to `self` and to each other.
+    fn any_orthonormal_pair(self) -> (Self, Self);
+}
+
+/// Methods common to all 4D floating point vector types.
+pub trait FloatVec4: FloatVecLike + Vec4Like {}
+
+macro_rules! impl_vec_like {
+    ($t:ty, $vecn:ident, $mask:ident, $dim:expr) => {
+        impl VecLike for $vecn {
+            type Scalar = $t;
+            type Mask = $mask;
+            const DIM: usize = $dim;
+
+            #[inline(always)]
+            fn zero() -> Self {
+                $vecn::zero()
+            }
+            #[inline(always)]
+            fn one() -> Self {
+                $vecn::one()
+            }
+            #[inline(always)]
+            fn splat(v: $t) -> Self {
+                $vecn::splat(v)
+            }
+            #[inline(always)]
+            fn select(mask: $mask, if_true: Self, if_false: Self) -> Self {
+                $vecn::select(mask, if_true, if_false)
+            }
+            #[inline(always)]
+            fn dot(self, other: Self) -> $t {
+                $vecn::dot(self, other)
+            }
+            #[inline(always)]
+            fn min(self, other: Self) -> Self {
+                $vecn::min(self, other)
+            }
+            #[inline(always)]
+            fn max(self, other: Self) -> Self {
+                $vecn::max(self, other)
+            }
+            #[inline(always)]
+            fn clamp(self, min: Self, max: Self) -> Self {
+                $vecn::clamp(self, min, max)
+            }
+            #[inline(always)]
+            fn min_element(self) -> $t {
+                $vecn::min_element(self)
+            }
+            #[inline(always)]
+            fn max_element(self) -> $t {
+                $vecn::max_element(self)
+            }
+            #[inline(always)]
+            fn min_position(self) -> usize {
+                $vecn::min_position(self)
+            }
+            #[inline(always)]
+            fn max_position(self) -> usize {
+                $vecn::max_position(self)
+            }
+            #[inline(always)]
+            fn element_sum(self) -> $t {
+                $vecn::element_sum(self)
+            }
+            #[inline(always)]
+            fn element_product(self) -> $t {
+                $vecn::element_product(self)
+            }
+            #[inline(always)]
+            fn div_euclid(self, rhs: Self) -> Self {
+                $vecn::div_euclid(self, rhs)
+            }
+            #[inline(always)]
+            fn rem_euclid(self, rhs: Self) -> Self {
+                $vecn::rem_euclid(self, rhs)
+            }
+            #[inline(always)]
+            fn cmpeq(self, other: Self) -> $mask {
+                $vecn::cmpeq(self, other)
+            }
+            #[inline(always)]
+            fn cmpne(self, other: Self) -> $mask {
+                $vecn::cmpne(self, other)
+            }
+            #[inline(always)]
+            fn cmpge(self, other: Self) -> $mask {
+                $vecn::cmpge(self, other)
+            }
+            #[inline(always)]
+            fn cmpgt(self, other: Self) -> $mask {
+                $vecn::cmpgt(self, other)
+            }
+            #[inline(always)]
+            fn cmple(self, other: Self) -> $mask {
+                $vecn::cmple(self, other)
+            }
+            #[inline(always)]
+            fn cmplt(self, other: Self) -> $mask {
+                $vecn::cmplt(self, other)
+            }
+            #[inline(always)]
+            fn from_slice_unaligned(slice: &[$t]) -> Self {
+                $vecn::from_slice_unaligned(slice)
+            }
+            #[inline(always)]
+            fn write_to_slice_unaligned(self, slice: &mut [$t]) {
+                $vecn::write_to_slice_unaligned(self, slice)
+            }
+        }
+    };
+}
+
+macro_rules! impl_signed_vec_like {
+    ($t:ty, $vecn:ident, $mask:ident, $dim:expr) => {
+        impl_vec_like!($t, $vecn, $mask, $dim);
+
+        impl SignedVecLike for $vecn {
+            #[inline(always)]
+            fn abs(self) -> Self {
+                $vecn::abs(self)
+            }
+            #[inline(always)]
+            fn signum(self) -> Self {
+                $vecn::signum(self)
+            }
+        }
+    };
+}
+
+macro_rules! impl_float_vec_like {
+    ($t:ty, $vecn:ident, $mask:ident, $dim:expr) => {
+        impl_signed_vec_like!($t, $vecn, $mask, $dim);
+
+        impl FloatVecLike for $vecn {
+            type Float = $t;
+
+            #[inline(always)]
+            fn is_finite(self) -> bool {
+                $vecn::is_finite(self)
+            }
+            #[inline(always)]
+            fn is_nan(self) -> bool {
+                $vecn::is_nan(self)
+            }
+            #[inline(always)]
+            fn length(self) -> $t {
+                $vecn::length(self)
+            }
+            #[inline(always)]
+            fn length_squared(self) -> $t {
+                $vecn::length_squared(self)
+            }
+            #[inline(always)]
+            fn length_recip(self) -> $t {
+                $vecn::length_recip(self)
+            }
+            #[inline(always)]
+            fn distance(self, other: Self) -> $t {
+                $vecn::distance(self, other)
+            }
+            #[inline(always)]
+            fn distance_squared(self, other: Self) -> $t {
+                $vecn::distance_squared(self, other)
+            }
+            #[inline(always)]
+            fn normalize(self) -> Self {
+                $vecn::normalize(self)
+            }
+            #[inline(always)]
+            fn try_normalize(self) -> Option<Self> {
+                $vecn::try_normalize(self)
+            }
+            #[inline(always)]
+            fn normalize_or_zero(self) -> Self {
+                $vecn::normalize_or_zero(self)
+            }
+            #[inline(always)]
+            fn is_normalized(self) -> bool {
+                $vecn::is_normalized(self)
+            }
+            #[inline(always)]
+            fn round(self) -> Self {
+                $vecn::round(self)
+            }
+            #[inline(always)]
+            fn floor(self) -> Self {
+                $vecn::floor(self)
+            }
+            #[inline(always)]
+            fn ceil(self) -> Self {
+                $vecn::ceil(self)
+            }
+            #[inline(always)]
+            fn trunc(self) -> Self {
+                $vecn::trunc(self)
+            }
+            #[inline(always)]
+            fn fract(self) -> Self {
+                $vecn::fract(self)
+            }
+            #[inline(always)]
+            fn sqrt(self) -> Self {
+                $vecn::sqrt(self)
+            }
+            #[inline(always)]
+            fn recip(self) -> Self {
+                $vecn::recip(self)
+            }
+            #[inline(always)]
+            fn lerp(self, other: Self, s: $t) -> Self {
+                $vecn::lerp(self, other, s)
+            }
+            #[inline(always)]
+            fn abs_diff_eq(self, other: Self, max_abs_diff: $t) -> bool {
+                $vecn::abs_diff_eq(self, other, max_abs_diff)
+            }
+            #[inline(always)]
+            fn relative_eq(self, other: Self, max_abs_diff: $t, max_relative: $t) -> bool {
+                $vecn::relative_eq(self, other, max_abs_diff, max_relative)
+            }
+            #[inline(always)]
+            fn ulps_eq(self, other: Self, max_abs_diff: $t, max_ulps: u32) -> bool {
+                $vecn::ulps_eq(self, other, max_abs_diff, max_ulps)
+            }
+            #[inline(always)]
+            fn reflect(self, normal: Self) -> Self {
+                $vecn::reflect(self, normal)
+            }
+            #[inline(always)]
+            fn project_onto(self, other: Self) -> Self {
+                $vecn::project_onto(self, other)
+            }
+            #[inline(always)]
+            fn reject_from(self, other: Self) -> Self {
+                $vecn::reject_from(self, other)
+            }
+        }
+    };
+}
+
+macro_rules! impl_vec2_like {
+    ($t:ty, $vec2:ident, $vec3:ident) => {
+        impl Vec2Like for $vec2 {
+            type Vec3 = $vec3;
+
+            #[inline(always)]
+            fn new(x: $t, y: $t) -> Self {
+                $vec2::new(x, y)
+            }
+            #[inline(always)]
+            fn from_array(a: [$t; 2]) -> Self {
+                Self::from(a)
+            }
+            #[inline(always)]
+            fn to_array(self) -> [$t; 2] {
+                self.into()
+            }
+            #[inline(always)]
+            fn extend(self, z: $t) -> $vec3 {
+                $vec2::extend(self, z)
+            }
+        }
+    };
+}
+
+macro_rules! impl_vec3_like {
+    ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident) => {
+        impl Vec3Like for $vec3 {
+            type Vec2 = $vec2;
+            type Vec4 = $vec4;
+
+            #[inline(always)]
+            fn new(x: $t, y: $t, z: $t) -> Self {
+                $vec3::new(x, y, z)
+            }
+            #[inline(always)]
+            fn from_array(a: [$t; 3]) -> Self {
+                Self::from(a)
+            }
+            #[inline(always)]
+            fn to_array(self) -> [$t; 3] {
+                self.into()
+            }
+            #[inline(always)]
+            fn extend(self, w: $t) -> $vec4 {
+                $vec3::extend(self, w)
+            }
+            #[inline(always)]
+            fn truncate(self) -> $vec2 {
+                $vec3::truncate(self)
+            }
+        }
+    };
+}
+
+macro_rules! impl_vec4_like {
+    ($t:ty, $vec3:ident, $vec4:ident) => {
+        impl Vec4Like for $vec4 {
+            type Vec3 = $vec3;
+
+            #[inline(always)]
+            fn new(x: $t, y: $t, z: $t, w: $t) -> Self {
+                $vec4::new(x, y, z, w)
+            }
+            #[inline(always)]
+            fn from_array(a: [$t; 4]) -> Self {
+                Self::from(a)
+            }
+            #[inline(always)]
+            fn to_array(self) -> [$t; 4] {
+                self.into()
+            }
+            #[inline(always)]
+            fn truncate(self) -> $vec3 {
+                $vec4::truncate(self)
+            }
+        }
+    };
+}
+
+macro_rules! impl_float_vec2 {
+    ($t:ty, $vec2:ident, $vec3:ident, $mask:ident) => {
+        impl_float_vec_like!($t, $vec2, $mask, 2);
+        impl_vec2_like!($t, $vec2, $vec3);
+
+        impl FloatVec2 for $vec2 {
+            #[inline(always)]
+            fn perp(self) -> Self {
+                $vec2::perp(self)
+            }
+            #[inline(always)]
+            fn perp_dot(self, other: Self) -> $t {
+                $vec2::perp_dot(self, other)
+            }
+            #[inline(always)]
+            fn angle_between(self, other: Self) -> $t {
+                $vec2::angle_between(self, other)
+            }
+        }
+    };
+}
+
+macro_rules! impl_float_vec3 {
+    ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident, $mask:ident) => {
+        impl_float_vec_like!($t, $vec3, $mask, 3);
+        impl_vec3_like!($t, $vec2, $vec3, $vec4);
+
+        impl FloatVec3 for $vec3 {
+            #[inline(always)]
+            fn cross(self, other: Self) -> Self {
+                $vec3::cross(self, other)
+            }
+            #[inline(always)]
+            fn angle_between(self, other: Self) -> $t {
+                $vec3::angle_between(self, other)
+            }
+            #[inline(always)]
+            fn any_orthonormal_vector(self) -> Self {
+                $vec3::any_orthonormal_vector(self)
+            }
+            #[inline(always)]
+            fn any_orthonormal_pair(self) -> (Self, Self) {
+                $vec3::any_orthonormal_pair(self)
+            }
+        }
+    };
+}
+
+macro_rules! impl_float_vec4 {
+    ($t:ty, $vec3:ident, $vec4:ident, $mask:ident) => {
+        impl_float_vec_like!($t, $vec4, $mask, 4);
+        impl_vec4_like!($t, $vec3, $vec4);
+
+        impl FloatVec4 for $vec4 {}
+    };
+}
+
+impl_float_vec2!(f32, Vec2, Vec3, BVec2);
+impl_float_vec3!(f32, Vec2, Vec3, Vec4, BVec3);
+#[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
+impl_float_vec4!(f32, Vec3, Vec4, BVec4);
+#[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+impl_float_vec4!(f32, Vec3, Vec4, BVec4A);
+impl_float_vec_like!(f32, Vec3A, BVec3A, 3);
+impl_vec3_like!(f32, Vec2, Vec3A, Vec4);
+impl FloatVec3 for Vec3A {
+    #[inline(always)]
+    fn cross(self, other: Self) -> Self {
+        Vec3A::cross(self, other)
+    }
+    #[inline(always)]
+    fn angle_between(self, other: Self) -> f32 {
+        Vec3A::angle_between(self, other)
+    }
+    #[inline(always)]
+    fn any_orthonormal_vector(self) -> Self {
+        Vec3A::any_orthonormal_vector(self)
+    }
+    #[inline(always)]
+    fn any_orthonormal_pair(self) -> (Self, Self) {
+        Vec3A::any_orthonormal_pair(self)
+    }
+}
+
+impl_float_vec2!(f64, DVec2, DVec3, BVec2);
+impl_float_vec3!(f64, DVec2, DVec3, DVec4, BVec3);
+impl_float_vec4!(f64, DVec3, DVec4, BVec4);
+
+impl_signed_vec_like!(i32, IVec2, BVec2, 2);
+impl_vec2_like!(i32, IVec2, IVec3);
+impl_signed_vec_like!(i32, IVec3, BVec3, 3);
+impl_vec3_like!(i32, IVec2, IVec3, IVec4);
+impl_signed_vec_like!(i32, IVec4, BVec4, 4);
+impl_vec4_like!(i32, IVec3, IVec4);
+
+impl_vec_like!(u32, UVec2, BVec2, 2);
+impl_vec2_like!(u32, UVec2, UVec3);
+impl_vec_like!(u32, UVec3, BVec3, 3);
+impl_vec3_like!(u32, UVec2, UVec3, UVec4);
+impl_vec_like!(u32, UVec4, BVec4, 4);
+impl_vec4_like!(u32, UVec3, UVec4);
+
+/// Methods common to all quaternion types.
+pub trait QuatLike:
+    Copy
+    + Clone
+    + Default
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + MulAssign
+    + Mul<<Self as QuatLike>::Scalar, Output = Self>
+    + Div<<Self as QuatLike>::Scalar, Output = Self>
+    + Mul<<Self as QuatLike>::Vec3, Output = <Self as QuatLike>::Vec3>
+    + Neg<Output = Self>
+{
+    /// The floating point element type.
+    type Scalar: FloatScalar;
+    /// The 3D vector type with the same element type.
+    type Vec3: FloatVec3<Scalar = Self::Scalar>;
+
+    /// The identity quaternion. Corresponds to no rotation.
+    fn identity() -> Self;
+    /// Creates a new rotation quaternion from its elements.
+    fn from_xyzw(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar, w: Self::Scalar) -> Self;
+    /// Create a quaternion for a normalized rotation `axis` and `angle` (in radians).
+    fn from_axis_angle(axis: Self::Vec3, angle: Self::Scalar) -> Self;
+    /// Creates a quaternion from the `angle` (in radians) around the x axis.
+    fn from_rotation_x(angle: Self::Scalar) -> Self;
+    /// Creates a quaternion from the `angle` (in radians) around the y axis.
+    fn from_rotation_y(angle: Self::Scalar) -> Self;
+    /// Creates a quaternion from the `angle` (in radians) around the z axis.
+    fn from_rotation_z(angle: Self::Scalar) -> Self;
+    /// Create a quaternion from the given yaw (around y), pitch (around x) and roll (around z)
+    /// in radians.
+    fn from_rotation_ypr(yaw: Self::Scalar, pitch: Self::Scalar, roll: Self::Scalar) -> Self;
+    /// Returns the rotation axis and angle of `self`.
+    fn to_axis_angle(self) -> (Self::Vec3, Self::Scalar);
+    /// Returns the quaternion conjugate of `self`.
+    fn conjugate(self) -> Self;
+    /// Computes the dot product of `self` and `other`.
+    fn dot(self, other: Self) -> Self::Scalar;
+    /// Computes the length of `self`.
+    fn length(self) -> Self::Scalar;
+    /// Computes the squared length of `self`.
+    fn length_squared(self) -> Self::Scalar;
+    /// Returns `self` normalized to length 1.0.
+    fn normalize(self) -> Self;
+    /// Returns `true` if, and only if, all elements are finite.
+    fn is_finite(self) -> bool;
+    /// Returns `true` if any elements are `NaN`.
+    fn is_nan(self) -> bool;
+    /// Returns whether `self` is of length `1.0` or not.
+    fn is_normalized(self) -> bool;
+    /// Returns true if the absolute difference of all elements between `self` and `other` is
+    /// less than or equal to `max_abs_diff`.
+    fn abs_diff_eq(self, other: Self, max_abs_diff: Self::Scalar) -> bool;
+    /// Returns true if the difference of all elements between `self` and `other` is less than
+    /// or equal to `max_abs_diff` or to `max_relative` times the larger magnitude.
+    fn relative_eq(
+        self,
+        other: Self,
+        max_abs_diff: Self::Scalar,
+        max_relative: Self::Scalar,
+    ) -> bool;
+    /// Returns true if all elements of `self` and `other` are within `max_abs_diff` of each other
+    /// or at most `max_ulps` representable values apart.
+    fn ulps_eq(self, other: Self, max_abs_diff: Self::Scalar, max_ulps: u32) -> bool;
+    /// Performs a linear interpolation between `self` and `end` based on the value `s`.
+    fn lerp(self, end: Self, s: Self::Scalar) -> Self;
+    /// Performs a spherical linear interpolation between `self` and `end` based on the value
+    /// `s`.
+    fn slerp(self, end: Self, s: Self::Scalar) -> Self;
+    /// Multiplies a quaternion and a 3D vector, returning the rotated vector.
+    fn mul_vec3(self, other: Self::Vec3) -> Self::Vec3;
+    /// Multiplies two quaternions.
+    fn mul_quat(self, other: Self) -> Self;
+}
+
+macro_rules! impl_quat_like {
+    ($t:ty, $quat:ident, $vec3:ident) => {
+        impl QuatLike for $quat {
+            type Scalar = $t;
+            type Vec3 = $vec3;
+
+            #[inline(always)]
+            fn identity() -> Self {
+                $quat::identity()
+            }
+            #[inline(always)]
+            fn from_xyzw(x: $t, y: $t, z: $t, w: $t) -> Self {
+                $quat::from_xyzw(x, y, z, w)
+            }
+            #[inline(always)]
+            fn from_axis_angle(axis: $vec3, angle: $t) -> Self {
+                $quat::from_axis_angle(axis, angle)
+            }
+            #[inline(always)]
+            fn from_rotation_x(angle: $t) -> Self {
+                $quat::from_rotation_x(angle)
+            }
+            #[inline(always)]
+            fn from_rotation_y(angle: $t) -> Self {
+                $quat::from_rotation_y(angle)
+            }
+            #[inline(always)]
+            fn from_rotation_z(angle: $t) -> Self {
+                $quat::from_rotation_z(angle)
+            }
+            #[inline(always)]
+            fn from_rotation_ypr(yaw: $t, pitch: $t, roll: $t) -> Self {
+                $quat::from_rotation_ypr(yaw, pitch, roll)
+            }
+            #[inline(always)]
+            fn to_axis_angle(self) -> ($vec3, $t) {
+                $quat::to_axis_angle(self)
+            }
+            #[inline(always)]
+            fn conjugate(self) -> Self {
+                $quat::conjugate(self)
+            }
+            #[inline(always)]
+            fn dot(self, other: Self) -> $t {
+                $quat::dot(self, other)
+            }
+            #[inline(always)]
+            fn length(self) -> $t {
+                $quat::length(self)
+            }
+            #[inline(always)]
+            fn length_squared(self) -> $t {
+                $quat::length_squared(self)
+            }
+            #[inline(always)]
+            fn normalize(self) -> Self {
+                $quat::normalize(self)
+            }
+            #[inline(always)]
+            fn is_finite(self) -> bool {
+                $quat::is_finite(self)
+            }
+            #[inline(always)]
+            fn is_nan(self) -> bool {
+                $quat::is_nan(self)
+            }
+            #[inline(always)]
+            fn is_normalized(self) -> bool {
+                $quat::is_normalized(self)
+            }
+            #[inline(always)]
+            fn abs_diff_eq(self, other: Self, max_abs_diff: $t) -> bool {
+                $quat::abs_diff_eq(self, other, max_abs_diff)
+            }
+            #[inline(always)]
+            fn relative_eq(self, other: Self, max_abs_diff: $t, max_relative: $t) -> bool {
+                $quat::relative_eq(self, other, max_abs_diff, max_relative)
+            }
+            #[inline(always)]
+            fn ulps_eq(self, other: Self, max_abs_diff: $t, max_ulps: u32) -> bool {
+                $quat::ulps_eq(self, other, max_abs_diff, max_ulps)
+            }
+            #[inline(always)]
+            fn lerp(self, end: Self, s: $t) -> Self {
+                $quat::lerp(self, end, s)
+            }
+            #[inline(always)]
+            fn slerp(self, end: Self, s: $t) -> Self {
+                $quat::slerp(self, end, s)
+            }
+            #[inline(always)]
+            fn mul_vec3(self, other: $vec3) -> $vec3 {
+                $quat::mul_vec3(self, other)
+            }
+            #[inline(always)]
+            fn mul_quat(self, other: Self) -> Self {
+                $quat::mul_quat(self, other)
+            }
+        }
+    };
+}
+
+impl_quat_like!(f32, Quat, Vec3);
+impl_quat_like!(f64, DQuat, DVec3);
+
+/// Methods common to all 2x2 matrix types.
+pub trait Mat2Like:
+    Copy
+    + Clone
+    + Default
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Mul<<Self as Mat2Like>::Scalar, Output = Self>
+    + Mul<<Self as Mat2Like>::Vec2, Output = <Self as Mat2Like>::Vec2>
+{
+    /// The floating point element type.
+    type Scalar: FloatScalar;
+    /// The 2D vector type with the same element type.
+    type Vec2: FloatVec2<Scalar = Self::Scalar>;
+
+    /// Creates a 2x2 matrix with all elements set to `0.0`.
+    fn zero() -> Self;
+    /// Creates a 2x2 identity matrix.
+    fn identity() -> Self;
+    /// Creates a 2x2 matrix from two column vectors.
+    fn from_cols(x_axis: Self::Vec2, y_axis: Self::Vec2) -> Self;
+    /// Creates a 2x2 matrix from a `[S; 4]` stored in column major order.
+    fn from_cols_array(m: &[Self::Scalar; 4]) -> Self;
+    /// Creates a `[S; 4]` storing data in column major order.
+    fn to_cols_array(&self) -> [Self::Scalar; 4];
+    /// Creates a 2x2 matrix containing the given non-uniform `scale` and rotation of `angle`
+    /// (in radians).
+    fn from_scale_angle(scale: Self::Vec2, angle: Self::Scalar) -> Self;
+    /// Creates a 2x2 matrix containing a rotation of `angle` (in radians).
+    fn from_angle(angle: Self::Scalar) -> Self;
+    /// Creates a 2x2 matrix containing the given non-uniform `scale`.
+    fn from_scale(scale: Self::Vec2) -> Self;
+    /// Returns `true` if, and only if, all elements are finite.
+    fn is_finite(&self) -> bool;
+    /// Returns `true` if any elements are `NaN`.
+    fn is_nan(&self) -> bool;
+    /// Returns the transpose of `self`.
+    fn transpose(&self) -> Self;
+    /// Returns the determinant of `self`.
+    fn determinant(&self) -> Self::Scalar;
+    /// Returns the inverse of `self`.
+    fn inverse(&self) -> Self;
+    /// Transforms a 2D vector.
+    fn mul_vec2(&self, other: Self::Vec2) -> Self::Vec2;
+    /// Multiplies two 2x2 matrices.
+    fn mul_mat2(&self, other: &Self) -> Self;
+    /// Returns true if the absolute difference of all elements between `self` and `other` is
+    /// less than or equal to `max_abs_diff`.
+    fn abs_diff_eq(&self, other: &Self, max_abs_diff: Self::Scalar) -> bool;
+    /// Returns true if the difference of all elements between `self` and `other` is less than
+    /// or equal to `max_abs_diff` or to `max_relative` times the larger magnitude.
+    fn relative_eq(
+        &self,
+        other: &Self,
+        max_abs_diff: Self::Scalar,
+        max_relative: Self::Scalar,
+    ) -> bool;
+    /// Returns true if all elements of `self` and `other` are within `max_abs_diff` of each other
+    /// or at most `max_ulps` representable values apart.
+    fn ulps_eq(&self, other: &Self, max_abs_diff: Self::Scalar, max_ulps: u32) -> bool;
+}
+
+/// Methods common to all 3x3 matrix types.
+pub trait Mat3Like:
+    Copy
+    + Clone
+    + Default
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Mul<<Self as Mat3Like>::Scalar, Output = Self>
+    + Mul<<Self as Mat3Like>::Vec3, Output = <Self as Mat3Like>::Vec3>
+{
+    /// The floating point element type.
+    type Scalar: FloatScalar;
+    /// The 2D vector type with the same element type.
+    type Vec2: FloatVec2<Scalar = Self::Scalar>;
+    /// The 3D vector type with the same element type.
+    type Vec3: FloatVec3<Scalar = Self::Scalar>;
+    /// The quaternion type with the same element type.
+    type Quat: QuatLike<Scalar = Self::Scalar>;
+
+    /// Creates a 3x3 matrix with all elements set to `0.0`.
+    fn zero() -> Self;
+    /// Creates a 3x3 identity matrix.
+    fn identity() -> Self;
+    /// Creates a 3x3 matrix from three column vectors.
+    fn from_cols(x_axis: Self::Vec3, y_axis: Self::Vec3, z_axis: Self::Vec3) -> Self;
+    /// Creates a 3x3 matrix from a `[S; 9]` stored in column major order.
+    fn from_cols_array(m: &[Self::Scalar; 9]) -> Self;
+    /// Creates a `[S; 9]` storing data in column major order.
+    fn to_cols_array(&self) -> [Self::Scalar; 9];
+    /// Creates a 3x3 homogeneous transformation matrix from the given `scale`, rotation
+    /// `angle` (in radians) and `translation`.
+    fn from_scale_angle_translation(
+        scale: Self::Vec2,
+        angle: Self::Scalar,
+        translation: Self::Vec2,
+    ) -> Self;
+    /// Creates a 3x3 rotation matrix from the given quaternion.
+    fn from_quat(rotation: Self::Quat) -> Self;
+    /// Creates a 3x3 rotation matrix from a normalized rotation `axis` and `angle` (in radians).
+    fn from_axis_angle(axis: Self::Vec3, angle: Self::Scalar) -> Self;
+    /// Creates a 3x3 rotation matrix from the `angle` (in radians) around the x axis.
+    fn from_rotation_x(angle: Self::Scalar) -> Self;
+    /// Creates a 3x3 rotation matrix from the `angle` (in radians) around the y axis.
+    fn from_rotation_y(angle: Self::Scalar) -> Self;
+    /// Creates a 3x3 rotation matrix from the `angle` (in radians) around the z axis.
+    fn from_rotation_z(angle: Self::Scalar) -> Self;
+    /// Creates a 3x3 matrix containing the given non-uniform `scale`.
+    fn from_scale(scale: Self::Vec3) -> Self;
+    /// Returns `true` if, and only if, all elements are finite.
+    fn is_finite(&self) -> bool;
+    /// Returns `true` if any elements are `NaN`.
+    fn is_nan(&self) -> bool;
+    /// Returns the transpose of `self`.
+    fn transpose(&self) -> Self;
+    /// Returns the determinant of `self`.
+    fn determinant(&self) -> Self::Scalar;
+    /// Returns the inverse of `self`.
+    fn inverse(&self) -> Self;
+    /// Transforms a 3D vector.
+    fn mul_vec3(&self, other: Self::Vec3) -> Self::Vec3;
+    /// Multiplies two 3x3 matrices.
+    fn mul_mat3(&self, other: &Self) -> Self;
+    /// Transforms the given 2D vector as a point.
+    fn transform_point2(&self, other: Self::Vec2) -> Self::Vec2;
+    /// Rotates the given 2D vector.
+    fn transform_vector2(&self, other: Self::Vec2) -> Self::Vec2;
+    /// Returns true if the absolute difference of all elements between `self` and `other` is
+    /// less than or equal to `max_abs_diff`.
+    fn abs_diff_eq(&self, other: Self, max_abs_diff: Self::Scalar) -> bool;
+    /// Returns true if the difference of all elements between `self` and `other` is less than
+    /// or equal to `max_abs_diff` or to `max_relative` times the larger magnitude.
+    fn relative_eq(
+        &self,
+        other: Self,
+        max_abs_diff: Self::Scalar,
+        max_relative: Self::Scalar,
+    ) -> bool;
+    /// Returns true if all elements of `self` and `other` are within `max_abs_diff` of each other
+    /// or at most `max_ulps` representable values apart.
+    fn ulps_eq(&self, other: Self, max_abs_diff: Self::Scalar, max_ulps: u32) -> bool;
+}
+
+/// Methods common to all 4x4 matrix types.
+pub trait Mat4Like:
+    Copy
+    + Clone
+    + Default
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Mul<<Self as Mat4Like>::Scalar, Output = Self>
+    + Mul<<Self as Mat4Like>::Vec4, Output = <Self as Mat4Like>::Vec4>
+{
+    /// The floating point element type.
+    type Scalar: FloatScalar;
+    /// The 3D vector type with the same element type.
+    type Vec3: FloatVec3<Scalar = Self::Scalar>;
+    /// The 4D vector type with the same element type.
+    type Vec4: FloatVec4<Scalar = Self::Scalar>;
+    /// The quaternion type with the same element type.
+    type Quat: QuatLike<Scalar = Self::Scalar, Vec3 = Self::Vec3>;
+
+    /// Creates a 4x4 matrix with all elements set to `0.0`.
+    fn zero() -> Self;
+    /// Creates a 4x4 identity matrix.
+    fn identity() -> Self;
+    /// Creates a 4x4 matrix from four column vectors.
+    fn from_cols(
+        x_axis: Self::Vec4,
+        y_axis: Self::Vec4,
+        z_axis: Self::Vec4,
+        w_axis: Self::Vec4,
+    ) -> Self;
+    /// Creates a 4x4 matrix from a `[S; 16]` stored in column major order.
+    fn from_cols_array(m: &[Self::Scalar; 16]) -> Self;
+    /// Creates a `[S; 16]` storing data in column major order.
+    fn to_cols_array(&self) -> [Self::Scalar; 16];
+    /// Creates an affine transformation matrix from the given 3D `scale`, `rotation` and
+    /// `translation`.
+    fn from_scale_rotation_translation(
+        scale: Self::Vec3,
+        rotation: Self::Quat,
+        translation: Self::Vec3,
+    ) -> Self;
+    /// Creates an affine transformation matrix from the given 3D `translation`.
+    fn from_rotation_translation(rotation: Self::Quat, translation: Self::Vec3) -> Self;
+    /// Extracts `scale`, `rotation` and `translation` from `self`.
+    fn to_scale_rotation_translation(&self) -> (Self::Vec3, Self::Quat, Self::Vec3);
+    /// Creates an affine transformation matrix from the given `rotation` quaternion.
+    fn from_quat(rotation: Self::Quat) -> Self;
+    /// Creates an affine transformation matrix from the given 3D `translation`.
+    fn from_translation(translation: Self::Vec3) -> Self;
+    /// Creates an affine transformation matrix containing a 3D rotation around a normalized
+    /// rotation `axis` of `angle` (in radians).
+    fn from_axis_angle(axis: Self::Vec3, angle: Self::Scalar) -> Self;
+    /// Creates an affine transformation matrix containing a 3D rotation around the x axis of
+    /// `angle` (in radians).
+    fn from_rotation_x(angle: Self::Scalar) -> Self;
+    /// Creates an affine transformation matrix containing a 3D rotation around the y axis of
+    /// `angle` (in radians).
+    fn from_rotation_y(angle: Self::Scalar) -> Self;
+    /// Creates an affine transformation matrix containing a 3D rotation around the z axis of
+    /// `angle` (in radians).
+    fn from_rotation_z(angle: Self::Scalar) -> Self;
+    /// Creates an affine transformation matrix containing the given 3D non-uniform `scale`.
+    fn from_scale(scale: Self::Vec3) -> Self;
+    /// Returns `true` if, and only if, all elements are finite.
+    fn is_finite(&self) -> bool;
+    /// Returns `true` if any elements are `NaN`.
+    fn is_nan(&self) -> bool;
+    /// Returns the transpose of `self`.
+    fn transpose(&self) -> Self;
+    /// Returns the determinant of `self`.
+    fn determinant(&self) -> Self::Scalar;
+    /// Returns the inverse of `self`.
+    fn inverse(&self) -> Self;
+    /// Creates a left-handed view matrix using a camera position, an up direction, and a focal
+    /// point.
+    fn look_at_lh(eye: Self::Vec3, center: Self::Vec3, up: Self::Vec3) -> Self;
+    /// Creates a right-handed view matrix using a camera position, an up direction, and a focal
+    /// point.
+    fn look_at_rh(eye: Self::Vec3, center: Self::Vec3, up: Self::Vec3) -> Self;
+    /// Creates a left-handed perspective projection matrix with `[0,1]` depth range.
+    fn perspective_lh(
+        fov_y_radians: Self::Scalar,
+        aspect_ratio: Self::Scalar,
+        z_near: Self::Scalar,
+        z_far: Self::Scalar,
+    ) -> Self;
+    /// Creates a right-handed perspective projection matrix with `[0,1]` depth range.
+    fn perspective_rh(
+        fov_y_radians: Self::Scalar,
+        aspect_ratio: Self::Scalar,
+        z_near: Self::Scalar,
+        z_far: Self::Scalar,
+    ) -> Self;
+    /// Creates a left-handed orthographic projection matrix with `[0,1]` depth range.
+    fn orthographic_lh(
+        left: Self::Scalar,
+        right: Self::Scalar,
+        bottom: Self::Scalar,
+        top: Self::Scalar,
+        near: Self::Scalar,
+        far: Self::Scalar,
+    ) -> Self;
+    /// Creates a right-handed orthographic projection matrix with `[0,1]` depth range.
+    fn orthographic_rh(
+        left: Self::Scalar,
+        right: Self::Scalar,
+        bottom: Self::Scalar,
+        top: Self::Scalar,
+        near: Self::Scalar,
+        far: Self::Scalar,
+    ) -> Self;
+    /// Transforms a 4D vector.
+    fn mul_vec4(&self, other: Self::Vec4) -> Self::Vec4;
+    /// Multiplies two 4x4 matrices.
+    fn mul_mat4(&self, other: &Self) -> Self;
+    /// Transforms the given 3D vector as a point, applying perspective correction.
+    fn transform_point3(&self, other: Self::Vec3) -> Self::Vec3;
+    /// Transforms the give 3D vector as a direction.
+    fn transform_vector3(&self, other: Self::Vec3) -> Self::Vec3;
+    /// Returns true if the absolute difference of all elements between `self` and `other` is
+    /// less than or equal to `max_abs_diff`.
+    fn abs_diff_eq(&self, other: Self, max_abs_diff: Self::Scalar) -> bool;
+    /// Returns true if the difference of all elements between `self` and `other` is less than
+    /// or equal to `max_abs_diff` or to `max_relative` times the larger magnitude.
+    fn relative_eq(
+        &self,
+        other: Self,
+        max_abs_diff: Self::Scalar,
+        max_relative: Self::Scalar,
+    ) -> bool;
+    /// Returns true if all elements of `self` and `other` are within `max_abs_diff` of each other
+    /// or at most `max_ulps` representable values apart.
+    fn ulps_eq(&self, other: Self, max_abs_diff: Self::Scalar, max_ulps: u32) -> bool;
+}
+
+macro_rules! impl_mat2_like {
+    ($t:ty, $mat2:ident, $vec2:ident) => {
+        impl Mat2Like for $mat2 {
+            type Scalar = $t;
+            type Vec2 = $vec2;
+
+            #[inline(always)]
+            fn zero() -> Self {
+                $mat2::zero()
+            }
+            #[inline(always)]
+            fn identity() -> Self {
+                $mat2::identity()
+            }
+            #[inline(always)]
+            fn from_cols(x_axis: $vec2, y_axis: $vec2) -> Self {
+                $mat2::from_cols(x_axis, y_axis)
+            }
+            #[inline(always)]
+            fn from_cols_array(m: &[$t; 4]) -> Self {
+                $mat2::from_cols_array(m)
+            }
+            #[inline(always)]
+            fn to_cols_array(&self) -> [$t; 4] {
+                $mat2::to_cols_array(self)
+            }
+            #[inline(always)]
+            fn from_scale_angle(scale: $vec2, angle: $t) -> Self {
+                $mat2::from_scale_angle(scale, angle)
+            }
+            #[inline(always)]
+            fn from_angle(angle: $t) -> Self {
+                $mat2::from_angle(angle)
+            }
+            #[inline(always)]
+            fn from_scale(scale: $vec2) -> Self {
+                $mat2::from_scale(scale)
+            }
+            #[inline(always)]
+            fn is_finite(&self) -> bool {
+                $mat2::is_finite(self)
+            }
+            #[inline(always)]
+            fn is_nan(&self) -> bool {
+                $mat2::is_nan(self)
+            }
+            #[inline(always)]
+            fn transpose(&self) -> Self {
+                $mat2::transpose(self)
+            }
+            #[inline(always)]
+            fn determinant(&self) -> $t {
+                $mat2::determinant(self)
+            }
+            #[inline(always)]
+            fn inverse(&self) -> Self {
+                $mat2::inverse(self)
+            }
+            #[inline(always)]
+            fn mul_vec2(&self, other: $vec2) -> $vec2 {
+                $mat2::mul_vec2(self, other)
+            }
+            #[inline(always)]
+            fn mul_mat2(&self, other: &Self) -> Self {
+                $mat2::mul_mat2(self, other)
+            }
+            #[inline(always)]
+            fn abs_diff_eq(&self, other: &Self, max_abs_diff: $t) -> bool {
+                $mat2::abs_diff_eq(self, other, max_abs_diff)
+            }
+            #[inline(always)]
+            fn relative_eq(&self, other: &Self, max_abs_diff: $t, max_relative: $t) -> bool {
+                $mat2::relative_eq(self, other, max_abs_diff, max_relative)
+            }
+            #[inline(always)]
+            fn ulps_eq(&self, other: &Self, max_abs_diff: $t, max_ulps: u32) -> bool {
+                $mat2::ulps_eq(self, other, max_abs_diff, max_ulps)
+            }
+        }
+    };
+}
+
+macro_rules! impl_mat3_like {
+    ($t:ty, $mat3:ident, $vec2:ident, $vec3:ident, $quat:ident, $mul_vec3:ident) => {
+        impl Mat3Like for $mat3 {
+            type Scalar = $t;
+            type Vec2 = $vec2;
+            type Vec3 = $vec3;
+            type Quat = $quat;
+
+            #[inline(always)]
+            fn zero() -> Self {
+                $mat3::zero()
+            }
+            #[inline(always)]
+            fn identity() -> Self {
+                $mat3::identity()
+            }
+            #[inline(always)]
+            fn from_cols(x_axis: $vec3, y_axis: $vec3, z_axis: $vec3) -> Self {
+                $mat3::from_cols(x_axis, y_axis, z_axis)
+            }
+            #[inline(always)]
+            fn from_cols_array(m: &[$t; 9]) -> Self {
+                $mat3::from_cols_array(m)
+            }
+            #[inline(always)]
+            fn to_cols_array(&self) -> [$t; 9] {
+                $mat3::to_cols_array(self)
+            }
+            #[inline(always)]
+            fn from_scale_angle_translation(scale: $vec2, angle: $t, translation: $vec2) -> Self {
+                $mat3::from_scale_angle_translation(scale, angle, translation)
+            }
+            #[inline(always)]
+            fn from_quat(rotation: $quat) -> Self {
+                $mat3::from_quat(rotation)
+            }
+            #[inline(always)]
+            fn from_axis_angle(axis: $vec3, angle: $t) -> Self {
+                $mat3::from_axis_angle(axis, angle)
+            }
+            #[inline(always)]
+            fn from_rotation_x(angle: $t) -> Self {
+                $mat3::from_rotation_x(angle)
+            }
+            #[inline(always)]
+            fn from_rotation_y(angle: $t) -> Self {
+                $mat3::from_rotation_y(angle)
+            }
+            #[inline(always)]
+            fn from_rotation_z(angle: $t) -> Self {
+                $mat3::from_rotation_z(angle)
+            }
+            #[inline(always)]
+            fn from_scale(scale: $vec3) -> Self {
+                $mat3::from_scale(scale)
+            }
+            #[inline(always)]
+            fn is_finite(&self) -> bool {
+                $mat3::is_finite(self)
+            }
+            #[inline(always)]
+            fn is_nan(&self) -> bool {
+                $mat3::is_nan(self)
+            }
+            #[inline(always)]
+            fn transpose(&self) -> Self {
+                $mat3::transpose(self)
+            }
+            #[inline(always)]
+            fn determinant(&self) -> $t {
+                $mat3::determinant(self)
+            }
+            #[inline(always)]
+            fn inverse(&self) -> Self {
+                $mat3::inverse(self)
+            }
+            #[inline(always)]
+            fn mul_vec3(&self, other: $vec3) -> $vec3 {
+                $mat3::$mul_vec3(self, other)
+            }
+            #[inline(always)]
+            fn mul_mat3(&self, other: &Self) -> Self {
+                $mat3::mul_mat3(self, other)
+            }
+            #[inline(always)]
+            fn transform_point2(&self, other: $vec2) -> $vec2 {
+                $mat3::transform_point2(self, other)
+            }
+            #[inline(always)]
+            fn transform_vector2(&self, other: $vec2) -> $vec2 {
+                $mat3::transform_vector2(self, other)
+            }
+            #[inline(always)]
+            fn abs_diff_eq(&self, other: Self, max_abs_diff: $t) -> bool {
+                $mat3::abs_diff_eq(self, other, max_abs_diff)
+            }
+            #[inline(always)]
+            fn relative_eq(&self, other: Self, max_abs_diff: $t, max_relative: $t) -> bool {
+                $mat3::relative_eq(self, other, max_abs_diff, max_relative)
+            }
+            #[inline(always)]
+            fn ulps_eq(&self, other: Self, max_abs_diff: $t, max_ulps: u32) -> bool {
+                $mat3::ulps_eq(self, other, max_abs_diff, max_ulps)
+            }
+        }
+    };
+}
+
+macro_rules! impl_mat4_like {
+    ($t:ty, $mat4:ident, $vec3:ident, $vec4:ident, $quat:ident) => {
+        impl Mat4Like for $mat4 {
+            type Scalar = $t;
+            type Vec3 = $vec3;
+            type Vec4 = $vec4;
+            type Quat = $quat;
+
+            #[inline(always)]
+            fn zero() -> Self {
+                $mat4::zero()
+            }
+            #[inline(always)]
+            fn identity() -> Self {
+                $mat4::identity()
+            }
+            #[inline(always)]
+            fn from_cols(x_axis: $vec4, y_axis: $vec4, z_axis: $vec4, w_axis: $vec4) -> Self {
+                $mat4::from_cols(x_axis, y_axis, z_axis, w_axis)
+            }
+            #[inline(always)]
+            fn from_cols_array(m: &[$t; 16]) -> Self {
+                $mat4::from_cols_array(m)
+            }
+            #[inline(always)]
+            fn to_cols_array(&self) -> [$t; 16] {
+                $mat4::to_cols_array(self)
+            }
+            #[inline(always)]
+            fn from_scale_rotation_translation(
+                scale: $vec3,
+                rotation: $quat,
+                translation: $vec3,
+            ) -> Self {
+                $mat4::from_scale_rotation_translation(scale, rotation, translation)
+            }
+            #[inline(always)]
+            fn from_rotation_translation(rotation: $quat, translation: $vec3) -> Self {
+                $mat4::from_rotation_translation(rotation, translation)
+            }
+            #[inline(always)]
+            fn to_scale_rotation_translation(&self) -> ($vec3, $quat, $vec3) {
+                $mat4::to_scale_rotation_translation(self)
+            }
+            #[inline(always)]
+            fn from_quat(rotation: $quat) -> Self {
+                $mat4::from_quat(rotation)
+            }
+            #[inline(always)]
+            fn from_translation(translation: $vec3) -> Self {
+                $mat4::from_translation(translation)
+            }
+            #[inline(always)]
+            fn from_axis_angle(axis: $vec3, angle: $t) -> Self {
+                $mat4::from_axis_angle(axis, angle)
+            }
+            #[inline(always)]
+            fn from_rotation_x(angle: $t) -> Self {
+                $mat4::from_rotation_x(angle)
+            }
+            #[inline(always)]
+            fn from_rotation_y(angle: $t) -> Self {
+                $mat4::from_rotation_y(angle)
+            }
+            #[inline(always)]
+            fn from_rotation_z(angle: $t) -> Self {
+                $mat4::from_rotation_z(angle)
+            }
+            #[inline(always)]
+            fn from_scale(scale: $vec3) -> Self {
+                $mat4::from_scale(scale)
+            }
+            #[inline(always)]
+            fn is_finite(&self) -> bool {
+                $mat4::is_finite(self)
+            }
+            #[inline(always)]
+            fn is_nan(&self) -> bool {
+                $mat4::is_nan(self)
+            }
+            #[inline(always)]
+            fn transpose(&self) -> Self {
+                $mat4::transpose(self)
+            }
+            #[inline(always)]
+            fn determinant(&self) -> $t {
+                $mat4::determinant(self)
+            }
+            #[inline(always)]
+            fn inverse(&self) -> Self {
+                $mat4::inverse(self)
+            }
+            #[inline(always)]
+            fn look_at_lh(eye: $vec3, center: $vec3, up: $vec3) -> Self {
+                $mat4::look_at_lh(eye, center, up)
+            }
+            #[inline(always)]
+            fn look_at_rh(eye: $vec3, center: $vec3, up: $vec3) -> Self {
+                $mat4::look_at_rh(eye, center, up)
+            }
+            #[inline(always)]
+            fn perspective_lh(fov_y_radians: $t, aspect_ratio: $t, z_near: $t, z_far: $t) -> Self {
+                $mat4::perspective_lh(fov_y_radians, aspect_ratio, z_near, z_far)
+            }
+            #[inline(always)]
+            fn perspective_rh(fov_y_radians: $t, aspect_ratio: $t, z_near: $t, z_far: $t) -> Self {
+                $mat4::perspective_rh(fov_y_radians, aspect_ratio, z_near, z_far)
+            }
+            #[inline(always)]
+            fn orthographic_lh(
+                left: $t,
+                right: $t,
+                bottom: $t,
+                top: $t,
+                near: $t,
+                far: $t,
+            ) -> Self {
+                $mat4::orthographic_lh(left, right, bottom, top, near, far)
+            }
+            #[inline(always)]
+            fn orthographic_rh(
+                left: $t,
+                right: $t,
+                bottom: $t,
+                top: $t,
+                near: $t,
+                far: $t,
+            ) -> Self {
+                $mat4::orthographic_rh(left, right, bottom, top, near, far)
+            }
+            #[inline(always)]
+            fn mul_vec4(&self, other: $vec4) -> $vec4 {
+                $mat4::mul_vec4(self, other)
+            }
+            #[inline(always)]
+            fn mul_mat4(&self, other: &Self) -> Self {
+                $mat4::mul_mat4(self, other)
+            }
+            #[inline(always)]
+            fn transform_point3(&self, other: $vec3) -> $vec3 {
+                $mat4::transform_point3(self, other)
+            }
+            #[inline(always)]
+            fn transform_vector3(&self, other: $vec3) -> $vec3 {
+                $mat4::transform_vector3(self, other)
+            }
+            #[inline(always)]
+            fn abs_diff_eq(&self, other: Self, max_abs_diff: $t) -> bool {
+                $mat4::abs_diff_eq(self, other, max_abs_diff)
+            }
+            #[inline(always)]
+            fn relative_eq(&self, other: Self, max_abs_diff: $t, max_relative: $t) -> bool {
+                $mat4::relative_eq(self, other, max_abs_diff, max_relative)
+            }
+            #[inline(always)]
+            fn ulps_eq(&self, other: Self, max_abs_diff: $t, max_ulps: u32) -> bool {
+                $mat4::ulps_eq(self, other, max_abs_diff, max_ulps)
+            }
+        }
+    };
+}
+
+impl_mat2_like!(f32, Mat2, Vec2);
+impl_mat2_like!(f64, DMat2, DVec2);
+impl_mat3_like!(f32, Mat3, Vec2, Vec3, Quat, mul_vec3);
+impl_mat3_like!(f32, Mat3A, Vec2, Vec3A, Quat, mul_vec3a);
+impl_mat3_like!(f64, DMat3, DVec2, DVec3, DQuat, mul_vec3);
+impl_mat4_like!(f32, Mat4, Vec3, Vec4, Quat);
+impl_mat4_like!(f64, DMat4, DVec3, DVec4, DQuat);
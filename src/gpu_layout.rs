@@ -0,0 +1,421 @@
+/*!
+`std140` and `std430` GPU buffer layouts.
+
+The [`Std140`] and [`Std430`] traits describe the alignment and size of scalar, vector, quaternion
+and matrix types in the respective GLSL buffer layouts and write them to, or read them from, byte
+slices including any padding the layout requires. Values are written in native byte order.
+
+In both layouts a 3 component vector is 16 byte aligned but only 12 bytes in size, so a scalar
+may directly follow it. Matrices are stored as an array of column vectors. In `std140` the stride
+of array elements, and so of matrix columns, is rounded up to 16 bytes, which means a `Mat3` is
+stored as three padded columns and a `Mat2` takes 32 bytes.
+
+The offsets of the members of a buffer struct can be computed with [`Std140Layout`] and
+[`Std430Layout`]:
+
+```
+use glam::gpu_layout::{Std140, Std140Layout};
+use glam::{Mat3, Vec3};
+
+// struct Light { vec3 position; float radius; mat3 basis; vec2 uv; };
+let mut layout = Std140Layout::new();
+let position = layout.push::<Vec3>();
+let radius = layout.push::<f32>();
+let basis = layout.push::<Mat3>();
+assert_eq!((position, radius, basis), (0, 12, 16));
+
+let mut bytes = vec![0; layout.size()];
+Vec3::new(1.0, 2.0, 3.0).write_std140(&mut bytes[position..]);
+Mat3::IDENTITY.write_std140(&mut bytes[basis..]);
+assert_eq!(Mat3::read_std140(&bytes[basis..]), Mat3::IDENTITY);
+```
+*/
+
+use crate::{
+    DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, IVec2, IVec3, IVec4, Mat2, Mat3, Mat3A, Mat4,
+    Quat, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
+};
+
+/// The `std140` layout, used by uniform buffers.
+pub trait Std140: Sized {
+    /// The alignment of `Self` in bytes.
+    const STD140_ALIGN: usize;
+
+    /// The size of `Self` in bytes, not including any trailing padding before the next member.
+    const STD140_SIZE: usize;
+
+    /// Writes `self` to the start of `bytes`, zeroing any padding.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `bytes` is shorter than `STD140_SIZE`.
+    fn write_std140(&self, bytes: &mut [u8]);
+
+    /// Reads a value from the start of `bytes`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `bytes` is shorter than `STD140_SIZE`.
+    fn read_std140(bytes: &[u8]) -> Self;
+}
+
+/// The `std430` layout, used by shader storage buffers and push constants.
+pub trait Std430: Sized {
+    /// The alignment of `Self` in bytes.
+    const STD430_ALIGN: usize;
+
+    /// The size of `Self` in bytes, not including any trailing padding before the next member.
+    const STD430_SIZE: usize;
+
+    /// Writes `self` to the start of `bytes`, zeroing any padding.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `bytes` is shorter than `STD430_SIZE`.
+    fn write_std430(&self, bytes: &mut [u8]);
+
+    /// Reads a value from the start of `bytes`.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `bytes` is shorter than `STD430_SIZE`.
+    fn read_std430(bytes: &[u8]) -> Self;
+}
+
+/// Rounds `offset` up to a multiple of `align`, which must be a power of two.
+#[inline]
+const fn round_up(offset: usize, align: usize) -> usize {
+    (offset + align - 1) & !(align - 1)
+}
+
+#[inline]
+const fn max(a: usize, b: usize) -> usize {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+/// Computes the member offsets and the size of a struct in the `std140` layout.
+#[derive(Clone, Copy, Debug)]
+pub struct Std140Layout {
+    offset: usize,
+    align: usize,
+}
+
+impl Std140Layout {
+    /// Creates an empty struct layout.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            offset: 0,
+            align: 16,
+        }
+    }
+
+    /// Appends a member of type `T`, returning its offset in bytes.
+    #[inline]
+    pub fn push<T: Std140>(&mut self) -> usize {
+        let offset = round_up(self.offset, T::STD140_ALIGN);
+        self.offset = offset + T::STD140_SIZE;
+        self.align = max(self.align, T::STD140_ALIGN);
+        offset
+    }
+
+    /// Appends an array of `len` elements of type `T`, returning its offset in bytes.
+    ///
+    /// Elements are `Self::array_stride::<T>()` bytes apart.
+    #[inline]
+    pub fn push_array<T: Std140>(&mut self, len: usize) -> usize {
+        let align = max(T::STD140_ALIGN, 16);
+        let offset = round_up(self.offset, align);
+        self.offset = offset + len * Self::array_stride::<T>();
+        self.align = max(self.align, align);
+        offset
+    }
+
+    /// Appends a nested struct with the given layout, returning its offset in bytes.
+    #[inline]
+    pub fn push_struct(&mut self, layout: &Self) -> usize {
+        let offset = round_up(self.offset, layout.align);
+        self.offset = offset + layout.size();
+        self.align = max(self.align, layout.align);
+        offset
+    }
+
+    /// Returns the distance in bytes between the elements of an array of `T`.
+    #[inline]
+    pub fn array_stride<T: Std140>() -> usize {
+        round_up(T::STD140_SIZE, max(T::STD140_ALIGN, 16))
+    }
+
+    /// Returns the size of the struct in bytes, including trailing padding.
+    #[inline]
+    pub fn size(&self) -> usize {
+        round_up(self.offset, self.align)
+    }
+}
+
+/// Computes the member offsets and the size of a struct in the `std430` layout.
+#[derive(Clone, Copy, Debug)]
+pub struct Std430Layout {
+    offset: usize,
+    align: usize,
+}
+
+impl Std430Layout {
+    /// Creates an empty struct layout.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            offset: 0,
+            align: 1,
+        }
+    }
+
+    /// Appends a member of type `T`, returning its offset in bytes.
+    #[inline]
+    pub fn push<T: Std430>(&mut self) -> usize {
+        let offset = round_up(self.offset, T::STD430_ALIGN);
+        self.offset = offset + T::STD430_SIZE;
+        self.align = max(self.align, T::STD430_ALIGN);
+        offset
+    }
+
+    /// Appends an array of `len` elements of type `T`, returning its offset in bytes.
+    ///
+    /// Elements are `Self::array_stride::<T>()` bytes apart.
+    #[inline]
+    pub fn push_array<T: Std430>(&mut self, len: usize) -> usize {
+        let offset = round_up(self.offset, T::STD430_ALIGN);
+        self.offset = offset + len * Self::array_stride::<T>();
+        self.align = max(self.align, T::STD430_ALIGN);
+        offset
+    }
+
+    /// Appends a nested struct with the given layout, returning its offset in bytes.
+    #[inline]
+    pub fn push_struct(&mut self, layout: &Self) -> usize {
+        let offset = round_up(self.offset, layout.align);
+        self.offset = offset + layout.size();
+        self.align = max(self.align, layout.align);
+        offset
+    }
+
+    /// Returns the distance in bytes between the elements of an array of `T`.
+    #[inline]
+    pub fn array_stride<T: Std430>() -> usize {
+        round_up(T::STD430_SIZE, T::STD430_ALIGN)
+    }
+
+    /// Returns the size of the struct in bytes, including trailing padding.
+    #[inline]
+    pub fn size(&self) -> usize {
+        round_up(self.offset, self.align)
+    }
+}
+
+impl Default for Std140Layout {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for Std430Layout {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+macro_rules! impl_scalar {
+    ($t:ty) => {
+        impl Std140 for $t {
+            const STD140_ALIGN: usize = core::mem::size_of::<$t>();
+            const STD140_SIZE: usize = core::mem::size_of::<$t>();
+
+            #[inline]
+            fn write_std140(&self, bytes: &mut [u8]) {
+                bytes[..Self::STD140_SIZE].copy_from_slice(&self.to_ne_bytes());
+            }
+
+            #[inline]
+            fn read_std140(bytes: &[u8]) -> Self {
+                let mut b = [0; core::mem::size_of::<$t>()];
+                b.copy_from_slice(&bytes[..Self::STD140_SIZE]);
+                <$t>::from_ne_bytes(b)
+            }
+        }
+
+        impl Std430 for $t {
+            const STD430_ALIGN: usize = Self::STD140_ALIGN;
+            const STD430_SIZE: usize = Self::STD140_SIZE;
+
+            #[inline(always)]
+            fn write_std430(&self, bytes: &mut [u8]) {
+                self.write_std140(bytes)
+            }
+
+            #[inline(always)]
+            fn read_std430(bytes: &[u8]) -> Self {
+                Self::read_std140(bytes)
+            }
+        }
+    };
+}
+
+impl_scalar!(f32);
+impl_scalar!(f64);
+impl_scalar!(i32);
+impl_scalar!(u32);
+
+// Vectors and quaternions have the same layout in `std140` and `std430`.
+macro_rules! impl_vec {
+    ($t:ty, $n:expr, $align_n:expr, $($vecn:ident),+) => {
+        $(
+            impl Std140 for $vecn {
+                const STD140_ALIGN: usize = $align_n * core::mem::size_of::<$t>();
+                const STD140_SIZE: usize = $n * core::mem::size_of::<$t>();
+
+                #[inline]
+                fn write_std140(&self, bytes: &mut [u8]) {
+                    let a: [$t; $n] = (*self).into();
+                    let size = core::mem::size_of::<$t>();
+                    for (i, e) in a.iter().enumerate() {
+                        e.write_std140(&mut bytes[i * size..]);
+                    }
+                }
+
+                #[inline]
+                fn read_std140(bytes: &[u8]) -> Self {
+                    let mut a = [<$t>::default(); $n];
+                    let size = core::mem::size_of::<$t>();
+                    for (i, e) in a.iter_mut().enumerate() {
+                        *e = <$t>::read_std140(&bytes[i * size..]);
+                    }
+                    a.into()
+                }
+            }
+
+            impl Std430 for $vecn {
+                const STD430_ALIGN: usize = Self::STD140_ALIGN;
+                const STD430_SIZE: usize = Self::STD140_SIZE;
+
+                #[inline(always)]
+                fn write_std430(&self, bytes: &mut [u8]) {
+                    self.write_std140(bytes)
+                }
+
+                #[inline(always)]
+                fn read_std430(bytes: &[u8]) -> Self {
+                    Self::read_std140(bytes)
+                }
+            }
+        )+
+    };
+}
+
+impl_vec!(f32, 2, 2, Vec2);
+impl_vec!(f32, 3, 4, Vec3, Vec3A);
+impl_vec!(f32, 4, 4, Vec4, Quat);
+impl_vec!(f64, 2, 2, DVec2);
+impl_vec!(f64, 3, 4, DVec3);
+impl_vec!(f64, 4, 4, DVec4, DQuat);
+impl_vec!(i32, 2, 2, IVec2);
+impl_vec!(i32, 3, 4, IVec3);
+impl_vec!(i32, 4, 4, IVec4);
+impl_vec!(u32, 2, 2, UVec2);
+impl_vec!(u32, 3, 4, UVec3);
+impl_vec!(u32, 4, 4, UVec4);
+
+// Matrices are stored as arrays of column vectors.
+macro_rules! impl_mat {
+    ($t:ty, $n:expr, $matn:ident, $col:ident) => {
+        impl Std140 for $matn {
+            const STD140_ALIGN: usize = max(<$col as Std140>::STD140_ALIGN, 16);
+            const STD140_SIZE: usize = $n
+                * round_up(
+                    <$col as Std140>::STD140_SIZE,
+                    max(<$col as Std140>::STD140_ALIGN, 16),
+                );
+
+            #[inline]
+            fn write_std140(&self, bytes: &mut [u8]) {
+                write_cols(
+                    &self.to_cols_array_2d(),
+                    bytes,
+                    Self::STD140_SIZE / $n,
+                    |col, bytes| $col::from(*col).write_std140(bytes),
+                );
+            }
+
+            #[inline]
+            fn read_std140(bytes: &[u8]) -> Self {
+                let mut cols = [[<$t>::default(); $n]; $n];
+                read_cols(&mut cols, bytes, Self::STD140_SIZE / $n, |bytes| {
+                    $col::read_std140(bytes).into()
+                });
+                Self::from_cols_array_2d(&cols)
+            }
+        }
+
+        impl Std430 for $matn {
+            const STD430_ALIGN: usize = <$col as Std430>::STD430_ALIGN;
+            const STD430_SIZE: usize = $n
+                * round_up(
+                    <$col as Std430>::STD430_SIZE,
+                    <$col as Std430>::STD430_ALIGN,
+                );
+
+            #[inline]
+            fn write_std430(&self, bytes: &mut [u8]) {
+                write_cols(
+                    &self.to_cols_array_2d(),
+                    bytes,
+                    Self::STD430_SIZE / $n,
+                    |col, bytes| $col::from(*col).write_std430(bytes),
+                );
+            }
+
+            #[inline]
+            fn read_std430(bytes: &[u8]) -> Self {
+                let mut cols = [[<$t>::default(); $n]; $n];
+                read_cols(&mut cols, bytes, Self::STD430_SIZE / $n, |bytes| {
+                    $col::read_std430(bytes).into()
+                });
+                Self::from_cols_array_2d(&cols)
+            }
+        }
+    };
+}
+
+/// Writes each column with `write` at `stride` byte intervals, zeroing the padding between them.
+#[inline]
+fn write_cols<C, F: Fn(&C, &mut [u8])>(cols: &[C], bytes: &mut [u8], stride: usize, write: F) {
+    let bytes = &mut bytes[..cols.len() * stride];
+    for b in bytes.iter_mut() {
+        *b = 0;
+    }
+    for (col, bytes) in cols.iter().zip(bytes.chunks_exact_mut(stride)) {
+        write(col, bytes);
+    }
+}
+
+/// Reads each column with `read` from `stride` byte intervals.
+#[inline]
+fn read_cols<C, F: Fn(&[u8]) -> C>(cols: &mut [C], bytes: &[u8], stride: usize, read: F) {
+    let bytes = &bytes[..cols.len() * stride];
+    for (col, bytes) in cols.iter_mut().zip(bytes.chunks_exact(stride)) {
+        *col = read(bytes);
+    }
+}
+
+impl_mat!(f32, 2, Mat2, Vec2);
+impl_mat!(f32, 3, Mat3, Vec3);
+impl_mat!(f32, 3, Mat3A, Vec3A);
+impl_mat!(f32, 4, Mat4, Vec4);
+impl_mat!(f64, 2, DMat2, DVec2);
+impl_mat!(f64, 3, DMat3, DVec3);
+impl_mat!(f64, 4, DMat4, DVec4);
@@ -10,9 +10,11 @@ supported as this is what stable Rust supports.
 
 * Vector, quaternion and matrix types support for [`f32`](mod@f32) and [`f64`](mod@f64)
 * Vector types supported for [`i32`](mod@i32), [`u32`](mod@u32) and [`bool`](mod@bool)
-* SSE2 storage and optimization for many [`f32`](mod@f32) types, including [`Mat2`], [`Mat4`],
-  [`Quat`], [`Vec3A`] and [`Vec4`]
+* SSE2 storage and optimization for many [`f32`](mod@f32) types, including [`Mat2`], [`Mat3A`],
+  [`Mat4`], [`Quat`], [`Vec3A`] and [`Vec4`]
 * Scalar math fallback implementations exist when SSE2 is not available
+* Traits in the [`generic`] module for writing code that is generic over [`f32`](mod@f32)
+  and [`f64`](mod@f64) types
 * Most functionality includes unit tests and benchmarks
 
 ## Linear algebra conventions
@@ -49,7 +51,7 @@ assert_eq!(4.0, v.z);
 ## Size and alignment of types
 
 Some `glam` types use SIMD for storage meaning they are 16 byte aligned, these
-types include [`Mat2`], [`Mat4`], [`Quat`], [`Vec3A`] and [`Vec4`].
+types include [`Mat2`], [`Mat3A`], [`Mat4`], [`Quat`], [`Vec3A`] and [`Vec4`].
 
 When SSE2 is not available on the target architecture this type will still be 16
 byte aligned so that object sizes and layouts will not change between
@@ -102,6 +104,28 @@ let v3a = Vec3A::from(v3);
 assert_eq!(Vec3A::new(1.0, 2.0, 3.0), v3a);
 ```
 
+Similarly [`Mat3A`] is a version of [`Mat3`] with [`Vec3A`] columns, making it 48
+bytes in size rather than 36. It can be converted to and from [`Mat3`] and [`Mat4`].
+
+## Non-square matrices
+
+[`Mat2x3`], [`Mat3x2`], [`Mat3x4`] and [`Mat4x3`] are column major matrices named after
+GLSL, so a [`Mat4x3`] has four [`Vec3`] columns and three rows. They can be multiplied
+with matrices and vectors of a compatible shape and transposed to their counterpart.
+
+A [`Mat4x3`] stores the affine part of a [`Mat4`], while [`Mat3x4::from_affine_rows()`]
+stores the same transform as rows, a common layout for skinning palettes in GPU buffers.
+Conversions with `From` keep the columns of the matrix in both cases.
+
+```
+use glam::{Mat3x4, Mat4, Mat4x3, Vec3};
+let m = Mat4::from_translation(Vec3::new(1.0, 2.0, 3.0));
+let rows = Mat3x4::from_affine_rows(&m);
+assert_eq!(rows, Mat4x3::from(m).transpose());
+assert_eq!(m.transform_point3(Vec3::ONE), rows.transform_point3_rows(Vec3::ONE));
+assert_eq!(m, Mat4::from_affine_rows(rows));
+```
+
 ## Vector swizzles
 
 `glam` vector types have functions allowing elements of vectors to be reordered,
@@ -150,12 +174,17 @@ that they will serialize and deserialize exactly the same whether or not
 SIMD support is being used.
 
 The SIMD versions implement the `core::fmt::Debug` and `core::fmt::Display`
-traits so they print the same as the scalar version.
+traits so they print the same as the scalar version. Formatting options such as
+precision, width and sign are applied to each element and the alternate flag
+prints matrices with one column per line.
 
 ```
-use glam::Vec4;
+use glam::{Mat2, Vec4};
 let a = Vec4::new(1.0, 2.0, 3.0, 4.0);
 assert_eq!(format!("{}", a), "[1, 2, 3, 4]");
+assert_eq!(format!("{:+.1}", a), "[+1.0, +2.0, +3.0, +4.0]");
+let m = Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]);
+assert_eq!(format!("{:#.1}", m), "[[1.0, 2.0],\n [3.0, 4.0]]");
 ```
 
 ## Feature gates
@@ -164,9 +193,30 @@ All `glam` dependencies are optional, however some are required for tests
 and benchmarks.
 
 * `std` - the default feature, has no dependencies.
-* `rand` - used to generate random values. Used in benchmarks.
-* `serde` - used for serialization and deserialization of types.
+* `rand` - used to generate random values. Used in benchmarks. Also adds geometric
+  sampling distributions to the `distributions` module.
+* `proptest` - implements `proptest::arbitrary::Arbitrary` for all types and the
+  `constrained` wrappers. The `proptest` module has `finite()`, `normalized()` and
+  `invertible()` strategies.
+* `rkyv` - implements `rkyv` zero-copy serialization for vector, quaternion, matrix
+  and transform types. The archived form of each type is the type itself. Enable
+  `bytecheck` as well to validate archived data with `rkyv::check_archived_root`.
+  This enables `rkyv/validation` which needs `alloc`.
+* `serde` - used for serialization and deserialization of types. Types serialize
+  as flat sequences and also deserialize from maps such as `{"x":1.0,"y":2.0}` in
+  self describing formats. The `serde` module has `as_map` and `as_nested_cols`
+  helpers for use with `#[serde(with = "...")]`.
+* `arbitrary` - implements `arbitrary::Arbitrary` for all types and for the
+  `Finite`, `Normalized` and `Invertible` wrappers in the `constrained` module, for
+  generating structured inputs when fuzzing.
+* `approx` - implements `AbsDiffEq`, `RelativeEq` and `UlpsEq` for float types.
+* `gpu-layout` - adds the `gpu_layout` module for reading and writing types in the
+  `std140` and `std430` GPU buffer layouts.
 * `mint` - used for interoperating with other linear algebra libraries.
+* `nalgebra` - conversions to and from `nalgebra` types, including `Isometry3` and
+  `Similarity3` for transform types.
+* `num-traits` - implements `num_traits` traits such as `Zero`, `One` and `Inv` for
+  generic numeric code. Also used by `no_std` builds via the `libm` feature.
 * `scalar-math` - disables SIMD support and uses native alignment for all
   types.
 * `debug-glam-assert` - adds assertions in debug builds which check the validity
@@ -174,9 +224,18 @@ and benchmarks.
 * `glam-assert` - adds assertions to all builds which check the validity of
   parameters passed to `glam` to help catch runtime errors.
 
+  The assertions check that inputs and results of float methods such as `normalize()`,
+  `inverse()` or `transform_point3()` are finite. Failed assertions panic unless a handler
+  is installed with `set_assert_handler()`, which can be used to log the failure and
+  continue instead. `set_assert_handler()` is only available when one of the assert
+  features is enabled and is not available on `spirv` targets.
+* `deterministic` - uses the pure Rust `libm` crate for transcendental functions
+  and avoids platform dependent instructions such as FMA. Results are bit for bit
+  identical between SIMD and `scalar-math` builds and across target platforms.
+
 ## Minimum Supported Version or Rust (MSVR)
 
-The minimum supported version of Rust for `glam` is `1.36.0`.
+The minimum supported version of Rust for `glam` is `1.56.0`.
 
 */
 #![doc(html_root_url = "https://docs.rs/glam/0.12.0")]
@@ -211,15 +270,35 @@ pub mod cast;
 
 mod core;
 mod mat2;
+mod mat2x3;
 mod mat3;
+mod mat3x2;
+mod mat3x4;
 mod mat4;
+mod mat4x3;
 mod quat;
 mod vec2;
 mod vec3;
 mod vec4;
 mod vec_mask;
 
+#[cfg(all(
+    any(feature = "glam-assert", feature = "debug-glam-assert"),
+    not(target_arch = "spirv")
+))]
+mod assert;
+#[cfg(not(target_arch = "spirv"))]
+mod display;
 mod features;
+mod float_ord;
+mod parse;
+
+pub mod generic;
+
+pub mod constrained;
+
+#[cfg(feature = "gpu-layout")]
+pub mod gpu_layout;
 
 #[cfg(feature = "transform-types")]
 mod transform;
@@ -227,6 +306,38 @@ mod transform;
 #[doc(hidden)]
 pub use self::core::storage::{XY, XYZ, XYZW};
 
+#[cfg(all(
+    any(feature = "glam-assert", feature = "debug-glam-assert"),
+    not(target_arch = "spirv")
+))]
+pub use self::assert::{set_assert_handler, AssertFailure, AssertHandler};
+pub use self::float_ord::FloatOrd;
+pub use self::parse::ParseError;
+
+#[cfg(all(feature = "nalgebra", feature = "transform-types"))]
+pub use self::features::impl_nalgebra::SimilarityError;
+
+/** Random distributions for sampling points and directions, see the `rand` feature. */
+#[cfg(feature = "rand")]
+pub mod distributions {
+    pub use super::features::impl_rand::{
+        CosineHemisphere, Hemisphere, InAabb, OnTriangle, UnitBall, UnitCircle, UnitDisk,
+        UnitSphere,
+    };
+}
+
+/** `proptest` strategies for values with constraints, see the `proptest` feature. */
+#[cfg(feature = "proptest")]
+pub mod proptest {
+    pub use super::features::impl_proptest::{finite, invertible, normalized};
+}
+
+/** Helpers for alternative `serde` representations, see the `serde` feature. */
+#[cfg(feature = "serde")]
+pub mod serde {
+    pub use super::features::impl_serde::{as_map, as_nested_cols};
+}
+
 /** `bool` vector mask types. */
 pub mod bool {
     pub use super::vec_mask::{BVec2, BVec3, BVec3A, BVec4, BVec4A};
@@ -239,8 +350,12 @@ pub mod f32 {
     //     F32x16Cast, F32x9Cast, Mat2Cast, Mat3Cast, Mat4Cast, Vec2Cast, Vec3Cast, Vec4Cast,
     // };
     pub use super::mat2::{mat2, Mat2};
-    pub use super::mat3::{mat3, Mat3};
+    pub use super::mat2x3::{mat2x3, Mat2x3};
+    pub use super::mat3::{mat3, mat3a, Mat3, Mat3A};
+    pub use super::mat3x2::{mat3x2, Mat3x2};
+    pub use super::mat3x4::{mat3x4, Mat3x4};
     pub use super::mat4::{mat4, Mat4};
+    pub use super::mat4x3::{mat4x3, Mat4x3};
     pub use super::quat::{quat, Quat};
     pub use super::vec2::{vec2, Vec2};
     pub use super::vec3::{vec3, vec3a, Vec3, Vec3A};
@@ -257,8 +372,12 @@ pub mod f64 {
     //     DMat2Cast, DMat3Cast, DMat4Cast, DVec2Cast, DVec3Cast, DVec4Cast, F64x16Cast, F64x9Cast,
     // };
     pub use super::mat2::{dmat2, DMat2};
+    pub use super::mat2x3::{dmat2x3, DMat2x3};
     pub use super::mat3::{dmat3, DMat3};
+    pub use super::mat3x2::{dmat3x2, DMat3x2};
+    pub use super::mat3x4::{dmat3x4, DMat3x4};
     pub use super::mat4::{dmat4, DMat4};
+    pub use super::mat4x3::{dmat4x3, DMat4x3};
     pub use super::quat::{dquat, DQuat};
     pub use super::vec2::{dvec2, DVec2};
     pub use super::vec3::{dvec3, DVec3};
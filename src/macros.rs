@@ -1,9 +1,31 @@
-#[cfg(any(
-    all(debug_assertions, feature = "debug-glam-assert"),
-    feature = "glam-assert"
+#[cfg(all(
+    any(
+        all(debug_assertions, feature = "debug-glam-assert"),
+        feature = "glam-assert"
+    ),
+    not(target_arch = "spirv")
+))]
+macro_rules! glam_assert {
+    ($cond:expr $(,)?) => {
+        // `if !$cond` would trip clippy's `neg_cmp_op_on_partial_ord` for float comparisons
+        if $cond {
+        } else {
+            $crate::assert::assert_failed(stringify!($cond), file!(), line!());
+        }
+    };
+}
+// The assert handler is not available on spirv targets.
+#[cfg(all(
+    any(
+        all(debug_assertions, feature = "debug-glam-assert"),
+        feature = "glam-assert"
+    ),
+    target_arch = "spirv"
 ))]
 macro_rules! glam_assert {
-    ($($arg:tt)*) => ( assert!($($arg)*); )
+    ($cond:expr $(,)?) => {
+        assert!($cond);
+    };
 }
 #[cfg(not(any(
     all(debug_assertions, feature = "debug-glam-assert"),
@@ -94,7 +116,10 @@ macro_rules! const_mat2 {
     ($col0:expr, $col1:expr) => {
         unsafe {
             $crate::cast::Mat2Cast {
-                v2x2: [$crate::const_vec2!($col0), $crate::const_vec2!($col1)],
+                v2x2: [
+                    $crate::cast::Vec2Cast { fx2: $col0 }.v2,
+                    $crate::cast::Vec2Cast { fx2: $col1 }.v2,
+                ],
             }
             .m2
         }
@@ -120,9 +145,9 @@ macro_rules! const_mat3 {
         unsafe {
             $crate::cast::Mat3Cast {
                 v3x3: [
-                    $crate::const_vec3!($col0),
-                    $crate::const_vec3!($col1),
-                    $crate::const_vec3!($col2),
+                    $crate::cast::Vec3Cast { fx3: $col0 }.v3,
+                    $crate::cast::Vec3Cast { fx3: $col1 }.v3,
+                    $crate::cast::Vec3Cast { fx3: $col2 }.v3,
                 ],
             }
             .m3
@@ -137,6 +162,45 @@ macro_rules! const_mat3 {
     };
 }
 
+/// Creates a `Mat3A` from three column vectors that can be used to initialize a constant value.
+///
+/// ```
+/// use glam::{const_mat3a, Mat3A};
+/// const ZERO: Mat3A = const_mat3a!([0.0; 9]);
+/// const IDENTITY: Mat3A = const_mat3a!([1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]);
+/// ```
+#[macro_export]
+macro_rules! const_mat3a {
+    ($col0:expr, $col1:expr, $col2:expr) => {
+        unsafe {
+            $crate::cast::Mat3ACast {
+                v3x3: [
+                    $crate::cast::Vec4Cast {
+                        fx4: [$col0[0], $col0[1], $col0[2], 0.0],
+                    }
+                    .v3a,
+                    $crate::cast::Vec4Cast {
+                        fx4: [$col1[0], $col1[1], $col1[2], 0.0],
+                    }
+                    .v3a,
+                    $crate::cast::Vec4Cast {
+                        fx4: [$col2[0], $col2[1], $col2[2], 0.0],
+                    }
+                    .v3a,
+                ],
+            }
+            .m3
+        }
+    };
+    ($fx9:expr) => {
+        $crate::const_mat3a!(
+            $crate::cast::F32x9Cast { fx9: $fx9 }.fx3x3[0],
+            $crate::cast::F32x9Cast { fx9: $fx9 }.fx3x3[1],
+            $crate::cast::F32x9Cast { fx9: $fx9 }.fx3x3[2]
+        )
+    };
+}
+
 /// Creates a `Mat4` from four column vectors that can be used to initialize a constant value.
 ///
 /// ```
@@ -155,10 +219,10 @@ macro_rules! const_mat4 {
         unsafe {
             $crate::cast::Mat4Cast {
                 v4x4: [
-                    $crate::const_vec4!($col0),
-                    $crate::const_vec4!($col1),
-                    $crate::const_vec4!($col2),
-                    $crate::const_vec4!($col3),
+                    $crate::cast::Vec4Cast { fx4: $col0 }.v4,
+                    $crate::cast::Vec4Cast { fx4: $col1 }.v4,
+                    $crate::cast::Vec4Cast { fx4: $col2 }.v4,
+                    $crate::cast::Vec4Cast { fx4: $col3 }.v4,
                 ],
             }
             .m4
@@ -242,7 +306,10 @@ macro_rules! const_dmat2 {
     ($col0:expr, $col1:expr) => {
         unsafe {
             $crate::cast::DMat2Cast {
-                v2x2: [$crate::const_dvec2!($col0), $crate::const_dvec2!($col1)],
+                v2x2: [
+                    $crate::cast::DVec2Cast { fx2: $col0 }.v2,
+                    $crate::cast::DVec2Cast { fx2: $col1 }.v2,
+                ],
             }
             .m2
         }
@@ -269,9 +336,9 @@ macro_rules! const_dmat3 {
         unsafe {
             $crate::cast::DMat3Cast {
                 v3x3: [
-                    $crate::const_dvec3!($col0),
-                    $crate::const_dvec3!($col1),
-                    $crate::const_dvec3!($col2),
+                    $crate::cast::DVec3Cast { fx3: $col0 }.v3,
+                    $crate::cast::DVec3Cast { fx3: $col1 }.v3,
+                    $crate::cast::DVec3Cast { fx3: $col2 }.v3,
                 ],
             }
             .m3
@@ -304,10 +371,10 @@ macro_rules! const_dmat4 {
         unsafe {
             $crate::cast::DMat4Cast {
                 v4x4: [
-                    $crate::const_dvec4!($col0),
-                    $crate::const_dvec4!($col1),
-                    $crate::const_dvec4!($col2),
-                    $crate::const_dvec4!($col3),
+                    $crate::cast::DVec4Cast { fx4: $col0 }.v4,
+                    $crate::cast::DVec4Cast { fx4: $col1 }.v4,
+                    $crate::cast::DVec4Cast { fx4: $col2 }.v4,
+                    $crate::cast::DVec4Cast { fx4: $col3 }.v4,
                 ],
             }
             .m4
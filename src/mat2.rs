@@ -7,7 +7,7 @@ use crate::{DVec2, Vec2};
 use core::fmt;
 use core::{
     cmp::Ordering,
-    ops::{Add, Deref, DerefMut, Mul, Sub},
+    ops::{Add, Deref, DerefMut, Index, IndexMut, Mul, Sub},
 };
 
 #[cfg(all(
@@ -27,7 +27,14 @@ use core::arch::x86_64::*;
 use std::iter::{Product, Sum};
 
 macro_rules! impl_mat2_methods {
-    ($t:ty, $vec2:ident, $inner:ident) => {
+    ($t:ty, $vec2:ident, $inner:ident, $const_new:ident) => {
+        /// A 2x2 matrix with all elements set to `0.0`.
+        pub const ZERO: Self = Self($inner::ZERO);
+
+        /// A 2x2 identity matrix, where all diagonal elements are `1`, and all off-diagonal
+        /// elements are `0`.
+        pub const IDENTITY: Self = Self($inner::IDENTITY);
+
         /// Creates a 2x2 matrix with all elements set to `0.0`.
         #[inline(always)]
         pub const fn zero() -> Self {
@@ -42,16 +49,16 @@ macro_rules! impl_mat2_methods {
 
         /// Creates a 2x2 matrix from two column vectors.
         #[inline(always)]
-        pub fn from_cols(x_axis: $vec2, y_axis: $vec2) -> Self {
-            Self($inner::from_cols(x_axis.0, y_axis.0))
+        pub const fn from_cols(x_axis: $vec2, y_axis: $vec2) -> Self {
+            $const_new!([x_axis.0.x, x_axis.0.y], [y_axis.0.x, y_axis.0.y])
         }
 
         /// Creates a 2x2 matrix from a `[S; 4]` array stored in column major order.
         /// If your data is stored in row major you will need to `transpose` the returned
         /// matrix.
         #[inline(always)]
-        pub fn from_cols_array(m: &[$t; 4]) -> Self {
-            Self($inner::from_cols_array(m))
+        pub const fn from_cols_array(m: &[$t; 4]) -> Self {
+            $const_new!(*m)
         }
 
         /// Creates a `[S; 4]` array storing data in column major order.
@@ -65,8 +72,8 @@ macro_rules! impl_mat2_methods {
         /// If your data is in row major order you will need to `transpose` the returned
         /// matrix.
         #[inline(always)]
-        pub fn from_cols_array_2d(m: &[[$t; 2]; 2]) -> Self {
-            Self($inner::from_cols_array_2d(m))
+        pub const fn from_cols_array_2d(m: &[[$t; 2]; 2]) -> Self {
+            $const_new!(m[0], m[1])
         }
 
         /// Creates a `[[S; 2]; 2]` 2D array storing data in column major order.
@@ -80,44 +87,82 @@ macro_rules! impl_mat2_methods {
         /// `angle` (in radians).
         #[inline(always)]
         pub fn from_scale_angle(scale: $vec2, angle: $t) -> Self {
+            glam_assert!(scale.is_finite());
+            glam_assert!(angle.is_finite());
             Self($inner::from_scale_angle(scale.0, angle))
         }
 
         /// Creates a 2x2 matrix containing a rotation of `angle` (in radians).
         #[inline(always)]
         pub fn from_angle(angle: $t) -> Self {
+            glam_assert!(angle.is_finite());
             Self($inner::from_angle(angle))
         }
 
         /// Creates a 2x2 matrix containing the given non-uniform `scale`.
         #[inline(always)]
         pub fn from_scale(scale: $vec2) -> Self {
+            glam_assert!(scale.is_finite());
             Self($inner::from_scale(scale.0))
         }
 
-        // #[inline]
-        // pub(crate) fn col(&self, index: usize) -> $vec2 {
-        //     match index {
-        //         0 => self.x_axis(),
-        //         1 => self.y_axis(),
-        //         _ => panic!(
-        //             "index out of bounds: the len is 2 but the index is {}",
-        //             index
-        //         ),
-        //     }
-        // }
-
-        // #[inline]
-        // pub(crate) fn col_mut(&mut self, index: usize) -> &mut $vec2 {
-        //     match index {
-        //         0 => unsafe { &mut *(self.0.as_mut().as_mut_ptr() as *mut $vec2) },
-        //         1 => unsafe { &mut *(self.0.as_mut()[2..].as_mut_ptr() as *mut $vec2) },
-        //         _ => panic!(
-        //             "index out of bounds: the len is 2 but the index is {}",
-        //             index
-        //         ),
-        //     }
-        // }
+        /// Creates a 2x2 matrix from two row vectors.
+        #[inline(always)]
+        pub fn from_rows(x_row: $vec2, y_row: $vec2) -> Self {
+            Self(Matrix2x2::from_rows(x_row.0, y_row.0))
+        }
+
+        /// Creates a 2x2 matrix from a `[S; 4]` array stored in row major order.
+        #[inline(always)]
+        pub fn from_rows_array(m: &[$t; 4]) -> Self {
+            Self(Matrix2x2::from_rows_array(m))
+        }
+
+        /// Creates a `[S; 4]` array storing data in row major order.
+        #[inline(always)]
+        pub fn to_rows_array(&self) -> [$t; 4] {
+            self.0.to_rows_array()
+        }
+
+        /// Returns the matrix column for the given `index`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `index` is greater than 1.
+        #[inline]
+        pub fn col(&self, index: usize) -> $vec2 {
+            $vec2(*self.0.col(index))
+        }
+
+        /// Returns a mutable reference to the matrix column for the given `index`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `index` is greater than 1.
+        #[inline]
+        pub fn col_mut(&mut self, index: usize) -> &mut $vec2 {
+            unsafe { &mut *(self.0.col_mut(index) as *mut _ as *mut $vec2) }
+        }
+
+        /// Returns the matrix row for the given `index`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `index` is greater than 1.
+        #[inline]
+        pub fn row(&self, index: usize) -> $vec2 {
+            $vec2(self.0.row(index))
+        }
+
+        /// Sets the matrix row for the given `index`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `index` is greater than 1.
+        #[inline]
+        pub fn set_row(&mut self, index: usize, row: $vec2) {
+            self.0.set_row(index, row.0)
+        }
 
         /// Returns `true` if, and only if, all elements are finite.
         /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
@@ -148,9 +193,17 @@ macro_rules! impl_mat2_methods {
         /// Returns the inverse of `self`.
         ///
         /// If the matrix is not invertible the returned matrix will be invalid.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if `self` or the result is non-finite when `glam_assert` is enabled, unless
+        /// a handler set with `set_assert_handler()` handles the failure instead.
         #[inline(always)]
         pub fn inverse(&self) -> Self {
-            Self(self.0.inverse())
+            glam_assert!(self.is_finite());
+            let inverse = Self(self.0.inverse());
+            glam_assert!(inverse.is_finite());
+            inverse
         }
 
         /// Transforms a 2D vector.
@@ -196,6 +249,25 @@ macro_rules! impl_mat2_methods {
         pub fn abs_diff_eq(&self, other: &Self, max_abs_diff: $t) -> bool {
             self.0.abs_diff_eq(&other.0, max_abs_diff)
         }
+
+        /// Returns true if the difference of all elements between `self` and `other` is less
+        /// than or equal to `max_abs_diff` or to `max_relative` times the larger magnitude.
+        ///
+        /// This is the comparison used by `approx::relative_eq!` and suits values of widely
+        /// differing magnitudes, where a single `max_abs_diff` does not fit.
+        #[inline(always)]
+        pub fn relative_eq(&self, other: &Self, max_abs_diff: $t, max_relative: $t) -> bool {
+            self.0.relative_eq(&other.0, max_abs_diff, max_relative)
+        }
+
+        /// Returns true if all elements of `self` and `other` are within `max_abs_diff` of each
+        /// other or at most `max_ulps` representable values apart.
+        ///
+        /// This is the comparison used by `approx::ulps_eq!`.
+        #[inline(always)]
+        pub fn ulps_eq(&self, other: &Self, max_abs_diff: $t, max_ulps: u32) -> bool {
+            self.0.ulps_eq(&other.0, max_abs_diff, max_ulps)
+        }
     };
 }
 
@@ -203,7 +275,7 @@ macro_rules! impl_mat2_traits {
     ($t:ty, $new:ident, $mat2:ident, $vec2:ident) => {
         /// Creates a 2x2 matrix from two column vectors.
         #[inline(always)]
-        pub fn $new(x_axis: $vec2, y_axis: $vec2) -> $mat2 {
+        pub const fn $new(x_axis: $vec2, y_axis: $vec2) -> $mat2 {
             $mat2::from_cols(x_axis, y_axis)
         }
 
@@ -214,6 +286,24 @@ macro_rules! impl_mat2_traits {
             }
         }
 
+        impl Index<(usize, usize)> for $mat2 {
+            type Output = $t;
+            /// Returns the element in column `index.0` and row `index.1`.
+            #[inline]
+            fn index(&self, (col, row): (usize, usize)) -> &$t {
+                let col = unsafe { &*(self.0.col(col) as *const _ as *const $vec2) };
+                &col[row]
+            }
+        }
+
+        impl IndexMut<(usize, usize)> for $mat2 {
+            /// Returns a mutable reference to the element in column `index.0` and row `index.1`.
+            #[inline]
+            fn index_mut(&mut self, (col, row): (usize, usize)) -> &mut $t {
+                &mut self.col_mut(col)[row]
+            }
+        }
+
         impl PartialEq for $mat2 {
             #[inline]
             fn eq(&self, other: &Self) -> bool {
@@ -318,7 +408,17 @@ macro_rules! impl_mat2_traits {
         #[cfg(not(target_arch = "spirv"))]
         impl fmt::Display for $mat2 {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                write!(f, "[{}, {}]", self.x_axis, self.y_axis)
+                crate::display::fmt_columns(f, &[self.x_axis, self.y_axis])
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl core::str::FromStr for $mat2 {
+            type Err = crate::ParseError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut a = [0.0; 4];
+                crate::parse::parse_elements(s, &mut a, 2)?;
+                Ok(Self::from_cols_array(&a))
             }
         }
 
@@ -356,7 +456,7 @@ type InnerF32 = crate::core::storage::Vector2x2<XY<f32>>;
 pub struct Mat2(pub(crate) InnerF32);
 
 impl Mat2 {
-    impl_mat2_methods!(f32, Vec2, InnerF32);
+    impl_mat2_methods!(f32, Vec2, InnerF32, const_mat2);
 
     #[inline(always)]
     pub fn as_f64(&self) -> DMat2 {
@@ -373,7 +473,7 @@ type InnerF64 = crate::core::storage::Vector2x2<XY<f64>>;
 pub struct DMat2(pub(crate) InnerF64);
 
 impl DMat2 {
-    impl_mat2_methods!(f64, DVec2, InnerF64);
+    impl_mat2_methods!(f64, DVec2, InnerF64, const_dmat2);
 
     #[inline(always)]
     pub fn as_f32(&self) -> Mat2 {
@@ -0,0 +1,306 @@
+use crate::core::storage::{Vector2x3, XYZ};
+use crate::{DMat2, DMat3, DMat3x2, DVec2, DVec3, Mat2, Mat3, Mat3x2, Vec2, Vec3};
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+use core::ops::{Add, Deref, DerefMut, Mul, Sub};
+
+macro_rules! impl_mat2x3_methods {
+    ($t:ty, $vec2:ident, $vec3:ident, $mat2:ident, $mat3:ident, $mat3x2:ident, $inner:ident) => {
+        /// A 2x3 matrix with all elements set to `0.0`.
+        pub const ZERO: Self = Self::from_cols($vec3::ZERO, $vec3::ZERO);
+
+        /// A 2x3 matrix where the diagonal elements are `1` and all other elements are `0`.
+        pub const IDENTITY: Self = Self::from_cols($vec3::X, $vec3::Y);
+
+        /// Creates a 2x3 matrix from two column vectors.
+        #[inline(always)]
+        pub const fn from_cols(x_axis: $vec3, y_axis: $vec3) -> Self {
+            Self($inner {
+                x_axis: x_axis.0,
+                y_axis: y_axis.0,
+            })
+        }
+
+        /// Creates a 2x3 matrix from a `[S; 6]` array stored in column major order.
+        #[inline(always)]
+        pub const fn from_cols_array(m: &[$t; 6]) -> Self {
+            Self::from_cols($vec3::new(m[0], m[1], m[2]), $vec3::new(m[3], m[4], m[5]))
+        }
+
+        /// Creates a `[S; 6]` array storing data in column major order.
+        #[inline]
+        pub fn to_cols_array(&self) -> [$t; 6] {
+            let [m00, m01, m02] = <[$t; 3]>::from(self.x_axis);
+            let [m10, m11, m12] = <[$t; 3]>::from(self.y_axis);
+            [m00, m01, m02, m10, m11, m12]
+        }
+
+        /// Creates a 2x3 matrix from a `[[S; 3]; 2]` 2D array stored in column major order.
+        #[inline(always)]
+        pub const fn from_cols_array_2d(m: &[[$t; 3]; 2]) -> Self {
+            Self::from_cols($vec3::from_array(m[0]), $vec3::from_array(m[1]))
+        }
+
+        /// Creates a `[[S; 3]; 2]` 2D array storing data in column major order.
+        #[inline]
+        pub fn to_cols_array_2d(&self) -> [[$t; 3]; 2] {
+            [self.x_axis.into(), self.y_axis.into()]
+        }
+
+        /// Returns `true` if, and only if, all elements are finite.
+        /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+        #[inline]
+        pub fn is_finite(&self) -> bool {
+            self.x_axis.is_finite() && self.y_axis.is_finite()
+        }
+
+        /// Returns `true` if any elements are `NaN`.
+        #[inline]
+        pub fn is_nan(&self) -> bool {
+            self.x_axis.is_nan() || self.y_axis.is_nan()
+        }
+
+        /// Returns the transpose of `self`, a 3x2 matrix whose columns are the rows of `self`.
+        #[inline]
+        pub fn transpose(&self) -> $mat3x2 {
+            $mat3x2::from_cols(
+                $vec2::new(self.x_axis.x, self.y_axis.x),
+                $vec2::new(self.x_axis.y, self.y_axis.y),
+                $vec2::new(self.x_axis.z, self.y_axis.z),
+            )
+        }
+
+        /// Transforms a 2D vector, returning a 3D vector.
+        #[inline]
+        pub fn mul_vec2(&self, other: $vec2) -> $vec3 {
+            glam_assert!(self.is_finite());
+            glam_assert!(other.is_finite());
+            let transformed = self.x_axis * other.x + self.y_axis * other.y;
+            glam_assert!(transformed.is_finite());
+            transformed
+        }
+
+        /// Multiplies this matrix by a 2x2 matrix, returning a 2x3 matrix.
+        #[inline]
+        pub fn mul_mat2(&self, other: &$mat2) -> Self {
+            Self::from_cols(self.mul_vec2(other.x_axis), self.mul_vec2(other.y_axis))
+        }
+
+        /// Multiplies this matrix by a 3x2 matrix, returning a 3x3 matrix.
+        #[inline]
+        pub fn mul_mat3x2(&self, other: &$mat3x2) -> $mat3 {
+            $mat3::from_cols(
+                self.mul_vec2(other.x_axis),
+                self.mul_vec2(other.y_axis),
+                self.mul_vec2(other.z_axis),
+            )
+        }
+
+        /// Adds two 2x3 matrices.
+        #[inline]
+        pub fn add_mat2x3(&self, other: &Self) -> Self {
+            Self::from_cols(self.x_axis + other.x_axis, self.y_axis + other.y_axis)
+        }
+
+        /// Subtracts two 2x3 matrices.
+        #[inline]
+        pub fn sub_mat2x3(&self, other: &Self) -> Self {
+            Self::from_cols(self.x_axis - other.x_axis, self.y_axis - other.y_axis)
+        }
+
+        /// Multiplies a 2x3 matrix by a scalar.
+        #[inline]
+        pub fn mul_scalar(&self, other: $t) -> Self {
+            Self::from_cols(self.x_axis * other, self.y_axis * other)
+        }
+
+        /// Returns true if the absolute difference of all elements between `self` and `other`
+        /// is less than or equal to `max_abs_diff`.
+        ///
+        /// This can be used to compare if two 2x3 matrices contain similar elements. It works
+        /// best when comparing with a known value. The `max_abs_diff` that should be used used
+        /// depends on the values being compared against.
+        ///
+        /// For more on floating point comparisons see
+        /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+        #[inline]
+        pub fn abs_diff_eq(&self, other: Self, max_abs_diff: $t) -> bool {
+            self.x_axis.abs_diff_eq(other.x_axis, max_abs_diff)
+                && self.y_axis.abs_diff_eq(other.y_axis, max_abs_diff)
+        }
+    };
+}
+
+macro_rules! impl_mat2x3_traits {
+    ($t:ty, $new:ident, $mat2x3:ident, $mat3x2:ident, $mat2:ident, $mat3:ident, $vec2:ident, $vec3:ident) => {
+        /// Creates a 2x3 matrix from two column vectors.
+        #[inline(always)]
+        pub const fn $new(x_axis: $vec3, y_axis: $vec3) -> $mat2x3 {
+            $mat2x3::from_cols(x_axis, y_axis)
+        }
+
+        impl Default for $mat2x3 {
+            #[inline(always)]
+            fn default() -> Self {
+                Self::IDENTITY
+            }
+        }
+
+        impl PartialEq for $mat2x3 {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.x_axis.eq(&other.x_axis) && self.y_axis.eq(&other.y_axis)
+            }
+        }
+
+        impl Deref for $mat2x3 {
+            type Target = Vector2x3<$vec3>;
+            #[inline(always)]
+            fn deref(&self) -> &Self::Target {
+                unsafe { &*(self as *const Self as *const Self::Target) }
+            }
+        }
+
+        impl DerefMut for $mat2x3 {
+            #[inline(always)]
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                unsafe { &mut *(self as *mut Self as *mut Self::Target) }
+            }
+        }
+
+        impl AsRef<[$t; 6]> for $mat2x3 {
+            #[inline(always)]
+            fn as_ref(&self) -> &[$t; 6] {
+                unsafe { &*(self as *const Self as *const [$t; 6]) }
+            }
+        }
+
+        impl AsMut<[$t; 6]> for $mat2x3 {
+            #[inline(always)]
+            fn as_mut(&mut self) -> &mut [$t; 6] {
+                unsafe { &mut *(self as *mut Self as *mut [$t; 6]) }
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl fmt::Display for $mat2x3 {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                crate::display::fmt_columns(f, &[self.x_axis, self.y_axis])
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl fmt::Debug for $mat2x3 {
+            fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                fmt.debug_struct(stringify!($mat2x3))
+                    .field("x_axis", &self.x_axis)
+                    .field("y_axis", &self.y_axis)
+                    .finish()
+            }
+        }
+
+        impl Add<$mat2x3> for $mat2x3 {
+            type Output = Self;
+            #[inline(always)]
+            fn add(self, other: Self) -> Self {
+                self.add_mat2x3(&other)
+            }
+        }
+
+        impl Sub<$mat2x3> for $mat2x3 {
+            type Output = Self;
+            #[inline(always)]
+            fn sub(self, other: Self) -> Self {
+                self.sub_mat2x3(&other)
+            }
+        }
+
+        impl Mul<$mat2> for $mat2x3 {
+            type Output = Self;
+            #[inline(always)]
+            fn mul(self, other: $mat2) -> Self {
+                self.mul_mat2(&other)
+            }
+        }
+
+        impl Mul<$mat3x2> for $mat2x3 {
+            type Output = $mat3;
+            #[inline(always)]
+            fn mul(self, other: $mat3x2) -> $mat3 {
+                self.mul_mat3x2(&other)
+            }
+        }
+
+        impl Mul<$mat2x3> for $mat3 {
+            type Output = $mat2x3;
+            #[inline]
+            fn mul(self, other: $mat2x3) -> $mat2x3 {
+                $mat2x3::from_cols(self.mul_vec3(other.x_axis), self.mul_vec3(other.y_axis))
+            }
+        }
+
+        impl Mul<$vec2> for $mat2x3 {
+            type Output = $vec3;
+            #[inline(always)]
+            fn mul(self, other: $vec2) -> $vec3 {
+                self.mul_vec2(other)
+            }
+        }
+
+        impl Mul<$mat2x3> for $t {
+            type Output = $mat2x3;
+            #[inline(always)]
+            fn mul(self, other: $mat2x3) -> $mat2x3 {
+                other.mul_scalar(self)
+            }
+        }
+
+        impl Mul<$t> for $mat2x3 {
+            type Output = Self;
+            #[inline(always)]
+            fn mul(self, other: $t) -> Self {
+                self.mul_scalar(other)
+            }
+        }
+
+        impl From<$mat3> for $mat2x3 {
+            /// Creates a 2x3 matrix from the first two columns of `m`.
+            #[inline]
+            fn from(m: $mat3) -> Self {
+                Self::from_cols(m.x_axis, m.y_axis)
+            }
+        }
+
+        impl From<$mat2x3> for $mat3 {
+            /// Creates a 3x3 matrix from `m` with a `z_axis` of `[0, 0, 1]`.
+            #[inline]
+            fn from(m: $mat2x3) -> Self {
+                Self::from_cols(m.x_axis, m.y_axis, $vec3::Z)
+            }
+        }
+    };
+}
+
+type InnerF32 = Vector2x3<XYZ<f32>>;
+
+/// A 2x3 column major matrix, with two columns and three rows.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Mat2x3(pub(crate) InnerF32);
+
+impl Mat2x3 {
+    impl_mat2x3_methods!(f32, Vec2, Vec3, Mat2, Mat3, Mat3x2, InnerF32);
+}
+impl_mat2x3_traits!(f32, mat2x3, Mat2x3, Mat3x2, Mat2, Mat3, Vec2, Vec3);
+
+type InnerF64 = Vector2x3<XYZ<f64>>;
+
+/// A 2x3 column major matrix, with two columns and three rows.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct DMat2x3(pub(crate) InnerF64);
+
+impl DMat2x3 {
+    impl_mat2x3_methods!(f64, DVec2, DVec3, DMat2, DMat3, DMat3x2, InnerF64);
+}
+impl_mat2x3_traits!(f64, dmat2x3, DMat2x3, DMat3x2, DMat2, DMat3, DVec2, DVec3);
@@ -1,20 +1,43 @@
+#[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
+use crate::core::storage::XYZF32A16;
 use crate::core::{
     storage::{Vector3x3, XYZ},
     traits::matrix::{FloatMatrix3x3, Matrix3x3, MatrixConst},
 };
-use crate::{DQuat, DVec2, DVec3, Quat, Vec2, Vec3, Vec3A, Vec3Swizzles};
+use crate::{DQuat, DVec2, DVec3, Mat4, Quat, Vec2, Vec3, Vec3A, Vec3Swizzles, Vec4};
+
+#[cfg(all(
+    target_feature = "sse2",
+    not(feature = "scalar-math"),
+    target_arch = "x86"
+))]
+use core::arch::x86::*;
+#[cfg(all(
+    target_feature = "sse2",
+    not(feature = "scalar-math"),
+    target_arch = "x86_64"
+))]
+use core::arch::x86_64::*;
+
 #[cfg(not(target_arch = "spirv"))]
 use core::fmt;
 use core::{
     cmp::Ordering,
-    ops::{Add, Deref, DerefMut, Mul, Sub},
+    ops::{Add, Deref, DerefMut, Index, IndexMut, Mul, Sub},
 };
 
 #[cfg(feature = "std")]
 use std::iter::{Product, Sum};
 
 macro_rules! impl_mat3_methods {
-    ($t:ty, $vec3: ident, $vec2:ident, $quat:ident, $inner:ident) => {
+    ($t:ty, $vec3: ident, $vec2:ident, $quat:ident, $inner:ident, $const_new:ident, $mul_vec3:ident) => {
+        /// A 3x3 matrix with all elements set to `0.0`.
+        pub const ZERO: Self = Self($inner::ZERO);
+
+        /// A 3x3 identity matrix, where all diagonal elements are `1`, and all off-diagonal
+        /// elements are `0`.
+        pub const IDENTITY: Self = Self($inner::IDENTITY);
+
         /// Creates a 3x3 matrix with all elements set to `0.0`.
         #[inline(always)]
         pub const fn zero() -> Self {
@@ -27,18 +50,12 @@ macro_rules! impl_mat3_methods {
             Self($inner::IDENTITY)
         }
 
-        /// Creates a 3x3 matrix from three column vectors.
-        #[inline(always)]
-        pub fn from_cols(x_axis: $vec3, y_axis: $vec3, z_axis: $vec3) -> Self {
-            Self(Matrix3x3::from_cols(x_axis.0, y_axis.0, z_axis.0))
-        }
-
         /// Creates a 3x3 matrix from a `[S; 9]` array stored in column major order.
         /// If your data is stored in row major you will need to `transpose` the returned
         /// matrix.
         #[inline(always)]
-        pub fn from_cols_array(m: &[$t; 9]) -> Self {
-            Self(Matrix3x3::from_cols_array(m))
+        pub const fn from_cols_array(m: &[$t; 9]) -> Self {
+            $const_new!(*m)
         }
 
         /// Creates a `[S; 9]` array storing data in column major order.
@@ -52,8 +69,8 @@ macro_rules! impl_mat3_methods {
         /// If your data is in row major order you will need to `transpose` the returned
         /// matrix.
         #[inline(always)]
-        pub fn from_cols_array_2d(m: &[[$t; 3]; 3]) -> Self {
-            Self(Matrix3x3::from_cols_array_2d(m))
+        pub const fn from_cols_array_2d(m: &[[$t; 3]; 3]) -> Self {
+            $const_new!(m[0], m[1], m[2])
         }
 
         /// Creates a `[[S; 3]; 3]` 2D array storing data in column major order.
@@ -69,6 +86,9 @@ macro_rules! impl_mat3_methods {
         /// The resulting matrix can be used to transform 2D points and vectors.
         #[inline(always)]
         pub fn from_scale_angle_translation(scale: $vec2, angle: $t, translation: $vec2) -> Self {
+            glam_assert!(scale.is_finite());
+            glam_assert!(angle.is_finite());
+            glam_assert!(translation.is_finite());
             Self(FloatMatrix3x3::from_scale_angle_translation(
                 scale.0,
                 angle,
@@ -79,6 +99,7 @@ macro_rules! impl_mat3_methods {
         #[inline(always)]
         /// Creates a 3x3 rotation matrix from the given quaternion.
         pub fn from_quat(rotation: $quat) -> Self {
+            glam_assert!(rotation.is_finite());
             // TODO: SIMD?
             Self($inner::from_quaternion(rotation.0.into()))
         }
@@ -87,6 +108,8 @@ macro_rules! impl_mat3_methods {
         /// `angle` (in radians).
         #[inline(always)]
         pub fn from_axis_angle(axis: $vec3, angle: $t) -> Self {
+            glam_assert!(axis.is_finite());
+            glam_assert!(angle.is_finite());
             Self(FloatMatrix3x3::from_axis_angle(axis.0, angle))
         }
 
@@ -100,52 +123,90 @@ macro_rules! impl_mat3_methods {
         /// Creates a 3x3 rotation matrix from `angle` (in radians) around the x axis.
         #[inline(always)]
         pub fn from_rotation_x(angle: $t) -> Self {
+            glam_assert!(angle.is_finite());
             Self($inner::from_rotation_x(angle))
         }
 
         /// Creates a 3x3 rotation matrix from `angle` (in radians) around the y axis.
         #[inline(always)]
         pub fn from_rotation_y(angle: $t) -> Self {
+            glam_assert!(angle.is_finite());
             Self($inner::from_rotation_y(angle))
         }
 
         /// Creates a 3x3 rotation matrix from `angle` (in radians) around the z axis.
         #[inline(always)]
         pub fn from_rotation_z(angle: $t) -> Self {
+            glam_assert!(angle.is_finite());
             Self($inner::from_rotation_z(angle))
         }
 
         /// Creates a 3x3 non-uniform scale matrix.
         #[inline(always)]
         pub fn from_scale(scale: $vec3) -> Self {
+            glam_assert!(scale.is_finite());
             Self(Matrix3x3::from_scale(scale.0))
         }
 
-        // #[inline]
-        // pub(crate) fn col(&self, index: usize) -> $vec3 {
-        //     match index {
-        //         0 => self.x_axis,
-        //         1 => self.y_axis,
-        //         2 => self.z_axis,
-        //         _ => panic!(
-        //             "index out of bounds: the len is 3 but the index is {}",
-        //             index
-        //         ),
-        //     }
-        // }
-
-        // #[inline]
-        // pub(crate) fn col_mut(&mut self, index: usize) -> &mut $vec3 {
-        //     match index {
-        //         0 => &mut self.x_axis,
-        //         1 => &mut self.y_axis,
-        //         2 => &mut self.z_axis,
-        //         _ => panic!(
-        //             "index out of bounds: the len is 3 but the index is {}",
-        //             index
-        //         ),
-        //     }
-        // }
+        /// Creates a 3x3 matrix from three row vectors.
+        #[inline(always)]
+        pub fn from_rows(x_row: $vec3, y_row: $vec3, z_row: $vec3) -> Self {
+            Self(Matrix3x3::from_rows(x_row.0, y_row.0, z_row.0))
+        }
+
+        /// Creates a 3x3 matrix from a `[S; 9]` array stored in row major order.
+        #[inline(always)]
+        pub fn from_rows_array(m: &[$t; 9]) -> Self {
+            Self(Matrix3x3::from_rows_array(m))
+        }
+
+        /// Creates a `[S; 9]` array storing data in row major order.
+        #[inline(always)]
+        pub fn to_rows_array(&self) -> [$t; 9] {
+            self.0.to_rows_array()
+        }
+
+        /// Returns the matrix column for the given `index`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `index` is greater than 2.
+        #[inline]
+        pub fn col(&self, index: usize) -> $vec3 {
+            $vec3(*self.0.col(index))
+        }
+
+        /// Returns a mutable reference to the matrix column for the given `index`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `index` is greater than 2.
+        #[inline]
+        pub fn col_mut(&mut self, index: usize) -> &mut $vec3 {
+            // The column storage has the same size and alignment as `$vec3`, including the
+            // padding of `Vec3A` columns.
+            unsafe { &mut *(self.0.col_storage_mut(index) as *mut _ as *mut $vec3) }
+        }
+
+        /// Returns the matrix row for the given `index`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `index` is greater than 2.
+        #[inline]
+        pub fn row(&self, index: usize) -> $vec3 {
+            $vec3(self.0.row(index))
+        }
+
+        /// Sets the matrix row for the given `index`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `index` is greater than 2.
+        #[inline]
+        pub fn set_row(&mut self, index: usize, row: $vec3) {
+            self.0.set_row(index, row.0)
+        }
 
         /// Returns `true` if, and only if, all elements are finite.
         /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
@@ -191,14 +252,22 @@ macro_rules! impl_mat3_methods {
         /// Returns the inverse of `self`.
         ///
         /// If the matrix is not invertible the returned matrix will be invalid.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if `self` or the result is non-finite when `glam_assert` is enabled, unless
+        /// a handler set with `set_assert_handler()` handles the failure instead.
         #[inline(always)]
         pub fn inverse(&self) -> Self {
-            Self(self.0.inverse())
+            glam_assert!(self.is_finite());
+            let inverse = Self(self.0.inverse());
+            glam_assert!(inverse.is_finite());
+            inverse
         }
 
         /// Transforms a 3D vector.
         #[inline(always)]
-        pub fn mul_vec3(&self, other: $vec3) -> $vec3 {
+        pub fn $mul_vec3(&self, other: $vec3) -> $vec3 {
             self.mul_vec3_as_vec3a(other)
         }
 
@@ -206,9 +275,9 @@ macro_rules! impl_mat3_methods {
         #[inline]
         pub fn mul_mat3(&self, other: &Self) -> Self {
             Self::from_cols(
-                self.mul_vec3(other.x_axis),
-                self.mul_vec3(other.y_axis),
-                self.mul_vec3(other.z_axis),
+                self.$mul_vec3(other.x_axis),
+                self.$mul_vec3(other.y_axis),
+                self.$mul_vec3(other.z_axis),
             )
         }
 
@@ -235,6 +304,7 @@ macro_rules! impl_mat3_methods {
         /// is `1.0`.
         #[inline(always)]
         pub fn transform_point2(&self, other: $vec2) -> $vec2 {
+            glam_assert!(other.is_finite());
             self.transform_point2_as_vec3a(other)
         }
 
@@ -243,6 +313,7 @@ macro_rules! impl_mat3_methods {
         /// is `0.0`.
         #[inline(always)]
         pub fn transform_vector2(&self, other: $vec2) -> $vec2 {
+            glam_assert!(other.is_finite());
             self.transform_vector2_as_vec3a(other)
         }
 
@@ -259,14 +330,33 @@ macro_rules! impl_mat3_methods {
         pub fn abs_diff_eq(&self, other: Self, max_abs_diff: $t) -> bool {
             self.0.abs_diff_eq(&other.0, max_abs_diff)
         }
+
+        /// Returns true if the difference of all elements between `self` and `other` is less
+        /// than or equal to `max_abs_diff` or to `max_relative` times the larger magnitude.
+        ///
+        /// This is the comparison used by `approx::relative_eq!` and suits values of widely
+        /// differing magnitudes, where a single `max_abs_diff` does not fit.
+        #[inline(always)]
+        pub fn relative_eq(&self, other: Self, max_abs_diff: $t, max_relative: $t) -> bool {
+            self.0.relative_eq(&other.0, max_abs_diff, max_relative)
+        }
+
+        /// Returns true if all elements of `self` and `other` are within `max_abs_diff` of each
+        /// other or at most `max_ulps` representable values apart.
+        ///
+        /// This is the comparison used by `approx::ulps_eq!`.
+        #[inline(always)]
+        pub fn ulps_eq(&self, other: Self, max_abs_diff: $t, max_ulps: u32) -> bool {
+            self.0.ulps_eq(&other.0, max_abs_diff, max_ulps)
+        }
     };
 }
 
 macro_rules! impl_mat3_traits {
-    ($t:ty, $new:ident, $mat3:ident, $vec3: ident) => {
+    ($t:ty, $new:ident, $mat3:ident, $vec3: ident, $mul_vec3:ident) => {
         /// Creates a 3x3 matrix from three column vectors.
         #[inline(always)]
-        pub fn $new(x_axis: $vec3, y_axis: $vec3, z_axis: $vec3) -> $mat3 {
+        pub const fn $new(x_axis: $vec3, y_axis: $vec3, z_axis: $vec3) -> $mat3 {
             $mat3::from_cols(x_axis, y_axis, z_axis)
         }
 
@@ -277,6 +367,24 @@ macro_rules! impl_mat3_traits {
             }
         }
 
+        impl Index<(usize, usize)> for $mat3 {
+            type Output = $t;
+            /// Returns the element in column `index.0` and row `index.1`.
+            #[inline]
+            fn index(&self, (col, row): (usize, usize)) -> &$t {
+                let col = unsafe { &*(self.0.col_storage(col) as *const _ as *const $vec3) };
+                &col[row]
+            }
+        }
+
+        impl IndexMut<(usize, usize)> for $mat3 {
+            /// Returns a mutable reference to the element in column `index.0` and row `index.1`.
+            #[inline]
+            fn index_mut(&mut self, (col, row): (usize, usize)) -> &mut $t {
+                &mut self.col_mut(col)[row]
+            }
+        }
+
         impl PartialEq for $mat3 {
             #[inline]
             fn eq(&self, other: &Self) -> bool {
@@ -286,13 +394,6 @@ macro_rules! impl_mat3_traits {
             }
         }
 
-        impl PartialOrd for $mat3 {
-            #[inline]
-            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-                self.as_ref().partial_cmp(other.as_ref())
-            }
-        }
-
         impl Deref for $mat3 {
             type Target = Vector3x3<$vec3>;
             #[inline(always)]
@@ -311,7 +412,17 @@ macro_rules! impl_mat3_traits {
         #[cfg(not(target_arch = "spirv"))]
         impl fmt::Display for $mat3 {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                write!(f, "[{}, {}, {}]", self.x_axis, self.y_axis, self.z_axis)
+                crate::display::fmt_columns(f, &[self.x_axis, self.y_axis, self.z_axis])
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl core::str::FromStr for $mat3 {
+            type Err = crate::ParseError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut a = [0.0; 9];
+                crate::parse::parse_elements(s, &mut a, 3)?;
+                Ok(Self::from_cols_array(&a))
             }
         }
 
@@ -325,20 +436,6 @@ macro_rules! impl_mat3_traits {
                     .finish()
             }
         }
-        impl AsRef<[$t; 9]> for $mat3 {
-            #[inline(always)]
-            fn as_ref(&self) -> &[$t; 9] {
-                unsafe { &*(self as *const Self as *const [$t; 9]) }
-            }
-        }
-
-        impl AsMut<[$t; 9]> for $mat3 {
-            #[inline(always)]
-            fn as_mut(&mut self) -> &mut [$t; 9] {
-                unsafe { &mut *(self as *mut Self as *mut [$t; 9]) }
-            }
-        }
-
         impl Add<$mat3> for $mat3 {
             type Output = Self;
             #[inline(always)]
@@ -367,7 +464,7 @@ macro_rules! impl_mat3_traits {
             type Output = $vec3;
             #[inline(always)]
             fn mul(self, other: $vec3) -> $vec3 {
-                self.mul_vec3(other)
+                self.$mul_vec3(other)
             }
         }
 
@@ -409,6 +506,32 @@ macro_rules! impl_mat3_traits {
     };
 }
 
+// implements traits for matrix types which are stored as a contiguous array of elements.
+macro_rules! impl_mat3_array_traits {
+    ($t:ty, $mat3:ident) => {
+        impl PartialOrd for $mat3 {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                self.as_ref().partial_cmp(other.as_ref())
+            }
+        }
+
+        impl AsRef<[$t; 9]> for $mat3 {
+            #[inline(always)]
+            fn as_ref(&self) -> &[$t; 9] {
+                unsafe { &*(self as *const Self as *const [$t; 9]) }
+            }
+        }
+
+        impl AsMut<[$t; 9]> for $mat3 {
+            #[inline(always)]
+            fn as_mut(&mut self) -> &mut [$t; 9] {
+                unsafe { &mut *(self as *mut Self as *mut [$t; 9]) }
+            }
+        }
+    };
+}
+
 type InnerF32 = Vector3x3<XYZ<f32>>;
 
 /// A 3x3 column major matrix.
@@ -417,7 +540,17 @@ type InnerF32 = Vector3x3<XYZ<f32>>;
 pub struct Mat3(pub(crate) InnerF32);
 
 impl Mat3 {
-    impl_mat3_methods!(f32, Vec3, Vec2, Quat, InnerF32);
+    impl_mat3_methods!(f32, Vec3, Vec2, Quat, InnerF32, const_mat3, mul_vec3);
+
+    /// Creates a 3x3 matrix from three column vectors.
+    #[inline(always)]
+    pub const fn from_cols(x_axis: Vec3, y_axis: Vec3, z_axis: Vec3) -> Self {
+        Self(InnerF32 {
+            x_axis: x_axis.0,
+            y_axis: y_axis.0,
+            z_axis: z_axis.0,
+        })
+    }
 
     /// Transforms a `Vec3A`.
     #[inline]
@@ -459,7 +592,8 @@ impl Mat3 {
         )
     }
 }
-impl_mat3_traits!(f32, mat3, Mat3, Vec3);
+impl_mat3_traits!(f32, mat3, Mat3, Vec3, mul_vec3);
+impl_mat3_array_traits!(f32, Mat3);
 
 impl Mul<Vec3A> for Mat3 {
     type Output = Vec3A;
@@ -469,6 +603,135 @@ impl Mul<Vec3A> for Mat3 {
     }
 }
 
+#[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+type InnerF32A = Vector3x3<__m128>;
+
+#[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
+type InnerF32A = Vector3x3<XYZF32A16>;
+
+/// A 3x3 column major matrix with `Vec3A` columns.
+///
+/// This type is 16 byte aligned and each column is padded to 16 bytes. SIMD is used for storage
+/// and operations on supported platforms for better performance than the `Mat3` type.
+///
+/// It is possible to convert between `Mat3` and `Mat3A` types using `From` trait implementations.
+#[derive(Clone, Copy)]
+#[cfg_attr(not(target_arch = "spirv"), repr(C))]
+pub struct Mat3A(pub(crate) InnerF32A);
+
+impl Mat3A {
+    impl_mat3_methods!(f32, Vec3A, Vec2, Quat, InnerF32A, const_mat3a, mul_vec3a);
+
+    /// Creates a 3x3 matrix from three column vectors.
+    #[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+    #[inline(always)]
+    pub const fn from_cols(x_axis: Vec3A, y_axis: Vec3A, z_axis: Vec3A) -> Self {
+        Self(InnerF32A {
+            x_axis: x_axis.0,
+            y_axis: y_axis.0,
+            z_axis: z_axis.0,
+        })
+    }
+
+    /// Creates a 3x3 matrix from three column vectors.
+    #[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
+    #[inline(always)]
+    pub const fn from_cols(x_axis: Vec3A, y_axis: Vec3A, z_axis: Vec3A) -> Self {
+        Self(InnerF32A {
+            x_axis: XYZF32A16::from_xyz(x_axis.0),
+            y_axis: XYZF32A16::from_xyz(y_axis.0),
+            z_axis: XYZF32A16::from_xyz(z_axis.0),
+        })
+    }
+
+    /// Transforms a `Vec3`.
+    #[inline(always)]
+    pub fn mul_vec3(&self, other: Vec3) -> Vec3 {
+        self.mul_vec3a(other.into()).into()
+    }
+
+    /// Creates a 3x3 matrix from the upper left 3x3 elements of `m`.
+    #[inline]
+    pub fn from_mat4(m: &Mat4) -> Self {
+        Self::from_cols(m.x_axis.into(), m.y_axis.into(), m.z_axis.into())
+    }
+
+    #[inline(always)]
+    fn mul_vec3_as_vec3a(&self, other: Vec3A) -> Vec3A {
+        Vec3A(self.0.mul_vector(other.0))
+    }
+
+    #[inline(always)]
+    fn transform_point2_as_vec3a(&self, other: Vec2) -> Vec2 {
+        Vec2(self.0.transform_point2(other.0))
+    }
+
+    #[inline(always)]
+    fn transform_vector2_as_vec3a(&self, other: Vec2) -> Vec2 {
+        Vec2(self.0.transform_vector2(other.0))
+    }
+
+    #[inline(always)]
+    pub fn as_f64(&self) -> DMat3 {
+        DMat3::from_cols(
+            self.x_axis.as_f64(),
+            self.y_axis.as_f64(),
+            self.z_axis.as_f64(),
+        )
+    }
+}
+impl_mat3_traits!(f32, mat3a, Mat3A, Vec3A, mul_vec3a);
+
+impl PartialOrd for Mat3A {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.to_cols_array().partial_cmp(&other.to_cols_array())
+    }
+}
+
+impl Mul<Vec3> for Mat3A {
+    type Output = Vec3;
+    #[inline(always)]
+    fn mul(self, other: Vec3) -> Vec3 {
+        self.mul_vec3(other)
+    }
+}
+
+impl From<Mat3> for Mat3A {
+    #[inline]
+    fn from(m: Mat3) -> Self {
+        Self::from_cols(m.x_axis.into(), m.y_axis.into(), m.z_axis.into())
+    }
+}
+
+impl From<Mat3A> for Mat3 {
+    #[inline]
+    fn from(m: Mat3A) -> Self {
+        Self::from_cols(m.x_axis.into(), m.y_axis.into(), m.z_axis.into())
+    }
+}
+
+impl From<Mat4> for Mat3A {
+    #[inline]
+    fn from(m: Mat4) -> Self {
+        Self::from_mat4(&m)
+    }
+}
+
+impl From<Mat3A> for Mat4 {
+    /// Creates a 4x4 homogeneous transformation matrix with the upper left 3x3 elements set to
+    /// `m`.
+    #[inline]
+    fn from(m: Mat3A) -> Self {
+        Self::from_cols(
+            m.x_axis.extend(0.0),
+            m.y_axis.extend(0.0),
+            m.z_axis.extend(0.0),
+            Vec4::W,
+        )
+    }
+}
+
 type InnerF64 = Vector3x3<XYZ<f64>>;
 
 /// A 3x3 column major matrix.
@@ -477,7 +740,17 @@ type InnerF64 = Vector3x3<XYZ<f64>>;
 pub struct DMat3(pub(crate) InnerF64);
 
 impl DMat3 {
-    impl_mat3_methods!(f64, DVec3, DVec2, DQuat, InnerF64);
+    impl_mat3_methods!(f64, DVec3, DVec2, DQuat, InnerF64, const_dmat3, mul_vec3);
+
+    /// Creates a 3x3 matrix from three column vectors.
+    #[inline(always)]
+    pub const fn from_cols(x_axis: DVec3, y_axis: DVec3, z_axis: DVec3) -> Self {
+        Self(InnerF64 {
+            x_axis: x_axis.0,
+            y_axis: y_axis.0,
+            z_axis: z_axis.0,
+        })
+    }
 
     #[inline(always)]
     pub fn mul_vec3_as_vec3a(&self, other: DVec3) -> DVec3 {
@@ -503,4 +776,5 @@ impl DMat3 {
         )
     }
 }
-impl_mat3_traits!(f64, dmat3, DMat3, DVec3);
+impl_mat3_traits!(f64, dmat3, DMat3, DVec3, mul_vec3);
+impl_mat3_array_traits!(f64, DMat3);
@@ -0,0 +1,377 @@
+use crate::core::storage::{Vector3x2, XY};
+use crate::{DMat2, DMat2x3, DMat3, DVec2, DVec3, Mat2, Mat2x3, Mat3, Vec2, Vec3};
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+use core::ops::{Add, Deref, DerefMut, Mul, Sub};
+
+macro_rules! impl_mat3x2_methods {
+    ($t:ty, $vec2:ident, $vec3:ident, $mat2:ident, $mat3:ident, $mat2x3:ident, $inner:ident) => {
+        /// A 3x2 matrix with all elements set to `0.0`.
+        pub const ZERO: Self = Self::from_cols($vec2::ZERO, $vec2::ZERO, $vec2::ZERO);
+
+        /// A 3x2 matrix where the diagonal elements are `1` and all other elements are `0`.
+        ///
+        /// As a 2D affine transform this is the identity transform.
+        pub const IDENTITY: Self = Self::from_cols($vec2::X, $vec2::Y, $vec2::ZERO);
+
+        /// Creates a 3x2 matrix from three column vectors.
+        #[inline(always)]
+        pub const fn from_cols(x_axis: $vec2, y_axis: $vec2, z_axis: $vec2) -> Self {
+            Self($inner {
+                x_axis: x_axis.0,
+                y_axis: y_axis.0,
+                z_axis: z_axis.0,
+            })
+        }
+
+        /// Creates a 3x2 matrix from a `[S; 6]` array stored in column major order.
+        #[inline(always)]
+        pub const fn from_cols_array(m: &[$t; 6]) -> Self {
+            Self::from_cols(
+                $vec2::new(m[0], m[1]),
+                $vec2::new(m[2], m[3]),
+                $vec2::new(m[4], m[5]),
+            )
+        }
+
+        /// Creates a `[S; 6]` array storing data in column major order.
+        #[inline]
+        pub fn to_cols_array(&self) -> [$t; 6] {
+            let [m00, m01] = <[$t; 2]>::from(self.x_axis);
+            let [m10, m11] = <[$t; 2]>::from(self.y_axis);
+            let [m20, m21] = <[$t; 2]>::from(self.z_axis);
+            [m00, m01, m10, m11, m20, m21]
+        }
+
+        /// Creates a 3x2 matrix from a `[[S; 2]; 3]` 2D array stored in column major order.
+        #[inline(always)]
+        pub const fn from_cols_array_2d(m: &[[$t; 2]; 3]) -> Self {
+            Self::from_cols(
+                $vec2::from_array(m[0]),
+                $vec2::from_array(m[1]),
+                $vec2::from_array(m[2]),
+            )
+        }
+
+        /// Creates a `[[S; 2]; 3]` 2D array storing data in column major order.
+        #[inline]
+        pub fn to_cols_array_2d(&self) -> [[$t; 2]; 3] {
+            [self.x_axis.into(), self.y_axis.into(), self.z_axis.into()]
+        }
+
+        /// Returns `true` if, and only if, all elements are finite.
+        /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+        #[inline]
+        pub fn is_finite(&self) -> bool {
+            self.x_axis.is_finite() && self.y_axis.is_finite() && self.z_axis.is_finite()
+        }
+
+        /// Returns `true` if any elements are `NaN`.
+        #[inline]
+        pub fn is_nan(&self) -> bool {
+            self.x_axis.is_nan() || self.y_axis.is_nan() || self.z_axis.is_nan()
+        }
+
+        /// Returns the transpose of `self`, a 2x3 matrix whose columns are the rows of `self`.
+        #[inline]
+        pub fn transpose(&self) -> $mat2x3 {
+            $mat2x3::from_cols(
+                $vec3::new(self.x_axis.x, self.y_axis.x, self.z_axis.x),
+                $vec3::new(self.x_axis.y, self.y_axis.y, self.z_axis.y),
+            )
+        }
+
+        /// Transforms a 3D vector, returning a 2D vector.
+        #[inline]
+        pub fn mul_vec3(&self, other: $vec3) -> $vec2 {
+            glam_assert!(self.is_finite());
+            glam_assert!(other.is_finite());
+            let transformed = self.x_axis * other.x + self.y_axis * other.y + self.z_axis * other.z;
+            glam_assert!(transformed.is_finite());
+            transformed
+        }
+
+        /// Multiplies this matrix by a 3x3 matrix, returning a 3x2 matrix.
+        #[inline]
+        pub fn mul_mat3(&self, other: &$mat3) -> Self {
+            Self::from_cols(
+                self.mul_vec3(other.x_axis),
+                self.mul_vec3(other.y_axis),
+                self.mul_vec3(other.z_axis),
+            )
+        }
+
+        /// Multiplies this matrix by a 2x3 matrix, returning a 2x2 matrix.
+        #[inline]
+        pub fn mul_mat2x3(&self, other: &$mat2x3) -> $mat2 {
+            $mat2::from_cols(self.mul_vec3(other.x_axis), self.mul_vec3(other.y_axis))
+        }
+
+        /// Adds two 3x2 matrices.
+        #[inline]
+        pub fn add_mat3x2(&self, other: &Self) -> Self {
+            Self::from_cols(
+                self.x_axis + other.x_axis,
+                self.y_axis + other.y_axis,
+                self.z_axis + other.z_axis,
+            )
+        }
+
+        /// Subtracts two 3x2 matrices.
+        #[inline]
+        pub fn sub_mat3x2(&self, other: &Self) -> Self {
+            Self::from_cols(
+                self.x_axis - other.x_axis,
+                self.y_axis - other.y_axis,
+                self.z_axis - other.z_axis,
+            )
+        }
+
+        /// Multiplies a 3x2 matrix by a scalar.
+        #[inline]
+        pub fn mul_scalar(&self, other: $t) -> Self {
+            Self::from_cols(
+                self.x_axis * other,
+                self.y_axis * other,
+                self.z_axis * other,
+            )
+        }
+
+        /// Transforms the given 2D vector as a point.
+        ///
+        /// The matrix is treated as a 2D affine transform, with the upper 2x2 elements as the
+        /// linear part and the `z_axis` as the translation.
+        #[inline]
+        pub fn transform_point2(&self, other: $vec2) -> $vec2 {
+            glam_assert!(self.is_finite());
+            glam_assert!(other.is_finite());
+            let transformed = self.x_axis * other.x + self.y_axis * other.y + self.z_axis;
+            glam_assert!(transformed.is_finite());
+            transformed
+        }
+
+        /// Transforms the given 2D vector as a direction.
+        ///
+        /// This is the same as [`Self::transform_point2()`] except the translation in the
+        /// `z_axis` is not applied.
+        #[inline]
+        pub fn transform_vector2(&self, other: $vec2) -> $vec2 {
+            glam_assert!(self.is_finite());
+            glam_assert!(other.is_finite());
+            let transformed = self.x_axis * other.x + self.y_axis * other.y;
+            glam_assert!(transformed.is_finite());
+            transformed
+        }
+
+        /// Returns true if the absolute difference of all elements between `self` and `other`
+        /// is less than or equal to `max_abs_diff`.
+        ///
+        /// This can be used to compare if two 3x2 matrices contain similar elements. It works
+        /// best when comparing with a known value. The `max_abs_diff` that should be used used
+        /// depends on the values being compared against.
+        ///
+        /// For more on floating point comparisons see
+        /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+        #[inline]
+        pub fn abs_diff_eq(&self, other: Self, max_abs_diff: $t) -> bool {
+            self.x_axis.abs_diff_eq(other.x_axis, max_abs_diff)
+                && self.y_axis.abs_diff_eq(other.y_axis, max_abs_diff)
+                && self.z_axis.abs_diff_eq(other.z_axis, max_abs_diff)
+        }
+    };
+}
+
+macro_rules! impl_mat3x2_traits {
+    ($t:ty, $new:ident, $mat3x2:ident, $mat2x3:ident, $mat2:ident, $mat3:ident, $vec2:ident, $vec3:ident) => {
+        /// Creates a 3x2 matrix from three column vectors.
+        #[inline(always)]
+        pub const fn $new(x_axis: $vec2, y_axis: $vec2, z_axis: $vec2) -> $mat3x2 {
+            $mat3x2::from_cols(x_axis, y_axis, z_axis)
+        }
+
+        impl Default for $mat3x2 {
+            #[inline(always)]
+            fn default() -> Self {
+                Self::IDENTITY
+            }
+        }
+
+        impl PartialEq for $mat3x2 {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.x_axis.eq(&other.x_axis)
+                    && self.y_axis.eq(&other.y_axis)
+                    && self.z_axis.eq(&other.z_axis)
+            }
+        }
+
+        impl Deref for $mat3x2 {
+            type Target = Vector3x2<$vec2>;
+            #[inline(always)]
+            fn deref(&self) -> &Self::Target {
+                unsafe { &*(self as *const Self as *const Self::Target) }
+            }
+        }
+
+        impl DerefMut for $mat3x2 {
+            #[inline(always)]
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                unsafe { &mut *(self as *mut Self as *mut Self::Target) }
+            }
+        }
+
+        impl AsRef<[$t; 6]> for $mat3x2 {
+            #[inline(always)]
+            fn as_ref(&self) -> &[$t; 6] {
+                unsafe { &*(self as *const Self as *const [$t; 6]) }
+            }
+        }
+
+        impl AsMut<[$t; 6]> for $mat3x2 {
+            #[inline(always)]
+            fn as_mut(&mut self) -> &mut [$t; 6] {
+                unsafe { &mut *(self as *mut Self as *mut [$t; 6]) }
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl fmt::Display for $mat3x2 {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                crate::display::fmt_columns(f, &[self.x_axis, self.y_axis, self.z_axis])
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl fmt::Debug for $mat3x2 {
+            fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                fmt.debug_struct(stringify!($mat3x2))
+                    .field("x_axis", &self.x_axis)
+                    .field("y_axis", &self.y_axis)
+                    .field("z_axis", &self.z_axis)
+                    .finish()
+            }
+        }
+
+        impl Add<$mat3x2> for $mat3x2 {
+            type Output = Self;
+            #[inline(always)]
+            fn add(self, other: Self) -> Self {
+                self.add_mat3x2(&other)
+            }
+        }
+
+        impl Sub<$mat3x2> for $mat3x2 {
+            type Output = Self;
+            #[inline(always)]
+            fn sub(self, other: Self) -> Self {
+                self.sub_mat3x2(&other)
+            }
+        }
+
+        impl Mul<$mat3> for $mat3x2 {
+            type Output = Self;
+            #[inline(always)]
+            fn mul(self, other: $mat3) -> Self {
+                self.mul_mat3(&other)
+            }
+        }
+
+        impl Mul<$mat2x3> for $mat3x2 {
+            type Output = $mat2;
+            #[inline(always)]
+            fn mul(self, other: $mat2x3) -> $mat2 {
+                self.mul_mat2x3(&other)
+            }
+        }
+
+        impl Mul<$mat3x2> for $mat2 {
+            type Output = $mat3x2;
+            #[inline]
+            fn mul(self, other: $mat3x2) -> $mat3x2 {
+                $mat3x2::from_cols(
+                    self.mul_vec2(other.x_axis),
+                    self.mul_vec2(other.y_axis),
+                    self.mul_vec2(other.z_axis),
+                )
+            }
+        }
+
+        impl Mul<$vec3> for $mat3x2 {
+            type Output = $vec2;
+            #[inline(always)]
+            fn mul(self, other: $vec3) -> $vec2 {
+                self.mul_vec3(other)
+            }
+        }
+
+        impl Mul<$mat3x2> for $t {
+            type Output = $mat3x2;
+            #[inline(always)]
+            fn mul(self, other: $mat3x2) -> $mat3x2 {
+                other.mul_scalar(self)
+            }
+        }
+
+        impl Mul<$t> for $mat3x2 {
+            type Output = Self;
+            #[inline(always)]
+            fn mul(self, other: $t) -> Self {
+                self.mul_scalar(other)
+            }
+        }
+
+        impl From<$mat3> for $mat3x2 {
+            /// Creates a 3x2 matrix from the upper two rows of `m`.
+            #[inline]
+            fn from(m: $mat3) -> Self {
+                Self::from_cols(
+                    m.x_axis.truncate(),
+                    m.y_axis.truncate(),
+                    m.z_axis.truncate(),
+                )
+            }
+        }
+
+        impl From<$mat3x2> for $mat3 {
+            /// Creates a 3x3 matrix from `m` with a bottom row of `[0, 0, 1]`.
+            #[inline]
+            fn from(m: $mat3x2) -> Self {
+                Self::from_cols(
+                    m.x_axis.extend(0.0),
+                    m.y_axis.extend(0.0),
+                    m.z_axis.extend(1.0),
+                )
+            }
+        }
+    };
+}
+
+type InnerF32 = Vector3x2<XY<f32>>;
+
+/// A 3x2 column major matrix, with three columns and two rows.
+///
+/// This is commonly used to store 2D affine transforms, with the last column holding the
+/// translation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Mat3x2(pub(crate) InnerF32);
+
+impl Mat3x2 {
+    impl_mat3x2_methods!(f32, Vec2, Vec3, Mat2, Mat3, Mat2x3, InnerF32);
+}
+impl_mat3x2_traits!(f32, mat3x2, Mat3x2, Mat2x3, Mat2, Mat3, Vec2, Vec3);
+
+type InnerF64 = Vector3x2<XY<f64>>;
+
+/// A 3x2 column major matrix, with three columns and two rows.
+///
+/// This is commonly used to store 2D affine transforms, with the last column holding the
+/// translation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct DMat3x2(pub(crate) InnerF64);
+
+impl DMat3x2 {
+    impl_mat3x2_methods!(f64, DVec2, DVec3, DMat2, DMat3, DMat2x3, InnerF64);
+}
+impl_mat3x2_traits!(f64, dmat3x2, DMat3x2, DMat2x3, DMat2, DMat3, DVec2, DVec3);
@@ -0,0 +1,450 @@
+use crate::core::storage::{Vector3x4, XYZW};
+use crate::{DMat3, DMat4, DMat4x3, DVec3, DVec4, Mat3, Mat4, Mat4x3, Vec3, Vec4};
+
+#[cfg(all(
+    target_feature = "sse2",
+    not(feature = "scalar-math"),
+    target_arch = "x86"
+))]
+use core::arch::x86::*;
+#[cfg(all(
+    target_feature = "sse2",
+    not(feature = "scalar-math"),
+    target_arch = "x86_64"
+))]
+use core::arch::x86_64::*;
+
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+use core::ops::{Add, Deref, DerefMut, Mul, Sub};
+
+macro_rules! impl_mat3x4_methods {
+    ($t:ty, $vec4:ident, $vec3:ident, $mat3:ident, $mat4:ident, $mat4x3:ident, $inner:ident) => {
+        /// A 3x4 matrix with all elements set to `0.0`.
+        pub const ZERO: Self = Self::from_cols($vec4::ZERO, $vec4::ZERO, $vec4::ZERO);
+
+        /// A 3x4 matrix where the diagonal elements are `1` and all other elements are `0`.
+        ///
+        /// As affine rows this is the identity transform, see [`Self::from_affine_rows()`].
+        pub const IDENTITY: Self = Self::from_cols($vec4::X, $vec4::Y, $vec4::Z);
+
+        /// Creates a 3x4 matrix from three column vectors.
+        #[inline(always)]
+        pub const fn from_cols(x_axis: $vec4, y_axis: $vec4, z_axis: $vec4) -> Self {
+            Self($inner {
+                x_axis: x_axis.0,
+                y_axis: y_axis.0,
+                z_axis: z_axis.0,
+            })
+        }
+
+        /// Creates a 3x4 matrix from a `[S; 12]` array stored in column major order.
+        #[inline(always)]
+        pub const fn from_cols_array(m: &[$t; 12]) -> Self {
+            Self::from_cols(
+                $vec4::new(m[0], m[1], m[2], m[3]),
+                $vec4::new(m[4], m[5], m[6], m[7]),
+                $vec4::new(m[8], m[9], m[10], m[11]),
+            )
+        }
+
+        /// Creates a `[S; 12]` array storing data in column major order.
+        #[inline]
+        pub fn to_cols_array(&self) -> [$t; 12] {
+            let [m00, m01, m02, m03] = <[$t; 4]>::from(self.x_axis);
+            let [m10, m11, m12, m13] = <[$t; 4]>::from(self.y_axis);
+            let [m20, m21, m22, m23] = <[$t; 4]>::from(self.z_axis);
+            [m00, m01, m02, m03, m10, m11, m12, m13, m20, m21, m22, m23]
+        }
+
+        /// Creates a 3x4 matrix from a `[[S; 4]; 3]` 2D array stored in column major order.
+        #[inline(always)]
+        pub const fn from_cols_array_2d(m: &[[$t; 4]; 3]) -> Self {
+            Self::from_cols(
+                $vec4::from_array(m[0]),
+                $vec4::from_array(m[1]),
+                $vec4::from_array(m[2]),
+            )
+        }
+
+        /// Creates a `[[S; 4]; 3]` 2D array storing data in column major order.
+        #[inline]
+        pub fn to_cols_array_2d(&self) -> [[$t; 4]; 3] {
+            [self.x_axis.into(), self.y_axis.into(), self.z_axis.into()]
+        }
+
+        /// Returns `true` if, and only if, all elements are finite.
+        /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+        #[inline]
+        pub fn is_finite(&self) -> bool {
+            self.x_axis.is_finite() && self.y_axis.is_finite() && self.z_axis.is_finite()
+        }
+
+        /// Returns `true` if any elements are `NaN`.
+        #[inline]
+        pub fn is_nan(&self) -> bool {
+            self.x_axis.is_nan() || self.y_axis.is_nan() || self.z_axis.is_nan()
+        }
+
+        /// Creates a 3x4 matrix holding the upper three rows of the 3D affine transform `m` in its
+        /// columns, with the translation in the `w` elements.
+        ///
+        /// This is the layout commonly used for GPU skinning palettes. The bottom row of `m` is
+        /// ignored and can be restored with `Mat4::from_affine_rows()`.
+        #[inline]
+        pub fn from_affine_rows(m: &$mat4) -> Self {
+            $mat4x3::from(*m).transpose()
+        }
+
+        /// Returns the transpose of `self`, a 4x3 matrix whose columns are the rows of `self`.
+        #[inline]
+        pub fn transpose(&self) -> $mat4x3 {
+            $mat4x3::from_cols(
+                $vec3::new(self.x_axis.x, self.y_axis.x, self.z_axis.x),
+                $vec3::new(self.x_axis.y, self.y_axis.y, self.z_axis.y),
+                $vec3::new(self.x_axis.z, self.y_axis.z, self.z_axis.z),
+                $vec3::new(self.x_axis.w, self.y_axis.w, self.z_axis.w),
+            )
+        }
+
+        /// Transforms a 3D vector, returning a 4D vector.
+        #[inline]
+        pub fn mul_vec3(&self, other: $vec3) -> $vec4 {
+            glam_assert!(self.is_finite());
+            glam_assert!(other.is_finite());
+            let transformed = self.x_axis * other.x + self.y_axis * other.y + self.z_axis * other.z;
+            glam_assert!(transformed.is_finite());
+            transformed
+        }
+
+        /// Multiplies this matrix by a 3x3 matrix, returning a 3x4 matrix.
+        #[inline]
+        pub fn mul_mat3(&self, other: &$mat3) -> Self {
+            Self::from_cols(
+                self.mul_vec3(other.x_axis),
+                self.mul_vec3(other.y_axis),
+                self.mul_vec3(other.z_axis),
+            )
+        }
+
+        /// Multiplies this matrix by a 4x3 matrix, returning a 4x4 matrix.
+        #[inline]
+        pub fn mul_mat4x3(&self, other: &$mat4x3) -> $mat4 {
+            $mat4::from_cols(
+                self.mul_vec3(other.x_axis),
+                self.mul_vec3(other.y_axis),
+                self.mul_vec3(other.z_axis),
+                self.mul_vec3(other.w_axis),
+            )
+        }
+
+        /// Adds two 3x4 matrices.
+        #[inline]
+        pub fn add_mat3x4(&self, other: &Self) -> Self {
+            Self::from_cols(
+                self.x_axis + other.x_axis,
+                self.y_axis + other.y_axis,
+                self.z_axis + other.z_axis,
+            )
+        }
+
+        /// Subtracts two 3x4 matrices.
+        #[inline]
+        pub fn sub_mat3x4(&self, other: &Self) -> Self {
+            Self::from_cols(
+                self.x_axis - other.x_axis,
+                self.y_axis - other.y_axis,
+                self.z_axis - other.z_axis,
+            )
+        }
+
+        /// Multiplies a 3x4 matrix by a scalar.
+        #[inline]
+        pub fn mul_scalar(&self, other: $t) -> Self {
+            Self::from_cols(
+                self.x_axis * other,
+                self.y_axis * other,
+                self.z_axis * other,
+            )
+        }
+
+        /// Transforms the given 3D vector as a point.
+        ///
+        /// The matrix is treated as the rows of a 3D affine transform as created by
+        /// [`Self::from_affine_rows()`], which is equivalent to
+        /// `self.transpose().transform_point3(other)`.
+        #[inline]
+        pub fn transform_point3_rows(&self, other: $vec3) -> $vec3 {
+            glam_assert!(self.is_finite());
+            glam_assert!(other.is_finite());
+            let other = other.extend(1.0);
+            let transformed = $vec3::new(
+                self.x_axis.dot(other),
+                self.y_axis.dot(other),
+                self.z_axis.dot(other),
+            );
+            glam_assert!(transformed.is_finite());
+            transformed
+        }
+
+        /// Transforms the given 3D vector as a direction.
+        ///
+        /// This is the same as [`Self::transform_point3_rows()`] except the translation in the `w`
+        /// elements is not applied.
+        #[inline]
+        pub fn transform_vector3_rows(&self, other: $vec3) -> $vec3 {
+            glam_assert!(self.is_finite());
+            glam_assert!(other.is_finite());
+            let other = other.extend(0.0);
+            let transformed = $vec3::new(
+                self.x_axis.dot(other),
+                self.y_axis.dot(other),
+                self.z_axis.dot(other),
+            );
+            glam_assert!(transformed.is_finite());
+            transformed
+        }
+
+        /// Returns true if the absolute difference of all elements between `self` and `other`
+        /// is less than or equal to `max_abs_diff`.
+        ///
+        /// This can be used to compare if two 3x4 matrices contain similar elements. It works
+        /// best when comparing with a known value. The `max_abs_diff` that should be used used
+        /// depends on the values being compared against.
+        ///
+        /// For more on floating point comparisons see
+        /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+        #[inline]
+        pub fn abs_diff_eq(&self, other: Self, max_abs_diff: $t) -> bool {
+            self.x_axis.abs_diff_eq(other.x_axis, max_abs_diff)
+                && self.y_axis.abs_diff_eq(other.y_axis, max_abs_diff)
+                && self.z_axis.abs_diff_eq(other.z_axis, max_abs_diff)
+        }
+    };
+}
+
+macro_rules! impl_mat3x4_traits {
+    ($t:ty, $new:ident, $mat3x4:ident, $mat4x3:ident, $mat3:ident, $mat4:ident, $vec3:ident, $vec4:ident) => {
+        /// Creates a 3x4 matrix from three column vectors.
+        #[inline(always)]
+        pub const fn $new(x_axis: $vec4, y_axis: $vec4, z_axis: $vec4) -> $mat3x4 {
+            $mat3x4::from_cols(x_axis, y_axis, z_axis)
+        }
+
+        impl Default for $mat3x4 {
+            #[inline(always)]
+            fn default() -> Self {
+                Self::IDENTITY
+            }
+        }
+
+        impl PartialEq for $mat3x4 {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.x_axis.eq(&other.x_axis)
+                    && self.y_axis.eq(&other.y_axis)
+                    && self.z_axis.eq(&other.z_axis)
+            }
+        }
+
+        impl Deref for $mat3x4 {
+            type Target = Vector3x4<$vec4>;
+            #[inline(always)]
+            fn deref(&self) -> &Self::Target {
+                unsafe { &*(self as *const Self as *const Self::Target) }
+            }
+        }
+
+        impl DerefMut for $mat3x4 {
+            #[inline(always)]
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                unsafe { &mut *(self as *mut Self as *mut Self::Target) }
+            }
+        }
+
+        impl AsRef<[$t; 12]> for $mat3x4 {
+            #[inline(always)]
+            fn as_ref(&self) -> &[$t; 12] {
+                unsafe { &*(self as *const Self as *const [$t; 12]) }
+            }
+        }
+
+        impl AsMut<[$t; 12]> for $mat3x4 {
+            #[inline(always)]
+            fn as_mut(&mut self) -> &mut [$t; 12] {
+                unsafe { &mut *(self as *mut Self as *mut [$t; 12]) }
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl fmt::Display for $mat3x4 {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                crate::display::fmt_columns(f, &[self.x_axis, self.y_axis, self.z_axis])
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl fmt::Debug for $mat3x4 {
+            fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                fmt.debug_struct(stringify!($mat3x4))
+                    .field("x_axis", &self.x_axis)
+                    .field("y_axis", &self.y_axis)
+                    .field("z_axis", &self.z_axis)
+                    .finish()
+            }
+        }
+
+        impl Add<$mat3x4> for $mat3x4 {
+            type Output = Self;
+            #[inline(always)]
+            fn add(self, other: Self) -> Self {
+                self.add_mat3x4(&other)
+            }
+        }
+
+        impl Sub<$mat3x4> for $mat3x4 {
+            type Output = Self;
+            #[inline(always)]
+            fn sub(self, other: Self) -> Self {
+                self.sub_mat3x4(&other)
+            }
+        }
+
+        impl Mul<$mat3> for $mat3x4 {
+            type Output = Self;
+            #[inline(always)]
+            fn mul(self, other: $mat3) -> Self {
+                self.mul_mat3(&other)
+            }
+        }
+
+        impl Mul<$mat4x3> for $mat3x4 {
+            type Output = $mat4;
+            #[inline(always)]
+            fn mul(self, other: $mat4x3) -> $mat4 {
+                self.mul_mat4x3(&other)
+            }
+        }
+
+        impl Mul<$mat3x4> for $mat4 {
+            type Output = $mat3x4;
+            #[inline]
+            fn mul(self, other: $mat3x4) -> $mat3x4 {
+                $mat3x4::from_cols(
+                    self.mul_vec4(other.x_axis),
+                    self.mul_vec4(other.y_axis),
+                    self.mul_vec4(other.z_axis),
+                )
+            }
+        }
+
+        impl Mul<$vec3> for $mat3x4 {
+            type Output = $vec4;
+            #[inline(always)]
+            fn mul(self, other: $vec3) -> $vec4 {
+                self.mul_vec3(other)
+            }
+        }
+
+        impl Mul<$mat3x4> for $t {
+            type Output = $mat3x4;
+            #[inline(always)]
+            fn mul(self, other: $mat3x4) -> $mat3x4 {
+                other.mul_scalar(self)
+            }
+        }
+
+        impl Mul<$t> for $mat3x4 {
+            type Output = Self;
+            #[inline(always)]
+            fn mul(self, other: $t) -> Self {
+                self.mul_scalar(other)
+            }
+        }
+
+        impl $mat4 {
+            /// Creates a 4x4 affine transform from the rows stored in the columns of `m`, as
+            /// created by `Mat3x4::from_affine_rows()`, with a bottom row of `[0, 0, 0, 1]`.
+            #[inline]
+            pub fn from_affine_rows(m: $mat3x4) -> Self {
+                Self::from(m.transpose())
+            }
+        }
+
+        impl From<$mat4> for $mat3x4 {
+            /// Creates a 3x4 matrix from the first three columns of `m`.
+            #[inline]
+            fn from(m: $mat4) -> Self {
+                Self::from_cols(m.x_axis, m.y_axis, m.z_axis)
+            }
+        }
+
+        impl From<$mat3x4> for $mat4 {
+            /// Creates a 4x4 matrix from `m` with a `w_axis` of `[0, 0, 0, 1]`.
+            #[inline]
+            fn from(m: $mat3x4) -> Self {
+                Self::from_cols(m.x_axis, m.y_axis, m.z_axis, $vec4::W)
+            }
+        }
+
+        impl From<$mat3> for $mat3x4 {
+            /// Creates a 3x4 matrix from `m` with a bottom row of zero.
+            #[inline]
+            fn from(m: $mat3) -> Self {
+                Self::from_cols(
+                    m.x_axis.extend(0.0),
+                    m.y_axis.extend(0.0),
+                    m.z_axis.extend(0.0),
+                )
+            }
+        }
+
+        impl From<$mat3x4> for $mat3 {
+            /// Creates a 3x3 matrix from the upper three rows of `m`.
+            #[inline]
+            fn from(m: $mat3x4) -> Self {
+                Self::from_cols(
+                    m.x_axis.truncate(),
+                    m.y_axis.truncate(),
+                    m.z_axis.truncate(),
+                )
+            }
+        }
+    };
+}
+
+#[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
+type InnerF32 = Vector3x4<__m128>;
+
+#[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
+type InnerF32 = Vector3x4<XYZW<f32>>;
+
+/// A 3x4 column major matrix, with three columns and four rows.
+///
+/// This is commonly used to store 3D affine transforms in GPU skinning palettes, transposed so
+/// that each column holds a row of the transform, see [`Self::from_affine_rows()`].
+///
+/// This type is 16 byte aligned.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Mat3x4(pub(crate) InnerF32);
+
+impl Mat3x4 {
+    impl_mat3x4_methods!(f32, Vec4, Vec3, Mat3, Mat4, Mat4x3, InnerF32);
+}
+impl_mat3x4_traits!(f32, mat3x4, Mat3x4, Mat4x3, Mat3, Mat4, Vec3, Vec4);
+
+type InnerF64 = Vector3x4<XYZW<f64>>;
+
+/// A 3x4 column major matrix, with three columns and four rows.
+///
+/// This is commonly used to store 3D affine transforms in GPU skinning palettes, transposed so
+/// that each column holds a row of the transform, see [`Self::from_affine_rows()`].
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct DMat3x4(pub(crate) InnerF64);
+
+impl DMat3x4 {
+    impl_mat3x4_methods!(f64, DVec4, DVec3, DMat3, DMat4, DMat4x3, InnerF64);
+}
+impl_mat3x4_traits!(f64, dmat3x4, DMat3x4, DMat4x3, DMat3, DMat4, DVec3, DVec4);
@@ -24,14 +24,21 @@ use core::arch::x86_64::*;
 use core::fmt;
 use core::{
     cmp::Ordering,
-    ops::{Add, Deref, DerefMut, Mul, Sub},
+    ops::{Add, Deref, DerefMut, Index, IndexMut, Mul, Sub},
 };
 
 #[cfg(feature = "std")]
 use std::iter::{Product, Sum};
 
 macro_rules! impl_mat4_methods {
-    ($t:ty, $vec4:ident, $vec3:ident, $quat:ident, $inner:ident) => {
+    ($t:ty, $vec4:ident, $vec3:ident, $quat:ident, $inner:ident, $const_new:ident) => {
+        /// A 4x4 matrix with all elements set to `0.0`.
+        pub const ZERO: Self = Self($inner::ZERO);
+
+        /// A 4x4 identity matrix, where all diagonal elements are `1`, and all off-diagonal
+        /// elements are `0`.
+        pub const IDENTITY: Self = Self($inner::IDENTITY);
+
         /// Creates a 4x4 matrix with all elements set to `0.0`.
         #[inline(always)]
         pub const fn zero() -> Self {
@@ -46,16 +53,21 @@ macro_rules! impl_mat4_methods {
 
         /// Creates a 4x4 matrix from four column vectors.
         #[inline(always)]
-        pub fn from_cols(x_axis: $vec4, y_axis: $vec4, z_axis: $vec4, w_axis: $vec4) -> Self {
-            Self($inner::from_cols(x_axis.0, y_axis.0, z_axis.0, w_axis.0))
+        pub const fn from_cols(x_axis: $vec4, y_axis: $vec4, z_axis: $vec4, w_axis: $vec4) -> Self {
+            Self($inner {
+                x_axis: x_axis.0,
+                y_axis: y_axis.0,
+                z_axis: z_axis.0,
+                w_axis: w_axis.0,
+            })
         }
 
         /// Creates a 4x4 matrix from a `[S; 16]` array stored in column major order.
         /// If your data is stored in row major you will need to `transpose` the returned
         /// matrix.
         #[inline(always)]
-        pub fn from_cols_array(m: &[$t; 16]) -> Self {
-            Self($inner::from_cols_array(m))
+        pub const fn from_cols_array(m: &[$t; 16]) -> Self {
+            $const_new!(*m)
         }
 
         /// Creates a `[S; 16]` array storing data in column major order.
@@ -69,8 +81,8 @@ macro_rules! impl_mat4_methods {
         /// If your data is in row major order you will need to `transpose` the returned
         /// matrix.
         #[inline(always)]
-        pub fn from_cols_array_2d(m: &[[$t; 4]; 4]) -> Self {
-            Self($inner::from_cols_array_2d(m))
+        pub const fn from_cols_array_2d(m: &[[$t; 4]; 4]) -> Self {
+            $const_new!(m[0], m[1], m[2], m[3])
         }
 
         /// Creates a `[[S; 4]; 4]` 2D array storing data in column major order.
@@ -88,6 +100,9 @@ macro_rules! impl_mat4_methods {
             rotation: $quat,
             translation: $vec3,
         ) -> Self {
+            glam_assert!(scale.is_finite());
+            glam_assert!(rotation.is_finite());
+            glam_assert!(translation.is_finite());
             Self($inner::from_scale_quaternion_translation(
                 scale.0,
                 rotation.0,
@@ -98,6 +113,8 @@ macro_rules! impl_mat4_methods {
         /// Creates a 4x4 homogeneous transformation matrix from the given `translation`.
         #[inline(always)]
         pub fn from_rotation_translation(rotation: $quat, translation: $vec3) -> Self {
+            glam_assert!(rotation.is_finite());
+            glam_assert!(translation.is_finite());
             Self($inner::from_quaternion_translation(
                 rotation.0,
                 translation.0,
@@ -108,6 +125,7 @@ macro_rules! impl_mat4_methods {
         /// be a 4x4 homogeneous transformation matrix otherwise the output will be invalid.
         #[inline(always)]
         pub fn to_scale_rotation_translation(&self) -> ($vec3, $quat, $vec3) {
+            glam_assert!(self.is_finite());
             let (scale, rotation, translation) = self.0.to_scale_quaternion_translation();
             ($vec3(scale), $quat(rotation), $vec3(translation))
         }
@@ -115,12 +133,14 @@ macro_rules! impl_mat4_methods {
         /// Creates a 4x4 homogeneous transformation matrix from the given `rotation`.
         #[inline(always)]
         pub fn from_quat(rotation: $quat) -> Self {
+            glam_assert!(rotation.is_finite());
             Self($inner::from_quaternion(rotation.0))
         }
 
         /// Creates a 4x4 homogeneous transformation matrix from the given `translation`.
         #[inline(always)]
         pub fn from_translation(translation: $vec3) -> Self {
+            glam_assert!(translation.is_finite());
             Self($inner::from_translation(translation.0))
         }
 
@@ -128,6 +148,8 @@ macro_rules! impl_mat4_methods {
         /// around a normalized rotation `axis` of `angle` (in radians).
         #[inline(always)]
         pub fn from_axis_angle(axis: $vec3, angle: $t) -> Self {
+            glam_assert!(axis.is_finite());
+            glam_assert!(angle.is_finite());
             Self($inner::from_axis_angle(axis.0, angle))
         }
 
@@ -143,6 +165,7 @@ macro_rules! impl_mat4_methods {
         /// around the x axis of `angle` (in radians).
         #[inline(always)]
         pub fn from_rotation_x(angle: $t) -> Self {
+            glam_assert!(angle.is_finite());
             Self($inner::from_rotation_x(angle))
         }
 
@@ -150,6 +173,7 @@ macro_rules! impl_mat4_methods {
         /// around the y axis of `angle` (in radians).
         #[inline(always)]
         pub fn from_rotation_y(angle: $t) -> Self {
+            glam_assert!(angle.is_finite());
             Self($inner::from_rotation_y(angle))
         }
 
@@ -157,6 +181,7 @@ macro_rules! impl_mat4_methods {
         /// around the z axis of `angle` (in radians).
         #[inline(always)]
         pub fn from_rotation_z(angle: $t) -> Self {
+            glam_assert!(angle.is_finite());
             Self($inner::from_rotation_z(angle))
         }
 
@@ -164,36 +189,67 @@ macro_rules! impl_mat4_methods {
         /// non-uniform `scale`.
         #[inline(always)]
         pub fn from_scale(scale: $vec3) -> Self {
+            glam_assert!(scale.is_finite());
             Self($inner::from_scale(scale.0))
         }
 
-        // #[inline]
-        // pub(crate) fn col(&self, index: usize) -> $vec4 {
-        //     match index {
-        //         0 => self.x_axis,
-        //         1 => self.y_axis,
-        //         2 => self.z_axis,
-        //         3 => self.w_axis,
-        //         _ => panic!(
-        //             "index out of bounds: the len is 4 but the index is {}",
-        //             index
-        //         ),
-        //     }
-        // }
-
-        // #[inline]
-        // pub(crate) fn col_mut(&mut self, index: usize) -> &mut $vec4 {
-        //     match index {
-        //         0 => &mut self.x_axis,
-        //         1 => &mut self.y_axis,
-        //         2 => &mut self.z_axis,
-        //         3 => &mut self.w_axis,
-        //         _ => panic!(
-        //             "index out of bounds: the len is 4 but the index is {}",
-        //             index
-        //         ),
-        //     }
-        // }
+        /// Creates a 4x4 matrix from four row vectors.
+        #[inline(always)]
+        pub fn from_rows(x_row: $vec4, y_row: $vec4, z_row: $vec4, w_row: $vec4) -> Self {
+            Self(Matrix4x4::from_rows(x_row.0, y_row.0, z_row.0, w_row.0))
+        }
+
+        /// Creates a 4x4 matrix from a `[S; 16]` array stored in row major order.
+        #[inline(always)]
+        pub fn from_rows_array(m: &[$t; 16]) -> Self {
+            Self(Matrix4x4::from_rows_array(m))
+        }
+
+        /// Creates a `[S; 16]` array storing data in row major order.
+        #[inline(always)]
+        pub fn to_rows_array(&self) -> [$t; 16] {
+            self.0.to_rows_array()
+        }
+
+        /// Returns the matrix column for the given `index`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `index` is greater than 3.
+        #[inline]
+        pub fn col(&self, index: usize) -> $vec4 {
+            $vec4(*self.0.col(index))
+        }
+
+        /// Returns a mutable reference to the matrix column for the given `index`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `index` is greater than 3.
+        #[inline]
+        pub fn col_mut(&mut self, index: usize) -> &mut $vec4 {
+            unsafe { &mut *(self.0.col_mut(index) as *mut _ as *mut $vec4) }
+        }
+
+        /// Returns the matrix row for the given `index`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `index` is greater than 3.
+        #[inline]
+        pub fn row(&self, index: usize) -> $vec4 {
+            $vec4(self.0.row(index))
+        }
+
+        /// Sets the matrix row for the given `index`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `index` is greater than 3.
+        #[inline]
+        pub fn set_row(&mut self, index: usize, row: $vec4) {
+            self.0.set_row(index, row.0)
+        }
 
         /// Returns `true` if, and only if, all elements are finite.
         /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
@@ -229,23 +285,37 @@ macro_rules! impl_mat4_methods {
         /// Returns the inverse of `self`.
         ///
         /// If the matrix is not invertible the returned matrix will be invalid.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if `self` or the result is non-finite when `glam_assert` is enabled, unless
+        /// a handler set with `set_assert_handler()` handles the failure instead.
         #[inline(always)]
         pub fn inverse(&self) -> Self {
-            Self(self.0.inverse())
+            glam_assert!(self.is_finite());
+            let inverse = Self(self.0.inverse());
+            glam_assert!(inverse.is_finite());
+            inverse
         }
 
         /// Creates a left-handed view matrix using a camera position, an up direction, and a focal
         /// point.
         #[inline(always)]
         pub fn look_at_lh(eye: $vec3, center: $vec3, up: $vec3) -> Self {
-            Self($inner::look_at_lh(eye.0, center.0, up.0))
+            glam_assert!(eye.is_finite() && center.is_finite() && up.is_finite());
+            let view = Self($inner::look_at_lh(eye.0, center.0, up.0));
+            glam_assert!(view.is_finite());
+            view
         }
 
         /// Creates a right-handed view matrix using a camera position, an up direction, and a focal
         /// point.
         #[inline(always)]
         pub fn look_at_rh(eye: $vec3, center: $vec3, up: $vec3) -> Self {
-            Self($inner::look_at_rh(eye.0, center.0, up.0))
+            glam_assert!(eye.is_finite() && center.is_finite() && up.is_finite());
+            let view = Self($inner::look_at_rh(eye.0, center.0, up.0));
+            glam_assert!(view.is_finite());
+            view
         }
 
         /// Creates a right-handed perspective projection matrix with [-1,1] depth range.
@@ -258,44 +328,52 @@ macro_rules! impl_mat4_methods {
             z_near: $t,
             z_far: $t,
         ) -> Self {
-            Self($inner::perspective_rh_gl(
+            let projection = Self($inner::perspective_rh_gl(
                 fov_y_radians,
                 aspect_ratio,
                 z_near,
                 z_far,
-            ))
+            ));
+            glam_assert!(projection.is_finite());
+            projection
         }
 
         /// Creates a left-handed perspective projection matrix with [0,1] depth range.
         #[inline(always)]
         pub fn perspective_lh(fov_y_radians: $t, aspect_ratio: $t, z_near: $t, z_far: $t) -> Self {
-            Self($inner::perspective_lh(
+            let projection = Self($inner::perspective_lh(
                 fov_y_radians,
                 aspect_ratio,
                 z_near,
                 z_far,
-            ))
+            ));
+            glam_assert!(projection.is_finite());
+            projection
         }
 
         /// Creates a right-handed perspective projection matrix with [0,1] depth range.
         #[inline(always)]
         pub fn perspective_rh(fov_y_radians: $t, aspect_ratio: $t, z_near: $t, z_far: $t) -> Self {
-            Self($inner::perspective_rh(
+            let projection = Self($inner::perspective_rh(
                 fov_y_radians,
                 aspect_ratio,
                 z_near,
                 z_far,
-            ))
+            ));
+            glam_assert!(projection.is_finite());
+            projection
         }
 
         /// Creates an infinite left-handed perspective projection matrix with [0,1] depth range.
         #[inline(always)]
         pub fn perspective_infinite_lh(fov_y_radians: $t, aspect_ratio: $t, z_near: $t) -> Self {
-            Self($inner::perspective_infinite_lh(
+            let projection = Self($inner::perspective_infinite_lh(
                 fov_y_radians,
                 aspect_ratio,
                 z_near,
-            ))
+            ));
+            glam_assert!(projection.is_finite());
+            projection
         }
 
         /// Creates an infinite left-handed perspective projection matrix with [0,1] depth range.
@@ -305,22 +383,26 @@ macro_rules! impl_mat4_methods {
             aspect_ratio: $t,
             z_near: $t,
         ) -> Self {
-            Self($inner::perspective_infinite_reverse_lh(
+            let projection = Self($inner::perspective_infinite_reverse_lh(
                 fov_y_radians,
                 aspect_ratio,
                 z_near,
-            ))
+            ));
+            glam_assert!(projection.is_finite());
+            projection
         }
 
         /// Creates an infinite right-handed perspective projection matrix with
         /// [0,1] depth range.
         #[inline(always)]
         pub fn perspective_infinite_rh(fov_y_radians: $t, aspect_ratio: $t, z_near: $t) -> Self {
-            Self($inner::perspective_infinite_rh(
+            let projection = Self($inner::perspective_infinite_rh(
                 fov_y_radians,
                 aspect_ratio,
                 z_near,
-            ))
+            ));
+            glam_assert!(projection.is_finite());
+            projection
         }
 
         /// Creates an infinite reverse right-handed perspective projection matrix
@@ -331,11 +413,13 @@ macro_rules! impl_mat4_methods {
             aspect_ratio: $t,
             z_near: $t,
         ) -> Self {
-            Self($inner::perspective_infinite_reverse_rh(
+            let projection = Self($inner::perspective_infinite_reverse_rh(
                 fov_y_radians,
                 aspect_ratio,
                 z_near,
-            ))
+            ));
+            glam_assert!(projection.is_finite());
+            projection
         }
 
         /// Creates a right-handed orthographic projection matrix with [-1,1] depth
@@ -351,9 +435,11 @@ macro_rules! impl_mat4_methods {
             near: $t,
             far: $t,
         ) -> Self {
-            Self($inner::orthographic_rh_gl(
+            let projection = Self($inner::orthographic_rh_gl(
                 left, right, bottom, top, near, far,
-            ))
+            ));
+            glam_assert!(projection.is_finite());
+            projection
         }
 
         /// Creates a left-handed orthographic projection matrix with [0,1] depth range.
@@ -366,7 +452,9 @@ macro_rules! impl_mat4_methods {
             near: $t,
             far: $t,
         ) -> Self {
-            Self($inner::orthographic_lh(left, right, bottom, top, near, far))
+            let projection = Self($inner::orthographic_lh(left, right, bottom, top, near, far));
+            glam_assert!(projection.is_finite());
+            projection
         }
 
         /// Creates a right-handed orthographic projection matrix with [0,1] depth range.
@@ -379,7 +467,9 @@ macro_rules! impl_mat4_methods {
             near: $t,
             far: $t,
         ) -> Self {
-            Self($inner::orthographic_rh(left, right, bottom, top, near, far))
+            let projection = Self($inner::orthographic_rh(left, right, bottom, top, near, far));
+            glam_assert!(projection.is_finite());
+            projection
         }
 
         /// Transforms a 4D vector.
@@ -419,7 +509,10 @@ macro_rules! impl_mat4_methods {
         /// values are divided by `w`.
         #[inline]
         pub fn transform_point3(&self, other: $vec3) -> $vec3 {
-            $vec3(self.0.transform_point3(other.0))
+            glam_assert!(other.is_finite());
+            let point = $vec3(self.0.transform_point3(other.0));
+            glam_assert!(point.is_finite());
+            point
         }
 
         /// Transforms the give 3D vector as a direction.
@@ -428,6 +521,7 @@ macro_rules! impl_mat4_methods {
         /// `0.0`.
         #[inline]
         pub fn transform_vector3(&self, other: $vec3) -> $vec3 {
+            glam_assert!(other.is_finite());
             $vec3(self.0.transform_vector3(other.0))
         }
 
@@ -444,6 +538,25 @@ macro_rules! impl_mat4_methods {
         pub fn abs_diff_eq(&self, other: Self, max_abs_diff: $t) -> bool {
             self.0.abs_diff_eq(&other.0, max_abs_diff)
         }
+
+        /// Returns true if the difference of all elements between `self` and `other` is less
+        /// than or equal to `max_abs_diff` or to `max_relative` times the larger magnitude.
+        ///
+        /// This is the comparison used by `approx::relative_eq!` and suits values of widely
+        /// differing magnitudes, where a single `max_abs_diff` does not fit.
+        #[inline(always)]
+        pub fn relative_eq(&self, other: Self, max_abs_diff: $t, max_relative: $t) -> bool {
+            self.0.relative_eq(&other.0, max_abs_diff, max_relative)
+        }
+
+        /// Returns true if all elements of `self` and `other` are within `max_abs_diff` of each
+        /// other or at most `max_ulps` representable values apart.
+        ///
+        /// This is the comparison used by `approx::ulps_eq!`.
+        #[inline(always)]
+        pub fn ulps_eq(&self, other: Self, max_abs_diff: $t, max_ulps: u32) -> bool {
+            self.0.ulps_eq(&other.0, max_abs_diff, max_ulps)
+        }
     };
 }
 
@@ -451,7 +564,7 @@ macro_rules! impl_mat4_traits {
     ($t:ty, $new:ident, $mat4:ident, $vec4:ident) => {
         /// Creates a 4x4 matrix from four column vectors.
         #[inline(always)]
-        pub fn $new(x_axis: $vec4, y_axis: $vec4, z_axis: $vec4, w_axis: $vec4) -> $mat4 {
+        pub const fn $new(x_axis: $vec4, y_axis: $vec4, z_axis: $vec4, w_axis: $vec4) -> $mat4 {
             $mat4::from_cols(x_axis, y_axis, z_axis, w_axis)
         }
 
@@ -462,6 +575,24 @@ macro_rules! impl_mat4_traits {
             }
         }
 
+        impl Index<(usize, usize)> for $mat4 {
+            type Output = $t;
+            /// Returns the element in column `index.0` and row `index.1`.
+            #[inline]
+            fn index(&self, (col, row): (usize, usize)) -> &$t {
+                let col = unsafe { &*(self.0.col(col) as *const _ as *const $vec4) };
+                &col[row]
+            }
+        }
+
+        impl IndexMut<(usize, usize)> for $mat4 {
+            /// Returns a mutable reference to the element in column `index.0` and row `index.1`.
+            #[inline]
+            fn index_mut(&mut self, (col, row): (usize, usize)) -> &mut $t {
+                &mut self.col_mut(col)[row]
+            }
+        }
+
         impl PartialEq for $mat4 {
             #[inline]
             fn eq(&self, other: &Self) -> bool {
@@ -567,14 +698,23 @@ macro_rules! impl_mat4_traits {
         #[cfg(not(target_arch = "spirv"))]
         impl fmt::Display for $mat4 {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                write!(
+                crate::display::fmt_columns(
                     f,
-                    "[{}, {}, {}, {}]",
-                    self.x_axis, self.y_axis, self.z_axis, self.w_axis
+                    &[self.x_axis, self.y_axis, self.z_axis, self.w_axis],
                 )
             }
         }
 
+        #[cfg(not(target_arch = "spirv"))]
+        impl core::str::FromStr for $mat4 {
+            type Err = crate::ParseError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut a = [0.0; 16];
+                crate::parse::parse_elements(s, &mut a, 4)?;
+                Ok(Self::from_cols_array(&a))
+            }
+        }
+
         #[cfg(feature = "std")]
         impl<'a> Sum<&'a Self> for $mat4 {
             fn sum<I>(iter: I) -> Self
@@ -611,14 +751,17 @@ type InnerF32 = Vector4x4<XYZW<f32>>;
 pub struct Mat4(pub(crate) InnerF32);
 
 impl Mat4 {
-    impl_mat4_methods!(f32, Vec4, Vec3, Quat, InnerF32);
+    impl_mat4_methods!(f32, Vec4, Vec3, Quat, InnerF32, const_mat4);
 
     /// Transforms the given `Vec3A` as 3D point.
     ///
     /// This is the equivalent of multiplying the `Vec3A` as a 4D vector where `w` is `1.0`.
     #[inline(always)]
     pub fn transform_point3a(&self, other: Vec3A) -> Vec3A {
-        Vec3A(self.0.transform_float4_as_point3(other.0))
+        glam_assert!(other.is_finite());
+        let point = Vec3A(self.0.transform_float4_as_point3(other.0));
+        glam_assert!(point.is_finite());
+        point
     }
 
     /// Transforms the give `Vec3A` as 3D vector.
@@ -626,6 +769,7 @@ impl Mat4 {
     /// This is the equivalent of multiplying the `Vec3A` as a 4D vector where `w` is `0.0`.
     #[inline(always)]
     pub fn transform_vector3a(&self, other: Vec3A) -> Vec3A {
+        glam_assert!(other.is_finite());
         Vec3A(self.0.transform_float4_as_vector3(other.0))
     }
 
@@ -649,7 +793,7 @@ type InnerF64 = Vector4x4<XYZW<f64>>;
 pub struct DMat4(pub(crate) InnerF64);
 
 impl DMat4 {
-    impl_mat4_methods!(f64, DVec4, DVec3, DQuat, InnerF64);
+    impl_mat4_methods!(f64, DVec4, DVec3, DQuat, InnerF64, const_dmat4);
 
     #[inline(always)]
     pub fn as_f32(&self) -> Mat4 {
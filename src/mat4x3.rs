@@ -0,0 +1,430 @@
+use crate::core::storage::{Vector4x3, XYZ};
+use crate::{DMat3, DMat3x4, DMat4, DVec3, DVec4, Mat3, Mat3x4, Mat4, Vec3, Vec4};
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+use core::ops::{Add, Deref, DerefMut, Mul, Sub};
+
+macro_rules! impl_mat4x3_methods {
+    ($t:ty, $vec4:ident, $vec3:ident, $mat3:ident, $mat4:ident, $mat3x4:ident, $inner:ident) => {
+        /// A 4x3 matrix with all elements set to `0.0`.
+        pub const ZERO: Self = Self::from_cols($vec3::ZERO, $vec3::ZERO, $vec3::ZERO, $vec3::ZERO);
+
+        /// A 4x3 matrix where the diagonal elements are `1` and all other elements are `0`.
+        ///
+        /// As a 3D affine transform this is the identity transform.
+        pub const IDENTITY: Self = Self::from_cols($vec3::X, $vec3::Y, $vec3::Z, $vec3::ZERO);
+
+        /// Creates a 4x3 matrix from four column vectors.
+        #[inline(always)]
+        pub const fn from_cols(x_axis: $vec3, y_axis: $vec3, z_axis: $vec3, w_axis: $vec3) -> Self {
+            Self($inner {
+                x_axis: x_axis.0,
+                y_axis: y_axis.0,
+                z_axis: z_axis.0,
+                w_axis: w_axis.0,
+            })
+        }
+
+        /// Creates a 4x3 matrix from a `[S; 12]` array stored in column major order.
+        #[inline(always)]
+        pub const fn from_cols_array(m: &[$t; 12]) -> Self {
+            Self::from_cols(
+                $vec3::new(m[0], m[1], m[2]),
+                $vec3::new(m[3], m[4], m[5]),
+                $vec3::new(m[6], m[7], m[8]),
+                $vec3::new(m[9], m[10], m[11]),
+            )
+        }
+
+        /// Creates a `[S; 12]` array storing data in column major order.
+        #[inline]
+        pub fn to_cols_array(&self) -> [$t; 12] {
+            let [m00, m01, m02] = <[$t; 3]>::from(self.x_axis);
+            let [m10, m11, m12] = <[$t; 3]>::from(self.y_axis);
+            let [m20, m21, m22] = <[$t; 3]>::from(self.z_axis);
+            let [m30, m31, m32] = <[$t; 3]>::from(self.w_axis);
+            [m00, m01, m02, m10, m11, m12, m20, m21, m22, m30, m31, m32]
+        }
+
+        /// Creates a 4x3 matrix from a `[[S; 3]; 4]` 2D array stored in column major order.
+        #[inline(always)]
+        pub const fn from_cols_array_2d(m: &[[$t; 3]; 4]) -> Self {
+            Self::from_cols(
+                $vec3::from_array(m[0]),
+                $vec3::from_array(m[1]),
+                $vec3::from_array(m[2]),
+                $vec3::from_array(m[3]),
+            )
+        }
+
+        /// Creates a `[[S; 3]; 4]` 2D array storing data in column major order.
+        #[inline]
+        pub fn to_cols_array_2d(&self) -> [[$t; 3]; 4] {
+            [
+                self.x_axis.into(),
+                self.y_axis.into(),
+                self.z_axis.into(),
+                self.w_axis.into(),
+            ]
+        }
+
+        /// Returns `true` if, and only if, all elements are finite.
+        /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+        #[inline]
+        pub fn is_finite(&self) -> bool {
+            self.x_axis.is_finite()
+                && self.y_axis.is_finite()
+                && self.z_axis.is_finite()
+                && self.w_axis.is_finite()
+        }
+
+        /// Returns `true` if any elements are `NaN`.
+        #[inline]
+        pub fn is_nan(&self) -> bool {
+            self.x_axis.is_nan()
+                || self.y_axis.is_nan()
+                || self.z_axis.is_nan()
+                || self.w_axis.is_nan()
+        }
+
+        /// Returns the transpose of `self`, a 3x4 matrix whose columns are the rows of `self`.
+        #[inline]
+        pub fn transpose(&self) -> $mat3x4 {
+            $mat3x4::from_cols(
+                $vec4::new(self.x_axis.x, self.y_axis.x, self.z_axis.x, self.w_axis.x),
+                $vec4::new(self.x_axis.y, self.y_axis.y, self.z_axis.y, self.w_axis.y),
+                $vec4::new(self.x_axis.z, self.y_axis.z, self.z_axis.z, self.w_axis.z),
+            )
+        }
+
+        /// Transforms a 4D vector, returning a 3D vector.
+        #[inline]
+        pub fn mul_vec4(&self, other: $vec4) -> $vec3 {
+            glam_assert!(self.is_finite());
+            glam_assert!(other.is_finite());
+            let transformed = self.x_axis * other.x
+                + self.y_axis * other.y
+                + self.z_axis * other.z
+                + self.w_axis * other.w;
+            glam_assert!(transformed.is_finite());
+            transformed
+        }
+
+        /// Multiplies this matrix by a 4x4 matrix, returning a 4x3 matrix.
+        #[inline]
+        pub fn mul_mat4(&self, other: &$mat4) -> Self {
+            Self::from_cols(
+                self.mul_vec4(other.x_axis),
+                self.mul_vec4(other.y_axis),
+                self.mul_vec4(other.z_axis),
+                self.mul_vec4(other.w_axis),
+            )
+        }
+
+        /// Multiplies this matrix by a 3x4 matrix, returning a 3x3 matrix.
+        #[inline]
+        pub fn mul_mat3x4(&self, other: &$mat3x4) -> $mat3 {
+            $mat3::from_cols(
+                self.mul_vec4(other.x_axis),
+                self.mul_vec4(other.y_axis),
+                self.mul_vec4(other.z_axis),
+            )
+        }
+
+        /// Adds two 4x3 matrices.
+        #[inline]
+        pub fn add_mat4x3(&self, other: &Self) -> Self {
+            Self::from_cols(
+                self.x_axis + other.x_axis,
+                self.y_axis + other.y_axis,
+                self.z_axis + other.z_axis,
+                self.w_axis + other.w_axis,
+            )
+        }
+
+        /// Subtracts two 4x3 matrices.
+        #[inline]
+        pub fn sub_mat4x3(&self, other: &Self) -> Self {
+            Self::from_cols(
+                self.x_axis - other.x_axis,
+                self.y_axis - other.y_axis,
+                self.z_axis - other.z_axis,
+                self.w_axis - other.w_axis,
+            )
+        }
+
+        /// Multiplies a 4x3 matrix by a scalar.
+        #[inline]
+        pub fn mul_scalar(&self, other: $t) -> Self {
+            Self::from_cols(
+                self.x_axis * other,
+                self.y_axis * other,
+                self.z_axis * other,
+                self.w_axis * other,
+            )
+        }
+
+        /// Transforms the given 3D vector as a point.
+        ///
+        /// The matrix is treated as a 3D affine transform, with the upper 3x3 elements as the
+        /// linear part and the `w_axis` as the translation.
+        #[inline]
+        pub fn transform_point3(&self, other: $vec3) -> $vec3 {
+            glam_assert!(self.is_finite());
+            glam_assert!(other.is_finite());
+            let transformed =
+                self.x_axis * other.x + self.y_axis * other.y + self.z_axis * other.z + self.w_axis;
+            glam_assert!(transformed.is_finite());
+            transformed
+        }
+
+        /// Transforms the given 3D vector as a direction.
+        ///
+        /// This is the same as [`Self::transform_point3()`] except the translation in the
+        /// `w_axis` is not applied.
+        #[inline]
+        pub fn transform_vector3(&self, other: $vec3) -> $vec3 {
+            glam_assert!(self.is_finite());
+            glam_assert!(other.is_finite());
+            let transformed = self.x_axis * other.x + self.y_axis * other.y + self.z_axis * other.z;
+            glam_assert!(transformed.is_finite());
+            transformed
+        }
+
+        /// Returns true if the absolute difference of all elements between `self` and `other`
+        /// is less than or equal to `max_abs_diff`.
+        ///
+        /// This can be used to compare if two 4x3 matrices contain similar elements. It works
+        /// best when comparing with a known value. The `max_abs_diff` that should be used used
+        /// depends on the values being compared against.
+        ///
+        /// For more on floating point comparisons see
+        /// https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/
+        #[inline]
+        pub fn abs_diff_eq(&self, other: Self, max_abs_diff: $t) -> bool {
+            self.x_axis.abs_diff_eq(other.x_axis, max_abs_diff)
+                && self.y_axis.abs_diff_eq(other.y_axis, max_abs_diff)
+                && self.z_axis.abs_diff_eq(other.z_axis, max_abs_diff)
+                && self.w_axis.abs_diff_eq(other.w_axis, max_abs_diff)
+        }
+    };
+}
+
+macro_rules! impl_mat4x3_traits {
+    ($t:ty, $new:ident, $mat4x3:ident, $mat3x4:ident, $mat3:ident, $mat4:ident, $vec3:ident, $vec4:ident) => {
+        /// Creates a 4x3 matrix from four column vectors.
+        #[inline(always)]
+        pub const fn $new(x_axis: $vec3, y_axis: $vec3, z_axis: $vec3, w_axis: $vec3) -> $mat4x3 {
+            $mat4x3::from_cols(x_axis, y_axis, z_axis, w_axis)
+        }
+
+        impl Default for $mat4x3 {
+            #[inline(always)]
+            fn default() -> Self {
+                Self::IDENTITY
+            }
+        }
+
+        impl PartialEq for $mat4x3 {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.x_axis.eq(&other.x_axis)
+                    && self.y_axis.eq(&other.y_axis)
+                    && self.z_axis.eq(&other.z_axis)
+                    && self.w_axis.eq(&other.w_axis)
+            }
+        }
+
+        impl Deref for $mat4x3 {
+            type Target = Vector4x3<$vec3>;
+            #[inline(always)]
+            fn deref(&self) -> &Self::Target {
+                unsafe { &*(self as *const Self as *const Self::Target) }
+            }
+        }
+
+        impl DerefMut for $mat4x3 {
+            #[inline(always)]
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                unsafe { &mut *(self as *mut Self as *mut Self::Target) }
+            }
+        }
+
+        impl AsRef<[$t; 12]> for $mat4x3 {
+            #[inline(always)]
+            fn as_ref(&self) -> &[$t; 12] {
+                unsafe { &*(self as *const Self as *const [$t; 12]) }
+            }
+        }
+
+        impl AsMut<[$t; 12]> for $mat4x3 {
+            #[inline(always)]
+            fn as_mut(&mut self) -> &mut [$t; 12] {
+                unsafe { &mut *(self as *mut Self as *mut [$t; 12]) }
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl fmt::Display for $mat4x3 {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                crate::display::fmt_columns(
+                    f,
+                    &[self.x_axis, self.y_axis, self.z_axis, self.w_axis],
+                )
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl fmt::Debug for $mat4x3 {
+            fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+                fmt.debug_struct(stringify!($mat4x3))
+                    .field("x_axis", &self.x_axis)
+                    .field("y_axis", &self.y_axis)
+                    .field("z_axis", &self.z_axis)
+                    .field("w_axis", &self.w_axis)
+                    .finish()
+            }
+        }
+
+        impl Add<$mat4x3> for $mat4x3 {
+            type Output = Self;
+            #[inline(always)]
+            fn add(self, other: Self) -> Self {
+                self.add_mat4x3(&other)
+            }
+        }
+
+        impl Sub<$mat4x3> for $mat4x3 {
+            type Output = Self;
+            #[inline(always)]
+            fn sub(self, other: Self) -> Self {
+                self.sub_mat4x3(&other)
+            }
+        }
+
+        impl Mul<$mat4> for $mat4x3 {
+            type Output = Self;
+            #[inline(always)]
+            fn mul(self, other: $mat4) -> Self {
+                self.mul_mat4(&other)
+            }
+        }
+
+        impl Mul<$mat3x4> for $mat4x3 {
+            type Output = $mat3;
+            #[inline(always)]
+            fn mul(self, other: $mat3x4) -> $mat3 {
+                self.mul_mat3x4(&other)
+            }
+        }
+
+        impl Mul<$mat4x3> for $mat3 {
+            type Output = $mat4x3;
+            #[inline]
+            fn mul(self, other: $mat4x3) -> $mat4x3 {
+                $mat4x3::from_cols(
+                    self.mul_vec3(other.x_axis),
+                    self.mul_vec3(other.y_axis),
+                    self.mul_vec3(other.z_axis),
+                    self.mul_vec3(other.w_axis),
+                )
+            }
+        }
+
+        impl Mul<$vec4> for $mat4x3 {
+            type Output = $vec3;
+            #[inline(always)]
+            fn mul(self, other: $vec4) -> $vec3 {
+                self.mul_vec4(other)
+            }
+        }
+
+        impl Mul<$mat4x3> for $t {
+            type Output = $mat4x3;
+            #[inline(always)]
+            fn mul(self, other: $mat4x3) -> $mat4x3 {
+                other.mul_scalar(self)
+            }
+        }
+
+        impl Mul<$t> for $mat4x3 {
+            type Output = Self;
+            #[inline(always)]
+            fn mul(self, other: $t) -> Self {
+                self.mul_scalar(other)
+            }
+        }
+
+        impl From<$mat4> for $mat4x3 {
+            /// Creates a 4x3 matrix from the upper three rows of `m`.
+            #[inline]
+            fn from(m: $mat4) -> Self {
+                Self::from_cols(
+                    m.x_axis.truncate(),
+                    m.y_axis.truncate(),
+                    m.z_axis.truncate(),
+                    m.w_axis.truncate(),
+                )
+            }
+        }
+
+        impl From<$mat4x3> for $mat4 {
+            /// Creates a 4x4 matrix from `m` with a bottom row of `[0, 0, 0, 1]`.
+            #[inline]
+            fn from(m: $mat4x3) -> Self {
+                Self::from_cols(
+                    m.x_axis.extend(0.0),
+                    m.y_axis.extend(0.0),
+                    m.z_axis.extend(0.0),
+                    m.w_axis.extend(1.0),
+                )
+            }
+        }
+
+        impl From<$mat3> for $mat4x3 {
+            /// Creates a 4x3 matrix from `m` with a `w_axis` of zero.
+            #[inline]
+            fn from(m: $mat3) -> Self {
+                Self::from_cols(m.x_axis, m.y_axis, m.z_axis, $vec3::ZERO)
+            }
+        }
+
+        impl From<$mat4x3> for $mat3 {
+            /// Creates a 3x3 matrix from the first three columns of `m`.
+            #[inline]
+            fn from(m: $mat4x3) -> Self {
+                Self::from_cols(m.x_axis, m.y_axis, m.z_axis)
+            }
+        }
+    };
+}
+
+type InnerF32 = Vector4x3<XYZ<f32>>;
+
+/// A 4x3 column major matrix, with four columns and three rows.
+///
+/// This is commonly used to store 3D affine transforms in GPU buffers, with the last column
+/// holding the translation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Mat4x3(pub(crate) InnerF32);
+
+impl Mat4x3 {
+    impl_mat4x3_methods!(f32, Vec4, Vec3, Mat3, Mat4, Mat3x4, InnerF32);
+}
+impl_mat4x3_traits!(f32, mat4x3, Mat4x3, Mat3x4, Mat3, Mat4, Vec3, Vec4);
+
+type InnerF64 = Vector4x3<XYZ<f64>>;
+
+/// A 4x3 column major matrix, with four columns and three rows.
+///
+/// This is commonly used to store 3D affine transforms in GPU buffers, with the last column
+/// holding the translation.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct DMat4x3(pub(crate) InnerF64);
+
+impl DMat4x3 {
+    impl_mat4x3_methods!(f64, DVec4, DVec3, DMat3, DMat4, DMat3x4, InnerF64);
+}
+impl_mat4x3_traits!(f64, dmat4x3, DMat4x3, DMat3x4, DMat3, DMat4, DVec3, DVec4);
@@ -0,0 +1,283 @@
+#[cfg(not(target_arch = "spirv"))]
+use core::fmt;
+use core::str::FromStr;
+
+/// An error which can be returned when parsing a vector, quaternion or matrix from a string.
+///
+/// Parsing accepts the `Display` output of each type, e.g. `[1, 2, 3]` for a 3D vector or
+/// `[[1, 2], [3, 4]]` for a 2x2 matrix, as well as common alternatives such as `1,2,3` and
+/// `(1 2 3)`. Elements may be separated by commas, whitespace or both. Matrices may also be given
+/// as a flat list of elements in column major order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The input is not a well formed list, e.g. it has unbalanced brackets, nested brackets or
+    /// a missing element between commas. `position` is the byte offset of the offending
+    /// character.
+    InvalidSyntax { position: usize },
+    /// The element at `index` could not be parsed as the element type.
+    InvalidElement { index: usize },
+    /// The input contains the wrong number of elements.
+    ElementCount { expected: usize, found: usize },
+    /// A bracketed matrix column contains the wrong number of elements.
+    ColumnLength {
+        column: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseError::InvalidSyntax { position } => {
+                write!(f, "invalid syntax at byte offset {}", position)
+            }
+            ParseError::InvalidElement { index } => write!(f, "invalid element at index {}", index),
+            ParseError::ElementCount { expected, found } => {
+                write!(f, "expected {} elements, found {}", expected, found)
+            }
+            ParseError::ColumnLength {
+                column,
+                expected,
+                found,
+            } => write!(
+                f,
+                "expected {} elements in column {}, found {}",
+                expected, column, found
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// Returns the closing bracket for `c` if `c` is an opening bracket.
+#[inline]
+fn closing_bracket(c: u8) -> Option<u8> {
+    match c {
+        b'[' => Some(b']'),
+        b'(' => Some(b')'),
+        _ => None,
+    }
+}
+
+#[inline]
+fn is_separator(c: u8) -> bool {
+    matches!(c, b',' | b'[' | b']' | b'(' | b')') || c.is_ascii_whitespace()
+}
+
+/// Strips a pair of brackets enclosing all of `s`, returning the inner string and its offset.
+///
+/// `offset` is the byte offset of `s` in the original input, used for error reporting.
+fn strip_outer_brackets(s: &str, offset: usize) -> Result<(&str, usize), ParseError> {
+    let bytes = s.as_bytes();
+    let close = match bytes.first().copied().and_then(closing_bracket) {
+        Some(close) => close,
+        None => return Ok((s, offset)),
+    };
+    let mut depth = 0;
+    for (i, &c) in bytes.iter().enumerate() {
+        if closing_bracket(c).is_some() {
+            depth += 1;
+        } else if c == b']' || c == b')' {
+            depth -= 1;
+            if depth == 0 {
+                if i + 1 != bytes.len() {
+                    // the first bracket closes before the end, e.g. `(1 2) (3 4)`
+                    return Ok((s, offset));
+                }
+                if c != close {
+                    return Err(ParseError::InvalidSyntax {
+                        position: offset + i,
+                    });
+                }
+                return Ok((&s[1..i], offset + 1));
+            }
+        }
+    }
+    Err(ParseError::InvalidSyntax {
+        position: offset + bytes.len(),
+    })
+}
+
+/// Parses `s` into `out`.
+///
+/// If `rows` is non-zero the elements may be grouped into bracketed columns of `rows` elements.
+pub(crate) fn parse_elements<T: FromStr>(
+    s: &str,
+    out: &mut [T],
+    rows: usize,
+) -> Result<(), ParseError> {
+    let trimmed = s.trim_start();
+    let start = s.len() - trimmed.len();
+    let (inner, offset) = strip_outer_brackets(trimmed.trim_end(), start)?;
+    let bytes = inner.as_bytes();
+
+    let mut count = 0;
+    // the closing bracket of the current column, if any
+    let mut column_close = None;
+    let mut column_start = 0;
+    let mut columns = 0;
+    let mut flat = false;
+    // true if the last token was an element or a column, false after a comma or opening bracket
+    let mut after_item = false;
+    let mut expect_item = false;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        if c.is_ascii_whitespace() {
+            i += 1;
+        } else if c == b',' {
+            if !after_item {
+                return Err(ParseError::InvalidSyntax {
+                    position: offset + i,
+                });
+            }
+            after_item = false;
+            expect_item = true;
+            i += 1;
+        } else if let Some(close) = closing_bracket(c) {
+            if rows == 0 || flat || column_close.is_some() {
+                return Err(ParseError::InvalidSyntax {
+                    position: offset + i,
+                });
+            }
+            column_close = Some(close);
+            column_start = count;
+            after_item = false;
+            expect_item = false;
+            i += 1;
+        } else if c == b']' || c == b')' {
+            if column_close != Some(c) || expect_item {
+                return Err(ParseError::InvalidSyntax {
+                    position: offset + i,
+                });
+            }
+            let found = count - column_start;
+            if found != rows {
+                return Err(ParseError::ColumnLength {
+                    column: columns,
+                    expected: rows,
+                    found,
+                });
+            }
+            column_close = None;
+            columns += 1;
+            after_item = true;
+            expect_item = false;
+            i += 1;
+        } else {
+            if column_close.is_none() {
+                if columns != 0 {
+                    return Err(ParseError::InvalidSyntax {
+                        position: offset + i,
+                    });
+                }
+                flat = true;
+            }
+            let begin = i;
+            while i < bytes.len() && !is_separator(bytes[i]) {
+                i += 1;
+            }
+            if let Some(element) = out.get_mut(count) {
+                *element = inner[begin..i]
+                    .parse()
+                    .map_err(|_| ParseError::InvalidElement { index: count })?;
+            }
+            count += 1;
+            after_item = true;
+            expect_item = false;
+        }
+    }
+
+    if column_close.is_some() || expect_item {
+        return Err(ParseError::InvalidSyntax {
+            position: offset + bytes.len(),
+        });
+    }
+    if count != out.len() {
+        return Err(ParseError::ElementCount {
+            expected: out.len(),
+            found: count,
+        });
+    }
+    Ok(())
+}
+
+#[test]
+fn test_parse_elements() {
+    let mut a = [0.0_f32; 3];
+    assert_eq!(parse_elements(" [1, 2.5, -3] ", &mut a, 0), Ok(()));
+    assert_eq!(a, [1.0, 2.5, -3.0]);
+    assert_eq!(parse_elements("(4 5\t6)", &mut a, 0), Ok(()));
+    assert_eq!(a, [4.0, 5.0, 6.0]);
+    assert_eq!(parse_elements("7,8 , 9", &mut a, 0), Ok(()));
+    assert_eq!(a, [7.0, 8.0, 9.0]);
+
+    assert_eq!(
+        parse_elements("[1, 2, 3)", &mut a, 0),
+        Err(ParseError::InvalidSyntax { position: 8 })
+    );
+    assert_eq!(
+        parse_elements("[1, 2, 3", &mut a, 0),
+        Err(ParseError::InvalidSyntax { position: 8 })
+    );
+    assert_eq!(
+        parse_elements("1,,2,3", &mut a, 0),
+        Err(ParseError::InvalidSyntax { position: 2 })
+    );
+    assert_eq!(
+        parse_elements("1,2,3,", &mut a, 0),
+        Err(ParseError::InvalidSyntax { position: 6 })
+    );
+    assert_eq!(
+        parse_elements("[[1, 2, 3]]", &mut a, 0),
+        Err(ParseError::InvalidSyntax { position: 1 })
+    );
+    assert_eq!(
+        parse_elements("1 x 3", &mut a, 0),
+        Err(ParseError::InvalidElement { index: 1 })
+    );
+    assert_eq!(
+        parse_elements("1 2", &mut a, 0),
+        Err(ParseError::ElementCount {
+            expected: 3,
+            found: 2
+        })
+    );
+    assert_eq!(
+        parse_elements("", &mut a, 0),
+        Err(ParseError::ElementCount {
+            expected: 3,
+            found: 0
+        })
+    );
+
+    let mut m = [0_i32; 4];
+    assert_eq!(parse_elements("[[1, 2], [3, 4]]", &mut m, 2), Ok(()));
+    assert_eq!(m, [1, 2, 3, 4]);
+    assert_eq!(parse_elements("(5 6) (7 8)", &mut m, 2), Ok(()));
+    assert_eq!(m, [5, 6, 7, 8]);
+    assert_eq!(parse_elements("1, 2, 3, 4", &mut m, 2), Ok(()));
+    assert_eq!(m, [1, 2, 3, 4]);
+    assert_eq!(
+        parse_elements("[[1, 2, 3], [4]]", &mut m, 2),
+        Err(ParseError::ColumnLength {
+            column: 0,
+            expected: 2,
+            found: 3
+        })
+    );
+    assert_eq!(
+        parse_elements("[1, 2, [3, 4]]", &mut m, 2),
+        Err(ParseError::InvalidSyntax { position: 7 })
+    );
+    assert_eq!(
+        parse_elements("[[1, 2], 3, 4]", &mut m, 2),
+        Err(ParseError::InvalidSyntax { position: 9 })
+    );
+}
@@ -3,7 +3,7 @@ use crate::core::traits::{
     vector::{FloatVector4, MaskVector4, Vector, Vector4, Vector4Const},
 };
 use crate::{DMat3, DMat4, DVec3, DVec4};
-use crate::{Mat3, Mat4, Vec3, Vec3A, Vec4};
+use crate::{Mat3, Mat3A, Mat4, Vec3, Vec3A, Vec4};
 
 #[cfg(all(
     target_arch = "x86",
@@ -29,7 +29,10 @@ use core::{
 use std::iter::{Product, Sum};
 
 macro_rules! impl_quat_methods {
-    ($t:ty, $quat:ident, $vec3:ident, $mat3:ident, $mat4:ident, $inner:ident) => {
+    ($t:ty, $quat:ident, $vec3:ident, $mat3:ident, $mat4:ident, $inner:ident, $const_new:ident) => {
+        /// The identity quaternion. Corresponds to no rotation.
+        pub const IDENTITY: Self = Self($inner::UNIT_W);
+
         /// Creates a new rotation quaternion.
         ///
         /// This should generally not be called manually unless you know what you are doing.
@@ -37,8 +40,19 @@ macro_rules! impl_quat_methods {
         ///
         /// `from_xyzw` is mostly used by unit tests and `serde` deserialization.
         #[inline(always)]
-        pub fn from_xyzw(x: $t, y: $t, z: $t, w: $t) -> Self {
-            Self(Vector4::new(x, y, z, w))
+        pub const fn from_xyzw(x: $t, y: $t, z: $t, w: $t) -> Self {
+            $const_new!([x, y, z, w])
+        }
+
+        /// Creates a rotation quaternion from an array.
+        ///
+        /// # Preconditions
+        ///
+        /// This function does not check if the input is normalized, it is up to the user to
+        /// provide normalized input or to normalized the resulting quaternion.
+        #[inline(always)]
+        pub const fn from_array(a: [$t; 4]) -> Self {
+            $const_new!(a)
         }
 
         #[inline(always)]
@@ -76,24 +90,29 @@ macro_rules! impl_quat_methods {
         /// Create a quaterion for a normalized rotation axis and angle (in radians).
         #[inline(always)]
         pub fn from_axis_angle(axis: $vec3, angle: $t) -> Self {
+            glam_assert!(axis.is_finite());
+            glam_assert!(angle.is_finite());
             Self($inner::from_axis_angle(axis.0, angle))
         }
 
         /// Creates a quaternion from the angle (in radians) around the x axis.
         #[inline(always)]
         pub fn from_rotation_x(angle: $t) -> Self {
+            glam_assert!(angle.is_finite());
             Self($inner::from_rotation_x(angle))
         }
 
         /// Creates a quaternion from the angle (in radians) around the y axis.
         #[inline(always)]
         pub fn from_rotation_y(angle: $t) -> Self {
+            glam_assert!(angle.is_finite());
             Self($inner::from_rotation_y(angle))
         }
 
         /// Creates a quaternion from the angle (in radians) around the z axis.
         #[inline(always)]
         pub fn from_rotation_z(angle: $t) -> Self {
+            glam_assert!(angle.is_finite());
             Self($inner::from_rotation_z(angle))
         }
 
@@ -101,32 +120,40 @@ macro_rules! impl_quat_methods {
         /// Create a quaternion from the given yaw (around y), pitch (around x) and roll (around z)
         /// in radians.
         pub fn from_rotation_ypr(yaw: $t, pitch: $t, roll: $t) -> Self {
+            glam_assert!(yaw.is_finite() && pitch.is_finite() && roll.is_finite());
             Self($inner::from_rotation_ypr(yaw, pitch, roll))
         }
 
         /// Creates a quaternion from a 3x3 rotation matrix.
         #[inline]
         pub fn from_rotation_mat3(mat: &$mat3) -> Self {
-            Self(Quaternion::from_rotation_axes(
+            glam_assert!(mat.is_finite());
+            let rotation = Self(Quaternion::from_rotation_axes(
                 mat.x_axis.0,
                 mat.y_axis.0,
                 mat.z_axis.0,
-            ))
+            ));
+            glam_assert!(rotation.is_finite());
+            rotation
         }
 
         /// Creates a quaternion from a 3x3 rotation matrix inside a homogeneous 4x4 matrix.
         #[inline]
         pub fn from_rotation_mat4(mat: &$mat4) -> Self {
-            Self(Quaternion::from_rotation_axes(
+            glam_assert!(mat.is_finite());
+            let rotation = Self(Quaternion::from_rotation_axes(
                 mat.x_axis.0.into(),
                 mat.y_axis.0.into(),
                 mat.z_axis.0.into(),
-            ))
+            ));
+            glam_assert!(rotation.is_finite());
+            rotation
         }
 
         /// Returns the rotation axis and angle of `self`.
         #[inline(always)]
         pub fn to_axis_angle(self) -> ($vec3, $t) {
+            glam_assert!(self.is_finite());
             let (axis, angle) = self.0.to_axis_angle();
             ($vec3(axis), angle)
         }
@@ -171,9 +198,55 @@ macro_rules! impl_quat_methods {
         /// Returns `self` normalized to length 1.0.
         ///
         /// For valid results, `self` must _not_ be of length zero.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if `self` is zero length or non-finite when `glam_assert` is enabled, unless
+        /// a handler set with `set_assert_handler()` handles the failure instead.
         #[inline(always)]
         pub fn normalize(self) -> Self {
-            Self(FloatVector4::normalize(self.0))
+            glam_assert!(self.is_finite());
+            let normalized = Self(FloatVector4::normalize(self.0));
+            glam_assert!(normalized.is_finite());
+            normalized
+        }
+
+        /// Returns `self` normalized to length 1.0 if possible, else returns `None`.
+        ///
+        /// In particular, if the input is zero (or very close to zero), or non-finite,
+        /// the result of this operation will be `None`.
+        #[inline(always)]
+        pub fn try_normalize(self) -> Option<Self> {
+            FloatVector4::try_normalize(self.0).map(Self)
+        }
+
+        /// Returns `self` normalized to length 1.0 if possible, else returns `fallback`.
+        ///
+        /// In particular, if the input is zero (or very close to zero), or non-finite,
+        /// the result of this operation will be `fallback`.
+        #[inline(always)]
+        pub fn normalize_or(self, fallback: Self) -> Self {
+            Self(FloatVector4::normalize_or(self.0, fallback.0))
+        }
+
+        /// Returns `self` normalized to length 1.0 if possible, else returns a zero
+        /// quaternion.
+        ///
+        /// Note that a zero quaternion is not a valid rotation, consider using
+        /// `normalize_or(Self::identity())` instead.
+        #[inline(always)]
+        pub fn normalize_or_zero(self) -> Self {
+            Self(FloatVector4::normalize_or_zero(self.0))
+        }
+
+        /// Returns the length of `self` and `self` normalized to length 1.0.
+        ///
+        /// If `self` is zero (or very close to zero), or non-finite, the direction will be a
+        /// zero quaternion.
+        #[inline(always)]
+        pub fn length_and_direction(self) -> ($t, Self) {
+            let (length, direction) = FloatVector4::length_and_direction(self.0);
+            (length, Self(direction))
         }
 
         /// Returns `true` if, and only if, all elements are finite.
@@ -215,6 +288,25 @@ macro_rules! impl_quat_methods {
             FloatVector4::abs_diff_eq(self.0, other.0, max_abs_diff)
         }
 
+        /// Returns true if the difference of all elements between `self` and `other` is less
+        /// than or equal to `max_abs_diff` or to `max_relative` times the larger magnitude.
+        ///
+        /// This is the comparison used by `approx::relative_eq!` and suits values of widely
+        /// differing magnitudes, where a single `max_abs_diff` does not fit.
+        #[inline(always)]
+        pub fn relative_eq(self, other: Self, max_abs_diff: $t, max_relative: $t) -> bool {
+            FloatVector4::relative_eq(self.0, other.0, max_abs_diff, max_relative)
+        }
+
+        /// Returns true if all elements of `self` and `other` are within `max_abs_diff` of each
+        /// other or at most `max_ulps` representable values apart.
+        ///
+        /// This is the comparison used by `approx::ulps_eq!`.
+        #[inline(always)]
+        pub fn ulps_eq(self, other: Self, max_abs_diff: $t, max_ulps: u32) -> bool {
+            FloatVector4::ulps_eq(self.0, other.0, max_abs_diff, max_ulps)
+        }
+
         /// Performs a linear interpolation between `self` and `other` based on
         /// the value `s`.
         ///
@@ -222,6 +314,9 @@ macro_rules! impl_quat_methods {
         /// is `1.0`, the result will be equal to `other`.
         #[inline(always)]
         pub fn lerp(self, end: Self, s: $t) -> Self {
+            glam_assert!(self.is_finite());
+            glam_assert!(end.is_finite());
+            glam_assert!(s.is_finite());
             Self(self.0.lerp(end.0, s))
         }
 
@@ -239,12 +334,32 @@ macro_rules! impl_quat_methods {
         /// product is negative, slerp between `-self` and `end`.
         #[inline(always)]
         pub fn slerp(self, end: Self, s: $t) -> Self {
+            glam_assert!(self.is_finite());
+            glam_assert!(end.is_finite());
+            glam_assert!(s.is_finite());
             Self(self.0.slerp(end.0, s))
         }
 
+        /// Performs an approximate spherical linear interpolation between `self` and `end`
+        /// based on the value `s`.
+        ///
+        /// This is faster but less precise than `slerp`, using polynomial
+        /// approximations of `sin` and an estimated reciprocal. The result may differ from
+        /// `slerp` by up to `1e-4` per component and is not guaranteed to pass
+        /// `is_normalized()`.
+        ///
+        /// As with `slerp`, the `dot` product between `self` and `end` should be positive.
+        #[inline(always)]
+        pub fn slerp_fast(self, end: Self, s: $t) -> Self {
+            glam_assert!(s.is_finite());
+            Self(self.0.slerp_fast(end.0, s))
+        }
+
         #[inline(always)]
         /// Multiplies a quaternion and a 3D vector, returning the rotated vector.
         pub fn mul_vec3(self, other: $vec3) -> $vec3 {
+            glam_assert!(self.is_finite());
+            glam_assert!(other.is_finite());
             $vec3(self.0.mul_vector3(other.0))
         }
 
@@ -253,6 +368,8 @@ macro_rules! impl_quat_methods {
         /// If they each represent a rotation, the result will represent the combined rotation.
         /// Note that due to floating point rounding the result may not be perfectly normalized.
         pub fn mul_quat(self, other: Self) -> Self {
+            glam_assert!(self.is_finite());
+            glam_assert!(other.is_finite());
             Self(self.0.mul_quaternion(other.0))
         }
     };
@@ -265,7 +382,7 @@ macro_rules! impl_quat_traits {
         /// This should generally not be called manually unless you know what you are doing. Use
         /// one of the other constructors instead such as `identity` or `from_axis_angle`.
         #[inline]
-        pub fn $new(x: $t, y: $t, z: $t, w: $t) -> $quat {
+        pub const fn $new(x: $t, y: $t, z: $t, w: $t) -> $quat {
             $quat::from_xyzw(x, y, z, w)
         }
 
@@ -284,7 +401,17 @@ macro_rules! impl_quat_traits {
         #[cfg(not(target_arch = "spirv"))]
         impl fmt::Display for $quat {
             fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-                write!(fmt, "[{}, {}, {}, {}]", self.x, self.y, self.z, self.w)
+                crate::display::fmt_elements(fmt, &[self.x, self.y, self.z, self.w])
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl core::str::FromStr for $quat {
+            type Err = crate::ParseError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut a = [0.0; 4];
+                crate::parse::parse_elements(s, &mut a, 0)?;
+                Ok(Self::from_array(a))
             }
         }
 
@@ -503,11 +630,19 @@ type InnerF32 = crate::XYZW<f32>;
 pub struct Quat(pub(crate) InnerF32);
 
 impl Quat {
-    impl_quat_methods!(f32, Quat, Vec3, Mat3, Mat4, InnerF32);
+    impl_quat_methods!(f32, Quat, Vec3, Mat3, Mat4, InnerF32, const_quat);
+
+    /// Creates a quaternion from a 3x3 SIMD aligned rotation matrix.
+    #[inline]
+    pub fn from_rotation_mat3a(mat: &Mat3A) -> Self {
+        Self::from_rotation_mat3(&Mat3::from(*mat))
+    }
 
     #[inline(always)]
     /// Multiplies a quaternion and a 3D vector, returning the rotated vector.
     pub fn mul_vec3a(self, other: Vec3A) -> Vec3A {
+        glam_assert!(self.is_finite());
+        glam_assert!(other.is_finite());
         Vec3A(self.0.mul_float4_as_vector3(other.0))
     }
 
@@ -538,7 +673,7 @@ type InnerF64 = crate::XYZW<f64>;
 pub struct DQuat(pub(crate) InnerF64);
 
 impl DQuat {
-    impl_quat_methods!(f64, DQuat, DVec3, DMat3, DMat4, InnerF64);
+    impl_quat_methods!(f64, DQuat, DVec3, DMat3, DMat4, InnerF64, const_dquat);
 
     #[inline(always)]
     pub fn as_f32(self) -> Quat {
@@ -8,6 +8,7 @@ use rand::{
 };
 
 #[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct TransformSRT {
     pub scale: Vec3,
@@ -27,6 +28,7 @@ impl Default for TransformSRT {
 }
 
 #[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct TransformRT {
     pub rotation: Quat,
@@ -71,9 +73,18 @@ impl TransformSRT {
         }
     }
 
+    /// Returns `true` if, and only if, all elements are finite.
+    /// If any element is either `NaN`, positive or negative infinity, this will return `false`.
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.scale.is_finite() && self.rotation.is_finite() && self.translation.is_finite()
+    }
+
     #[inline]
     pub fn inverse(&self) -> Self {
+        glam_assert!(self.is_finite());
         let scale = self.scale.recip();
+        glam_assert!(scale.is_finite());
         let rotation = self.rotation.conjugate();
         let translation = -(rotation * (self.translation * scale));
         Self {
@@ -95,7 +106,11 @@ impl TransformSRT {
 
     #[inline]
     pub fn mul_transform(&self, other: &Self) -> Self {
-        mul_srt_srt(self, other)
+        glam_assert!(self.is_finite());
+        glam_assert!(other.is_finite());
+        let result = mul_srt_srt(self, other);
+        glam_assert!(result.is_finite());
+        result
     }
 
     #[inline]
@@ -120,6 +135,33 @@ impl TransformSRT {
                 .translation
                 .abs_diff_eq(other.translation, max_abs_diff)
     }
+
+    /// Returns true if the difference of all elements between `self` and `other` is less
+    /// than or equal to `max_abs_diff` or to `max_relative` times the larger magnitude.
+    #[inline]
+    pub fn relative_eq(&self, other: Self, max_abs_diff: f32, max_relative: f32) -> bool {
+        self.scale
+            .relative_eq(other.scale, max_abs_diff, max_relative)
+            && self
+                .rotation
+                .relative_eq(other.rotation, max_abs_diff, max_relative)
+            && self
+                .translation
+                .relative_eq(other.translation, max_abs_diff, max_relative)
+    }
+
+    /// Returns true if all elements of `self` and `other` are within `max_abs_diff` of each
+    /// other or at most `max_ulps` representable values apart.
+    #[inline]
+    pub fn ulps_eq(&self, other: Self, max_abs_diff: f32, max_ulps: u32) -> bool {
+        self.scale.ulps_eq(other.scale, max_abs_diff, max_ulps)
+            && self
+                .rotation
+                .ulps_eq(other.rotation, max_abs_diff, max_ulps)
+            && self
+                .translation
+                .ulps_eq(other.translation, max_abs_diff, max_ulps)
+    }
 }
 
 #[inline]
@@ -207,12 +249,15 @@ impl TransformRT {
 
     #[inline]
     pub fn inverse(&self) -> Self {
+        glam_assert!(self.is_finite());
         let rotation = self.rotation.conjugate();
         let translation = -(rotation * self.translation);
-        Self {
+        let inverse = Self {
             rotation,
             translation,
-        }
+        };
+        glam_assert!(inverse.is_finite());
+        inverse
     }
 
     #[inline]
@@ -226,7 +271,11 @@ impl TransformRT {
 
     #[inline]
     pub fn mul_transform(&self, other: &Self) -> Self {
-        mul_rt_rt(self, other)
+        glam_assert!(self.is_finite());
+        glam_assert!(other.is_finite());
+        let result = mul_rt_rt(self, other);
+        glam_assert!(result.is_finite());
+        result
     }
 
     #[inline]
@@ -250,6 +299,28 @@ impl TransformRT {
                 .translation
                 .abs_diff_eq(other.translation, max_abs_diff)
     }
+
+    /// Returns true if the difference of all elements between `self` and `other` is less
+    /// than or equal to `max_abs_diff` or to `max_relative` times the larger magnitude.
+    #[inline]
+    pub fn relative_eq(&self, other: Self, max_abs_diff: f32, max_relative: f32) -> bool {
+        self.rotation
+            .relative_eq(other.rotation, max_abs_diff, max_relative)
+            && self
+                .translation
+                .relative_eq(other.translation, max_abs_diff, max_relative)
+    }
+
+    /// Returns true if all elements of `self` and `other` are within `max_abs_diff` of each
+    /// other or at most `max_ulps` representable values apart.
+    #[inline]
+    pub fn ulps_eq(&self, other: Self, max_abs_diff: f32, max_ulps: u32) -> bool {
+        self.rotation
+            .ulps_eq(other.rotation, max_abs_diff, max_ulps)
+            && self
+                .translation
+                .ulps_eq(other.translation, max_abs_diff, max_ulps)
+    }
 }
 
 impl AsRef<TransformRT> for TransformSRT {
@@ -2,6 +2,12 @@
 // The methods here should be supported for all types of $t and all sizes of vector.
 macro_rules! impl_vecn_common_methods {
     ($t:ty, $vecn:ident, $mask:ident, $inner:ident, $vectrait:ident) => {
+        /// All zeroes.
+        pub const ZERO: Self = Self($inner::ZERO);
+
+        /// All ones.
+        pub const ONE: Self = Self($inner::ONE);
+
         /// Creates a vector with all elements set to `0.0`.
         #[inline(always)]
         pub const fn zero() -> Self {
@@ -14,12 +20,6 @@ macro_rules! impl_vecn_common_methods {
             Self($inner::ONE)
         }
 
-        /// Creates a vector with all elements set to `v`.
-        #[inline(always)]
-        pub fn splat(v: $t) -> Self {
-            Self($inner::splat(v))
-        }
-
         /// Creates a vector from the elements in `if_true` and `if_false`, selecting which to use
         /// for each element of `self`.
         ///
@@ -52,6 +52,15 @@ macro_rules! impl_vecn_common_methods {
             Self(self.0.max(other.0))
         }
 
+        /// Component-wise clamping of values, similar to [`f32::clamp`].
+        ///
+        /// Each element in `min` must be less-or-equal to the corresponding element in `max`.
+        #[inline(always)]
+        pub fn clamp(self, min: Self, max: Self) -> Self {
+            glam_assert!(min.cmple(max).all());
+            Self(self.0.clamp(min.0, max.0))
+        }
+
         /// Returns the horizontal minimum of `self`.
         ///
         /// In other words this computes `min(x, y, ..)`.
@@ -68,6 +77,54 @@ macro_rules! impl_vecn_common_methods {
             $vectrait::max_element(self.0)
         }
 
+        /// Returns the index of the first minimum element of `self`.
+        #[inline(always)]
+        pub fn min_position(self) -> usize {
+            $vectrait::min_position(self.0)
+        }
+
+        /// Returns the index of the first maximum element of `self`.
+        #[inline(always)]
+        pub fn max_position(self) -> usize {
+            $vectrait::max_position(self.0)
+        }
+
+        /// Returns the sum of all elements of `self`.
+        ///
+        /// In other words, this computes `self.x + self.y + ..`.
+        #[inline(always)]
+        pub fn element_sum(self) -> $t {
+            $vectrait::element_sum(self.0)
+        }
+
+        /// Returns the product of all elements of `self`.
+        ///
+        /// In other words, this computes `self.x * self.y * ..`.
+        #[inline(always)]
+        pub fn element_product(self) -> $t {
+            $vectrait::element_product(self.0)
+        }
+
+        /// Returns the element-wise quotient of [Euclidean division] of `self` by `rhs`.
+        ///
+        /// For integer vectors this will panic if any element of `rhs` is zero.
+        ///
+        /// [Euclidean division]: f32::div_euclid
+        #[inline(always)]
+        pub fn div_euclid(self, rhs: Self) -> Self {
+            Self($vectrait::div_euclid(self.0, rhs.0))
+        }
+
+        /// Returns the element-wise remainder of [Euclidean division] of `self` by `rhs`.
+        ///
+        /// For integer vectors this will panic if any element of `rhs` is zero.
+        ///
+        /// [Euclidean division]: f32::rem_euclid
+        #[inline(always)]
+        pub fn rem_euclid(self, rhs: Self) -> Self {
+            Self($vectrait::rem_euclid(self.0, rhs.0))
+        }
+
         /// Returns a vector mask containing the result of a `==` comparison for each element of
         /// `self` and `other`.
         ///
@@ -245,9 +302,188 @@ macro_rules! impl_vecn_float_methods {
         /// Returns `self` normalized to length 1.0.
         ///
         /// For valid results, `self` must _not_ be of length zero.
+        ///
+        /// # Panics
+        ///
+        /// Will panic if `self` is zero length or non-finite when `glam_assert` is enabled, unless
+        /// a handler set with `set_assert_handler()` handles the failure instead.
         #[inline(always)]
         pub fn normalize(self) -> Self {
-            Self($flttrait::normalize(self.0))
+            glam_assert!(self.is_finite());
+            let normalized = Self($flttrait::normalize(self.0));
+            glam_assert!(normalized.is_finite());
+            normalized
+        }
+
+        /// Returns `self` normalized to length 1.0 if possible, else returns `None`.
+        ///
+        /// In particular, if the input is zero (or very close to zero), or non-finite,
+        /// the result of this operation will be `None`.
+        #[inline(always)]
+        pub fn try_normalize(self) -> Option<Self> {
+            $flttrait::try_normalize(self.0).map(Self)
+        }
+
+        /// Returns `self` normalized to length 1.0 if possible, else returns `fallback`.
+        ///
+        /// In particular, if the input is zero (or very close to zero), or non-finite,
+        /// the result of this operation will be `fallback`.
+        #[inline(always)]
+        pub fn normalize_or(self, fallback: Self) -> Self {
+            Self($flttrait::normalize_or(self.0, fallback.0))
+        }
+
+        /// Returns `self` normalized to length 1.0 if possible, else returns zero.
+        ///
+        /// In particular, if the input is zero (or very close to zero), or non-finite,
+        /// the result of this operation will be zero.
+        #[inline(always)]
+        pub fn normalize_or_zero(self) -> Self {
+            Self($flttrait::normalize_or_zero(self.0))
+        }
+
+        /// Returns the length of `self` and `self` normalized to length 1.0.
+        ///
+        /// If `self` is zero (or very close to zero), or non-finite, the direction will be
+        /// zero.
+        #[inline(always)]
+        pub fn length_and_direction(self) -> ($t, Self) {
+            let (length, direction) = $flttrait::length_and_direction(self.0);
+            (length, Self(direction))
+        }
+
+        /// Returns the reflection of `self` about a plane with the given `normal`.
+        ///
+        /// `normal` must be normalized.
+        #[inline(always)]
+        pub fn reflect(self, normal: Self) -> Self {
+            glam_assert!(self.is_finite());
+            glam_assert!(normal.is_finite());
+            let reflected = Self($flttrait::reflect(self.0, normal.0));
+            glam_assert!(reflected.is_finite());
+            reflected
+        }
+
+        /// Returns the refraction direction of the incident vector `self` through a surface with
+        /// the given `normal` and ratio of indices of refraction `eta`.
+        ///
+        /// When total internal reflection occurs, a zero vector is returned.
+        ///
+        /// `self` and `normal` must be normalized.
+        #[inline(always)]
+        pub fn refract(self, normal: Self, eta: $t) -> Self {
+            glam_assert!(self.is_finite());
+            glam_assert!(normal.is_finite());
+            glam_assert!(eta.is_finite());
+            let refracted = Self($flttrait::refract(self.0, normal.0, eta));
+            glam_assert!(refracted.is_finite());
+            refracted
+        }
+
+        /// Returns the vector projection of `self` onto `other`.
+        ///
+        /// For valid results, `other` must _not_ be of length zero.
+        #[inline(always)]
+        pub fn project_onto(self, other: Self) -> Self {
+            glam_assert!(self.is_finite());
+            glam_assert!(other.is_finite());
+            let projected = Self($flttrait::project_onto(self.0, other.0));
+            glam_assert!(projected.is_finite());
+            projected
+        }
+
+        /// Returns the vector rejection of `self` from `other`, that is the component of `self`
+        /// perpendicular to `other`.
+        ///
+        /// For valid results, `other` must _not_ be of length zero.
+        #[inline(always)]
+        pub fn reject_from(self, other: Self) -> Self {
+            glam_assert!(self.is_finite());
+            glam_assert!(other.is_finite());
+            let rejected = Self($flttrait::reject_from(self.0, other.0));
+            glam_assert!(rejected.is_finite());
+            rejected
+        }
+
+        /// Returns the vector projection of `self` onto `other`.
+        ///
+        /// `other` must be normalized. This is faster than `project_onto()` as it avoids a
+        /// division.
+        #[inline(always)]
+        pub fn project_onto_normalized(self, other: Self) -> Self {
+            glam_assert!(self.is_finite());
+            glam_assert!(other.is_finite());
+            let projected = Self($flttrait::project_onto_normalized(self.0, other.0));
+            glam_assert!(projected.is_finite());
+            projected
+        }
+
+        /// Returns `self` with the component along `normal` removed, which slides `self` along
+        /// the plane with the given `normal`.
+        ///
+        /// `normal` must be normalized.
+        #[inline(always)]
+        pub fn slide(self, normal: Self) -> Self {
+            glam_assert!(self.is_finite());
+            glam_assert!(normal.is_finite());
+            let slid = Self($flttrait::slide(self.0, normal.0));
+            glam_assert!(slid.is_finite());
+            slid
+        }
+
+        /// Returns some unit vector that is orthogonal to `self`.
+        ///
+        /// `self` must be normalized.
+        #[inline(always)]
+        pub fn any_orthonormal_vector(self) -> Self {
+            glam_assert!(self.is_finite());
+            let orthonormal = Self($flttrait::any_orthonormal_vector(self.0));
+            glam_assert!(orthonormal.is_finite());
+            orthonormal
+        }
+
+        /// Rotates `self` towards `target` by at most `max_angle` radians, preserving the length
+        /// of `self`.
+        ///
+        /// When `max_angle` is `0.0` the result will be equal to `self`. When `max_angle` is
+        /// greater than or equal to the angle between the two vectors the result will point in
+        /// the direction of `target`. If `max_angle` is negative `self` is rotated away from
+        /// `target`, up to the direction opposite to `target`.
+        ///
+        /// If either vector is zero `self` is returned unchanged.
+        #[inline(always)]
+        pub fn rotate_towards(self, target: Self, max_angle: $t) -> Self {
+            glam_assert!(self.is_finite());
+            glam_assert!(target.is_finite());
+            glam_assert!(max_angle.is_finite());
+            let rotated = Self($flttrait::rotate_towards(self.0, target.0, max_angle));
+            glam_assert!(rotated.is_finite());
+            rotated
+        }
+
+        /// Computes an approximation of `1.0 / length()`.
+        ///
+        /// When SIMD is available this uses a reciprocal square root estimate refined with one
+        /// Newton-Raphson step which has a maximum relative error of `1e-6`. Otherwise it is the
+        /// same as `length_recip()`.
+        ///
+        /// For valid results, `self` must _not_ be of length zero.
+        #[inline(always)]
+        pub fn length_recip_fast(self) -> $t {
+            $flttrait::length_recip_fast(self.0)
+        }
+
+        /// Returns `self` normalized to approximately length 1.0 using `length_recip_fast()`.
+        ///
+        /// The length of the result is within `1e-6` of 1.0 relative error.
+        ///
+        /// For valid results, `self` must _not_ be of length zero.
+        #[inline(always)]
+        pub fn normalize_fast(self) -> Self {
+            glam_assert!(self.is_finite());
+            let normalized = Self($flttrait::normalize_fast(self.0));
+            glam_assert!(normalized.is_finite());
+            normalized
         }
 
         /// Returns whether `self` is length `1.0` or not.
@@ -279,6 +515,25 @@ macro_rules! impl_vecn_float_methods {
             Self($flttrait::ceil(self.0))
         }
 
+        /// Returns a vector containing the integer part of each element of `self`, rounding
+        /// towards zero.
+        #[inline(always)]
+        pub fn trunc(self) -> Self {
+            Self($flttrait::trunc(self.0))
+        }
+
+        /// Returns a vector containing the fractional part of each element of `self`, computed
+        /// as `self - self.floor()`.
+        ///
+        /// This matches the GLSL `fract` function, so the result is in the range `[0.0, 1.0]` for
+        /// finite inputs. The result can be `1.0` when a small negative element is rounded, e.g.
+        /// `-1e-10`. Note that this is not the same as `f32::fract` which computes
+        /// `self - self.trunc()`.
+        #[inline(always)]
+        pub fn fract(self) -> Self {
+            Self($flttrait::fract(self.0))
+        }
+
         /// Returns a vector containing `e^self` (the exponential function) for each element of
         /// `self`.
         #[inline(always)]
@@ -286,27 +541,111 @@ macro_rules! impl_vecn_float_methods {
             Self($flttrait::exp(self.0))
         }
 
+        /// Returns a vector containing the natural logarithm of each element of `self`.
+        #[inline(always)]
+        pub fn ln(self) -> Self {
+            Self($flttrait::ln(self.0))
+        }
+
+        /// Returns a vector containing the square root of each element of `self`.
+        #[inline(always)]
+        pub fn sqrt(self) -> Self {
+            Self($flttrait::sqrt(self.0))
+        }
+
+        /// Returns a vector containing the sine of each element of `self`, in radians.
+        #[inline(always)]
+        pub fn sin(self) -> Self {
+            Self($flttrait::sin(self.0))
+        }
+
+        /// Returns a vector containing the cosine of each element of `self`, in radians.
+        #[inline(always)]
+        pub fn cos(self) -> Self {
+            Self($flttrait::cos(self.0))
+        }
+
         /// Returns a vector containing each element of `self` raised to the power of `n`.
         #[inline(always)]
         pub fn powf(self, n: $t) -> Self {
             Self($flttrait::powf(self.0, n))
         }
 
+        /// Returns a vector with the magnitude of each element of `self` and the sign of the
+        /// corresponding element of `sign`.
+        #[inline(always)]
+        pub fn copysign(self, sign: Self) -> Self {
+            Self($flttrait::copysign(self.0, sign.0))
+        }
+
         /// Returns a vector containing the reciprocal `1.0/n` of each element of `self`.
         #[inline(always)]
         pub fn recip(self) -> Self {
             Self($flttrait::recip(self.0))
         }
 
+        /// Returns a vector containing an approximation of the reciprocal `1.0/n` of each
+        /// element of `self`.
+        ///
+        /// When SIMD is available this uses a reciprocal estimate refined with one
+        /// Newton-Raphson step which has a maximum relative error of `1e-6`. Otherwise it is the
+        /// same as `recip()`.
+        #[inline(always)]
+        pub fn recip_fast(self) -> Self {
+            Self($flttrait::recip_fast(self.0))
+        }
+
+        /// Returns a tuple of vectors containing an approximation of the sine and cosine of
+        /// each element of `self`.
+        ///
+        /// Uses a polynomial approximation which has a maximum absolute error of `1e-5` for
+        /// `f32` elements in the range `[-PI, PI]`. The error increases for values outside of
+        /// this range.
+        #[inline(always)]
+        pub fn sin_cos_fast(self) -> (Self, Self) {
+            let (sin, cos) = $flttrait::sin_cos_fast(self.0);
+            (Self(sin), Self(cos))
+        }
+
         /// Performs a linear interpolation between `self` and `other` based on the value `s`.
         ///
         /// When `s` is `0.0`, the result will be equal to `self`.  When `s` is `1.0`, the result
         /// will be equal to `other`.
         #[inline]
         pub fn lerp(self, other: Self, s: $t) -> Self {
+            glam_assert!(self.is_finite());
+            glam_assert!(other.is_finite());
+            glam_assert!(s.is_finite());
             self + ((other - self) * s)
         }
 
+        /// Performs a per element linear interpolation between `self` and `other` using the
+        /// corresponding element of `a` as the weight.
+        ///
+        /// This matches the GLSL `mix` function.
+        #[inline(always)]
+        pub fn mix(self, other: Self, a: Self) -> Self {
+            Self($flttrait::mix(self.0, other.0, a.0))
+        }
+
+        /// Returns a vector containing `0.0` for each element of `self` that is less than the
+        /// corresponding element of `edge` and `1.0` otherwise.
+        ///
+        /// This matches the GLSL `step` function.
+        #[inline(always)]
+        pub fn step(self, edge: Self) -> Self {
+            Self($flttrait::step(self.0, edge.0))
+        }
+
+        /// Performs per element Hermite interpolation between `0.0` and `1.0` when each element
+        /// of `self` is between the corresponding elements of `edge0` and `edge1`.
+        ///
+        /// This matches the GLSL `smoothstep` function. Results are undefined if `edge0 >= edge1`.
+        #[inline(always)]
+        pub fn smoothstep(self, edge0: Self, edge1: Self) -> Self {
+            Self($flttrait::smoothstep(self.0, edge0.0, edge1.0))
+        }
+
         /// Returns true if the absolute difference of all elements between `self` and `other` is
         /// less than or equal to `max_abs_diff`.
         ///
@@ -321,37 +660,112 @@ macro_rules! impl_vecn_float_methods {
             $flttrait::abs_diff_eq(self.0, other.0, max_abs_diff)
         }
 
+        /// Returns true if the difference of all elements between `self` and `other` is less
+        /// than or equal to `max_abs_diff` or to `max_relative` times the larger magnitude.
+        ///
+        /// This is the comparison used by `approx::relative_eq!` and suits values of widely
+        /// differing magnitudes, where a single `max_abs_diff` does not fit.
+        #[inline(always)]
+        pub fn relative_eq(self, other: Self, max_abs_diff: $t, max_relative: $t) -> bool {
+            $flttrait::relative_eq(self.0, other.0, max_abs_diff, max_relative)
+        }
+
+        /// Returns true if all elements of `self` and `other` are within `max_abs_diff` of each
+        /// other or at most `max_ulps` representable values apart.
+        ///
+        /// This is the comparison used by `approx::ulps_eq!`.
+        #[inline(always)]
+        pub fn ulps_eq(self, other: Self, max_abs_diff: $t, max_ulps: u32) -> bool {
+            $flttrait::ulps_eq(self.0, other.0, max_abs_diff, max_ulps)
+        }
+
         /// Returns a vector with a length no less than `min` and no more than `max`
         #[inline]
         pub fn clamp_length(self, min: $t, max: $t) -> Self {
+            glam_assert!(self.is_finite());
+            glam_assert!(min <= max);
             let length_sq = self.length_squared();
-            if length_sq < min * min {
-                self * (length_sq.sqrt().recip() * min)
+            let clamped = if length_sq < min * min {
+                self * (self.length_recip() * min)
             } else if length_sq > max * max {
-                self * (length_sq.sqrt().recip() * max)
+                self * (self.length_recip() * max)
             } else {
                 self
-            }
+            };
+            glam_assert!(clamped.is_finite());
+            clamped
         }
 
         /// Returns a vector with a length no more than `max`
         pub fn clamp_length_max(self, max: $t) -> Self {
+            glam_assert!(self.is_finite());
             let length_sq = self.length_squared();
-            if length_sq > max * max {
-                self * (length_sq.sqrt().recip() * max)
+            let clamped = if length_sq > max * max {
+                self * (self.length_recip() * max)
             } else {
                 self
-            }
+            };
+            glam_assert!(clamped.is_finite());
+            clamped
         }
 
         /// Returns a vector with a length no less than `min`
         pub fn clamp_length_min(self, min: $t) -> Self {
+            glam_assert!(self.is_finite());
             let length_sq = self.length_squared();
-            if self.length_squared() < min * min {
-                self * (length_sq.sqrt().recip() * min)
+            let clamped = if length_sq < min * min {
+                self * (self.length_recip() * min)
             } else {
                 self
-            }
+            };
+            glam_assert!(clamped.is_finite());
+            clamped
+        }
+    };
+}
+
+// Adds methods which are only supported by integer vector types.
+macro_rules! impl_vecn_int_methods {
+    ($uvecn:ident, $vectrait:ident, $($c:ident),+) => {
+        /// Returns a vector containing each element of `self` raised to the power of `exp`.
+        #[inline(always)]
+        pub fn pow(self, exp: u32) -> Self {
+            Self($vectrait::pow(self.0, exp))
+        }
+
+        /// Returns a vector containing the absolute difference between each element of `self`
+        /// and `other`.
+        ///
+        /// The result is unsigned so it can not overflow.
+        #[inline]
+        pub fn abs_diff(self, other: Self) -> $uvecn {
+            // `i32::abs_diff` and `u32::abs_diff` are not available in the MSRV
+            $uvecn::new($(
+                if self.$c > other.$c {
+                    self.$c.wrapping_sub(other.$c) as u32
+                } else {
+                    other.$c.wrapping_sub(self.$c) as u32
+                }
+            ),+)
+        }
+
+        /// Computes the [manhattan distance] between two points.
+        ///
+        /// The result wraps around if the distance does not fit in a `u32`.
+        ///
+        /// [manhattan distance]: https://en.wikipedia.org/wiki/Taxicab_geometry
+        #[inline]
+        pub fn manhattan_distance(self, other: Self) -> u32 {
+            let d = self.abs_diff(other);
+            0_u32$(.wrapping_add(d.$c))+
+        }
+
+        /// Computes the [chebyshev distance] between two points.
+        ///
+        /// [chebyshev distance]: https://en.wikipedia.org/wiki/Chebyshev_distance
+        #[inline]
+        pub fn chebyshev_distance(self, other: Self) -> u32 {
+            self.abs_diff(other).max_element()
         }
     };
 }
@@ -367,6 +781,16 @@ macro_rules! impl_vecn_common_traits {
             }
         }
 
+        #[cfg(not(target_arch = "spirv"))]
+        impl core::str::FromStr for $vecn {
+            type Err = crate::ParseError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut a = [<$t>::default(); $size];
+                crate::parse::parse_elements(s, &mut a, 0)?;
+                Ok(Self::from_array(a))
+            }
+        }
+
         impl PartialEq for $vecn {
             #[inline(always)]
             fn eq(&self, other: &Self) -> bool {
@@ -374,13 +798,6 @@ macro_rules! impl_vecn_common_traits {
             }
         }
 
-        impl PartialOrd for $vecn {
-            #[inline]
-            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-                self.as_ref().partial_cmp(other.as_ref())
-            }
-        }
-
         impl From<$vecn> for $inner {
             #[inline(always)]
             fn from(t: $vecn) -> Self {
@@ -582,6 +999,48 @@ macro_rules! impl_vecn_signed_traits {
     };
 }
 
+// Adds float vector trait implementations.
+// Float vectors are only partially ordered, see `FloatOrd` for a total order.
+macro_rules! impl_vecn_float_traits {
+    ($vecn:ident) => {
+        impl PartialOrd for $vecn {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                self.as_ref().partial_cmp(other.as_ref())
+            }
+        }
+    };
+}
+
+// Adds integer vector trait implementations.
+// Integer vectors are totally ordered and hashable so they can be used as map keys.
+macro_rules! impl_vecn_int_traits {
+    ($vecn:ident) => {
+        impl Eq for $vecn {}
+
+        impl PartialOrd for $vecn {
+            #[inline]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $vecn {
+            #[inline]
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.as_ref().cmp(other.as_ref())
+            }
+        }
+
+        impl core::hash::Hash for $vecn {
+            #[inline]
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                core::hash::Hash::hash(self.as_ref(), state);
+            }
+        }
+    };
+}
+
 macro_rules! impl_vecn_as_f32 {
     ($ret:ident, $($e:ident),+) => {
         /// Casts all elements of `self` to `f32`.
@@ -8,7 +8,7 @@ use core::{cmp::Ordering, f32, ops::*};
 use std::iter::{Product, Sum};
 
 macro_rules! impl_vec2_common_methods {
-    ($t:ty, $vec2:ident, $vec3:ident, $mask:ident, $inner:ident) => {
+    ($t:ty, $vec2:ident, $vec3:ident, $mask:ident, $inner:ident, $const_new:ident) => {
         /// `[1, 0]`: a unit-length vector pointing along the positive X axis.
         pub const X: Self = Self($inner::UNIT_X);
 
@@ -17,8 +17,20 @@ macro_rules! impl_vec2_common_methods {
 
         /// Creates a new vector.
         #[inline(always)]
-        pub fn new(x: $t, y: $t) -> $vec2 {
-            Self(Vector2::new(x, y))
+        pub const fn new(x: $t, y: $t) -> $vec2 {
+            $const_new!([x, y])
+        }
+
+        /// Creates a vector with all elements set to `v`.
+        #[inline(always)]
+        pub const fn splat(v: $t) -> $vec2 {
+            $const_new!([v; 2])
+        }
+
+        /// Creates a new vector from an array.
+        #[inline(always)]
+        pub const fn from_array(a: [$t; 2]) -> $vec2 {
+            $const_new!(a)
         }
 
         /// Creates a vector with values `[x: 1.0, y: 0.0]`.
@@ -44,8 +56,8 @@ macro_rules! impl_vec2_common_methods {
 }
 
 macro_rules! impl_vec2_signed_methods {
-    ($t:ty, $vec2:ident, $vec3:ident, $mask:ident, $inner:ident) => {
-        impl_vec2_common_methods!($t, $vec2, $vec3, $mask, $inner);
+    ($t:ty, $vec2:ident, $vec3:ident, $mask:ident, $inner:ident, $const_new:ident) => {
+        impl_vec2_common_methods!($t, $vec2, $vec3, $mask, $inner, $const_new);
         impl_vecn_signed_methods!($t, $vec2, $mask, $inner, SignedVector2);
 
         /// Returns a vector that is equal to `self` rotated by 90 degrees.
@@ -63,8 +75,8 @@ macro_rules! impl_vec2_signed_methods {
 }
 
 macro_rules! impl_vec2_float_methods {
-    ($t:ty, $vec2:ident, $vec3:ident, $mask:ident, $inner:ident) => {
-        impl_vec2_signed_methods!($t, $vec2, $vec3, $mask, $inner);
+    ($t:ty, $vec2:ident, $vec3:ident, $mask:ident, $inner:ident, $const_new:ident) => {
+        impl_vec2_signed_methods!($t, $vec2, $vec3, $mask, $inner, $const_new);
         impl_vecn_float_methods!($t, $vec2, $mask, $inner, FloatVector2);
 
         /// Returns the angle between `self` and `other` in radians.
@@ -82,14 +94,14 @@ macro_rules! impl_vec2_common_traits {
     ($t:ty, $new:ident, $vec2:ident, $vec3:ident, $mask:ident, $inner:ident) => {
         /// Creates a 2-dimensional vector.
         #[inline(always)]
-        pub fn $new(x: $t, y: $t) -> $vec2 {
+        pub const fn $new(x: $t, y: $t) -> $vec2 {
             $vec2::new(x, y)
         }
 
         #[cfg(not(target_arch = "spirv"))]
         impl fmt::Display for $vec2 {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                write!(f, "[{}, {}]", self.x, self.y)
+                crate::display::fmt_elements(f, &[self.x, self.y])
             }
         }
 
@@ -157,12 +169,13 @@ type XYF32 = XY<f32>;
 pub struct Vec2(pub(crate) XYF32);
 
 impl Vec2 {
-    impl_vec2_float_methods!(f32, Vec2, Vec3, BVec2, XYF32);
+    impl_vec2_float_methods!(f32, Vec2, Vec3, BVec2, XYF32, const_vec2);
     impl_vecn_as_f64!(DVec2, x, y);
     impl_vecn_as_i32!(IVec2, x, y);
     impl_vecn_as_u32!(UVec2, x, y);
 }
 impl_vec2_signed_traits!(f32, vec2, Vec2, Vec3, BVec2, XYF32);
+impl_vecn_float_traits!(Vec2);
 
 type XYF64 = XY<f64>;
 
@@ -172,12 +185,13 @@ type XYF64 = XY<f64>;
 pub struct DVec2(pub(crate) XYF64);
 
 impl DVec2 {
-    impl_vec2_float_methods!(f64, DVec2, DVec3, BVec2, XYF64);
+    impl_vec2_float_methods!(f64, DVec2, DVec3, BVec2, XYF64, const_dvec2);
     impl_vecn_as_f32!(Vec2, x, y);
     impl_vecn_as_i32!(IVec2, x, y);
     impl_vecn_as_u32!(UVec2, x, y);
 }
 impl_vec2_signed_traits!(f64, dvec2, DVec2, DVec3, BVec2, XYF64);
+impl_vecn_float_traits!(DVec2);
 
 type XYI32 = XY<i32>;
 
@@ -187,12 +201,14 @@ type XYI32 = XY<i32>;
 pub struct IVec2(pub(crate) XYI32);
 
 impl IVec2 {
-    impl_vec2_signed_methods!(i32, IVec2, IVec3, BVec2, XYI32);
+    impl_vec2_signed_methods!(i32, IVec2, IVec3, BVec2, XYI32, const_ivec2);
     impl_vecn_as_f32!(Vec2, x, y);
     impl_vecn_as_f64!(DVec2, x, y);
     impl_vecn_as_u32!(UVec2, x, y);
+    impl_vecn_int_methods!(UVec2, Vector2, x, y);
 }
 impl_vec2_signed_traits!(i32, ivec2, IVec2, IVec3, BVec2, XYI32);
+impl_vecn_int_traits!(IVec2);
 
 type XYU32 = XY<u32>;
 
@@ -202,9 +218,11 @@ type XYU32 = XY<u32>;
 pub struct UVec2(pub(crate) XYU32);
 
 impl UVec2 {
-    impl_vec2_common_methods!(u32, UVec2, UVec3, BVec2, XYU32);
+    impl_vec2_common_methods!(u32, UVec2, UVec3, BVec2, XYU32, const_uvec2);
     impl_vecn_as_f32!(Vec2, x, y);
     impl_vecn_as_f64!(DVec2, x, y);
     impl_vecn_as_i32!(IVec2, x, y);
+    impl_vecn_int_methods!(UVec2, Vector2, x, y);
 }
 impl_vec2_unsigned_traits!(u32, uvec2, UVec2, UVec3, BVec2, XYU32);
+impl_vecn_int_traits!(UVec2);
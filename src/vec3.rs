@@ -23,7 +23,7 @@ use core::arch::x86_64::*;
 use std::iter::{Product, Sum};
 
 macro_rules! impl_vec3_common_methods {
-    ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident, $mask:ident, $inner:ident) => {
+    ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident, $mask:ident, $inner:ident, $const_new:ident) => {
         /// `[1, 0, 0]`: a unit-length vector pointing along the positive X axis.
         pub const X: Self = Self(Vector3Const::UNIT_X);
 
@@ -35,8 +35,20 @@ macro_rules! impl_vec3_common_methods {
 
         /// Creates a new 3D vector.
         #[inline(always)]
-        pub fn new(x: $t, y: $t, z: $t) -> Self {
-            Self(Vector3::new(x, y, z))
+        pub const fn new(x: $t, y: $t, z: $t) -> Self {
+            $const_new!([x, y, z])
+        }
+
+        /// Creates a vector with all elements set to `v`.
+        #[inline(always)]
+        pub const fn splat(v: $t) -> Self {
+            $const_new!([v; 3])
+        }
+
+        /// Creates a new 3D vector from an array.
+        #[inline(always)]
+        pub const fn from_array(a: [$t; 3]) -> Self {
+            $const_new!(a)
         }
 
         /// Creates a vector with values `[x: 1.0, y: 0.0, z: 0.0]`.
@@ -93,7 +105,7 @@ macro_rules! impl_vec3_common_traits {
     ($t:ty, $new:ident, $vec2:ident, $vec3:ident, $vec4:ident, $mask:ident, $inner:ident) => {
         /// Creates a 3-dimensional vector.
         #[inline(always)]
-        pub fn $new(x: $t, y: $t, z: $t) -> $vec3 {
+        pub const fn $new(x: $t, y: $t, z: $t) -> $vec3 {
             $vec3::new(x, y, z)
         }
 
@@ -112,7 +124,7 @@ macro_rules! impl_vec3_common_traits {
         #[cfg(not(target_arch = "spirv"))]
         impl fmt::Display for $vec3 {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-                write!(f, "[{}, {}, {}]", self.x, self.y, self.z)
+                crate::display::fmt_elements(f, &[self.x, self.y, self.z])
             }
         }
 
@@ -165,15 +177,15 @@ macro_rules! impl_vec3_common_traits {
 }
 
 macro_rules! impl_vec3_signed_methods {
-    ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident, $mask:ident, $inner:ident) => {
-        impl_vec3_common_methods!($t, $vec2, $vec3, $vec4, $mask, $inner);
+    ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident, $mask:ident, $inner:ident, $const_new:ident) => {
+        impl_vec3_common_methods!($t, $vec2, $vec3, $vec4, $mask, $inner, $const_new);
         impl_vecn_signed_methods!($t, $vec3, $mask, $inner, SignedVector3);
     };
 }
 
 macro_rules! impl_vec3_float_methods {
-    ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident, $mask:ident, $inner:ident) => {
-        impl_vec3_signed_methods!($t, $vec2, $vec3, $vec4, $mask, $inner);
+    ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident, $mask:ident, $inner:ident, $const_new:ident) => {
+        impl_vec3_signed_methods!($t, $vec2, $vec3, $vec4, $mask, $inner, $const_new);
         impl_vecn_float_methods!($t, $vec3, $mask, $inner, FloatVector3);
 
         /// Returns the angle between two vectors, in radians.
@@ -184,6 +196,16 @@ macro_rules! impl_vec3_float_methods {
         pub fn angle_between(self, other: Self) -> $t {
             self.0.angle_between(other.0)
         }
+
+        /// Returns two unit vectors that are orthogonal to `self` and to each other, which
+        /// together with `self` form an orthonormal basis.
+        ///
+        /// `self` must be normalized.
+        #[inline(always)]
+        pub fn any_orthonormal_pair(self) -> (Self, Self) {
+            let (a, b) = FloatVector3::any_orthonormal_pair(self.0);
+            (Self(a), Self(b))
+        }
     };
 }
 
@@ -192,14 +214,15 @@ macro_rules! impl_vec3_float_traits {
     ($t:ty, $new:ident, $vec2:ident, $vec3:ident, $vec4:ident, $mask:ident, $inner:ident) => {
         impl_vec3_common_traits!($t, $new, $vec2, $vec3, $vec4, $mask, $inner);
         impl_vecn_signed_traits!($t, 3, $vec3, $inner, SignedVector3);
+        impl_vecn_float_traits!($vec3);
     };
 }
 
 // implements f32 functionality common between `Vec3` and `Vec3A` types.
 macro_rules! impl_f32_vec3 {
-    ($new:ident, $vec2:ident, $vec3:ident, $vec4:ident, $mask:ident, $inner:ident) => {
+    ($new:ident, $vec2:ident, $vec3:ident, $vec4:ident, $mask:ident, $inner:ident, $const_new:ident) => {
         impl $vec3 {
-            impl_vec3_float_methods!(f32, $vec2, $vec3, $vec4, $mask, $inner);
+            impl_vec3_float_methods!(f32, $vec2, $vec3, $vec4, $mask, $inner, $const_new);
             impl_vecn_as_f64!(DVec3, x, y, z);
             impl_vecn_as_i32!(IVec3, x, y, z);
             impl_vecn_as_u32!(UVec3, x, y, z);
@@ -214,7 +237,7 @@ type XYZF32 = XYZ<f32>;
 #[derive(Clone, Copy)]
 #[repr(transparent)]
 pub struct Vec3(pub(crate) XYZF32);
-impl_f32_vec3!(vec3, Vec2, Vec3, Vec4, BVec3, XYZF32);
+impl_f32_vec3!(vec3, Vec2, Vec3, Vec4, BVec3, XYZF32, const_vec3);
 
 /// A 3-dimensional vector with SIMD support.
 ///
@@ -239,10 +262,21 @@ pub struct Vec3A(pub(crate) __m128);
 pub struct Vec3A(pub(crate) XYZF32);
 
 #[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
-impl_f32_vec3!(vec3a, Vec2, Vec3A, Vec4, BVec3A, __m128);
+impl_f32_vec3!(vec3a, Vec2, Vec3A, Vec4, BVec3A, __m128, const_vec3a);
 
 #[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
-impl_f32_vec3!(vec3a, Vec2, Vec3A, Vec4, BVec3, XYZF32);
+impl_f32_vec3!(vec3a, Vec2, Vec3A, Vec4, BVec3, XYZF32, const_vec3a);
+
+impl Vec3A {
+    /// Returns `[x, y, z, 0.0]`.
+    ///
+    /// The unused fourth element of a `Vec3A` has no defined value, this can be used to get a
+    /// 16 byte array with zeroed padding, e.g. for casting to bytes for a GPU buffer.
+    #[inline]
+    pub fn to_padded_array(self) -> [f32; 4] {
+        [self.x, self.y, self.z, 0.0]
+    }
+}
 
 impl From<Vec3> for Vec3A {
     #[inline(always)]
@@ -266,7 +300,7 @@ type XYZF64 = XYZ<f64>;
 pub struct DVec3(pub(crate) XYZF64);
 
 impl DVec3 {
-    impl_vec3_float_methods!(f64, DVec2, DVec3, DVec4, BVec3, XYZF64);
+    impl_vec3_float_methods!(f64, DVec2, DVec3, DVec4, BVec3, XYZF64, const_dvec3);
     impl_vecn_as_f32!(Vec3, x, y, z);
     impl_vecn_as_i32!(IVec3, x, y, z);
     impl_vecn_as_u32!(UVec3, x, y, z);
@@ -281,14 +315,16 @@ type XYZI32 = XYZ<i32>;
 pub struct IVec3(pub(crate) XYZI32);
 
 impl IVec3 {
-    impl_vec3_common_methods!(i32, IVec2, IVec3, IVec4, BVec3, XYZI32);
+    impl_vec3_common_methods!(i32, IVec2, IVec3, IVec4, BVec3, XYZI32, const_ivec3);
     impl_vecn_signed_methods!(i32, IVec3, BVec3, XYZI32, SignedVector3);
     impl_vecn_as_f32!(Vec3, x, y, z);
     impl_vecn_as_f64!(DVec3, x, y, z);
     impl_vecn_as_u32!(UVec3, x, y, z);
+    impl_vecn_int_methods!(UVec3, Vector3, x, y, z);
 }
 impl_vec3_common_traits!(i32, ivec3, IVec2, IVec3, IVec4, BVec3, XYZI32);
 impl_vecn_signed_traits!(i32, 3, IVec3, XYZI32, SignedVector3);
+impl_vecn_int_traits!(IVec3);
 
 type XYZU32 = XYZ<u32>;
 
@@ -298,12 +334,14 @@ type XYZU32 = XYZ<u32>;
 pub struct UVec3(pub(crate) XYZU32);
 
 impl UVec3 {
-    impl_vec3_common_methods!(u32, UVec2, UVec3, UVec4, BVec3, XYZU32);
+    impl_vec3_common_methods!(u32, UVec2, UVec3, UVec4, BVec3, XYZU32, const_uvec3);
     impl_vecn_as_f32!(Vec3, x, y, z);
     impl_vecn_as_f64!(DVec3, x, y, z);
     impl_vecn_as_i32!(IVec3, x, y, z);
+    impl_vecn_int_methods!(UVec3, Vector3, x, y, z);
 }
 impl_vec3_common_traits!(u32, uvec3, UVec2, UVec3, UVec4, BVec3, XYZU32);
+impl_vecn_int_traits!(UVec3);
 
 #[test]
 fn test_vec3_private() {
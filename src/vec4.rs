@@ -26,7 +26,7 @@ use std::iter::{Product, Sum};
 use core::{cmp::Ordering, f32};
 
 macro_rules! impl_vec4_common_methods {
-    ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident, $mask:ident, $inner:ident) => {
+    ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident, $mask:ident, $inner:ident, $const_new:ident) => {
         /// `[1, 0, 0, 0]`: a unit-length vector pointing along the positive X axis.
         pub const X: Self = Self(Vector4Const::UNIT_X);
 
@@ -41,8 +41,20 @@ macro_rules! impl_vec4_common_methods {
 
         /// Creates a new 4D vector.
         #[inline(always)]
-        pub fn new(x: $t, y: $t, z: $t, w: $t) -> Self {
-            Self(Vector4::new(x, y, z, w))
+        pub const fn new(x: $t, y: $t, z: $t, w: $t) -> Self {
+            $const_new!([x, y, z, w])
+        }
+
+        /// Creates a vector with all elements set to `v`.
+        #[inline(always)]
+        pub const fn splat(v: $t) -> Self {
+            $const_new!([v; 4])
+        }
+
+        /// Creates a new 4D vector from an array.
+        #[inline(always)]
+        pub const fn from_array(a: [$t; 4]) -> Self {
+            $const_new!(a)
         }
 
         /// Creates a 4D vector with values `[x: 1.0, y: 0.0, z: 0.0, w: 0.0]`.
@@ -87,7 +99,7 @@ macro_rules! impl_vec4_common_traits {
     ($t:ty, $new:ident, $vec2:ident, $vec3:ident, $vec4:ident, $mask:ident, $inner:ident) => {
         /// Creates a 4-dimensional vector.
         #[inline(always)]
-        pub fn $new(x: $t, y: $t, z: $t, w: $t) -> $vec4 {
+        pub const fn $new(x: $t, y: $t, z: $t, w: $t) -> $vec4 {
             $vec4::new(x, y, z, w)
         }
 
@@ -107,8 +119,7 @@ macro_rules! impl_vec4_common_traits {
         #[cfg(not(target_arch = "spirv"))]
         impl fmt::Display for $vec4 {
             fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-                let a = self.as_ref();
-                write!(fmt, "[{}, {}, {}, {}]", a[0], a[1], a[2], a[3])
+                crate::display::fmt_elements(fmt, self.as_ref())
             }
         }
 
@@ -170,8 +181,8 @@ macro_rules! impl_vec4_common_traits {
 }
 
 macro_rules! impl_vec4_signed_methods {
-    ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident, $mask:ident, $inner:ident) => {
-        impl_vec4_common_methods!($t, $vec2, $vec3, $vec4, $mask, $inner);
+    ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident, $mask:ident, $inner:ident, $const_new:ident) => {
+        impl_vec4_common_methods!($t, $vec2, $vec3, $vec4, $mask, $inner, $const_new);
         impl_vecn_signed_methods!($t, $vec4, $mask, $inner, SignedVector4);
     };
 }
@@ -184,22 +195,23 @@ macro_rules! impl_vec4_signed_traits {
 }
 
 macro_rules! impl_vec4_float_methods {
-    ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident, $mask:ident, $inner:ident) => {
-        impl_vec4_signed_methods!($t, $vec2, $vec3, $vec4, $mask, $inner);
+    ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident, $mask:ident, $inner:ident, $const_new:ident) => {
+        impl_vec4_signed_methods!($t, $vec2, $vec3, $vec4, $mask, $inner, $const_new);
         impl_vecn_float_methods!($t, $vec4, $mask, $inner, FloatVector4);
     };
 }
 
 // implement `Vec4` functionality
 macro_rules! impl_f32_vec4 {
-    ($new:ident, $vec2:ident, $vec3:ident, $vec4:ident, $mask:ident, $inner:ident) => {
+    ($new:ident, $vec2:ident, $vec3:ident, $vec4:ident, $mask:ident, $inner:ident, $const_new:ident) => {
         impl $vec4 {
-            impl_vec4_float_methods!(f32, $vec2, $vec3, $vec4, $mask, $inner);
+            impl_vec4_float_methods!(f32, $vec2, $vec3, $vec4, $mask, $inner, $const_new);
             impl_vecn_as_f64!(DVec4, x, y, z, w);
             impl_vecn_as_i32!(IVec4, x, y, z, w);
             impl_vecn_as_u32!(UVec4, x, y, z, w);
         }
         impl_vec4_signed_traits!(f32, $new, $vec2, $vec3, $vec4, $mask, $inner);
+        impl_vecn_float_traits!($vec4);
     };
 }
 
@@ -217,10 +229,10 @@ type XYZWF32 = __m128;
 pub struct Vec4(pub(crate) XYZWF32);
 
 #[cfg(any(not(target_feature = "sse2"), feature = "scalar-math"))]
-impl_f32_vec4!(vec4, Vec2, Vec3, Vec4, BVec4, XYZWF32);
+impl_f32_vec4!(vec4, Vec2, Vec3, Vec4, BVec4, XYZWF32, const_vec4);
 
 #[cfg(all(target_feature = "sse2", not(feature = "scalar-math")))]
-impl_f32_vec4!(vec4, Vec2, Vec3, Vec4, BVec4A, __m128);
+impl_f32_vec4!(vec4, Vec2, Vec3, Vec4, BVec4A, __m128, const_vec4);
 
 impl From<Vec4> for Vec3A {
     /// Creates a `Vec3A` from the `x`, `y` and `z` elements of `self` discarding `w`.
@@ -240,12 +252,13 @@ type XYZWF64 = XYZW<f64>;
 pub struct DVec4(pub(crate) XYZWF64);
 
 impl DVec4 {
-    impl_vec4_float_methods!(f64, DVec2, DVec3, DVec4, BVec4, XYZWF64);
+    impl_vec4_float_methods!(f64, DVec2, DVec3, DVec4, BVec4, XYZWF64, const_dvec4);
     impl_vecn_as_f32!(Vec4, x, y, z, w);
     impl_vecn_as_i32!(IVec4, x, y, z, w);
     impl_vecn_as_u32!(UVec4, x, y, z, w);
 }
 impl_vec4_signed_traits!(f64, dvec4, DVec2, DVec3, DVec4, BVec4, XYZWF64);
+impl_vecn_float_traits!(DVec4);
 
 type XYZWI32 = XYZW<i32>;
 
@@ -255,12 +268,14 @@ type XYZWI32 = XYZW<i32>;
 pub struct IVec4(pub(crate) XYZWI32);
 
 impl IVec4 {
-    impl_vec4_signed_methods!(i32, IVec2, IVec3, IVec4, BVec4, XYZWI32);
+    impl_vec4_signed_methods!(i32, IVec2, IVec3, IVec4, BVec4, XYZWI32, const_ivec4);
     impl_vecn_as_f32!(Vec4, x, y, z, w);
     impl_vecn_as_f64!(DVec4, x, y, z, w);
     impl_vecn_as_u32!(UVec4, x, y, z, w);
+    impl_vecn_int_methods!(UVec4, Vector4, x, y, z, w);
 }
 impl_vec4_signed_traits!(i32, ivec4, IVec2, IVec3, IVec4, BVec4, XYZWI32);
+impl_vecn_int_traits!(IVec4);
 
 type XYZWU32 = XYZW<u32>;
 
@@ -270,12 +285,14 @@ type XYZWU32 = XYZW<u32>;
 pub struct UVec4(pub(crate) XYZWU32);
 
 impl UVec4 {
-    impl_vec4_common_methods!(u32, UVec2, UVec3, UVec4, BVec4, XYZWU32);
+    impl_vec4_common_methods!(u32, UVec2, UVec3, UVec4, BVec4, XYZWU32, const_uvec4);
     impl_vecn_as_f32!(Vec4, x, y, z, w);
     impl_vecn_as_f64!(DVec4, x, y, z, w);
     impl_vecn_as_i32!(IVec4, x, y, z, w);
+    impl_vecn_int_methods!(UVec4, Vector4, x, y, z, w);
 }
 impl_vec4_common_traits!(u32, uvec4, UVec2, UVec3, UVec4, BVec4, XYZWU32);
+impl_vecn_int_traits!(UVec4);
 
 #[test]
 fn test_vec4_private() {
@@ -157,8 +157,17 @@ macro_rules! impl_vec2mask {
         #[cfg(not(target_arch = "spirv"))]
         impl fmt::Display for $vec2mask {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                let arr = self.0.into_bool_array();
-                write!(f, "[{}, {}]", arr[0], arr[1])
+                crate::display::fmt_elements(f, &self.0.into_bool_array())
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl core::str::FromStr for $vec2mask {
+            type Err = crate::ParseError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut a = [false; 2];
+                crate::parse::parse_elements(s, &mut a, 0)?;
+                Ok(Self::new(a[0], a[1]))
             }
         }
 
@@ -218,8 +227,17 @@ macro_rules! impl_vec3mask {
         #[cfg(not(target_arch = "spirv"))]
         impl fmt::Display for $vec3mask {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                let arr = MaskVector3::into_bool_array(self.0);
-                write!(f, "[{}, {}, {}]", arr[0], arr[1], arr[2])
+                crate::display::fmt_elements(f, &MaskVector3::into_bool_array(self.0))
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl core::str::FromStr for $vec3mask {
+            type Err = crate::ParseError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut a = [false; 3];
+                crate::parse::parse_elements(s, &mut a, 0)?;
+                Ok(Self::new(a[0], a[1], a[2]))
             }
         }
 
@@ -280,8 +298,17 @@ macro_rules! impl_vec4mask {
         #[cfg(not(target_arch = "spirv"))]
         impl fmt::Display for $vec4mask {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                let arr = MaskVector4::into_bool_array(self.0);
-                write!(f, "[{}, {}, {}, {}]", arr[0], arr[1], arr[2], arr[3])
+                crate::display::fmt_elements(f, &MaskVector4::into_bool_array(self.0))
+            }
+        }
+
+        #[cfg(not(target_arch = "spirv"))]
+        impl core::str::FromStr for $vec4mask {
+            type Err = crate::ParseError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut a = [false; 4];
+                crate::parse::parse_elements(s, &mut a, 0)?;
+                Ok(Self::new(a[0], a[1], a[2], a[3]))
             }
         }
 
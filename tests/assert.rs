@@ -0,0 +1,84 @@
+#![cfg(any(
+    feature = "glam-assert",
+    all(debug_assertions, feature = "debug-glam-assert")
+))]
+
+use glam::{AssertFailure, DMat2x3, DQuat, DVec2, DVec3, Mat3, Mat4, Mat4x3, Quat, Vec3, Vec3A};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static FAILURES: AtomicUsize = AtomicUsize::new(0);
+
+fn count_failure(failure: &AssertFailure) {
+    assert!(!failure.condition.is_empty());
+    assert!(failure.line > 0);
+    FAILURES.fetch_add(1, Ordering::SeqCst);
+}
+
+fn failures<T, F: FnOnce() -> T>(f: F) -> usize {
+    let before = FAILURES.load(Ordering::SeqCst);
+    let _ = f();
+    FAILURES.load(Ordering::SeqCst) - before
+}
+
+// The handler is global so everything is checked in a single test.
+#[test]
+fn test_assert_handler() {
+    assert!(glam::set_assert_handler(Some(count_failure)).is_none());
+
+    // valid inputs do not trigger assertions
+    assert_eq!(
+        failures(|| {
+            Vec3::new(1.0, 2.0, 3.0).normalize();
+            Mat4::from_scale(Vec3::splat(2.0)).inverse();
+            Quat::from_rotation_y(1.0).slerp(Quat::IDENTITY, 0.5);
+        }),
+        0
+    );
+
+    // execution continues with the invalid result after the handler returns
+    let mut result = Vec3::ZERO;
+    assert_ne!(failures(|| result = Vec3::ZERO.normalize()), 0);
+    assert!(result.is_nan());
+
+    assert_ne!(failures(|| Vec3A::ZERO.normalize()), 0);
+    assert_ne!(failures(|| DVec2::X.project_onto(DVec2::ZERO)), 0);
+    assert_ne!(failures(|| Mat4::ZERO.inverse()), 0);
+    assert_ne!(failures(|| Mat3::ZERO.inverse()), 0);
+    assert_ne!(
+        failures(|| Mat4::IDENTITY.transform_point3(Vec3::new(f32::NAN, 0.0, 0.0))),
+        0
+    );
+    assert_ne!(
+        failures(|| Vec3::X.reflect(Vec3::new(0.0, f32::NAN, 0.0))),
+        0
+    );
+    assert_ne!(
+        failures(|| Vec3::X.rotate_towards(Vec3::Y, f32::INFINITY)),
+        0
+    );
+    assert_ne!(
+        failures(|| DMat2x3::from_cols(DVec3::X, DVec3::Y).mul_vec2(DVec2::new(f64::NAN, 0.0))),
+        0
+    );
+    assert_ne!(
+        failures(
+            || Mat4x3::from_cols(Vec3::X, Vec3::Y, Vec3::Z, Vec3::splat(f32::MAX))
+                .transform_point3(Vec3::splat(f32::MAX))
+        ),
+        0
+    );
+    assert_ne!(failures(|| Quat::from_rotation_x(f32::INFINITY)), 0);
+    assert_ne!(
+        failures(|| DQuat::IDENTITY.slerp(DQuat::IDENTITY, f64::NAN)),
+        0
+    );
+    assert_ne!(
+        failures(|| Mat4::look_at_rh(Vec3::ZERO, Vec3::ZERO, Vec3::Y)),
+        0
+    );
+
+    // removing the handler restores panicking
+    let previous = glam::set_assert_handler(None);
+    assert!(previous.is_some());
+    assert!(std::panic::catch_unwind(|| Vec3::ZERO.normalize()).is_err());
+}
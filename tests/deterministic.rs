@@ -0,0 +1,236 @@
+// Golden values for the `deterministic` feature. These tests are run with and without
+// `scalar-math` and must produce the same bits on every target platform.
+#![cfg(feature = "deterministic")]
+
+use glam::{Mat2, Mat4, Quat, Vec3, Vec3A, Vec4};
+
+macro_rules! assert_bits_eq {
+    ($actual:expr, $expected:expr) => {{
+        let actual: &[f32] = &$actual;
+        let expected: &[u32] = &$expected;
+        assert_eq!(actual.len(), expected.len());
+        for (i, (a, e)) in actual.iter().zip(expected.iter()).enumerate() {
+            assert_eq!(
+                a.to_bits(),
+                *e,
+                "element {}: {:?} (0x{:08x}) != {:?} (0x{:08x})",
+                i,
+                a,
+                a.to_bits(),
+                f32::from_bits(*e),
+                e
+            );
+        }
+    }};
+}
+
+fn quat() -> (Quat, Quat) {
+    let q0 = Quat::from_axis_angle(Vec3::new(1.0, 2.0, 3.0).normalize(), 0.75);
+    let q1 = Quat::from_rotation_ypr(0.3, -1.2, 2.1);
+    (q0, q1)
+}
+
+fn mat4() -> (Mat4, Mat4) {
+    let (_, q1) = quat();
+    let m = Mat4::from_scale_rotation_translation(
+        Vec3::new(1.5, 0.5, 2.0),
+        q1,
+        Vec3::new(1.0, -2.0, 3.5),
+    );
+    let p = Mat4::perspective_rh(1.1, 1.7, 0.1, 100.0);
+    (m, p)
+}
+
+#[test]
+fn test_sin_cos() {
+    let angles = [0.1_f32, 0.5, 1.0, 2.0, 3.0, -1.25, 10.0, 100.0];
+    let mut sin = [0.0; 8];
+    let mut cos = [0.0; 8];
+    for (i, angle) in angles.iter().enumerate() {
+        let q = Quat::from_rotation_x(angle * 2.0);
+        sin[i] = q.x;
+        cos[i] = q.w;
+    }
+    assert_bits_eq!(
+        sin,
+        [
+            0x3dcc7577, 0x3ef57744, 0x3f576aa4, 0x3f68c7b7, 0x3e1081c3, 0xbf72f0a8, 0xbf0b44f8,
+            0xbf01a12e
+        ]
+    );
+    assert_bits_eq!(
+        cos,
+        [
+            0x3f7eb898, 0x3f60a940, 0x3f0a5140, 0xbed51133, 0xbf7d7026, 0x3ea171ef, 0xbf56cd64,
+            0x3f5cc0ee
+        ]
+    );
+}
+
+#[test]
+fn test_vec4() {
+    let v = Vec4::new(1.5, -2.25, 3.125, 0.7);
+    let w = Vec4::new(-0.3, 4.1, 0.9, -1.7);
+    assert_bits_eq!(
+        [v.dot(w), v.length(), v.length_recip()],
+        [0xc100d70a, 0x4086203c, 0x3e744ec8]
+    );
+    assert_bits_eq!(
+        <[f32; 4]>::from(v.normalize()),
+        [0x3eb73b16, 0xbf096c50, 0x3f3edd8c, 0x3e2b03f2]
+    );
+    assert_bits_eq!(
+        <[f32; 4]>::from(Vec4::new(0.5, -1.5, 2.25, 3.0).exp()),
+        [0x3fd3094c, 0x3e647c3c, 0x4117cdc4, 0x41a0af2e]
+    );
+    assert_bits_eq!(
+        <[f32; 4]>::from(Vec4::new(0.5, 1.5, 2.25, 3.0).powf(2.7)),
+        [0x3e1d9624, 0x403f42d0, 0x410ee4c3, 0x419b5a2a]
+    );
+}
+
+#[test]
+fn test_vec4_sin_cos_fast() {
+    let (sin, cos) = Vec4::new(0.3, -2.5, 4.0, 100.0).sin_cos_fast();
+    assert_bits_eq!(
+        <[f32; 4]>::from(sin),
+        [0x3e974e73, 0xbf193584, 0xbf41bdcd, 0xbf01a15f]
+    );
+    assert_bits_eq!(
+        <[f32; 4]>::from(cos),
+        [0x3f749146, 0xbf4d1846, 0xbf275528, 0x3f5cc170]
+    );
+}
+
+#[test]
+fn test_vec4_rounding() {
+    // zero results keep the sign of the input
+    assert_bits_eq!(
+        <[f32; 4]>::from(Vec4::new(0.5, -1.5, -0.3, 2.499_999_8).round()),
+        [0x3f800000, 0xc0000000, 0x80000000, 0x40000000]
+    );
+    assert_bits_eq!(
+        <[f32; 4]>::from(Vec4::new(-0.0, -1.5, 0.3, 2.5).floor()),
+        [0x80000000, 0xc0000000, 0x00000000, 0x40000000]
+    );
+    assert_bits_eq!(
+        <[f32; 4]>::from(Vec4::new(-0.5, -1.5, 0.3, 2.5).ceil()),
+        [0x80000000, 0xbf800000, 0x3f800000, 0x40400000]
+    );
+}
+
+#[test]
+fn test_vec3a() {
+    let a = Vec3A::new(1.5, -2.25, 3.125);
+    let b = Vec3A::new(-0.3, 4.1, 0.9);
+    assert_bits_eq!(
+        <[f32; 3]>::from(a.normalize()),
+        [0x3eb9d740, 0xbf0b6170, 0x3f41958e]
+    );
+    assert_bits_eq!(
+        <[f32; 3]>::from(a.cross(b)),
+        [0xc16d6666, 0xc0126666, 0x40af3332]
+    );
+}
+
+#[test]
+fn test_quat() {
+    let (q0, q1) = quat();
+    assert_bits_eq!(
+        <[f32; 4]>::from(q0),
+        [0x3dc87aca, 0x3e487aca, 0x3e965c17, 0x3f6e35bf]
+    );
+    assert_bits_eq!(
+        <[f32; 4]>::from(q1),
+        [0xbe2ee8d0, 0x3f0bafea, 0x3f3ff6e2, 0x3eaa6c8e]
+    );
+    assert_bits_eq!(
+        <[f32; 4]>::from(q0 * q1),
+        [0xbe0f2539, 0x3ee60f4f, 0x3f61e237, 0xba1b3c00]
+    );
+    let v = [0x3f8f0f76, 0x40ca91de, 0xc03c88e0];
+    assert_bits_eq!(<[f32; 3]>::from(q1 * Vec3::new(3.0, -4.0, 5.0)), v);
+    assert_bits_eq!(<[f32; 3]>::from(q1 * Vec3A::new(3.0, -4.0, 5.0)), v);
+    assert_bits_eq!(
+        <[f32; 4]>::from(q0.slerp(q1, 0.3)),
+        [0x3c7d2aa3, 0x3eaa99e5, 0x3ef3ee83, 0x3f504044]
+    );
+    assert_bits_eq!(
+        <[f32; 4]>::from(q0.slerp_fast(q1, 0.3)),
+        [0x3c7d2ad2, 0x3eaa99f2, 0x3ef3ee96, 0x3f504056]
+    );
+    assert_bits_eq!(
+        <[f32; 4]>::from(q0.lerp(-q1, 0.3)),
+        [0x3c9a6a9d, 0x3ea7f6d2, 0x3ef072ff, 0x3f51c68b]
+    );
+}
+
+#[test]
+fn test_mat4() {
+    let (m, p) = mat4();
+    let pm = p * m;
+    assert_bits_eq!(
+        m.to_cols_array(),
+        [
+            0xbf8a403e, 0x3ef03922, 0xbf6ddb5d, 0x00000000, 0xbeaf83a6, 0xbdbb5340, 0x3eb461bb,
+            0x00000000, 0x3e5b4ee4, 0x3fee9a1d, 0x3f313da8, 0x00000000, 0x3f800000, 0xc0000000,
+            0x40600000, 0x3f800000
+        ]
+    );
+    assert_bits_eq!(
+        pm.to_cols_array(),
+        [
+            0xbf84a497, 0x3f43e82e, 0x3f6e1851, 0x3f6ddb5d, 0xbea8650b, 0xbe18c47f, 0xbeb48ff4,
+            0xbeb461bb, 0x3e526983, 0x404295b9, 0xbf316b13, 0xbf313da8, 0x3f759d99, 0xc050c5f6,
+            0xc066a171, 0xc0600000
+        ]
+    );
+    assert_bits_eq!(
+        m.inverse().to_cols_array(),
+        [
+            0xbef5c78e, 0xbfaf83a7, 0x3d5b4ee6, 0x80000000, 0x3e558822, 0xbebb5343, 0x3eee9a1f,
+            0x80000000, 0xbed36da8, 0x3fb461bb, 0x3e313da8, 0x80000000, 0x4015e9f0, 0xc0895f01,
+            0x3e8b9e7b, 0x3f800000
+        ]
+    );
+    assert_bits_eq!(
+        [m.determinant(), pm.determinant()],
+        [0x3fbfffff, 0xbe709b20]
+    );
+    assert_bits_eq!(
+        <[f32; 3]>::from(pm.transform_point3(Vec3::new(0.25, -3.0, 7.0))),
+        [0xbee2bf9e, 0xc02937cb, 0x3f81f196]
+    );
+    assert_bits_eq!(
+        <[f32; 4]>::from(pm.mul_vec4(Vec4::new(0.25, -3.0, 7.0, 1.0))),
+        [0x404806e0, 0x419546ae, 0xc0e542aa, 0xc0e1d4c5]
+    );
+    let (scale, rotation, translation) = m.to_scale_rotation_translation();
+    assert_bits_eq!(
+        [
+            scale.x,
+            scale.y,
+            scale.z,
+            rotation.x,
+            rotation.y,
+            rotation.z,
+            rotation.w,
+            translation.x,
+            translation.y,
+            translation.z
+        ],
+        [
+            0x3fc00000, 0x3f000000, 0x3fffffff, 0xbe2ee8d0, 0x3f0bafeb, 0x3f3ff6e2, 0x3eaa6c8f,
+            0x3f800000, 0xc0000000, 0x40600000
+        ]
+    );
+}
+
+#[test]
+fn test_mat2() {
+    let m = Mat2::from_cols_array(&[1.5, -0.25, 0.75, 2.0]);
+    assert_bits_eq!(
+        m.inverse().to_cols_array(),
+        [0x3f20a0a1, 0x3da0a0a1, 0xbe70f0f2, 0x3ef0f0f2]
+    );
+}
@@ -0,0 +1,131 @@
+#[macro_use]
+mod support;
+
+use glam::generic::*;
+use glam::*;
+
+fn triangle_normal<V: FloatVec3>(a: V, b: V, c: V) -> V {
+    (b - a).cross(c - a).normalize()
+}
+
+fn centroid<V: VecLike>(points: &[V]) -> V
+where
+    V::Scalar: FloatScalar,
+{
+    let mut sum = V::zero();
+    for p in points {
+        sum += *p;
+    }
+    sum / V::Scalar::from_f32(points.len() as f32)
+}
+
+fn transform_points<M: Mat4Like>(m: &M, points: &mut [M::Vec3]) {
+    for p in points {
+        *p = m.transform_point3(*p);
+    }
+}
+
+fn rotate_half<Q: QuatLike>(from: Q, to: Q, v: Q::Vec3) -> Q::Vec3 {
+    from.slerp(to, Q::Scalar::from_f32(0.5)).normalize() * v
+}
+
+fn manhattan<V: Vec3Like + SignedVecLike>(a: V, b: V) -> V::Scalar {
+    (a - b).abs().element_sum()
+}
+
+macro_rules! impl_generic_tests {
+    ($t:ty, $vec2:ident, $vec3:ident, $vec4:ident, $quat:ident, $mat2:ident, $mat3:ident, $mat4:ident) => {
+        #[test]
+        fn test_generic_vec() {
+            let n = triangle_normal($vec3::zero(), $vec3::X, $vec3::Y);
+            assert_eq!(n, $vec3::Z);
+
+            let c = centroid(&[$vec2::zero(), $vec2::new(2.0, 0.0), $vec2::new(1.0, 3.0)]);
+            assert_approx_eq!(c, $vec2::new(1.0, 1.0), 1.0e-6);
+
+            let c = centroid(&[$vec4::one(), $vec4::splat(3.0)]);
+            assert_eq!(c, $vec4::splat(2.0));
+
+            assert_eq!(<$vec2 as VecLike>::DIM, 2);
+            assert_eq!(<$vec3 as VecLike>::DIM, 3);
+            assert_eq!(<$vec4 as VecLike>::DIM, 4);
+
+            let v = <$vec3 as Vec3Like>::from_array([1.0, 2.0, 3.0]);
+            assert_eq!(Vec3Like::to_array(v), [1.0, 2.0, 3.0]);
+            assert_eq!(Vec3Like::truncate(v), $vec2::new(1.0, 2.0));
+            assert_eq!(Vec3Like::extend(v, 4.0), $vec4::new(1.0, 2.0, 3.0, 4.0));
+            assert!(VecLike::cmplt(v, $vec3::splat(2.0)).any());
+        }
+
+        #[test]
+        fn test_generic_quat() {
+            let v = rotate_half(
+                $quat::identity(),
+                $quat::from_rotation_z(deg(90.0)),
+                $vec3::X,
+            );
+            let expected = $vec3::new(deg(45.0).cos(), deg(45.0).sin(), 0.0);
+            assert_approx_eq!(v, expected, 1.0e-3);
+        }
+
+        #[test]
+        fn test_generic_mat() {
+            let m = $mat4::from_translation($vec3::new(1.0, 2.0, 3.0));
+            let mut points = [$vec3::zero(), $vec3::one()];
+            transform_points(&m, &mut points);
+            assert_eq!(
+                points,
+                [$vec3::new(1.0, 2.0, 3.0), $vec3::new(2.0, 3.0, 4.0)]
+            );
+
+            let m = <$mat3 as Mat3Like>::from_scale($vec3::splat(2.0));
+            assert_eq!(Mat3Like::determinant(&m), 8.0);
+            let m = <$mat2 as Mat2Like>::from_scale($vec2::splat(2.0));
+            assert_eq!(Mat2Like::inverse(&m), $mat2::from_scale($vec2::splat(0.5)));
+        }
+
+        fn deg(a: $t) -> $t {
+            a.to_radians()
+        }
+    };
+}
+
+mod generic_f32 {
+    use super::*;
+    impl_generic_tests!(f32, Vec2, Vec3, Vec4, Quat, Mat2, Mat3, Mat4);
+
+    #[test]
+    fn test_generic_vec3a() {
+        let n = triangle_normal(Vec3A::zero(), Vec3A::X, Vec3A::Y);
+        assert_eq!(n, Vec3A::Z);
+        assert_eq!(<Vec3A as Vec3Like>::truncate(n), Vec2::zero());
+    }
+
+    #[test]
+    fn test_generic_mat3a() {
+        fn rotate<M: Mat3Like>(rotation: M::Quat, v: M::Vec3) -> M::Vec3 {
+            M::from_quat(rotation).mul_vec3(v)
+        }
+        let q = Quat::from_rotation_z(90.0_f32.to_radians());
+        assert_approx_eq!(rotate::<Mat3A>(q, Vec3A::X), Vec3A::Y);
+        assert_approx_eq!(rotate::<Mat3>(q, Vec3::X), Vec3::Y);
+
+        let m = <Mat3A as Mat3Like>::from_scale(Vec3A::splat(2.0));
+        assert_eq!(Mat3Like::determinant(&m), 8.0);
+    }
+}
+
+mod generic_f64 {
+    use super::*;
+    impl_generic_tests!(f64, DVec2, DVec3, DVec4, DQuat, DMat2, DMat3, DMat4);
+}
+
+#[test]
+fn test_generic_int() {
+    assert_eq!(manhattan(IVec3::new(1, -2, 3), IVec3::new(-1, 2, 3)), 6);
+    assert_eq!(
+        <UVec2 as Vec2Like>::extend(UVec2::new(1, 2), 3),
+        UVec3::new(1, 2, 3)
+    );
+    assert_eq!(VecLike::max_position(UVec4::new(1, 5, 2, 5)), 1);
+}
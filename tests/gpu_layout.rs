@@ -0,0 +1,156 @@
+#![cfg(feature = "gpu-layout")]
+
+use glam::gpu_layout::{Std140, Std140Layout, Std430, Std430Layout};
+use glam::*;
+
+macro_rules! assert_layout {
+    ($t:ty, std140: ($align140:expr, $size140:expr), std430: ($align430:expr, $size430:expr)) => {
+        assert_eq!(
+            (<$t as Std140>::STD140_ALIGN, <$t as Std140>::STD140_SIZE),
+            ($align140, $size140),
+            "std140 {}",
+            stringify!($t)
+        );
+        assert_eq!(
+            (<$t as Std430>::STD430_ALIGN, <$t as Std430>::STD430_SIZE),
+            ($align430, $size430),
+            "std430 {}",
+            stringify!($t)
+        );
+    };
+}
+
+#[test]
+fn test_align_and_size() {
+    // base alignment and size from the std140 and std430 rules in the GLSL specification
+    assert_layout!(f32, std140: (4, 4), std430: (4, 4));
+    assert_layout!(f64, std140: (8, 8), std430: (8, 8));
+    assert_layout!(Vec2, std140: (8, 8), std430: (8, 8));
+    assert_layout!(Vec3, std140: (16, 12), std430: (16, 12));
+    assert_layout!(Vec3A, std140: (16, 12), std430: (16, 12));
+    assert_layout!(Vec4, std140: (16, 16), std430: (16, 16));
+    assert_layout!(Quat, std140: (16, 16), std430: (16, 16));
+    assert_layout!(IVec3, std140: (16, 12), std430: (16, 12));
+    assert_layout!(UVec2, std140: (8, 8), std430: (8, 8));
+    assert_layout!(DVec2, std140: (16, 16), std430: (16, 16));
+    assert_layout!(DVec3, std140: (32, 24), std430: (32, 24));
+    assert_layout!(DVec4, std140: (32, 32), std430: (32, 32));
+    assert_layout!(DQuat, std140: (32, 32), std430: (32, 32));
+
+    // matrices are laid out as arrays of column vectors
+    assert_layout!(Mat2, std140: (16, 32), std430: (8, 16));
+    assert_layout!(Mat3, std140: (16, 48), std430: (16, 48));
+    assert_layout!(Mat3A, std140: (16, 48), std430: (16, 48));
+    assert_layout!(Mat4, std140: (16, 64), std430: (16, 64));
+    assert_layout!(DMat2, std140: (16, 32), std430: (16, 32));
+    assert_layout!(DMat3, std140: (32, 96), std430: (32, 96));
+    assert_layout!(DMat4, std140: (32, 128), std430: (32, 128));
+
+    assert_eq!(Std140Layout::array_stride::<f32>(), 16);
+    assert_eq!(Std140Layout::array_stride::<Vec3>(), 16);
+    assert_eq!(Std140Layout::array_stride::<DVec3>(), 32);
+    assert_eq!(Std430Layout::array_stride::<f32>(), 4);
+    assert_eq!(Std430Layout::array_stride::<Vec2>(), 8);
+    assert_eq!(Std430Layout::array_stride::<Vec3>(), 16);
+}
+
+#[test]
+fn test_struct_layout() {
+    // struct Inner { int d; uvec2 e; };
+    // struct Example { float a; vec2 b; vec3 c; Inner f; float g; float h[2]; mat3 i; };
+    let mut inner = Std140Layout::new();
+    assert_eq!(inner.push::<i32>(), 0);
+    assert_eq!(inner.push::<UVec2>(), 8);
+    assert_eq!(inner.size(), 16);
+
+    let mut layout = Std140Layout::new();
+    assert_eq!(layout.push::<f32>(), 0);
+    assert_eq!(layout.push::<Vec2>(), 8);
+    assert_eq!(layout.push::<Vec3>(), 16);
+    assert_eq!(layout.push_struct(&inner), 32);
+    assert_eq!(layout.push::<f32>(), 48);
+    assert_eq!(layout.push_array::<f32>(2), 64);
+    assert_eq!(layout.push::<Mat3>(), 96);
+    assert_eq!(layout.size(), 144);
+
+    let mut inner = Std430Layout::new();
+    assert_eq!(inner.push::<i32>(), 0);
+    assert_eq!(inner.push::<UVec2>(), 8);
+    assert_eq!(inner.size(), 16);
+
+    let mut layout = Std430Layout::new();
+    assert_eq!(layout.push::<f32>(), 0);
+    assert_eq!(layout.push::<Vec2>(), 8);
+    assert_eq!(layout.push::<Vec3>(), 16);
+    assert_eq!(layout.push_struct(&inner), 32);
+    assert_eq!(layout.push::<f32>(), 48);
+    assert_eq!(layout.push_array::<f32>(2), 52);
+    assert_eq!(layout.push::<Mat3>(), 64);
+    assert_eq!(layout.size(), 112);
+
+    // a scalar packs into the last 4 bytes of a vec3
+    let mut layout = Std140Layout::new();
+    assert_eq!(layout.push::<Vec3>(), 0);
+    assert_eq!(layout.push::<f32>(), 12);
+    assert_eq!(layout.size(), 16);
+}
+
+fn to_floats(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|b| f32::from_ne_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
+#[test]
+fn test_write_read() {
+    let m = Mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    let mut bytes = [0xff; 48];
+    m.write_std140(&mut bytes);
+    assert_eq!(
+        to_floats(&bytes),
+        [1.0, 2.0, 3.0, 0.0, 4.0, 5.0, 6.0, 0.0, 7.0, 8.0, 9.0, 0.0]
+    );
+    assert_eq!(m, Mat3::read_std140(&bytes));
+    assert_eq!(m, Mat3::read_std430(&bytes));
+    Mat3A::from(m).write_std430(&mut bytes);
+    assert_eq!(m, Mat3A::read_std140(&bytes).into());
+
+    let m = Mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]);
+    let mut bytes = [0xff; 32];
+    m.write_std140(&mut bytes);
+    assert_eq!(to_floats(&bytes), [1.0, 2.0, 0.0, 0.0, 3.0, 4.0, 0.0, 0.0]);
+    assert_eq!(m, Mat2::read_std140(&bytes));
+    m.write_std430(&mut bytes);
+    assert_eq!(to_floats(&bytes[..16]), [1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(m, Mat2::read_std430(&bytes));
+
+    // only the size of the value is written
+    let mut bytes = [0xff; 16];
+    Vec3A::new(1.0, 2.0, 3.0).write_std140(&mut bytes);
+    assert_eq!(bytes[12..], [0xff; 4]);
+    assert_eq!(Vec3A::new(1.0, 2.0, 3.0), Vec3A::read_std140(&bytes));
+
+    let q = Quat::from_rotation_x(1.0);
+    let mut bytes = [0; 16];
+    q.write_std430(&mut bytes);
+    assert_eq!(q, Quat::read_std430(&bytes));
+
+    let m = DMat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    let mut bytes = [0; 96];
+    m.write_std140(&mut bytes);
+    assert_eq!(f64::read_std140(&bytes[32..]), 4.0);
+    assert_eq!(m, DMat3::read_std140(&bytes));
+
+    let v = IVec3::new(-1, 2, -3);
+    let mut bytes = [0; 12];
+    v.write_std430(&mut bytes);
+    assert_eq!(v, IVec3::read_std430(&bytes));
+}
+
+#[test]
+#[should_panic]
+fn test_write_too_short() {
+    let mut bytes = [0; 44];
+    Mat3::IDENTITY.write_std140(&mut bytes);
+}
@@ -19,6 +19,20 @@ macro_rules! impl_mat2_tests {
             assert_eq!($mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]), M2);
         }
 
+        #[test]
+        fn test_const_fn() {
+            const M0: $mat2 = $mat2::from_cols($vec2::new(1.0, 2.0), $vec2::new(3.0, 4.0));
+            const M1: $mat2 = $newmat2($vec2::new(1.0, 2.0), $vec2::new(3.0, 4.0));
+            const M2: $mat2 = $mat2::from_cols_array(&[1.0, 2.0, 3.0, 4.0]);
+            const M3: $mat2 = $mat2::from_cols_array_2d(&MATRIX);
+            const MS: [$mat2; 2] = [$mat2::ZERO, $mat2::IDENTITY];
+            assert_eq!($const_new!([1.0, 2.0], [3.0, 4.0]), M0);
+            assert_eq!(M0, M1);
+            assert_eq!(M0, M2);
+            assert_eq!(M0, M3);
+            assert_eq!([$mat2::zero(), $mat2::identity()], MS);
+        }
+
         #[test]
         fn test_mat2_identity() {
             let identity = $mat2::identity();
@@ -85,6 +99,32 @@ macro_rules! impl_mat2_tests {
             assert_eq!(mt.y_axis, $newvec2(2.0, 4.0));
         }
 
+        #[test]
+        fn test_mat2_rows_and_cols() {
+            let mut m = $mat2::from_cols_array_2d(&MATRIX);
+            assert_eq!($newvec2(1.0, 2.0), m.col(0));
+            assert_eq!($newvec2(2.0, 4.0), m.row(1));
+            assert_eq!(m, $mat2::from_rows(m.row(0), m.row(1)));
+            assert_eq!([1.0, 3.0, 2.0, 4.0], m.to_rows_array());
+            assert_eq!(m, $mat2::from_rows_array(&m.to_rows_array()));
+            assert_eq!(m.transpose().to_cols_array(), m.to_rows_array());
+
+            *m.col_mut(1) = $newvec2(5.0, 6.0);
+            assert_eq!($newvec2(5.0, 6.0), m.y_axis);
+            m.set_row(0, $newvec2(7.0, 8.0));
+            assert_eq!($mat2::from_cols_array(&[7.0, 2.0, 8.0, 6.0]), m);
+
+            assert_eq!(8.0, m[(1, 0)]);
+            m[(1, 0)] = 9.0;
+            assert_eq!(9.0, m.y_axis.x);
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_mat2_col_out_of_bounds() {
+            $mat2::IDENTITY.col(2);
+        }
+
         #[test]
         fn test_mat2_det() {
             assert_eq!(0.0, $mat2::zero().determinant());
@@ -124,6 +164,21 @@ macro_rules! impl_mat2_tests {
             assert_approx_eq!(m_inv, rot_inv * scale_inv);
         }
 
+        #[test]
+        fn test_mat2_inverse_random() {
+            use crate::support::{random_constrained, random_unit_array};
+            use glam::constrained::Invertible;
+            use rand_xoshiro::rand_core::SeedableRng;
+            let mut rng = rand_xoshiro::Xoshiro256Plus::seed_from_u64(0);
+            for _ in 0..100 {
+                let m = random_constrained(&mut rng, |rng| {
+                    Invertible::<$mat2>::new($mat2::from_cols_array(&random_unit_array(rng)))
+                })
+                .0;
+                assert_approx_eq!($mat2::identity(), m * m.inverse(), 1.0e-3);
+            }
+        }
+
         #[test]
         fn test_mat2_ops() {
             let m0 = $mat2::from_cols_array_2d(&MATRIX);
@@ -154,6 +209,40 @@ macro_rules! impl_mat2_tests {
         fn test_mat2_fmt() {
             let a = $mat2::from_cols_array_2d(&MATRIX);
             assert_eq!(format!("{}", a), "[[1, 2], [3, 4]]");
+            assert_eq!(format!("{:.1}", a), "[[1.0, 2.0], [3.0, 4.0]]");
+            assert_eq!(format!("{:#}", a), "[[1, 2],\n [3, 4]]");
+            assert_eq!(format!("{:+#5.1}", a), "[[ +1.0,  +2.0],\n [ +3.0,  +4.0]]");
+        }
+
+        #[test]
+        fn test_mat2_from_str() {
+            use glam::ParseError;
+            let a = $mat2::from_cols_array_2d(&MATRIX);
+            assert_eq!(a.to_string().parse(), Ok(a));
+            assert_eq!("1, 2, 3, 4".parse(), Ok(a));
+            assert_eq!("(1 2) (3 4)".parse(), Ok(a));
+            assert_eq!(
+                "[[1, 2], [3]]".parse::<$mat2>(),
+                Err(ParseError::ColumnLength {
+                    column: 1,
+                    expected: 2,
+                    found: 1
+                })
+            );
+            assert_eq!(
+                "[1, 2, 3]".parse::<$mat2>(),
+                Err(ParseError::ElementCount {
+                    expected: 4,
+                    found: 3
+                })
+            );
+
+            use rand_xoshiro::rand_core::SeedableRng;
+            let mut rng = rand_xoshiro::Xoshiro256Plus::seed_from_u64(0);
+            for _ in 0..1000 {
+                let s = $mat2::from_cols_array(&crate::support::random_array(&mut rng)).to_string();
+                assert_eq!(s, s.parse::<$mat2>().unwrap().to_string());
+            }
         }
 
         #[cfg(feature = "std")]
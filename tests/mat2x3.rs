@@ -0,0 +1,108 @@
+macro_rules! impl_mat2x3_tests {
+    ($t:ident, $newmat2x3:ident, $mat2x3:ident, $mat3x2:ident, $mat2:ident, $mat3:ident, $vec2:ident, $vec3:ident) => {
+        const MATRIX: [[$t; 3]; 2] = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]];
+
+        #[test]
+        fn test_const_fn() {
+            const M0: $mat2x3 =
+                $mat2x3::from_cols($vec3::new(1.0, 2.0, 3.0), $vec3::new(4.0, 5.0, 6.0));
+            const M1: $mat2x3 = $mat2x3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+            const M2: $mat2x3 = $mat2x3::from_cols_array_2d(&MATRIX);
+            const MS: [$mat2x3; 2] = [$mat2x3::ZERO, $mat2x3::IDENTITY];
+            assert_eq!(M0, M1);
+            assert_eq!(M0, M2);
+            assert_eq!([$mat2x3::ZERO, $mat2x3::default()], MS);
+        }
+
+        #[test]
+        fn test_mat2x3_from_axes() {
+            let a = $mat2x3::from_cols_array_2d(&MATRIX);
+            assert_eq!(MATRIX, a.to_cols_array_2d());
+            let b = $newmat2x3($vec3::new(1.0, 2.0, 3.0), $vec3::new(4.0, 5.0, 6.0));
+            assert_eq!(a, b);
+            assert_eq!(a, $mat2x3::from_cols_array(&a.to_cols_array()));
+            assert_eq!(a.to_cols_array(), *a.as_ref());
+            assert_eq!($vec3::new(4.0, 5.0, 6.0), a.y_axis);
+        }
+
+        #[test]
+        fn test_mat2x3_transpose() {
+            let m = $mat2x3::from_cols_array_2d(&MATRIX);
+            let t = m.transpose();
+            assert_eq!(
+                $mat3x2::from_cols_array_2d(&[[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]),
+                t
+            );
+            assert_eq!(m, t.transpose());
+        }
+
+        #[test]
+        fn test_mat2x3_mul() {
+            let m = $mat2x3::from_cols_array_2d(&MATRIX);
+            let m2 = $mat2::from_cols_array(&[1.0, -2.0, 3.0, 4.0]);
+            let m3 = $mat3::from_cols_array(&[1.0, -2.0, 3.0, 4.0, 5.0, -6.0, 7.0, 8.0, 9.0]);
+            let v = $vec2::new(1.0, -2.0);
+            assert_eq!($mat3::from(m) * v.extend(0.0), m * v);
+            assert_eq!($mat2x3::from_cols(m * m2.x_axis, m * m2.y_axis), m * m2);
+            assert_eq!($mat2x3::from(m3 * $mat3::from(m)), m3 * m);
+            let t = m.transpose();
+            assert_eq!(
+                $mat3::from_cols(m * t.x_axis, m * t.y_axis, m * t.z_axis),
+                m * t
+            );
+            assert_eq!(m * 2.0, 2.0 * m);
+            assert_eq!(m + m, m * 2.0);
+            assert_eq!(m - m, $mat2x3::ZERO);
+        }
+
+        #[test]
+        fn test_mat2x3_from_mat3() {
+            let m3 = $mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+            let m = $mat2x3::from(m3);
+            assert_eq!($mat2x3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]), m);
+            assert_eq!($vec3::Z, $mat3::from(m).z_axis);
+        }
+
+        #[test]
+        fn test_mat2x3_is_finite() {
+            let mut m = $mat2x3::IDENTITY;
+            assert!(m.is_finite());
+            assert!(!m.is_nan());
+            m.y_axis.z = $t::NAN;
+            assert!(!m.is_finite());
+            assert!(m.is_nan());
+        }
+
+        #[test]
+        fn test_mat2x3_fmt() {
+            let a = $mat2x3::from_cols_array_2d(&MATRIX);
+            assert_eq!(format!("{}", a), "[[1, 2, 3], [4, 5, 6]]");
+        }
+    };
+}
+
+mod mat2x3 {
+    use glam::{mat2x3, Mat2, Mat2x3, Mat3, Mat3x2, Vec2, Vec3};
+
+    #[test]
+    fn test_align() {
+        use std::mem;
+        assert_eq!(24, mem::size_of::<Mat2x3>());
+        assert_eq!(4, mem::align_of::<Mat2x3>());
+    }
+
+    impl_mat2x3_tests!(f32, mat2x3, Mat2x3, Mat3x2, Mat2, Mat3, Vec2, Vec3);
+}
+
+mod dmat2x3 {
+    use glam::{dmat2x3, DMat2, DMat2x3, DMat3, DMat3x2, DVec2, DVec3};
+
+    #[test]
+    fn test_align() {
+        use std::mem;
+        assert_eq!(48, mem::size_of::<DMat2x3>());
+        assert_eq!(8, mem::align_of::<DMat2x3>());
+    }
+
+    impl_mat2x3_tests!(f64, dmat2x3, DMat2x3, DMat3x2, DMat2, DMat3, DVec2, DVec3);
+}
@@ -2,7 +2,7 @@
 mod support;
 
 macro_rules! impl_mat3_tests {
-    ($t:ident, $const_new:ident, $newmat3:ident, $mat3:ident, $newvec3:ident, $vec3:ident, $vec2:ident) => {
+    ($t:ident, $const_new:ident, $newmat3:ident, $mat3:ident, $mul_vec3:ident, $newvec3:ident, $vec3:ident, $vec2:ident) => {
         use core::$t::INFINITY;
         use core::$t::NAN;
         use core::$t::NEG_INFINITY;
@@ -29,6 +29,32 @@ macro_rules! impl_mat3_tests {
             );
         }
 
+        #[test]
+        fn test_const_fn() {
+            const M0: $mat3 = $mat3::from_cols(
+                $vec3::new(1.0, 2.0, 3.0),
+                $vec3::new(4.0, 5.0, 6.0),
+                $vec3::new(7.0, 8.0, 9.0),
+            );
+            const M1: $mat3 = $newmat3(
+                $vec3::new(1.0, 2.0, 3.0),
+                $vec3::new(4.0, 5.0, 6.0),
+                $vec3::new(7.0, 8.0, 9.0),
+            );
+            const M2: $mat3 =
+                $mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+            const M3: $mat3 = $mat3::from_cols_array_2d(&MATRIX);
+            const MS: [$mat3; 2] = [$mat3::ZERO, $mat3::IDENTITY];
+            assert_eq!(
+                $const_new!([1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]),
+                M0
+            );
+            assert_eq!(M0, M1);
+            assert_eq!(M0, M2);
+            assert_eq!(M0, M3);
+            assert_eq!([$mat3::zero(), $mat3::identity()], MS);
+        }
+
         #[test]
         fn test_mat3_identity() {
             let identity = $mat3::identity();
@@ -93,7 +119,7 @@ macro_rules! impl_mat3_tests {
         fn test_mat3_mul() {
             let mat_a = $mat3::from_axis_angle($vec3::unit_z(), deg(90.0));
             assert_approx_eq!($newvec3(-1.0, 0.0, 0.0), mat_a * $vec3::unit_y());
-            assert_approx_eq!($newvec3(-1.0, 0.0, 0.0), mat_a.mul_vec3($vec3::unit_y()));
+            assert_approx_eq!($newvec3(-1.0, 0.0, 0.0), mat_a.$mul_vec3($vec3::unit_y()));
         }
 
         #[test]
@@ -161,6 +187,38 @@ macro_rules! impl_mat3_tests {
             assert_eq!(mt.z_axis, $newvec3(3.0, 6.0, 9.0));
         }
 
+        #[test]
+        fn test_mat3_rows_and_cols() {
+            let mut m = $mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+            assert_eq!($newvec3(4.0, 5.0, 6.0), m.col(1));
+            assert_eq!($newvec3(3.0, 6.0, 9.0), m.row(2));
+            assert_eq!(m, $mat3::from_rows(m.row(0), m.row(1), m.row(2)));
+            assert_eq!(
+                [1.0, 4.0, 7.0, 2.0, 5.0, 8.0, 3.0, 6.0, 9.0],
+                m.to_rows_array()
+            );
+            assert_eq!(m, $mat3::from_rows_array(&m.to_rows_array()));
+            assert_eq!(m.transpose().to_cols_array(), m.to_rows_array());
+
+            *m.col_mut(2) = $newvec3(-1.0, -2.0, -3.0);
+            assert_eq!($newvec3(-1.0, -2.0, -3.0), m.z_axis);
+            m.set_row(1, $newvec3(10.0, 11.0, 12.0));
+            assert_eq!(
+                $mat3::from_cols_array(&[1.0, 10.0, 3.0, 4.0, 11.0, 6.0, -1.0, 12.0, -3.0]),
+                m
+            );
+
+            assert_eq!(12.0, m[(2, 1)]);
+            m[(0, 2)] = 13.0;
+            assert_eq!(13.0, m.x_axis.z);
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_mat3_index_out_of_bounds() {
+            let _ = $mat3::IDENTITY[(0, 3)];
+        }
+
         #[test]
         fn test_mat3_det() {
             assert_eq!(0.0, $mat3::zero().determinant());
@@ -204,6 +262,21 @@ macro_rules! impl_mat3_tests {
             assert_approx_eq!(m_inv, rotz_inv * scale_inv);
         }
 
+        #[test]
+        fn test_mat3_inverse_random() {
+            use crate::support::{random_constrained, random_unit_array};
+            use glam::constrained::Invertible;
+            use rand_xoshiro::rand_core::SeedableRng;
+            let mut rng = rand_xoshiro::Xoshiro256Plus::seed_from_u64(0);
+            for _ in 0..100 {
+                let m = random_constrained(&mut rng, |rng| {
+                    Invertible::<$mat3>::new($mat3::from_cols_array(&random_unit_array(rng)))
+                })
+                .0;
+                assert_approx_eq!($mat3::identity(), m * m.inverse(), 1.0e-3);
+            }
+        }
+
         #[test]
         fn test_mat3_ops() {
             let m0 = $mat3::from_cols_array_2d(&MATRIX);
@@ -224,6 +297,45 @@ macro_rules! impl_mat3_tests {
         fn test_mat3_fmt() {
             let a = $mat3::from_cols_array_2d(&MATRIX);
             assert_eq!(format!("{}", a), "[[1, 2, 3], [4, 5, 6], [7, 8, 9]]");
+            assert_eq!(
+                format!("{:.1}", a),
+                "[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]"
+            );
+            assert_eq!(
+                format!("{:#3}", a),
+                "[[  1,   2,   3],\n [  4,   5,   6],\n [  7,   8,   9]]"
+            );
+        }
+
+        #[test]
+        fn test_mat3_from_str() {
+            use glam::ParseError;
+            let a = $mat3::from_cols_array_2d(&MATRIX);
+            assert_eq!(a.to_string().parse(), Ok(a));
+            assert_eq!("1, 2, 3, 4, 5, 6, 7, 8, 9".parse(), Ok(a));
+            assert_eq!("(1 2 3) (4 5 6) (7 8 9)".parse(), Ok(a));
+            assert_eq!(
+                "[[1, 2, 3], [4, 5, 6], [7, 8]]".parse::<$mat3>(),
+                Err(ParseError::ColumnLength {
+                    column: 2,
+                    expected: 3,
+                    found: 2
+                })
+            );
+            assert_eq!(
+                "[1, 2, 3, 4, 5, 6, 7, 8]".parse::<$mat3>(),
+                Err(ParseError::ElementCount {
+                    expected: 9,
+                    found: 8
+                })
+            );
+
+            use rand_xoshiro::rand_core::SeedableRng;
+            let mut rng = rand_xoshiro::Xoshiro256Plus::seed_from_u64(0);
+            for _ in 0..1000 {
+                let s = $mat3::from_cols_array(&crate::support::random_array(&mut rng)).to_string();
+                assert_eq!(s, s.parse::<$mat3>().unwrap().to_string());
+            }
         }
 
         #[cfg(feature = "std")]
@@ -281,7 +393,97 @@ mod mat3 {
         );
     }
 
-    impl_mat3_tests!(f32, const_mat3, mat3, Mat3, vec3, Vec3, Vec2);
+    impl_mat3_tests!(f32, const_mat3, mat3, Mat3, mul_vec3, vec3, Vec3, Vec2);
+}
+
+mod mat3a {
+    use super::support::deg;
+    use glam::{const_mat3a, mat3a, vec3, vec3a, Mat3, Mat3A, Mat4, Vec2, Vec3, Vec3A, Vec4};
+
+    #[test]
+    fn test_align() {
+        use std::mem;
+        assert_eq!(48, mem::size_of::<Mat3A>());
+        assert_eq!(16, mem::align_of::<Mat3A>());
+    }
+
+    #[test]
+    fn test_mul_vec3() {
+        let mat_a = Mat3A::from_axis_angle(Vec3A::unit_z(), deg(90.0));
+        assert_approx_eq!(vec3(-1.0, 0.0, 0.0), mat_a * Vec3::unit_y());
+        assert_approx_eq!(vec3(-1.0, 0.0, 0.0), mat_a.mul_vec3(Vec3::unit_y()));
+    }
+
+    #[test]
+    fn test_conversions() {
+        let m = Mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+        let ma = Mat3A::from(m);
+        assert_eq!(m.to_cols_array(), ma.to_cols_array());
+        assert_eq!(m, Mat3::from(ma));
+
+        let m4 = Mat4::from(ma);
+        assert_eq!(Vec4::new(1.0, 2.0, 3.0, 0.0), m4.x_axis);
+        assert_eq!(Vec4::new(4.0, 5.0, 6.0, 0.0), m4.y_axis);
+        assert_eq!(Vec4::new(7.0, 8.0, 9.0, 0.0), m4.z_axis);
+        assert_eq!(Vec4::W, m4.w_axis);
+        assert_eq!(ma, Mat3A::from(m4));
+        assert_eq!(ma, Mat3A::from_mat4(&m4));
+    }
+
+    #[test]
+    fn test_matches_mat3() {
+        let m =
+            Mat3::from_scale_angle_translation(Vec2::new(0.5, 1.5), deg(30.0), Vec2::new(1.0, 2.0));
+        let ma = Mat3A::from(m);
+        assert_approx_eq!(m.inverse(), Mat3::from(ma.inverse()));
+        assert_approx_eq!(m.determinant(), ma.determinant());
+        assert_approx_eq!(m * m, Mat3::from(ma * ma));
+        assert_approx_eq!(
+            m.transform_point2(Vec2::ONE),
+            ma.transform_point2(Vec2::ONE)
+        );
+        assert_approx_eq!(
+            m.transform_vector2(Vec2::ONE),
+            ma.transform_vector2(Vec2::ONE)
+        );
+        assert_eq!(m.transpose(), Mat3::from(ma.transpose()));
+    }
+
+    #[test]
+    fn test_col_mut() {
+        // each column is a whole `Vec3A` including its padding
+        let mut m = Mat3A::zero();
+        *m.col_mut(0) = Vec3A::new(1.0, 2.0, 3.0);
+        *m.col_mut(1) = Vec3A::new(4.0, 5.0, 6.0);
+        *m.col_mut(2) = Vec3A::new(7.0, 8.0, 9.0);
+        assert_eq!(
+            Mat3A::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]),
+            m
+        );
+        m[(1, 2)] = -6.0;
+        assert_eq!(Vec3A::new(4.0, 5.0, -6.0), m.y_axis);
+        assert_eq!(7.0, m[(2, 0)]);
+    }
+
+    #[test]
+    fn test_as() {
+        use glam::DMat3;
+        assert_eq!(
+            DMat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]),
+            Mat3A::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]).as_f64()
+        );
+    }
+
+    impl_mat3_tests!(
+        f32,
+        const_mat3a,
+        mat3a,
+        Mat3A,
+        mul_vec3a,
+        vec3a,
+        Vec3A,
+        Vec2
+    );
 }
 
 mod dmat3 {
@@ -295,5 +497,14 @@ mod dmat3 {
         assert_eq!(8, mem::align_of::<DMat3>());
     }
 
-    impl_mat3_tests!(f64, const_dmat3, dmat3, DMat3, dvec3, DVec3, DVec2);
+    impl_mat3_tests!(
+        f64,
+        const_dmat3,
+        dmat3,
+        DMat3,
+        mul_vec3,
+        dvec3,
+        DVec3,
+        DVec2
+    );
 }
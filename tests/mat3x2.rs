@@ -0,0 +1,127 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_mat3x2_tests {
+    ($t:ident, $newmat3x2:ident, $mat3x2:ident, $mat2x3:ident, $mat2:ident, $mat3:ident, $vec2:ident, $vec3:ident) => {
+        const MATRIX: [[$t; 2]; 3] = [[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+
+        #[test]
+        fn test_const_fn() {
+            const M0: $mat3x2 = $mat3x2::from_cols(
+                $vec2::new(1.0, 2.0),
+                $vec2::new(3.0, 4.0),
+                $vec2::new(5.0, 6.0),
+            );
+            const M1: $mat3x2 = $mat3x2::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+            const M2: $mat3x2 = $mat3x2::from_cols_array_2d(&MATRIX);
+            const MS: [$mat3x2; 2] = [$mat3x2::ZERO, $mat3x2::IDENTITY];
+            assert_eq!(M0, M1);
+            assert_eq!(M0, M2);
+            assert_eq!([$mat3x2::ZERO, $mat3x2::default()], MS);
+        }
+
+        #[test]
+        fn test_mat3x2_from_axes() {
+            let a = $mat3x2::from_cols_array_2d(&MATRIX);
+            assert_eq!(MATRIX, a.to_cols_array_2d());
+            let b = $newmat3x2(
+                $vec2::new(1.0, 2.0),
+                $vec2::new(3.0, 4.0),
+                $vec2::new(5.0, 6.0),
+            );
+            assert_eq!(a, b);
+            assert_eq!(a, $mat3x2::from_cols_array(&a.to_cols_array()));
+            assert_eq!(a.to_cols_array(), *a.as_ref());
+            assert_eq!($vec2::new(5.0, 6.0), a.z_axis);
+        }
+
+        #[test]
+        fn test_mat3x2_transpose() {
+            let m = $mat3x2::from_cols_array_2d(&MATRIX);
+            let t = m.transpose();
+            assert_eq!(
+                $mat2x3::from_cols_array_2d(&[[1.0, 3.0, 5.0], [2.0, 4.0, 6.0]]),
+                t
+            );
+            assert_eq!(m, t.transpose());
+        }
+
+        #[test]
+        fn test_mat3x2_mul() {
+            let m = $mat3x2::from_cols_array_2d(&MATRIX);
+            let m2 = $mat2::from_cols_array(&[1.0, -2.0, 3.0, 4.0]);
+            let m3 = $mat3::from_cols_array(&[1.0, -2.0, 3.0, 4.0, 5.0, -6.0, 7.0, 8.0, 9.0]);
+            let v = $vec3::new(1.0, -2.0, 3.0);
+            assert_eq!($mat3::from(m) * v, (m * v).extend(v.z));
+            assert_eq!($mat3x2::from($mat3::from(m) * m3), m * m3);
+            assert_eq!(
+                $mat3x2::from_cols(m2 * m.x_axis, m2 * m.y_axis, m2 * m.z_axis),
+                m2 * m
+            );
+            let t = m.transpose();
+            assert_eq!($mat2::from_cols(m * t.x_axis, m * t.y_axis), m * t);
+            assert_eq!(m * 2.0, 2.0 * m);
+            assert_eq!(m + m, m * 2.0);
+            assert_eq!(m - m, $mat3x2::ZERO);
+        }
+
+        #[test]
+        fn test_mat3x2_transform() {
+            let m3 = $mat3::from_scale_angle_translation(
+                $vec2::new(0.5, 1.5),
+                deg(90.0),
+                $vec2::new(1.0, 2.0),
+            );
+            let m = $mat3x2::from(m3);
+            assert_eq!(m3, $mat3::from(m));
+            let p = $vec2::new(1.0, 2.0);
+            assert_approx_eq!(m3.transform_point2(p), m.transform_point2(p));
+            assert_approx_eq!(m3.transform_vector2(p), m.transform_vector2(p));
+            assert_eq!(p, $mat3x2::IDENTITY.transform_point2(p));
+        }
+
+        #[test]
+        fn test_mat3x2_is_finite() {
+            let mut m = $mat3x2::IDENTITY;
+            assert!(m.is_finite());
+            assert!(!m.is_nan());
+            m.z_axis.y = $t::NAN;
+            assert!(!m.is_finite());
+            assert!(m.is_nan());
+        }
+
+        #[test]
+        fn test_mat3x2_fmt() {
+            let a = $mat3x2::from_cols_array_2d(&MATRIX);
+            assert_eq!(format!("{}", a), "[[1, 2], [3, 4], [5, 6]]");
+        }
+    };
+}
+
+mod mat3x2 {
+    use super::support::deg;
+    use glam::{mat3x2, Mat2, Mat2x3, Mat3, Mat3x2, Vec2, Vec3};
+
+    #[test]
+    fn test_align() {
+        use std::mem;
+        assert_eq!(24, mem::size_of::<Mat3x2>());
+        assert_eq!(4, mem::align_of::<Mat3x2>());
+    }
+
+    impl_mat3x2_tests!(f32, mat3x2, Mat3x2, Mat2x3, Mat2, Mat3, Vec2, Vec3);
+}
+
+mod dmat3x2 {
+    use super::support::deg;
+    use glam::{dmat3x2, DMat2, DMat2x3, DMat3, DMat3x2, DVec2, DVec3};
+
+    #[test]
+    fn test_align() {
+        use std::mem;
+        assert_eq!(48, mem::size_of::<DMat3x2>());
+        assert_eq!(8, mem::align_of::<DMat3x2>());
+    }
+
+    impl_mat3x2_tests!(f64, dmat3x2, DMat3x2, DMat2x3, DMat2, DMat3, DVec2, DVec3);
+}
@@ -0,0 +1,153 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_mat3x4_tests {
+    ($t:ident, $newmat3x4:ident, $mat3x4:ident, $mat4x3:ident, $mat3:ident, $mat4:ident, $quat:ident, $vec3:ident, $vec4:ident) => {
+        const MATRIX: [[$t; 4]; 3] = [
+            [1.0, 2.0, 3.0, 4.0],
+            [5.0, 6.0, 7.0, 8.0],
+            [9.0, 10.0, 11.0, 12.0],
+        ];
+
+        #[test]
+        fn test_const_fn() {
+            const M0: $mat3x4 = $mat3x4::from_cols(
+                $vec4::new(1.0, 2.0, 3.0, 4.0),
+                $vec4::new(5.0, 6.0, 7.0, 8.0),
+                $vec4::new(9.0, 10.0, 11.0, 12.0),
+            );
+            const M1: $mat3x4 = $mat3x4::from_cols_array(&[
+                1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0,
+            ]);
+            const M2: $mat3x4 = $mat3x4::from_cols_array_2d(&MATRIX);
+            const MS: [$mat3x4; 2] = [$mat3x4::ZERO, $mat3x4::IDENTITY];
+            assert_eq!(M0, M1);
+            assert_eq!(M0, M2);
+            assert_eq!([$mat3x4::ZERO, $mat3x4::default()], MS);
+        }
+
+        #[test]
+        fn test_mat3x4_from_axes() {
+            let a = $mat3x4::from_cols_array_2d(&MATRIX);
+            assert_eq!(MATRIX, a.to_cols_array_2d());
+            let b = $newmat3x4(
+                $vec4::new(1.0, 2.0, 3.0, 4.0),
+                $vec4::new(5.0, 6.0, 7.0, 8.0),
+                $vec4::new(9.0, 10.0, 11.0, 12.0),
+            );
+            assert_eq!(a, b);
+            assert_eq!(a, $mat3x4::from_cols_array(&a.to_cols_array()));
+            assert_eq!(a.to_cols_array(), *a.as_ref());
+            assert_eq!($vec4::new(9.0, 10.0, 11.0, 12.0), a.z_axis);
+        }
+
+        #[test]
+        fn test_mat3x4_mul() {
+            let m = $mat3x4::from_cols_array_2d(&MATRIX);
+            let m3 = $mat3::from_cols_array(&[1.0, -2.0, 3.0, 4.0, 5.0, -6.0, 7.0, 8.0, 9.0]);
+            let v = $vec3::new(1.0, -2.0, 3.0);
+            assert_eq!($mat4::from(m) * v.extend(0.0), m * v);
+            assert_eq!(
+                $mat3x4::from($mat4::from(m) * $mat4::from($mat4x3::from(m3))),
+                m * m3
+            );
+            let t = m.transpose();
+            let mut expected = $mat4::from(m) * $mat4::from(t);
+            expected.w_axis = m * t.w_axis;
+            assert_eq!(expected, m * t);
+            let m4 = $mat4::from_cols_array(&[
+                1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0,
+                16.0,
+            ]);
+            assert_eq!($mat3x4::from(m4 * $mat4::from(m)), m4 * m);
+            assert_eq!(m * 2.0, 2.0 * m);
+            assert_eq!(m + m, m * 2.0);
+            assert_eq!(m - m, $mat3x4::ZERO);
+        }
+
+        #[test]
+        fn test_mat3x4_transform() {
+            let m4 = $mat4::from_scale_rotation_translation(
+                $vec3::new(0.5, 1.5, 2.0),
+                $quat::from_rotation_y(deg(90.0)),
+                $vec3::new(1.0, 2.0, 3.0),
+            );
+            // the affine part of `m4` stored as rows, as used for skinning palettes
+            let m = $mat3x4::from_affine_rows(&m4);
+            assert_eq!($mat4x3::from(m4).transpose(), m);
+            assert_eq!(m4, $mat4::from_affine_rows(m));
+            let p = $vec3::new(1.0, 2.0, 3.0);
+            assert_approx_eq!(m4.transform_point3(p), m.transform_point3_rows(p));
+            assert_approx_eq!(m4.transform_vector3(p), m.transform_vector3_rows(p));
+
+            let m4 = $mat4::from_translation($vec3::new(1.0, 2.0, 3.0));
+            let m = $mat3x4::from_affine_rows(&m4);
+            assert_eq!($vec4::new(1.0, 0.0, 0.0, 1.0), m.x_axis);
+            assert_eq!(m4.transform_point3(p), m.transform_point3_rows(p));
+            assert_eq!(p, m.transform_vector3_rows(p));
+            assert_eq!(
+                $mat3x4::IDENTITY,
+                $mat3x4::from_affine_rows(&$mat4::IDENTITY)
+            );
+        }
+
+        #[test]
+        fn test_mat3x4_from_mat3() {
+            let m3 = $mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+            let m = $mat3x4::from(m3);
+            assert_eq!($vec4::new(7.0, 8.0, 9.0, 0.0), m.z_axis);
+            assert_eq!(m3, $mat3::from(m));
+        }
+
+        #[test]
+        fn test_mat3x4_is_finite() {
+            let mut m = $mat3x4::IDENTITY;
+            assert!(m.is_finite());
+            assert!(!m.is_nan());
+            m.z_axis.w = $t::NAN;
+            assert!(!m.is_finite());
+            assert!(m.is_nan());
+        }
+
+        #[test]
+        fn test_mat3x4_fmt() {
+            let a = $mat3x4::from_cols_array_2d(&MATRIX);
+            assert_eq!(
+                format!("{}", a),
+                "[[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]"
+            );
+        }
+    };
+}
+
+mod mat3x4 {
+    use super::support::deg;
+    use glam::{mat3x4, Mat3, Mat3x4, Mat4, Mat4x3, Quat, Vec3, Vec4};
+
+    #[test]
+    fn test_align() {
+        use std::mem;
+        assert_eq!(48, mem::size_of::<Mat3x4>());
+        if cfg!(feature = "scalar-math") {
+            assert_eq!(4, mem::align_of::<Mat3x4>());
+        } else {
+            assert_eq!(16, mem::align_of::<Mat3x4>());
+        }
+    }
+
+    impl_mat3x4_tests!(f32, mat3x4, Mat3x4, Mat4x3, Mat3, Mat4, Quat, Vec3, Vec4);
+}
+
+mod dmat3x4 {
+    use super::support::deg;
+    use glam::{dmat3x4, DMat3, DMat3x4, DMat4, DMat4x3, DQuat, DVec3, DVec4};
+
+    #[test]
+    fn test_align() {
+        use std::mem;
+        assert_eq!(96, mem::size_of::<DMat3x4>());
+        assert_eq!(8, mem::align_of::<DMat3x4>());
+    }
+
+    impl_mat3x4_tests!(f64, dmat3x4, DMat3x4, DMat4x3, DMat3, DMat4, DQuat, DVec3, DVec4);
+}
@@ -55,6 +55,32 @@ macro_rules! impl_mat4_tests {
             );
         }
 
+        #[test]
+        fn test_const_fn() {
+            const M0: $mat4 = $mat4::from_cols(
+                $vec4::new(1.0, 2.0, 3.0, 4.0),
+                $vec4::new(5.0, 6.0, 7.0, 8.0),
+                $vec4::new(9.0, 10.0, 11.0, 12.0),
+                $vec4::new(13.0, 14.0, 15.0, 16.0),
+            );
+            const M1: $mat4 = $newmat4(
+                $vec4::new(1.0, 2.0, 3.0, 4.0),
+                $vec4::new(5.0, 6.0, 7.0, 8.0),
+                $vec4::new(9.0, 10.0, 11.0, 12.0),
+                $vec4::new(13.0, 14.0, 15.0, 16.0),
+            );
+            const M2: $mat4 = $mat4::from_cols_array(&[
+                1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0, 13.0, 14.0, 15.0,
+                16.0,
+            ]);
+            const M3: $mat4 = $mat4::from_cols_array_2d(&MATRIX);
+            const MS: [$mat4; 2] = [$mat4::ZERO, $mat4::IDENTITY];
+            assert_eq!(M0, M1);
+            assert_eq!(M0, M2);
+            assert_eq!(M0, M3);
+            assert_eq!([$mat4::zero(), $mat4::identity()], MS);
+        }
+
         #[test]
         fn test_mat4_identity() {
             let identity = $mat4::identity();
@@ -226,6 +252,39 @@ macro_rules! impl_mat4_tests {
             assert_eq!(mt.w_axis, $newvec4(4.0, 8.0, 12.0, 16.0));
         }
 
+        #[test]
+        fn test_mat4_rows_and_cols() {
+            let mut m = $newmat4(
+                $newvec4(1.0, 2.0, 3.0, 4.0),
+                $newvec4(5.0, 6.0, 7.0, 8.0),
+                $newvec4(9.0, 10.0, 11.0, 12.0),
+                $newvec4(13.0, 14.0, 15.0, 16.0),
+            );
+            assert_eq!($newvec4(13.0, 14.0, 15.0, 16.0), m.col(3));
+            assert_eq!($newvec4(2.0, 6.0, 10.0, 14.0), m.row(1));
+            assert_eq!(m, $mat4::from_rows(m.row(0), m.row(1), m.row(2), m.row(3)));
+            assert_eq!(m, $mat4::from_rows_array(&m.to_rows_array()));
+            assert_eq!(m.transpose().to_cols_array(), m.to_rows_array());
+            assert_eq!(m.transpose(), $mat4::from_rows_array(&m.to_cols_array()));
+
+            *m.col_mut(0) = $vec4::ZERO;
+            assert_eq!($vec4::ZERO, m.x_axis);
+            m.set_row(3, $newvec4(-1.0, -2.0, -3.0, -4.0));
+            assert_eq!($newvec4(-1.0, -2.0, -3.0, -4.0), m.row(3));
+            assert_eq!($newvec4(5.0, 6.0, 7.0, -2.0), m.y_axis);
+
+            assert_eq!(11.0, m[(2, 2)]);
+            m[(3, 0)] = 17.0;
+            assert_eq!(17.0, m.w_axis.x);
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_mat4_col_mut_out_of_bounds() {
+            let mut m = $mat4::IDENTITY;
+            m.col_mut(4);
+        }
+
         #[test]
         fn test_mat4_det() {
             assert_eq!(0.0, $mat4::zero().determinant());
@@ -276,6 +335,21 @@ macro_rules! impl_mat4_tests {
             assert_approx_eq!(m_inv, trans_inv * rotz_inv * scale_inv, 1.0e-6);
         }
 
+        #[test]
+        fn test_mat4_inverse_random() {
+            use crate::support::{random_constrained, random_unit_array};
+            use glam::constrained::Invertible;
+            use rand_xoshiro::rand_core::SeedableRng;
+            let mut rng = rand_xoshiro::Xoshiro256Plus::seed_from_u64(0);
+            for _ in 0..100 {
+                let m = random_constrained(&mut rng, |rng| {
+                    Invertible::<$mat4>::new($mat4::from_cols_array(&random_unit_array(rng)))
+                })
+                .0;
+                assert_approx_eq!($mat4::identity(), m * m.inverse(), 1.0e-3);
+            }
+        }
+
         #[test]
         fn test_mat4_decompose() {
             // identity
@@ -511,6 +585,48 @@ macro_rules! impl_mat4_tests {
                 format!("{}", a),
                 "[[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12], [13, 14, 15, 16]]"
             );
+            assert_eq!(
+                format!("{:#3}", a),
+                "[[  1,   2,   3,   4],\n [  5,   6,   7,   8],\n [  9,  10,  11,  12],\n [ 13,  14,  15,  16]]"
+            );
+            assert_eq!(format!("{:#}", a).parse(), Ok(a));
+        }
+
+        #[test]
+        fn test_mat4_from_str() {
+            use glam::ParseError;
+            let a = $mat4::from_cols_array_2d(&MATRIX);
+            assert_eq!(a.to_string().parse(), Ok(a));
+            assert_eq!(
+                "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16".parse(),
+                Ok(a)
+            );
+            assert_eq!(
+                "(1 2 3 4) (5 6 7 8) (9 10 11 12) (13 14 15 16)".parse(),
+                Ok(a)
+            );
+            assert_eq!(
+                "[[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12], [13, 14, 15]]".parse::<$mat4>(),
+                Err(ParseError::ColumnLength {
+                    column: 3,
+                    expected: 4,
+                    found: 3
+                })
+            );
+            assert_eq!(
+                "[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]".parse::<$mat4>(),
+                Err(ParseError::ElementCount {
+                    expected: 16,
+                    found: 15
+                })
+            );
+
+            use rand_xoshiro::rand_core::SeedableRng;
+            let mut rng = rand_xoshiro::Xoshiro256Plus::seed_from_u64(0);
+            for _ in 0..1000 {
+                let s = $mat4::from_cols_array(&crate::support::random_array(&mut rng)).to_string();
+                assert_eq!(s, s.parse::<$mat4>().unwrap().to_string());
+            }
         }
 
         #[cfg(feature = "std")]
@@ -0,0 +1,162 @@
+#[macro_use]
+mod support;
+
+macro_rules! impl_mat4x3_tests {
+    ($t:ident, $newmat4x3:ident, $mat4x3:ident, $mat3x4:ident, $mat3:ident, $mat4:ident, $quat:ident, $vec3:ident, $vec4:ident) => {
+        const MATRIX: [[$t; 3]; 4] = [
+            [1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0],
+            [7.0, 8.0, 9.0],
+            [10.0, 11.0, 12.0],
+        ];
+
+        #[test]
+        fn test_const_fn() {
+            const M0: $mat4x3 = $mat4x3::from_cols(
+                $vec3::new(1.0, 2.0, 3.0),
+                $vec3::new(4.0, 5.0, 6.0),
+                $vec3::new(7.0, 8.0, 9.0),
+                $vec3::new(10.0, 11.0, 12.0),
+            );
+            const M1: $mat4x3 = $mat4x3::from_cols_array(&[
+                1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0,
+            ]);
+            const M2: $mat4x3 = $mat4x3::from_cols_array_2d(&MATRIX);
+            const MS: [$mat4x3; 2] = [$mat4x3::ZERO, $mat4x3::IDENTITY];
+            assert_eq!(M0, M1);
+            assert_eq!(M0, M2);
+            assert_eq!([$mat4x3::ZERO, $mat4x3::default()], MS);
+        }
+
+        #[test]
+        fn test_mat4x3_from_axes() {
+            let a = $mat4x3::from_cols_array_2d(&MATRIX);
+            assert_eq!(MATRIX, a.to_cols_array_2d());
+            let b = $newmat4x3(
+                $vec3::new(1.0, 2.0, 3.0),
+                $vec3::new(4.0, 5.0, 6.0),
+                $vec3::new(7.0, 8.0, 9.0),
+                $vec3::new(10.0, 11.0, 12.0),
+            );
+            assert_eq!(a, b);
+            assert_eq!(a, $mat4x3::from_cols_array(&a.to_cols_array()));
+            assert_eq!(a.to_cols_array(), *a.as_ref());
+            assert_eq!($vec3::new(10.0, 11.0, 12.0), a.w_axis);
+        }
+
+        #[test]
+        fn test_mat4x3_transpose() {
+            let m = $mat4x3::from_cols_array_2d(&MATRIX);
+            let t = m.transpose();
+            assert_eq!(
+                $mat3x4::from_cols_array_2d(&[
+                    [1.0, 4.0, 7.0, 10.0],
+                    [2.0, 5.0, 8.0, 11.0],
+                    [3.0, 6.0, 9.0, 12.0],
+                ]),
+                t
+            );
+            assert_eq!(m, t.transpose());
+        }
+
+        #[test]
+        fn test_mat4x3_mul() {
+            let m = $mat4x3::from_cols_array_2d(&MATRIX);
+            let m4 =
+                $mat4::from_rotation_x(deg(30.0)) * $mat4::from_scale($vec3::new(2.0, 3.0, 4.0));
+            let v = $vec4::new(1.0, -2.0, 3.0, 1.0);
+            assert_eq!(($mat4::from(m) * v).truncate(), m * v);
+            assert_approx_eq!($mat4x3::from($mat4::from(m) * m4), m * m4);
+            let t = m.transpose();
+            assert_eq!(
+                $mat3::from($mat4x3::from($mat4::from(m) * $mat4::from(t))),
+                m * t
+            );
+            let m3 = $mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+            assert_eq!(
+                $mat4x3::from($mat4::from($mat4x3::from(m3)) * $mat4::from(m)),
+                m3 * m
+            );
+            assert_eq!(m * 2.0, 2.0 * m);
+            assert_eq!(m + m, m * 2.0);
+            assert_eq!(m - m, $mat4x3::ZERO);
+        }
+
+        #[test]
+        fn test_mat4x3_transform() {
+            let m4 = $mat4::from_scale_rotation_translation(
+                $vec3::new(0.5, 1.5, 2.0),
+                $quat::from_rotation_y(deg(90.0)),
+                $vec3::new(1.0, 2.0, 3.0),
+            );
+            let m = $mat4x3::from(m4);
+            assert_eq!(m4, $mat4::from(m));
+            let p = $vec3::new(1.0, 2.0, 3.0);
+            assert_approx_eq!(m4.transform_point3(p), m.transform_point3(p));
+            assert_approx_eq!(m4.transform_vector3(p), m.transform_vector3(p));
+            assert_approx_eq!(
+                m.transform_point3(p),
+                m.transpose().transform_point3_rows(p)
+            );
+            assert_approx_eq!(
+                m.transform_vector3(p),
+                m.transpose().transform_vector3_rows(p)
+            );
+        }
+
+        #[test]
+        fn test_mat4x3_from_mat3() {
+            let m3 = $mat3::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+            let m = $mat4x3::from(m3);
+            assert_eq!($vec3::ZERO, m.w_axis);
+            assert_eq!(m3, $mat3::from(m));
+        }
+
+        #[test]
+        fn test_mat4x3_is_finite() {
+            let mut m = $mat4x3::IDENTITY;
+            assert!(m.is_finite());
+            assert!(!m.is_nan());
+            m.w_axis.y = $t::NAN;
+            assert!(!m.is_finite());
+            assert!(m.is_nan());
+        }
+
+        #[test]
+        fn test_mat4x3_fmt() {
+            let a = $mat4x3::from_cols_array_2d(&MATRIX);
+            assert_eq!(
+                format!("{}", a),
+                "[[1, 2, 3], [4, 5, 6], [7, 8, 9], [10, 11, 12]]"
+            );
+        }
+    };
+}
+
+mod mat4x3 {
+    use super::support::deg;
+    use glam::{mat4x3, Mat3, Mat3x4, Mat4, Mat4x3, Quat, Vec3, Vec4};
+
+    #[test]
+    fn test_align() {
+        use std::mem;
+        assert_eq!(48, mem::size_of::<Mat4x3>());
+        assert_eq!(4, mem::align_of::<Mat4x3>());
+    }
+
+    impl_mat4x3_tests!(f32, mat4x3, Mat4x3, Mat3x4, Mat3, Mat4, Quat, Vec3, Vec4);
+}
+
+mod dmat4x3 {
+    use super::support::deg;
+    use glam::{dmat4x3, DMat3, DMat3x4, DMat4, DMat4x3, DQuat, DVec3, DVec4};
+
+    #[test]
+    fn test_align() {
+        use std::mem;
+        assert_eq!(96, mem::size_of::<DMat4x3>());
+        assert_eq!(8, mem::align_of::<DMat4x3>());
+    }
+
+    impl_mat4x3_tests!(f64, dmat4x3, DMat4x3, DMat3x4, DMat3, DMat4, DQuat, DVec3, DVec4);
+}
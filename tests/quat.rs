@@ -13,6 +13,18 @@ macro_rules! impl_quat_tests {
             assert_eq!($quat::from_xyzw(1.0, 2.0, 3.0, 4.0), Q);
         }
 
+        #[test]
+        fn test_const_fn() {
+            const Q0: $quat = $quat::from_xyzw(1.0, 2.0, 3.0, 4.0);
+            const Q1: $quat = $new(1.0, 2.0, 3.0, 4.0);
+            const Q2: $quat = $quat::from_array([1.0, 2.0, 3.0, 4.0]);
+            const Q3: $quat = $quat::IDENTITY;
+            assert_eq!($const_new!([1.0, 2.0, 3.0, 4.0]), Q0);
+            assert_eq!(Q0, Q1);
+            assert_eq!(Q0, Q2);
+            assert_eq!($quat::identity(), Q3);
+        }
+
         #[test]
         fn test_new() {
             let ytheta = deg(45.0);
@@ -182,6 +194,26 @@ macro_rules! impl_quat_tests {
             assert_approx_eq!(-$vec3::unit_x(), mrzx * $vec3::unit_y());
             assert_approx_eq!(-$vec3::unit_x(), mrzx.mul_vec3($vec3::unit_y()));
         }
+
+        #[test]
+        fn test_mul_vec3_random() {
+            use crate::support::{random_array, random_constrained, random_unit_array};
+            use glam::constrained::Normalized;
+            use rand_xoshiro::rand_core::SeedableRng;
+            let mut rng = rand_xoshiro::Xoshiro256Plus::seed_from_u64(0);
+            for _ in 0..100 {
+                let q = random_constrained(&mut rng, |rng| {
+                    Normalized::<$quat>::new($quat::from_array(random_array(rng)))
+                })
+                .0;
+                let v = random_constrained(&mut rng, |rng| {
+                    Normalized::<$vec3>::new($vec3::from(random_unit_array::<$t, 3>(rng)))
+                })
+                .0;
+                assert!((q * v).is_normalized());
+                assert_approx_eq!($mat3::from_quat(q) * v, q * v, 1.0e-5);
+            }
+        }
         #[test]
         fn test_lerp() {
             let q0 = $quat::from_rotation_y(deg(0.0));
@@ -216,6 +248,47 @@ macro_rules! impl_quat_tests {
             }
         }
 
+        #[test]
+        fn test_slerp_fast() {
+            let q0 = $quat::from_rotation_y(deg(0.0));
+            let q1 = $quat::from_rotation_y(deg(90.0));
+            let q2 = $quat::from_rotation_ypr(deg(10.0), deg(20.0), deg(-30.0));
+            let mut s = 0.0;
+            while s <= 1.0 {
+                let q = q0.slerp_fast(q1, s);
+                assert_approx_eq!($quat::from_rotation_y(deg(s * 90.0)), q, 1.0e-4);
+                assert!((q.length() - 1.0).abs() <= 1.0e-4);
+                assert_approx_eq!(q0.slerp(q2, s), q0.slerp_fast(q2, s), 2.0e-4);
+                s += 0.01;
+            }
+        }
+
+        #[test]
+        fn test_try_normalize() {
+            let q = $quat::from_xyzw(0.0, 3.0, 0.0, 4.0);
+            let n = $quat::from_xyzw(0.0, 0.6, 0.0, 0.8);
+            assert_approx_eq!(n, q.try_normalize().unwrap());
+            assert_approx_eq!(n, q.normalize_or_zero());
+            assert_approx_eq!(n, q.normalize_or($quat::identity()));
+            let (length, direction) = q.length_and_direction();
+            assert_eq!(5.0, length);
+            assert_approx_eq!(n, direction);
+
+            let zero = $quat::from_xyzw(0.0, 0.0, 0.0, 0.0);
+            for q in [
+                zero,
+                $quat::from_xyzw(NAN, 0.0, 0.0, 1.0),
+                $quat::from_xyzw(INFINITY, 0.0, 0.0, 1.0),
+            ]
+            .iter()
+            {
+                assert_eq!(None, q.try_normalize());
+                assert_eq!(zero, q.normalize_or_zero());
+                assert_eq!($quat::identity(), q.normalize_or($quat::identity()));
+                assert_eq!(zero, q.length_and_direction().1);
+            }
+        }
+
         #[test]
         fn test_fmt() {
             let a = $quat::identity();
@@ -228,6 +301,33 @@ macro_rules! impl_quat_tests {
             //     "$quat(\n    1.0,\n    2.0,\n    3.0,\n    4.0\n)"
             // );
             assert_eq!(format!("{}", a), "[0, 0, 0, 1]");
+            let a = $quat::from_xyzw(1.0, -2.5, 0.125, 4.0);
+            assert_eq!(format!("{:.2}", a), "[1.00, -2.50, 0.12, 4.00]");
+            assert_eq!(format!("{:+.1}", a), "[+1.0, -2.5, +0.1, +4.0]");
+            assert_eq!(format!("{:5}", a), "[    1,  -2.5, 0.125,     4]");
+        }
+
+        #[test]
+        fn test_from_str() {
+            use glam::ParseError;
+            let a = $quat::from_xyzw(1.0, 2.0, 3.0, 4.0);
+            assert_eq!(a.to_string().parse(), Ok(a));
+            assert_eq!("1,2,3,4".parse(), Ok(a));
+            assert_eq!("(1 2 3 4)".parse(), Ok(a));
+            assert_eq!(
+                "[1, 2, 3]".parse::<$quat>(),
+                Err(ParseError::ElementCount {
+                    expected: 4,
+                    found: 3
+                })
+            );
+
+            use rand_xoshiro::rand_core::SeedableRng;
+            let mut rng = rand_xoshiro::Xoshiro256Plus::seed_from_u64(0);
+            for _ in 0..1000 {
+                let s = $quat::from_array(crate::support::random_array(&mut rng)).to_string();
+                assert_eq!(s, s.parse::<$quat>().unwrap().to_string());
+            }
         }
 
         #[test]
@@ -323,7 +423,7 @@ macro_rules! impl_quat_tests {
 mod quat {
     use crate::support::{deg, rad};
     use core::ops::Neg;
-    use glam::{const_quat, quat, Mat3, Mat4, Quat, Vec3, Vec3A, Vec4};
+    use glam::{const_quat, quat, Mat3, Mat3A, Mat4, Quat, Vec3, Vec3A, Vec4};
 
     #[test]
     fn test_align() {
@@ -336,6 +436,14 @@ mod quat {
         }
     }
 
+    #[test]
+    fn test_from_rotation_mat3a() {
+        let q = Quat::from_rotation_ypr(deg(30.0), deg(60.0), deg(90.0));
+        let m = Mat3A::from_quat(q);
+        assert_approx_eq!(q, Quat::from_rotation_mat3a(&m));
+        assert_approx_eq!(q * Vec3A::unit_y(), m * Vec3A::unit_y());
+    }
+
     #[test]
     fn test_mul_vec3a() {
         let qrz = Quat::from_rotation_z(deg(90.0));
@@ -2,8 +2,8 @@
 mod macros;
 
 use glam::{
-    DMat2, DMat3, DMat4, DQuat, DVec2, DVec3, DVec4, Mat2, Mat3, Mat4, Quat, Vec2, Vec3, Vec3A,
-    Vec4,
+    DMat2, DMat3, DMat3x4, DMat4, DMat4x3, DQuat, DVec2, DVec3, DVec4, Mat2, Mat3, Mat3A, Mat3x4,
+    Mat4, Mat4x3, Quat, Vec2, Vec3, Vec3A, Vec4,
 };
 
 #[cfg(feature = "transform-types")]
@@ -114,6 +114,21 @@ impl FloatCompare for Mat3 {
     }
 }
 
+impl FloatCompare for Mat3A {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::from_cols(
+            (self.x_axis - other.x_axis).abs(),
+            (self.y_axis - other.y_axis).abs(),
+            (self.z_axis - other.z_axis).abs(),
+        )
+    }
+}
+
 impl FloatCompare for DMat3 {
     #[inline]
     fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
@@ -161,6 +176,68 @@ impl FloatCompare for Mat4 {
     }
 }
 
+impl FloatCompare for Mat3x4 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::from_cols(
+            (self.x_axis - other.x_axis).abs(),
+            (self.y_axis - other.y_axis).abs(),
+            (self.z_axis - other.z_axis).abs(),
+        )
+    }
+}
+
+impl FloatCompare for DMat3x4 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::from_cols(
+            (self.x_axis - other.x_axis).abs(),
+            (self.y_axis - other.y_axis).abs(),
+            (self.z_axis - other.z_axis).abs(),
+        )
+    }
+}
+
+impl FloatCompare for Mat4x3 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::from_cols(
+            (self.x_axis - other.x_axis).abs(),
+            (self.y_axis - other.y_axis).abs(),
+            (self.z_axis - other.z_axis).abs(),
+            (self.w_axis - other.w_axis).abs(),
+        )
+    }
+}
+
+impl FloatCompare for DMat4x3 {
+    #[inline]
+    fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
+        self.abs_diff_eq(*other, max_abs_diff as f64)
+    }
+    #[inline]
+    fn abs_diff(&self, other: &Self) -> Self {
+        Self::from_cols(
+            (self.x_axis - other.x_axis).abs(),
+            (self.y_axis - other.y_axis).abs(),
+            (self.z_axis - other.z_axis).abs(),
+            (self.w_axis - other.w_axis).abs(),
+        )
+    }
+}
+
 impl FloatCompare for Quat {
     #[inline]
     fn approx_eq(&self, other: &Self, max_abs_diff: f32) -> bool {
@@ -296,3 +373,61 @@ impl FloatCompare for TransformRT {
         )
     }
 }
+
+/// Creates a value from random bits, covering special values such as `NaN`, infinities and
+/// negative zero for floating point types.
+#[allow(dead_code)]
+pub trait FromRandomBits {
+    fn from_random_bits(bits: u64) -> Self;
+}
+
+impl FromRandomBits for f32 {
+    fn from_random_bits(bits: u64) -> Self {
+        f32::from_bits(bits as u32)
+    }
+}
+
+impl FromRandomBits for f64 {
+    fn from_random_bits(bits: u64) -> Self {
+        f64::from_bits(bits)
+    }
+}
+
+impl FromRandomBits for i32 {
+    fn from_random_bits(bits: u64) -> Self {
+        bits as i32
+    }
+}
+
+impl FromRandomBits for u32 {
+    fn from_random_bits(bits: u64) -> Self {
+        bits as u32
+    }
+}
+
+/// Returns an array of `N` random values from `rng`.
+#[allow(dead_code)]
+pub fn random_array<T: FromRandomBits, const N: usize>(
+    rng: &mut impl rand_xoshiro::rand_core::RngCore,
+) -> [T; N] {
+    [(); N].map(|_| T::from_random_bits(rng.next_u64()))
+}
+
+/// Returns an array of `N` random values in the range `[-1.0, 1.0)` from `rng`.
+#[allow(dead_code)]
+pub fn random_unit_array<T: From<f32>, const N: usize>(
+    rng: &mut impl rand_xoshiro::rand_core::RngCore,
+) -> [T; N] {
+    [(); N].map(|_| T::from((rng.next_u32() >> 8) as f32 / (1 << 23) as f32 - 1.0))
+}
+
+/// Calls `f` until it returns `Some`, for generating random values which satisfy the
+/// preconditions checked by the `glam::constrained` wrappers, e.g. `Invertible::new`.
+#[allow(dead_code)]
+pub fn random_constrained<R, T>(rng: &mut R, mut f: impl FnMut(&mut R) -> Option<T>) -> T {
+    loop {
+        if let Some(value) = f(rng) {
+            return value;
+        }
+    }
+}
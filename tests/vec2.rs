@@ -9,6 +9,20 @@ macro_rules! impl_vec2_tests {
             assert_eq!($vec2::new(1 as $t, 2 as $t), V);
         }
 
+        #[test]
+        fn test_const_fn() {
+            const V0: $vec2 = $vec2::new(1 as $t, 2 as $t);
+            const V1: $vec2 = $new(1 as $t, 2 as $t);
+            const V2: $vec2 = $vec2::from_array([1 as $t, 2 as $t]);
+            const V3: $vec2 = $vec2::splat(2 as $t);
+            const VS: [$vec2; 2] = [$vec2::ZERO, $vec2::ONE];
+            assert_eq!($const_new!([1 as $t, 2 as $t]), V0);
+            assert_eq!(V0, V1);
+            assert_eq!(V0, V2);
+            assert_eq!($const_new!([2 as $t; 2]), V3);
+            assert_eq!([$vec2::zero(), $vec2::one()], VS);
+        }
+
         #[test]
         fn test_new() {
             let v = $new(1 as $t, 2 as $t);
@@ -41,6 +55,43 @@ macro_rules! impl_vec2_tests {
             );
             // assert_eq!(format!("{:#?}", a), "$vec2(\n    1.0,\n    2.0\n)");
             assert_eq!(format!("{}", a), "[1, 2]");
+            assert_eq!(format!("{:+}", a), "[+1, +2]");
+            assert_eq!(format!("{:3}", a), "[  1,   2]");
+        }
+
+        #[test]
+        fn test_from_str() {
+            use glam::ParseError;
+            let a = $vec2::new(1 as $t, 2 as $t);
+            assert_eq!(a.to_string().parse(), Ok(a));
+            assert_eq!("1,2".parse(), Ok(a));
+            assert_eq!("(1 2)".parse(), Ok(a));
+            assert_eq!(" [ 1 ,2 ] ".parse(), Ok(a));
+            assert_eq!(
+                "[1, 2, 3]".parse::<$vec2>(),
+                Err(ParseError::ElementCount {
+                    expected: 2,
+                    found: 3
+                })
+            );
+            assert_eq!(
+                "[1, x]".parse::<$vec2>(),
+                Err(ParseError::InvalidElement { index: 1 })
+            );
+            assert_eq!(
+                "[1, 2".parse::<$vec2>(),
+                Err(ParseError::InvalidSyntax { position: 5 })
+            );
+        }
+
+        #[test]
+        fn test_from_str_round_trip() {
+            use rand_xoshiro::rand_core::SeedableRng;
+            let mut rng = rand_xoshiro::Xoshiro256Plus::seed_from_u64(0);
+            for _ in 0..1000 {
+                let s = $vec2::from_array(crate::support::random_array(&mut rng)).to_string();
+                assert_eq!(s, s.parse::<$vec2>().unwrap().to_string());
+            }
         }
 
         #[test]
@@ -122,6 +173,43 @@ macro_rules! impl_vec2_tests {
             assert_eq!($new(1 as $t, 2 as $t), b.max(a));
         }
 
+        #[test]
+        fn test_clamp() {
+            let min = $new(1 as $t, 2 as $t);
+            let max = $new(5 as $t, 6 as $t);
+            assert_eq!(
+                $new(1 as $t, 2 as $t),
+                $new(0 as $t, 0 as $t).clamp(min, max)
+            );
+            assert_eq!(
+                $new(5 as $t, 6 as $t),
+                $new(9 as $t, 9 as $t).clamp(min, max)
+            );
+            assert_eq!(
+                $new(3 as $t, 6 as $t),
+                $new(3 as $t, 9 as $t).clamp(min, max)
+            );
+        }
+
+        #[test]
+        fn test_element_reductions() {
+            let a = $new(2 as $t, 5 as $t);
+            assert_eq!(7 as $t, a.element_sum());
+            assert_eq!(10 as $t, a.element_product());
+            assert_eq!(0, a.min_position());
+            assert_eq!(1, a.max_position());
+            assert_eq!(0, $vec2::splat(1 as $t).min_position());
+            assert_eq!(0, $vec2::splat(1 as $t).max_position());
+        }
+
+        #[test]
+        fn test_div_rem_euclid() {
+            let a = $new(7 as $t, 3 as $t);
+            let b = $vec2::splat(3 as $t);
+            assert_eq!($new(2 as $t, 1 as $t), a.div_euclid(b));
+            assert_eq!($new(1 as $t, 0 as $t), a.rem_euclid(b));
+        }
+
         #[test]
         fn test_hmin_hmax() {
             let a = $new(1 as $t, 2 as $t);
@@ -313,6 +401,15 @@ macro_rules! impl_vec2_tests {
                 format!("{}(0xffffffff, 0x0)", stringify!($mask))
             );
             assert_eq!(format!("{}", a), "[true, false]");
+            assert_eq!(format!("{:5}", a), "[true , false]");
+        }
+
+        #[test]
+        fn test_mask_from_str() {
+            let a = $mask::new(true, false);
+            assert_eq!(a.to_string().parse(), Ok(a));
+            assert_eq!("true,false".parse(), Ok(a));
+            assert!("[true, 1]".parse::<$mask>().is_err());
         }
 
         #[test]
@@ -411,12 +508,44 @@ macro_rules! impl_vec2_float_tests {
         use core::$t::NAN;
         use core::$t::NEG_INFINITY;
 
+        #[test]
+        fn test_float_ord() {
+            use glam::FloatOrd;
+            use std::collections::HashMap;
+            let a = FloatOrd($vec2::new(0.0, $t::NAN));
+            let b = FloatOrd($vec2::new(-0.0, -$t::NAN));
+            let c = FloatOrd($vec2::splat($t::INFINITY));
+            let d = FloatOrd($vec2::splat($t::NEG_INFINITY));
+            assert_eq!(a, b);
+            assert!(d < a && a < c);
+            assert!(FloatOrd($vec2::splat(1.0)) < FloatOrd($vec2::splat(2.0)));
+            assert!(FloatOrd($vec2::splat(-2.0)) < FloatOrd($vec2::splat(-1.0)));
+            let mut map = HashMap::new();
+            map.insert(a, 1);
+            map.insert(b, 2);
+            map.insert(c, 3);
+            assert_eq!(2, map.len());
+            assert_eq!(Some(&2), map.get(&a));
+        }
+
         #[test]
         fn test_vec2_consts() {
             assert_eq!($vec2::X, $new(1 as $t, 0 as $t));
             assert_eq!($vec2::Y, $new(0 as $t, 1 as $t));
         }
 
+        #[test]
+        fn test_fmt_float() {
+            let a = $vec2::new(1.0, -2.5);
+            assert_eq!(format!("{:.2}", a), "[1.00, -2.50]");
+            assert_eq!(format!("{:+.1}", a), "[+1.0, -2.5]");
+            assert_eq!(format!("{:6.2}", a), "[  1.00,  -2.50]");
+            assert_eq!(
+                format!("{:.2?}", a),
+                format!("{}(1.00, -2.50)", stringify!($vec2))
+            );
+        }
+
         #[test]
         fn test_length() {
             let x = $new(1.0, 0.0);
@@ -552,10 +681,18 @@ macro_rules! impl_vec2_float_tests {
 
         #[test]
         fn test_exp() {
+            // std and libm implementations of exp may differ in the last bit
+            #[cfg(not(feature = "deterministic"))]
             assert_eq!(
                 $vec2::new(1.0, 2.0).exp(),
                 $vec2::new((1.0 as $t).exp(), (2.0 as $t).exp())
             );
+            #[cfg(feature = "deterministic")]
+            assert_approx_eq!(
+                $vec2::new(1.0, 2.0).exp(),
+                $vec2::new((1.0 as $t).exp(), (2.0 as $t).exp()),
+                1e-5
+            );
         }
 
         #[test]
@@ -616,6 +753,190 @@ macro_rules! impl_vec2_float_tests {
                 $vec2::new(6.0, 8.0) // lengthened to length 10.0
             );
         }
+
+        #[test]
+        fn test_try_normalize() {
+            let v = $new(3.0, 4.0);
+            let n = $new(0.6, 0.8);
+            assert_approx_eq!(n, v.try_normalize().unwrap());
+            assert_approx_eq!(n, v.normalize_or_zero());
+            assert_approx_eq!(n, v.normalize_or($vec2::one()));
+            let (length, direction) = v.length_and_direction();
+            assert_eq!(5.0, length);
+            assert_approx_eq!(n, direction);
+
+            let fallback = $new(1.0, 0.0);
+            for v in [$vec2::zero(), $vec2::splat(NAN), $vec2::splat(INFINITY)].iter() {
+                assert_eq!(None, v.try_normalize());
+                assert_eq!($vec2::zero(), v.normalize_or_zero());
+                assert_eq!(fallback, v.normalize_or(fallback));
+                assert_eq!($vec2::zero(), v.length_and_direction().1);
+            }
+        }
+
+        #[test]
+        fn test_reflect_project() {
+            let v = $new(1.0, -1.0);
+            let normal = $new(0.0, 1.0);
+            assert_approx_eq!($new(1.0, 1.0), v.reflect(normal));
+            assert_approx_eq!($new(1.0, 0.0), v.slide(normal));
+            assert_approx_eq!($new(0.0, -1.0), v.project_onto_normalized(normal));
+            assert_approx_eq!($new(0.0, -1.0), v.project_onto($new(0.0, 3.0)));
+            assert_approx_eq!($new(1.0, 0.0), v.reject_from($new(0.0, 3.0)));
+            let other = $new(3.0, 4.0);
+            assert_approx_eq!(v, v.project_onto(other) + v.reject_from(other), 1e-6);
+        }
+
+        #[test]
+        fn test_refract() {
+            let incident = $new(1.0, -1.0).normalize();
+            let normal = $new(0.0, 1.0);
+            assert_approx_eq!(incident, incident.refract(normal, 1.0));
+            // total internal reflection
+            assert_eq!($vec2::zero(), incident.refract(normal, 1.5));
+            // the tangential component is scaled by `eta`
+            let refracted = incident.refract(normal, 0.5);
+            assert!(refracted.is_normalized());
+            assert_approx_eq!(incident.x * 0.5, refracted.x);
+            assert!(refracted.y < 0.0);
+        }
+
+        #[test]
+        fn test_any_orthonormal_vector() {
+            for v in [$vec2::X, -$vec2::Y, $new(1.0, -2.0).normalize()].iter() {
+                let ortho = v.any_orthonormal_vector();
+                assert!(ortho.is_normalized());
+                assert_approx_eq!(0.0, v.dot(ortho));
+            }
+        }
+
+        #[test]
+        fn test_rotate_towards() {
+            use core::$t::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+            let v = $new(2.0, 0.0);
+            let target = $new(0.0, 5.0);
+            assert_approx_eq!(v, v.rotate_towards(target, 0.0));
+            assert_approx_eq!(
+                $new(2.0_f64.sqrt() as $t, 2.0_f64.sqrt() as $t),
+                v.rotate_towards(target, FRAC_PI_4),
+                1e-6
+            );
+            assert_approx_eq!($new(0.0, 2.0), v.rotate_towards(target, FRAC_PI_2), 1e-6);
+            assert_approx_eq!($new(0.0, 2.0), v.rotate_towards(target, PI));
+            assert_approx_eq!($new(0.0, -2.0), v.rotate_towards(target, -FRAC_PI_2), 1e-6);
+            assert_approx_eq!($new(0.0, -2.0), v.rotate_towards(target, -PI), 1e-6);
+
+            let rotated = v.rotate_towards(-v, FRAC_PI_2);
+            assert_approx_eq!(0.0, rotated.dot(v), 1e-6);
+            assert_approx_eq!(2.0, rotated.length());
+
+            assert_eq!(v, v.rotate_towards($vec2::zero(), 1.0));
+            assert_eq!($vec2::zero(), $vec2::zero().rotate_towards(target, 1.0));
+        }
+
+        #[test]
+        fn test_per_component_math() {
+            let a = $new(-1.5, 2.25);
+            assert_eq!($new(-1.0, 2.0), a.trunc());
+            assert_eq!($new(0.5, 0.25), a.fract());
+            assert_eq!($new(1.0, 0.5), $new(-1e-20, 0.5).fract());
+            assert_eq!($new(2.0, 3.0), $new(4.0, 9.0).sqrt());
+            assert_approx_eq!($vec2::one(), $vec2::one().exp().ln());
+            assert_approx_eq!($vec2::zero(), $vec2::zero().sin());
+            assert_approx_eq!($vec2::one(), $vec2::zero().cos());
+            assert_eq!($new(-1.0, 1.0), $vec2::one().copysign(a));
+            assert_eq!(-$new(-1.0, 1.0), $vec2::one().copysign(-a));
+
+            let b = $new(7.0, -7.0);
+            let rhs = $new(4.0, 4.0);
+            assert_eq!($new(1.0, -2.0), b.div_euclid(rhs));
+            assert_eq!($new(3.0, 1.0), b.rem_euclid(rhs));
+            assert_eq!(b, b.div_euclid(rhs) * rhs + b.rem_euclid(rhs));
+
+            assert_eq!($new(-1.0, 1.0), a.clamp(-$vec2::one(), $vec2::one()));
+            assert_eq!($new(0.0, 1.0), a.step($vec2::zero()));
+            assert_eq!(
+                $new(0.0, 0.5),
+                $new(-1.0, 1.0).smoothstep($vec2::zero(), $vec2::splat(2.0))
+            );
+            assert_eq!(
+                $new(0.5, 1.0),
+                $vec2::zero().mix($vec2::splat(2.0), $new(0.25, 0.5))
+            );
+        }
+
+        #[test]
+        fn test_fast_approximations() {
+            use core::$t::consts::PI;
+            let v = $new(2.0, -3.0);
+            let length_recip = v.length_recip();
+            assert!(((v.length_recip_fast() - length_recip) / length_recip).abs() <= 1e-6);
+            assert!((v.normalize_fast().length() - 1.0).abs() <= 1e-6);
+            assert_approx_eq!(v.normalize(), v.normalize_fast(), 1e-6);
+            assert_approx_eq!(v.recip(), v.recip_fast(), 1e-6);
+
+            let mut a = -PI;
+            while a <= PI {
+                let (sin, cos) = $new(a, -a).sin_cos_fast();
+                assert_approx_eq!(sin.x, a.sin(), 1e-5);
+                assert_approx_eq!(cos.x, a.cos(), 1e-5);
+                assert_approx_eq!(sin.y, (-a).sin(), 1e-5);
+                assert_approx_eq!(cos.y, (-a).cos(), 1e-5);
+                a += PI / 512.0;
+            }
+        }
+    };
+}
+
+macro_rules! impl_vec2_int_tests {
+    ($t:ident, $new:ident, $vec2:ident, $uvec2:ident) => {
+        #[test]
+        fn test_pow() {
+            let a = $new(0 as $t, 1 as $t);
+            assert_eq!($vec2::one(), a.pow(0));
+            assert_eq!(a, a.pow(1));
+            assert_eq!($new(0 as $t, 1 as $t), a.pow(3));
+        }
+
+        #[test]
+        fn test_abs_diff_distance() {
+            let a = $new(0 as $t, 1 as $t);
+            let b = $new(4 as $t, 1 as $t);
+            assert_eq!($uvec2::new(4, 0), a.abs_diff(b));
+            assert_eq!($uvec2::new(4, 0), b.abs_diff(a));
+            assert_eq!(4, a.manhattan_distance(b));
+            assert_eq!(4, a.chebyshev_distance(b));
+            assert_eq!(0, a.manhattan_distance(a));
+            assert_eq!(0, a.chebyshev_distance(a));
+
+            let min = $vec2::splat($t::MIN);
+            let max = $vec2::splat($t::MAX);
+            assert_eq!($uvec2::splat(u32::MAX), min.abs_diff(max));
+            assert_eq!(u32::MAX.wrapping_mul(2), min.manhattan_distance(max));
+            assert_eq!(u32::MAX, max.chebyshev_distance(min));
+        }
+
+        #[test]
+        fn test_hash_ord() {
+            use std::collections::{BTreeSet, HashSet};
+            let a = $new(1 as $t, 1 as $t);
+            let b = $new(1 as $t, 2 as $t);
+            let c = $new(2 as $t, 0 as $t);
+            assert!(a < b && b < c);
+            assert_eq!(core::cmp::Ordering::Equal, a.cmp(&a));
+            assert_eq!(
+                vec![a, b, c],
+                [c, a, b, a]
+                    .iter()
+                    .copied()
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .collect::<Vec<_>>()
+            );
+            let set: HashSet<$vec2> = [a, b, a].iter().copied().collect();
+            assert_eq!(2, set.len());
+            assert!(set.contains(&b) && !set.contains(&c));
+        }
     };
 }
 
@@ -670,7 +991,7 @@ mod dvec2 {
 }
 
 mod ivec2 {
-    use glam::{const_ivec2, ivec2, BVec2, IVec2, IVec3};
+    use glam::{const_ivec2, ivec2, BVec2, IVec2, IVec3, UVec2};
 
     #[test]
     fn test_align() {
@@ -682,6 +1003,8 @@ mod ivec2 {
     }
 
     impl_vec2_signed_tests!(i32, const_ivec2, ivec2, IVec2, IVec3, BVec2);
+
+    impl_vec2_int_tests!(i32, ivec2, IVec2, UVec2);
 }
 
 mod uvec2 {
@@ -697,4 +1020,6 @@ mod uvec2 {
     }
 
     impl_vec2_tests!(u32, const_uvec2, uvec2, UVec2, UVec3, BVec2);
+
+    impl_vec2_int_tests!(u32, uvec2, UVec2, UVec2);
 }
@@ -9,6 +9,20 @@ macro_rules! impl_vec4_tests {
             assert_eq!($vec4::new(1 as $t, 2 as $t, 3 as $t, 4 as $t), V);
         }
 
+        #[test]
+        fn test_const_fn() {
+            const V0: $vec4 = $vec4::new(1 as $t, 2 as $t, 3 as $t, 4 as $t);
+            const V1: $vec4 = $new(1 as $t, 2 as $t, 3 as $t, 4 as $t);
+            const V2: $vec4 = $vec4::from_array([1 as $t, 2 as $t, 3 as $t, 4 as $t]);
+            const V3: $vec4 = $vec4::splat(2 as $t);
+            const VS: [$vec4; 2] = [$vec4::ZERO, $vec4::ONE];
+            assert_eq!($const_new!([1 as $t, 2 as $t, 3 as $t, 4 as $t]), V0);
+            assert_eq!(V0, V1);
+            assert_eq!(V0, V2);
+            assert_eq!($const_new!([2 as $t; 4]), V3);
+            assert_eq!([$vec4::zero(), $vec4::one()], VS);
+        }
+
         #[test]
         fn test_vec4_consts() {
             assert_eq!($vec4::X, $new(1 as $t, 0 as $t, 0 as $t, 0 as $t));
@@ -75,6 +89,43 @@ macro_rules! impl_vec4_tests {
             //     "$vec4(\n    1.0,\n    2.0,\n    3.0,\n    4.0\n)"
             // );
             assert_eq!(format!("{}", a), "[1, 2, 3, 4]");
+            assert_eq!(format!("{:+}", a), "[+1, +2, +3, +4]");
+            assert_eq!(format!("{:3}", a), "[  1,   2,   3,   4]");
+        }
+
+        #[test]
+        fn test_from_str() {
+            use glam::ParseError;
+            let a = $vec4::new(1 as $t, 2 as $t, 3 as $t, 4 as $t);
+            assert_eq!(a.to_string().parse(), Ok(a));
+            assert_eq!("1,2,3,4".parse(), Ok(a));
+            assert_eq!("(1 2 3 4)".parse(), Ok(a));
+            assert_eq!(" [ 1 ,2 ,3 ,4 ] ".parse(), Ok(a));
+            assert_eq!(
+                "[1, 2, 3, 4, 5]".parse::<$vec4>(),
+                Err(ParseError::ElementCount {
+                    expected: 4,
+                    found: 5
+                })
+            );
+            assert_eq!(
+                "[1, 2, 3, x]".parse::<$vec4>(),
+                Err(ParseError::InvalidElement { index: 3 })
+            );
+            assert_eq!(
+                "[1, 2, 3, 4".parse::<$vec4>(),
+                Err(ParseError::InvalidSyntax { position: 11 })
+            );
+        }
+
+        #[test]
+        fn test_from_str_round_trip() {
+            use rand_xoshiro::rand_core::SeedableRng;
+            let mut rng = rand_xoshiro::Xoshiro256Plus::seed_from_u64(0);
+            for _ in 0..1000 {
+                let s = $vec4::from_array(crate::support::random_array(&mut rng)).to_string();
+                assert_eq!(s, s.parse::<$vec4>().unwrap().to_string());
+            }
         }
 
         #[test]
@@ -168,6 +219,43 @@ macro_rules! impl_vec4_tests {
             assert_eq!((5 as $t, 6 as $t, 7 as $t, 8 as $t), b.max(a).into());
         }
 
+        #[test]
+        fn test_clamp() {
+            let min = $new(1 as $t, 2 as $t, 3 as $t, 4 as $t);
+            let max = $new(5 as $t, 6 as $t, 7 as $t, 8 as $t);
+            assert_eq!(
+                $new(1 as $t, 2 as $t, 3 as $t, 4 as $t),
+                $new(0 as $t, 0 as $t, 0 as $t, 0 as $t).clamp(min, max)
+            );
+            assert_eq!(
+                $new(5 as $t, 6 as $t, 7 as $t, 8 as $t),
+                $new(9 as $t, 9 as $t, 9 as $t, 9 as $t).clamp(min, max)
+            );
+            assert_eq!(
+                $new(3 as $t, 6 as $t, 3 as $t, 8 as $t),
+                $new(3 as $t, 9 as $t, 3 as $t, 9 as $t).clamp(min, max)
+            );
+        }
+
+        #[test]
+        fn test_element_reductions() {
+            let a = $new(2 as $t, 5 as $t, 1 as $t, 4 as $t);
+            assert_eq!(12 as $t, a.element_sum());
+            assert_eq!(40 as $t, a.element_product());
+            assert_eq!(2, a.min_position());
+            assert_eq!(1, a.max_position());
+            assert_eq!(0, $vec4::splat(1 as $t).min_position());
+            assert_eq!(0, $vec4::splat(1 as $t).max_position());
+        }
+
+        #[test]
+        fn test_div_rem_euclid() {
+            let a = $new(7 as $t, 3 as $t, 11 as $t, 2 as $t);
+            let b = $vec4::splat(3 as $t);
+            assert_eq!($new(2 as $t, 1 as $t, 3 as $t, 0 as $t), a.div_euclid(b));
+            assert_eq!($new(1 as $t, 0 as $t, 2 as $t, 2 as $t), a.rem_euclid(b));
+        }
+
         #[test]
         fn test_hmin_hmax() {
             let a = $new(3 as $t, 4 as $t, 1 as $t, 2 as $t);
@@ -421,6 +509,15 @@ macro_rules! impl_vec4_tests {
             //     format!("{:?}", a),
             //     format!("{}(0xffffffff, 0x0, 0xffffffff, 0x0)", stringify!($mask))
             // );
+            assert_eq!(format!("{:5}", a), "[true , false, true , false]");
+        }
+
+        #[test]
+        fn test_mask_from_str() {
+            let a = $mask::new(true, false, true, false);
+            assert_eq!(a.to_string().parse(), Ok(a));
+            assert_eq!("true,false,true,false".parse(), Ok(a));
+            assert!("[true, 1]".parse::<$mask>().is_err());
         }
 
         #[test]
@@ -522,6 +619,38 @@ macro_rules! impl_vec4_float_tests {
         use core::$t::NAN;
         use core::$t::NEG_INFINITY;
 
+        #[test]
+        fn test_float_ord() {
+            use glam::FloatOrd;
+            use std::collections::HashMap;
+            let a = FloatOrd($vec4::new(0.0, 0.0, 0.0, $t::NAN));
+            let b = FloatOrd($vec4::new(-0.0, -0.0, -0.0, -$t::NAN));
+            let c = FloatOrd($vec4::splat($t::INFINITY));
+            let d = FloatOrd($vec4::splat($t::NEG_INFINITY));
+            assert_eq!(a, b);
+            assert!(d < a && a < c);
+            assert!(FloatOrd($vec4::splat(1.0)) < FloatOrd($vec4::splat(2.0)));
+            assert!(FloatOrd($vec4::splat(-2.0)) < FloatOrd($vec4::splat(-1.0)));
+            let mut map = HashMap::new();
+            map.insert(a, 1);
+            map.insert(b, 2);
+            map.insert(c, 3);
+            assert_eq!(2, map.len());
+            assert_eq!(Some(&2), map.get(&a));
+        }
+
+        #[test]
+        fn test_fmt_float() {
+            let a = $vec4::new(1.0, -2.5, 0.125, 4.0);
+            assert_eq!(format!("{:.2}", a), "[1.00, -2.50, 0.12, 4.00]");
+            assert_eq!(format!("{:+.1}", a), "[+1.0, -2.5, +0.1, +4.0]");
+            assert_eq!(format!("{:6.2}", a), "[  1.00,  -2.50,   0.12,   4.00]");
+            assert_eq!(
+                format!("{:.2?}", a),
+                format!("{}(1.00, -2.50, 0.12, 4.00)", stringify!($vec4))
+            );
+        }
+
         #[test]
         fn test_funcs() {
             let x = $new(1.0, 0.0, 0.0, 0.0);
@@ -667,6 +796,8 @@ macro_rules! impl_vec4_float_tests {
 
         #[test]
         fn test_exp() {
+            // std and libm implementations of exp may differ in the last bit
+            #[cfg(not(feature = "deterministic"))]
             assert_eq!(
                 $vec4::new(1.0, 2.0, 3.0, 4.0).exp(),
                 $vec4::new(
@@ -676,6 +807,17 @@ macro_rules! impl_vec4_float_tests {
                     (4.0 as $t).exp()
                 )
             );
+            #[cfg(feature = "deterministic")]
+            assert_approx_eq!(
+                $vec4::new(1.0, 2.0, 3.0, 4.0).exp(),
+                $vec4::new(
+                    (1.0 as $t).exp(),
+                    (2.0 as $t).exp(),
+                    (3.0 as $t).exp(),
+                    (4.0 as $t).exp()
+                ),
+                1e-5
+            );
         }
 
         #[test]
@@ -724,6 +866,206 @@ macro_rules! impl_vec4_float_tests {
                 $vec4::new(6.0, 8.0, 0.0, 0.0) // lengthened to length 10.0
             );
         }
+
+        #[test]
+        fn test_try_normalize() {
+            let v = $new(0.0, 3.0, 0.0, 4.0);
+            let n = $new(0.0, 0.6, 0.0, 0.8);
+            assert_approx_eq!(n, v.try_normalize().unwrap());
+            assert_approx_eq!(n, v.normalize_or_zero());
+            assert_approx_eq!(n, v.normalize_or($vec4::one()));
+            let (length, direction) = v.length_and_direction();
+            assert_eq!(5.0, length);
+            assert_approx_eq!(n, direction);
+
+            let fallback = $new(1.0, 0.0, 0.0, 0.0);
+            for v in [$vec4::zero(), $vec4::splat(NAN), $vec4::splat(INFINITY)].iter() {
+                assert_eq!(None, v.try_normalize());
+                assert_eq!($vec4::zero(), v.normalize_or_zero());
+                assert_eq!(fallback, v.normalize_or(fallback));
+                assert_eq!($vec4::zero(), v.length_and_direction().1);
+            }
+        }
+
+        #[test]
+        fn test_reflect_project() {
+            let v = $new(1.0, -1.0, 2.0, 3.0);
+            let normal = $new(0.0, 1.0, 0.0, 0.0);
+            assert_approx_eq!($new(1.0, 1.0, 2.0, 3.0), v.reflect(normal));
+            assert_approx_eq!($new(1.0, 0.0, 2.0, 3.0), v.slide(normal));
+            assert_approx_eq!($new(0.0, -1.0, 0.0, 0.0), v.project_onto_normalized(normal));
+            assert_approx_eq!(
+                $new(0.0, -1.0, 0.0, 0.0),
+                v.project_onto($new(0.0, 3.0, 0.0, 0.0))
+            );
+            assert_approx_eq!(
+                $new(1.0, 0.0, 2.0, 3.0),
+                v.reject_from($new(0.0, 3.0, 0.0, 0.0))
+            );
+        }
+
+        #[test]
+        fn test_refract() {
+            let incident = $new(1.0, -1.0, 0.0, 0.0).normalize();
+            let normal = $new(0.0, 1.0, 0.0, 0.0);
+            assert_approx_eq!(incident, incident.refract(normal, 1.0));
+            // total internal reflection
+            assert_eq!($vec4::zero(), incident.refract(normal, 1.5));
+            let refracted = incident.refract(normal, 0.5);
+            assert!(refracted.is_normalized());
+            assert_approx_eq!(incident.x * 0.5, refracted.x);
+        }
+
+        #[test]
+        fn test_any_orthonormal_vector() {
+            for v in [
+                $vec4::X,
+                -$vec4::W,
+                $new(1.0, -2.0, 3.0, 0.5).normalize(),
+                $new(0.5, 0.5, 0.5, 0.5),
+            ]
+            .iter()
+            {
+                let ortho = v.any_orthonormal_vector();
+                assert!(ortho.is_normalized());
+                assert_approx_eq!(0.0, v.dot(ortho), 1e-6);
+            }
+        }
+
+        #[test]
+        fn test_rotate_towards() {
+            use core::$t::consts::{FRAC_PI_2, PI};
+            let v = $new(0.0, 0.0, 0.0, 2.0);
+            let target = $new(0.0, 5.0, 0.0, 0.0);
+            assert_approx_eq!(v, v.rotate_towards(target, 0.0));
+            assert_approx_eq!(
+                $new(0.0, 2.0, 0.0, 0.0),
+                v.rotate_towards(target, FRAC_PI_2),
+                1e-6
+            );
+            assert_approx_eq!($new(0.0, 2.0, 0.0, 0.0), v.rotate_towards(target, PI));
+            assert_approx_eq!(
+                $new(0.0, -2.0, 0.0, 0.0),
+                v.rotate_towards(target, -PI),
+                1e-6
+            );
+
+            let rotated = v.rotate_towards(-v, FRAC_PI_2);
+            assert_approx_eq!(0.0, rotated.dot(v), 1e-6);
+            assert_approx_eq!(2.0, rotated.length());
+
+            assert_eq!(v, v.rotate_towards($vec4::zero(), 1.0));
+        }
+
+        #[test]
+        fn test_per_component_math() {
+            let a = $new(-1.5, 2.25, -0.0, 7.0);
+            assert_eq!($new(-1.0, 2.0, -0.0, 7.0), a.trunc());
+            assert_eq!($new(0.5, 0.25, 0.0, 0.0), a.fract());
+            assert_eq!($new(2.0, 3.0, 0.0, 0.5), $new(4.0, 9.0, 0.0, 0.25).sqrt());
+            assert_approx_eq!($vec4::one(), $vec4::one().exp().ln());
+            assert_approx_eq!($vec4::zero(), $vec4::zero().sin());
+            assert_approx_eq!($vec4::one(), $vec4::zero().cos());
+            assert_eq!($new(-1.0, 1.0, -1.0, 1.0), $vec4::one().copysign(a));
+            assert_eq!(-$new(-1.0, 1.0, -1.0, 1.0), $vec4::one().copysign(-a));
+
+            let b = $new(7.0, -7.0, 7.0, -7.0);
+            let rhs = $new(4.0, 4.0, -4.0, -4.0);
+            assert_eq!($new(1.0, -2.0, -1.0, 2.0), b.div_euclid(rhs));
+            assert_eq!($new(3.0, 1.0, 3.0, 1.0), b.rem_euclid(rhs));
+            assert_eq!(b, b.div_euclid(rhs) * rhs + b.rem_euclid(rhs));
+
+            assert_eq!(
+                $new(-1.0, 1.0, -0.0, 1.0),
+                a.clamp(-$vec4::one(), $vec4::one())
+            );
+            assert_eq!($new(0.0, 1.0, 1.0, 1.0), a.step($vec4::zero()));
+            assert_eq!(
+                $new(0.0, 0.5, 1.0, 0.15625),
+                $new(-1.0, 1.0, 3.0, 0.5).smoothstep($vec4::zero(), $vec4::splat(2.0))
+            );
+            assert_eq!(
+                $new(0.5, 1.0, 2.0, 0.0),
+                $vec4::zero().mix($vec4::splat(2.0), $new(0.25, 0.5, 1.0, 0.0))
+            );
+        }
+
+        #[test]
+        fn test_fast_approximations() {
+            use core::$t::consts::PI;
+            let v = $new(2.0, -3.0, 4.0, -0.5);
+            let length_recip = v.length_recip();
+            assert!(((v.length_recip_fast() - length_recip) / length_recip).abs() <= 1e-6);
+            assert!((v.normalize_fast().length() - 1.0).abs() <= 1e-6);
+            assert_approx_eq!(v.normalize(), v.normalize_fast(), 1e-6);
+            assert_approx_eq!(v.recip(), v.recip_fast(), 1e-6);
+
+            let mut a = -PI;
+            while a <= PI {
+                let (sin, cos) = $new(a, a * 0.5, -a, a * 0.25).sin_cos_fast();
+                assert_approx_eq!(sin.x, a.sin(), 1e-5);
+                assert_approx_eq!(cos.x, a.cos(), 1e-5);
+                assert_approx_eq!(sin.y, (a * 0.5).sin(), 1e-5);
+                assert_approx_eq!(cos.y, (a * 0.5).cos(), 1e-5);
+                assert_approx_eq!(sin.z, (-a).sin(), 1e-5);
+                assert_approx_eq!(cos.z, (-a).cos(), 1e-5);
+                assert_approx_eq!(sin.w, (a * 0.25).sin(), 1e-5);
+                assert_approx_eq!(cos.w, (a * 0.25).cos(), 1e-5);
+                a += PI / 512.0;
+            }
+        }
+    };
+}
+
+macro_rules! impl_vec4_int_tests {
+    ($t:ident, $new:ident, $vec4:ident, $uvec4:ident) => {
+        #[test]
+        fn test_pow() {
+            let a = $new(0 as $t, 1 as $t, 2 as $t, 3 as $t);
+            assert_eq!($vec4::one(), a.pow(0));
+            assert_eq!(a, a.pow(1));
+            assert_eq!($new(0 as $t, 1 as $t, 8 as $t, 27 as $t), a.pow(3));
+        }
+
+        #[test]
+        fn test_abs_diff_distance() {
+            let a = $new(0 as $t, 1 as $t, 2 as $t, 3 as $t);
+            let b = $new(4 as $t, 1 as $t, 7 as $t, 2 as $t);
+            assert_eq!($uvec4::new(4, 0, 5, 1), a.abs_diff(b));
+            assert_eq!($uvec4::new(4, 0, 5, 1), b.abs_diff(a));
+            assert_eq!(10, a.manhattan_distance(b));
+            assert_eq!(5, a.chebyshev_distance(b));
+            assert_eq!(0, a.manhattan_distance(a));
+            assert_eq!(0, a.chebyshev_distance(a));
+
+            let min = $vec4::splat($t::MIN);
+            let max = $vec4::splat($t::MAX);
+            assert_eq!($uvec4::splat(u32::MAX), min.abs_diff(max));
+            assert_eq!(u32::MAX.wrapping_mul(4), min.manhattan_distance(max));
+            assert_eq!(u32::MAX, max.chebyshev_distance(min));
+        }
+
+        #[test]
+        fn test_hash_ord() {
+            use std::collections::{BTreeSet, HashSet};
+            let a = $new(1 as $t, 1 as $t, 1 as $t, 1 as $t);
+            let b = $new(1 as $t, 1 as $t, 1 as $t, 2 as $t);
+            let c = $new(2 as $t, 0 as $t, 0 as $t, 0 as $t);
+            assert!(a < b && b < c);
+            assert_eq!(core::cmp::Ordering::Equal, a.cmp(&a));
+            assert_eq!(
+                vec![a, b, c],
+                [c, a, b, a]
+                    .iter()
+                    .copied()
+                    .collect::<BTreeSet<_>>()
+                    .into_iter()
+                    .collect::<Vec<_>>()
+            );
+            let set: HashSet<$vec4> = [a, b, a].iter().copied().collect();
+            assert_eq!(2, set.len());
+            assert!(set.contains(&b) && !set.contains(&c));
+        }
     };
 }
 
@@ -853,7 +1195,7 @@ mod dvec4 {
 }
 
 mod ivec4 {
-    use glam::{const_ivec4, ivec4, BVec4, IVec4};
+    use glam::{const_ivec4, ivec4, BVec4, IVec4, UVec4};
 
     #[test]
     fn test_align() {
@@ -865,6 +1207,8 @@ mod ivec4 {
     }
 
     impl_vec4_signed_tests!(i32, const_ivec4, ivec4, IVec4, BVec4);
+
+    impl_vec4_int_tests!(i32, ivec4, IVec4, UVec4);
 }
 
 mod uvec4 {
@@ -880,4 +1224,6 @@ mod uvec4 {
     }
 
     impl_vec4_tests!(u32, const_uvec4, uvec4, UVec4, BVec4);
+
+    impl_vec4_int_tests!(u32, uvec4, UVec4, UVec4);
 }